@@ -28,6 +28,9 @@ class AudioLibrary {
 
   static AudioLibrary? _instance;
 
+  /// CUE 虚拟音轨的路径：音乐文件路径#音轨号
+  static final _cueTrackPath = RegExp(r"#\d+$");
+
   /// 目前 index 结构：
   /// ```json
   /// {
@@ -81,6 +84,13 @@ class AudioLibrary {
               "by": audio.by,
             }));
           }
+          for (final audio in audios) {
+            if (!_cueTrackPath.hasMatch(audio.path)) continue;
+            audio.cue = await library_db.getCueTrack(
+              indexPath: supportPath,
+              path: audio.path,
+            );
+          }
           folders.add(
             AudioFolder(
               audios,
@@ -260,9 +270,12 @@ class Audio {
   /// secs since UNIX EPOCH
   int created;
 
-  /// 标签来源（Lofty、Windows、null）
+  /// 标签来源（Lofty、System、null）
   String? by;
 
+  /// CUE 虚拟音轨在音乐文件中的位置。不是虚拟音轨时为 null，[path] 是音乐文件本身
+  library_db.IndexCueTrack? cue;
+
   ImageProvider? _cover;
   Future<ImageProvider?>? _coverFuture;
 
@@ -280,8 +293,9 @@ class Audio {
     this.path,
     this.modified,
     this.created,
-    this.by,
-  )   : splitedArtists = artist.split(
+    this.by, [
+    this.cue,
+  ])  : splitedArtists = artist.split(
           RegExp(AppSettings.instance.artistSplitPattern),
         ),
         splitedAlbumArtists = (albumArtist ?? "").isEmpty
@@ -302,8 +316,22 @@ class Audio {
         map["modified"],
         map["created"],
         map["by"],
+        _cueFromMap(map["path"], map["cue"]),
       );
 
+  /// index.json 中的 cue：{"source", "track", "start", "end"}
+  static library_db.IndexCueTrack? _cueFromMap(String path, Object? cue) {
+    if (cue is! Map || cue["source"] == null) return null;
+    final int? end = cue["end"];
+    return library_db.IndexCueTrack(
+      path: path,
+      source: cue["source"],
+      track: cue["track"] ?? 0,
+      start: BigInt.from(cue["start"] ?? 0),
+      end: end == null ? null : BigInt.from(end),
+    );
+  }
+
   Map toMap() => {
         "title": title,
         "artist": artist,
@@ -29,21 +29,41 @@ class PlaybackService extends ChangeNotifier {
 
     _smtcEventStreamSub = _smtc.subscribeToControlEvents().listen((event) {
       switch (event) {
-        case SMTCControlEvent.play:
+        case SMTCControlEvent_Play():
           start();
-          break;
-        case SMTCControlEvent.pause:
+        case SMTCControlEvent_Pause():
           pause();
-          break;
-        case SMTCControlEvent.previous:
+        case SMTCControlEvent_Previous():
           lastAudio();
-          break;
-        case SMTCControlEvent.next:
+        case SMTCControlEvent_Next():
           nextAudio();
-          break;
-        case SMTCControlEvent.unknown:
+        case SMTCControlEvent_Stop():
+          pause();
+          seek(0);
+        case SMTCControlEvent_FastForward():
+          seek(math.min(position + 10, length));
+        case SMTCControlEvent_Rewind():
+          seek(math.max(position - 10, 0));
+        case SMTCControlEvent_Seek(position: final ms):
+          seek(ms.toInt() / 1000);
+        case SMTCControlEvent_SeekBy(:final offset):
+          seek((position + offset / 1000).clamp(0, length).toDouble());
+        case SMTCControlEvent_Shuffle(:final enabled):
+          useShuffle(enabled);
+        case SMTCControlEvent_Repeat(:final mode):
+          setPlayMode(switch (mode) {
+            SMTCRepeatMode.none => PlayMode.forward,
+            SMTCRepeatMode.track => PlayMode.singleLoop,
+            SMTCRepeatMode.list => PlayMode.loop,
+          });
+        case SMTCControlEvent_Rate(:final rate):
+          setRate(rate);
+        case SMTCControlEvent_Volume(:final volume):
+          setVolumeDsp(volume);
+        case SMTCControlEvent_Unknown():
       }
     });
+    _smtc.updateRepeatMode(mode: _repeatModeOf(_pref.playMode));
 
     positionStream.listen((progress) {
       _smtc.updateTimeProperties(progress: (progress * 1000).floor());
@@ -193,8 +213,15 @@ class PlaybackService extends ChangeNotifier {
   void setPlayMode(PlayMode playMode) {
     this.playMode.value = playMode;
     _pref.playMode = playMode;
+    _smtc.updateRepeatMode(mode: _repeatModeOf(playMode));
   }
 
+  SMTCRepeatMode _repeatModeOf(PlayMode playMode) => switch (playMode) {
+        PlayMode.forward => SMTCRepeatMode.none,
+        PlayMode.loop => SMTCRepeatMode.list,
+        PlayMode.singleLoop => SMTCRepeatMode.track,
+      };
+
   late final _pitch = ValueNotifier(0.0);
   ValueNotifier<double> get pitch => _pitch;
 
@@ -213,6 +240,7 @@ class PlaybackService extends ChangeNotifier {
     AudioEchoLogRecorder.instance.mark('setRate', extra: {'value': value});
     _rate.value = value;
     _player.setRate(value);
+    _smtc.updatePlaybackRate(rate: value);
   }
 
   late final _shuffle = ValueNotifier(false);
@@ -258,6 +286,20 @@ class PlaybackService extends ChangeNotifier {
   bool get nowPlayingChangedRecently =>
       nowPlayingChangeAge.inMilliseconds < 220;
 
+  /// CUE 虚拟音轨播放音乐文件中 start 到 end 的一段
+  void _setSourceOf(Audio audio) {
+    final cue = audio.cue;
+    if (cue == null) {
+      _player.setSource(audio.path);
+      return;
+    }
+    _player.setSource(
+      cue.source,
+      start: cue.start.toInt() / 1000,
+      end: cue.end == null ? null : cue.end!.toInt() / 1000,
+    );
+  }
+
   /// 1. 更新 [_playlistIndex] 为 [audioIndex]
   /// 2. 更新 [nowPlaying] 为 playlist[_nowPlayingIndex]
   /// 3. _bassPlayer.setSource
@@ -270,7 +312,7 @@ class PlaybackService extends ChangeNotifier {
       _playlistIndex = audioIndex;
       nowPlaying = playlist[audioIndex];
       _lastNowPlayingChangedMs = DateTime.now().millisecondsSinceEpoch;
-      _player.setSource(nowPlaying!.path);
+      _setSourceOf(nowPlaying!);
       setVolumeDsp(AppPreference.instance.playbackPref.volumeDsp);
 
       playService.lyricService.updateLyric();
@@ -344,6 +386,7 @@ class PlaybackService extends ChangeNotifier {
     _playlistBackup = List.from(audios);
 
     shuffle.value = true;
+    _smtc.updateShuffle(enabled: true);
 
     _loadAndPlay(0, playlist.value);
   }
@@ -383,6 +426,7 @@ class PlaybackService extends ChangeNotifier {
       _playlistIndex = playlist.value.indexOf(nowPlaying!);
       shuffle.value = false;
     }
+    _smtc.updateShuffle(enabled: flag);
 
     if (_playlistIndex != null) {
       _persistLastSession(
@@ -447,7 +491,7 @@ class PlaybackService extends ChangeNotifier {
     _lastNowPlayingChangedMs = DateTime.now().millisecondsSinceEpoch;
 
     try {
-      _player.setSource(nowPlaying!.path);
+      _setSourceOf(nowPlaying!);
       setVolumeDsp(_pref.volumeDsp);
       playService.lyricService.updateLyric();
       ThemeProvider.instance.applyThemeFromAudio(nowPlaying!);
@@ -63,6 +63,10 @@ class BassPlayer {
 
   String? _fPath;
   int? _fstream;
+
+  /// 只播放文件中的一段（CUE 虚拟音轨），秒。[_rangeEnd] 为 null 时到文件结尾
+  double _rangeStart = 0.0;
+  double? _rangeEnd;
   bool _streamWasapiExclusive = false;
 
   // Equalizer
@@ -136,21 +140,26 @@ class BassPlayer {
   /// audio's length in seconds
   double get length {
     if (_fstream == null) return 1.0;
-    final len = _bass.BASS_ChannelBytes2Seconds(
+    final fileLen = _bass.BASS_ChannelBytes2Seconds(
       _fstream!,
       _bass.BASS_ChannelGetLength(_fstream!, bass.BASS_POS_BYTE),
     );
+    final len = (_rangeEnd ?? fileLen) - _rangeStart;
     return len > 0 ? len : 1.0;
   }
 
-  /// current position in seconds
-  double get position => _fstream == null
+  /// position in the file, in seconds
+  double get _filePosition => _fstream == null
       ? 0.0
       : _bass.BASS_ChannelBytes2Seconds(
           _fstream!,
           _bass.BASS_ChannelGetPosition(_fstream!, bass.BASS_POS_BYTE),
         );
 
+  /// current position in seconds, relative to the start of the range
+  double get position =>
+      math.max(0.0, _filePosition - _rangeStart).toDouble();
+
   PlayerState get playerState {
     if (_fstream == null) {
       return PlayerState.unknown;
@@ -232,6 +241,12 @@ class BassPlayer {
       /// check if the channel has completed
       if (playerState == PlayerState.stopped) {
         _playerStateStreamController.add(PlayerState.completed);
+      } else if (_rangeEnd != null &&
+          playerState == PlayerState.playing &&
+          _filePosition >= _rangeEnd!) {
+        /// 播放到虚拟音轨的结尾时暂停，不继续播放下一轨的内容
+        pause();
+        _playerStateStreamController.add(PlayerState.completed);
       }
     });
   }
@@ -728,6 +743,7 @@ class BassPlayer {
         return false;
       }
       final pathToReload = _fPath;
+      final rangeToReload = (_rangeStart, _rangeEnd);
       final lastPos = position;
       if (_fstream != null) {
         _positionUpdater?.cancel();
@@ -747,7 +763,11 @@ class BassPlayer {
       }
       wasapiExclusive = exclusive;
       if (pathToReload != null) {
-        setSource(pathToReload);
+        setSource(
+          pathToReload,
+          start: rangeToReload.\$1,
+          end: rangeToReload.\$2,
+        );
         setVolumeDsp(AppPreference.instance.playbackPref.volumeDsp);
         seek(lastPos);
         start();
@@ -764,7 +784,10 @@ class BassPlayer {
 
   /// if setSource has been called once,
   /// it will pause current channel and free current stream.
-  void setSource(String path) {
+  ///
+  /// [start] 和 [end]（秒）限定只播放文件中的一段，用于 CUE 虚拟音轨。
+  /// [length], [position] 和 [seek] 都相对于这一段
+  void setSource(String path, {double start = 0.0, double? end}) {
     _logAudioState("setSource(begin)");
     if (_fstream != null) {
       _positionUpdater?.cancel();
@@ -862,6 +885,8 @@ class BassPlayer {
     if (handle != 0) {
       _fstream = handle;
       _fPath = path;
+      _rangeStart = start;
+      _rangeEnd = end;
       _streamWasapiExclusive = wasapiExclusive;
 
       try {
@@ -876,6 +901,9 @@ class BassPlayer {
       if (_pitch != 0.0) {
         setPitch(_pitch);
       }
+      if (start > 0) {
+        seek(0.0);
+      }
       _logAudioState("setSource(ok)");
     } else {
       _fstream = null;
@@ -883,7 +911,7 @@ class BassPlayer {
       switch (_bass.BASS_ErrorGetCode()) {
         case bass.BASS_ERROR_INIT:
           _bassInit();
-          setSource(path);
+          setSource(path, start: start, end: end);
           break;
         case bass.BASS_ERROR_NOTAVAIL:
           throw const FormatException(
@@ -1188,7 +1216,7 @@ class BassPlayer {
 
     if (_bass.BASS_ChannelSetPosition(
           _fstream!,
-          _bass.BASS_ChannelSeconds2Bytes(_fstream!, position),
+          _bass.BASS_ChannelSeconds2Bytes(_fstream!, position + _rangeStart),
           bass.BASS_POS_BYTE,
         ) ==
         0) {
@@ -1233,6 +1261,8 @@ class BassPlayer {
     }
     _fstream = null;
     _fPath = null;
+    _rangeStart = 0.0;
+    _rangeEnd = null;
     _streamWasapiExclusive = false;
     _eqHandles.clear();
   }
@@ -0,0 +1,78 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `_argb`, `_contrast`, `_distance2`, `_hsl_to_rgb`, `_kmeans`, `_on_color`, `_palette_from_pixels`, `_relative_luminance`, `_rgb_to_hsl`, `_swatch_from_json`, `_swatch_to_json`, `_swatch`, `accepts`, `derive_from`, `from_json_str`, `new`, `score`, `swatches`, `to_json_string`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Cluster`, `Hsl`, `Target`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`
+
+
+            /// for Flutter
+/// 从封面提取主题色。图片获取方式和 [tag_reader::get_picture_from_path] 相同。
+/// 结果按专辑保存在 index_path 的曲库数据库中，同一专辑的曲目共用一份，封面变化后重新计算。
+/// 没有图片时返回 None。
+Future<AlbumPalette?>  extractPalette({required String indexPath , required String path }) => RustLib.instance.api.crateApiAlbumPaletteExtractPalette(indexPath: indexPath, path: path);
+
+            class AlbumPalette  {
+                /// 占比最大的颜色
+final PaletteSwatch dominant;
+final PaletteSwatch vibrant;
+final PaletteSwatch lightVibrant;
+final PaletteSwatch darkVibrant;
+final PaletteSwatch muted;
+final PaletteSwatch lightMuted;
+final PaletteSwatch darkMuted;
+
+                const AlbumPalette({required this.dominant ,required this.vibrant ,required this.lightVibrant ,required this.darkVibrant ,required this.muted ,required this.lightMuted ,required this.darkMuted ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => dominant.hashCode^vibrant.hashCode^lightVibrant.hashCode^darkVibrant.hashCode^muted.hashCode^lightMuted.hashCode^darkMuted.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AlbumPalette &&
+                runtimeType == other.runtimeType
+                && dominant == other.dominant&& vibrant == other.vibrant&& lightVibrant == other.lightVibrant&& darkVibrant == other.darkVibrant&& muted == other.muted&& lightMuted == other.lightMuted&& darkMuted == other.darkMuted;
+        
+            }
+
+class PaletteSwatch  {
+                /// a, r, g, b
+final (int,int,int,int) color;
+/// 放在 color 上的文字颜色，对比度不低于 4.5。a, r, g, b
+final (int,int,int,int) onColor;
+/// 在图片中的占比，0~1。由其他颜色推算出来时为 0
+final double population;
+
+                const PaletteSwatch({required this.color ,required this.onColor ,required this.population ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => color.hashCode^onColor.hashCode^population.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PaletteSwatch &&
+                runtimeType == other.runtimeType
+                && color == other.color&& onColor == other.onColor&& population == other.population;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,101 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'embedded_picture.freezed.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `_dimensions_of`, `_extension_of`, `_mime_type_of`, `_pic_type_in`, `_picture_tag_mut`, `_picture_tag`, `_prepare_cover`, `_save`, `_tag_type_supports_pictures`, `from_lofty`, `to_lofty`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`
+
+
+            /// for Flutter
+/// 列出音频文件内嵌的所有图片
+Future<List<EmbeddedPicture>>  listEmbeddedPictures({required String path }) => RustLib.instance.api.crateApiEmbeddedPictureListEmbeddedPictures(path: path);
+
+/// for Flutter
+/// 插入封面。已有封面时替换它，其他类型的图片保持不变。
+/// 封面会被放在图片列表的第一位。
+///
+/// max_size: 最长边的上限（px），超过时等比缩小。
+/// jpeg_quality: 1~100，指定时重新压缩为 JPEG。缩小后的图片也会编码为 JPEG。
+Future<void>  setFrontCover({required String path , required List<int> data , int? maxSize , int? jpegQuality }) => RustLib.instance.api.crateApiEmbeddedPictureSetFrontCover(path: path, data: data, maxSize: maxSize, jpegQuality: jpegQuality);
+
+/// for Flutter
+/// 删除内嵌图片。picture_type 为 None 时删除全部图片。返回删除的数量。
+Future<int>  removeEmbeddedPictures({required String path , EmbeddedPictureType? pictureType }) => RustLib.instance.api.crateApiEmbeddedPictureRemoveEmbeddedPictures(path: path, pictureType: pictureType);
+
+/// for Flutter
+/// 把第 index 张内嵌图片写到 output_path。
+/// output_path 没有扩展名时按图片格式补上。返回实际写入的路径。
+Future<String>  extractEmbeddedPicture({required String path , required int index , required String outputPath }) => RustLib.instance.api.crateApiEmbeddedPictureExtractEmbeddedPicture(path: path, index: index, outputPath: outputPath);
+
+            class EmbeddedPicture  {
+                /// 在标签图片列表中的位置，用于提取
+final int index;
+final EmbeddedPictureType pictureType;
+final String? mimeType;
+final String? description;
+/// 无法解码时为 None
+final int? width;
+final int? height;
+/// bytes
+final BigInt size;
+
+                const EmbeddedPicture({required this.index ,required this.pictureType ,this.mimeType ,this.description ,this.width ,this.height ,required this.size ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => index.hashCode^pictureType.hashCode^mimeType.hashCode^description.hashCode^width.hashCode^height.hashCode^size.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EmbeddedPicture &&
+                runtimeType == other.runtimeType
+                && index == other.index&& pictureType == other.pictureType&& mimeType == other.mimeType&& description == other.description&& width == other.width&& height == other.height&& size == other.size;
+        
+            }
+
+/// 内嵌图片类型，对应 ID3v2 APIC 的图片类型
+@freezed
+                sealed class EmbeddedPictureType with _$EmbeddedPictureType  {
+                    const EmbeddedPictureType._();
+
+                    const factory EmbeddedPictureType.other() = EmbeddedPictureType_Other;
+ const factory EmbeddedPictureType.icon() = EmbeddedPictureType_Icon;
+ const factory EmbeddedPictureType.otherIcon() = EmbeddedPictureType_OtherIcon;
+ const factory EmbeddedPictureType.coverFront() = EmbeddedPictureType_CoverFront;
+ const factory EmbeddedPictureType.coverBack() = EmbeddedPictureType_CoverBack;
+ const factory EmbeddedPictureType.leaflet() = EmbeddedPictureType_Leaflet;
+ const factory EmbeddedPictureType.media() = EmbeddedPictureType_Media;
+ const factory EmbeddedPictureType.leadArtist() = EmbeddedPictureType_LeadArtist;
+ const factory EmbeddedPictureType.artist() = EmbeddedPictureType_Artist;
+ const factory EmbeddedPictureType.conductor() = EmbeddedPictureType_Conductor;
+ const factory EmbeddedPictureType.band() = EmbeddedPictureType_Band;
+ const factory EmbeddedPictureType.composer() = EmbeddedPictureType_Composer;
+ const factory EmbeddedPictureType.lyricist() = EmbeddedPictureType_Lyricist;
+ const factory EmbeddedPictureType.recordingLocation() = EmbeddedPictureType_RecordingLocation;
+ const factory EmbeddedPictureType.duringRecording() = EmbeddedPictureType_DuringRecording;
+ const factory EmbeddedPictureType.duringPerformance() = EmbeddedPictureType_DuringPerformance;
+ const factory EmbeddedPictureType.screenCapture() = EmbeddedPictureType_ScreenCapture;
+ const factory EmbeddedPictureType.brightFish() = EmbeddedPictureType_BrightFish;
+ const factory EmbeddedPictureType.illustration() = EmbeddedPictureType_Illustration;
+ const factory EmbeddedPictureType.bandLogo() = EmbeddedPictureType_BandLogo;
+ const factory EmbeddedPictureType.publisherLogo() = EmbeddedPictureType_PublisherLogo;
+ /// 标准之外的类型，raw 为原始的类型字节
+const factory EmbeddedPictureType.undefined({   required int raw , }) = EmbeddedPictureType_Undefined;
+
+                    
+
+                    
+                }
@@ -0,0 +1,648 @@
+// dart format width=80
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'embedded_picture.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$EmbeddedPictureType {
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EmbeddedPictureType);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'EmbeddedPictureType()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class EmbeddedPictureType_Other extends EmbeddedPictureType {
+  const EmbeddedPictureType_Other(): super._();
+  
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EmbeddedPictureType_Other);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'EmbeddedPictureType.other()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class EmbeddedPictureType_Icon extends EmbeddedPictureType {
+  const EmbeddedPictureType_Icon(): super._();
+  
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EmbeddedPictureType_Icon);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'EmbeddedPictureType.icon()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class EmbeddedPictureType_OtherIcon extends EmbeddedPictureType {
+  const EmbeddedPictureType_OtherIcon(): super._();
+  
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EmbeddedPictureType_OtherIcon);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'EmbeddedPictureType.otherIcon()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class EmbeddedPictureType_CoverFront extends EmbeddedPictureType {
+  const EmbeddedPictureType_CoverFront(): super._();
+  
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EmbeddedPictureType_CoverFront);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'EmbeddedPictureType.coverFront()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class EmbeddedPictureType_CoverBack extends EmbeddedPictureType {
+  const EmbeddedPictureType_CoverBack(): super._();
+  
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EmbeddedPictureType_CoverBack);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'EmbeddedPictureType.coverBack()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class EmbeddedPictureType_Leaflet extends EmbeddedPictureType {
+  const EmbeddedPictureType_Leaflet(): super._();
+  
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EmbeddedPictureType_Leaflet);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'EmbeddedPictureType.leaflet()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class EmbeddedPictureType_Media extends EmbeddedPictureType {
+  const EmbeddedPictureType_Media(): super._();
+  
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EmbeddedPictureType_Media);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'EmbeddedPictureType.media()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class EmbeddedPictureType_LeadArtist extends EmbeddedPictureType {
+  const EmbeddedPictureType_LeadArtist(): super._();
+  
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EmbeddedPictureType_LeadArtist);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'EmbeddedPictureType.leadArtist()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class EmbeddedPictureType_Artist extends EmbeddedPictureType {
+  const EmbeddedPictureType_Artist(): super._();
+  
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EmbeddedPictureType_Artist);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'EmbeddedPictureType.artist()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class EmbeddedPictureType_Conductor extends EmbeddedPictureType {
+  const EmbeddedPictureType_Conductor(): super._();
+  
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EmbeddedPictureType_Conductor);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'EmbeddedPictureType.conductor()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class EmbeddedPictureType_Band extends EmbeddedPictureType {
+  const EmbeddedPictureType_Band(): super._();
+  
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EmbeddedPictureType_Band);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'EmbeddedPictureType.band()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class EmbeddedPictureType_Composer extends EmbeddedPictureType {
+  const EmbeddedPictureType_Composer(): super._();
+  
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EmbeddedPictureType_Composer);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'EmbeddedPictureType.composer()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class EmbeddedPictureType_Lyricist extends EmbeddedPictureType {
+  const EmbeddedPictureType_Lyricist(): super._();
+  
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EmbeddedPictureType_Lyricist);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'EmbeddedPictureType.lyricist()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class EmbeddedPictureType_RecordingLocation extends EmbeddedPictureType {
+  const EmbeddedPictureType_RecordingLocation(): super._();
+  
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EmbeddedPictureType_RecordingLocation);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'EmbeddedPictureType.recordingLocation()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class EmbeddedPictureType_DuringRecording extends EmbeddedPictureType {
+  const EmbeddedPictureType_DuringRecording(): super._();
+  
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EmbeddedPictureType_DuringRecording);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'EmbeddedPictureType.duringRecording()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class EmbeddedPictureType_DuringPerformance extends EmbeddedPictureType {
+  const EmbeddedPictureType_DuringPerformance(): super._();
+  
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EmbeddedPictureType_DuringPerformance);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'EmbeddedPictureType.duringPerformance()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class EmbeddedPictureType_ScreenCapture extends EmbeddedPictureType {
+  const EmbeddedPictureType_ScreenCapture(): super._();
+  
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EmbeddedPictureType_ScreenCapture);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'EmbeddedPictureType.screenCapture()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class EmbeddedPictureType_BrightFish extends EmbeddedPictureType {
+  const EmbeddedPictureType_BrightFish(): super._();
+  
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EmbeddedPictureType_BrightFish);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'EmbeddedPictureType.brightFish()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class EmbeddedPictureType_Illustration extends EmbeddedPictureType {
+  const EmbeddedPictureType_Illustration(): super._();
+  
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EmbeddedPictureType_Illustration);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'EmbeddedPictureType.illustration()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class EmbeddedPictureType_BandLogo extends EmbeddedPictureType {
+  const EmbeddedPictureType_BandLogo(): super._();
+  
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EmbeddedPictureType_BandLogo);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'EmbeddedPictureType.bandLogo()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class EmbeddedPictureType_PublisherLogo extends EmbeddedPictureType {
+  const EmbeddedPictureType_PublisherLogo(): super._();
+  
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EmbeddedPictureType_PublisherLogo);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'EmbeddedPictureType.publisherLogo()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class EmbeddedPictureType_Undefined extends EmbeddedPictureType {
+  const EmbeddedPictureType_Undefined({required this.raw}): super._();
+  
+
+ final  int raw;
+
+/// Create a copy of EmbeddedPictureType
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$EmbeddedPictureType_UndefinedCopyWith<EmbeddedPictureType_Undefined> get copyWith => _$EmbeddedPictureType_UndefinedCopyWithImpl<EmbeddedPictureType_Undefined>(this, _$identity);
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EmbeddedPictureType_Undefined&&(identical(other.raw, raw) || other.raw == raw));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,raw);
+
+@override
+String toString() {
+  return 'EmbeddedPictureType.undefined(raw: $raw)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $EmbeddedPictureType_UndefinedCopyWith<$Res>  {
+  factory $EmbeddedPictureType_UndefinedCopyWith(EmbeddedPictureType_Undefined value, $Res Function(EmbeddedPictureType_Undefined) _then) = _$EmbeddedPictureType_UndefinedCopyWithImpl;
+@useResult
+$Res call({
+ int raw
+});
+
+
+
+
+}
+/// @nodoc
+class _$EmbeddedPictureType_UndefinedCopyWithImpl<$Res>
+    implements $EmbeddedPictureType_UndefinedCopyWith<$Res> {
+  _$EmbeddedPictureType_UndefinedCopyWithImpl(this._self, this._then);
+
+  final EmbeddedPictureType_Undefined _self;
+  final $Res Function(EmbeddedPictureType_Undefined) _then;
+
+/// Create a copy of EmbeddedPictureType
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? raw = null,}) {
+  return _then(EmbeddedPictureType_Undefined(
+raw: null == raw ? _self.raw : raw // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+// dart format on
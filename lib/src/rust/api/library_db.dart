@@ -4,106 +4,182 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'lyric.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `init_schema`, `open_connection`, `sqlite_path`, `write_index_value_to_sqlite`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`
-
-Future<void> migrateIndexJsonToSqlite({required String indexPath}) =>
-    RustLib.instance.api
-        .crateApiLibraryDbMigrateIndexJsonToSqlite(indexPath: indexPath);
-
-Future<List<IndexFolder>> readIndexFromSqlite({required String indexPath}) =>
-    RustLib.instance.api
-        .crateApiLibraryDbReadIndexFromSqlite(indexPath: indexPath);
-
-class IndexAudio {
-  final String title;
-  final String artist;
-  final String album;
-  final String? albumArtist;
-  final int track;
-  final BigInt duration;
-  final int? bitrate;
-  final int? sampleRate;
-  final String path;
-  final BigInt modified;
-  final BigInt created;
-  final String? by;
-
-  const IndexAudio({
-    required this.title,
-    required this.artist,
-    required this.album,
-    this.albumArtist,
-    required this.track,
-    required this.duration,
-    this.bitrate,
-    this.sampleRate,
-    required this.path,
-    required this.modified,
-    required this.created,
-    this.by,
-  });
-
-  @override
-  int get hashCode =>
-      title.hashCode ^
-      artist.hashCode ^
-      album.hashCode ^
-      albumArtist.hashCode ^
-      track.hashCode ^
-      duration.hashCode ^
-      bitrate.hashCode ^
-      sampleRate.hashCode ^
-      path.hashCode ^
-      modified.hashCode ^
-      created.hashCode ^
-      by.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is IndexAudio &&
-          runtimeType == other.runtimeType &&
-          title == other.title &&
-          artist == other.artist &&
-          album == other.album &&
-          albumArtist == other.albumArtist &&
-          track == other.track &&
-          duration == other.duration &&
-          bitrate == other.bitrate &&
-          sampleRate == other.sampleRate &&
-          path == other.path &&
-          modified == other.modified &&
-          created == other.created &&
-          by == other.by;
-}
-
-class IndexFolder {
-  final String path;
-  final BigInt modified;
-  final BigInt latest;
-  final List<IndexAudio> audios;
-
-  const IndexFolder({
-    required this.path,
-    required this.modified,
-    required this.latest,
-    required this.audios,
-  });
-
-  @override
-  int get hashCode =>
-      path.hashCode ^ modified.hashCode ^ latest.hashCode ^ audios.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is IndexFolder &&
-          runtimeType == other.runtimeType &&
-          path == other.path &&
-          modified == other.modified &&
-          latest == other.latest &&
-          audios == other.audios;
-}
+
+            // These functions are ignored because they are not marked as `pub`: `_cached_lyric_from_row`, `_lyric_hash`, `as_str`, `from_str`, `get_album_palette`, `init_schema`, `open_connection`, `save_album_palette`, `save_parsed_lyric`, `sqlite_path`, `write_index_value_to_sqlite`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`
+
+
+            Future<void>  migrateIndexJsonToSqlite({required String indexPath }) => RustLib.instance.api.crateApiLibraryDbMigrateIndexJsonToSqlite(indexPath: indexPath);
+
+Future<List<IndexFolder>>  readIndexFromSqlite({required String indexPath }) => RustLib.instance.api.crateApiLibraryDbReadIndexFromSqlite(indexPath: indexPath);
+
+/// 播放 CUE 虚拟音轨时需要的音乐文件路径和范围。不是虚拟音轨时为 None
+Future<IndexCueTrack?>  getCueTrack({required String indexPath , required String path }) => RustLib.instance.api.crateApiLibraryDbGetCueTrack(indexPath: indexPath, path: path);
+
+/// 歌词时间偏移（ms），正数表示歌词延后。不随索引重建清除
+Future<void>  setLyricOffset({required String indexPath , required String path , required PlatformInt64 offset }) => RustLib.instance.api.crateApiLibraryDbSetLyricOffset(indexPath: indexPath, path: path, offset: offset);
+
+/// 没有保存时为 0
+Future<PlatformInt64>  getLyricOffset({required String indexPath , required String path }) => RustLib.instance.api.crateApiLibraryDbGetLyricOffset(indexPath: indexPath, path: path);
+
+/// 保存歌词到缓存，按内容自动识别格式并解析
+Future<CachedLyric>  saveLyric({required String indexPath , required String path , required LyricSource source , String? provider , required String raw , required bool preferred }) => RustLib.instance.api.crateApiLibraryDbSaveLyric(indexPath: indexPath, path: path, source: source, provider: provider, raw: raw, preferred: preferred);
+
+/// 这首歌缓存的所有歌词，首选的在最前
+Future<List<CachedLyric>>  getCachedLyrics({required String indexPath , required String path }) => RustLib.instance.api.crateApiLibraryDbGetCachedLyrics(indexPath: indexPath, path: path);
+
+/// 首选歌词。没有设置首选时依次为用户编辑、外挂、内嵌、在线歌词，相同来源取最新的
+Future<CachedLyric?>  getPreferredLyric({required String indexPath , required String path }) => RustLib.instance.api.crateApiLibraryDbGetPreferredLyric(indexPath: indexPath, path: path);
+
+Future<void>  setPreferredLyric({required String indexPath , required String path , required String hash }) => RustLib.instance.api.crateApiLibraryDbSetPreferredLyric(indexPath: indexPath, path: path, hash: hash);
+
+/// 和 [set_lyric_offset] 叠加
+Future<void>  setCachedLyricOffset({required String indexPath , required String path , required String hash , required PlatformInt64 offset }) => RustLib.instance.api.crateApiLibraryDbSetCachedLyricOffset(indexPath: indexPath, path: path, hash: hash, offset: offset);
+
+Future<void>  removeCachedLyric({required String indexPath , required String path , required String hash }) => RustLib.instance.api.crateApiLibraryDbRemoveCachedLyric(indexPath: indexPath, path: path, hash: hash);
+
+            class CachedLyric  {
+                final String path;
+/// raw 的 xxh3_128
+final String hash;
+final LyricSource source;
+/// 在线歌词的来源名称，外挂歌词的文件路径
+final String? provider;
+/// 无法解析时为 None
+final LyricFormat? format;
+final String raw;
+final Lyric? parsed;
+/// 这条歌词自身的时间偏移（ms），正数表示歌词延后
+final PlatformInt64 offset;
+final bool preferred;
+/// 保存时间，unix 秒
+final BigInt updated;
+
+                const CachedLyric({required this.path ,required this.hash ,required this.source ,this.provider ,this.format ,required this.raw ,this.parsed ,required this.offset ,required this.preferred ,required this.updated ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => path.hashCode^hash.hashCode^source.hashCode^provider.hashCode^format.hashCode^raw.hashCode^parsed.hashCode^offset.hashCode^preferred.hashCode^updated.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CachedLyric &&
+                runtimeType == other.runtimeType
+                && path == other.path&& hash == other.hash&& source == other.source&& provider == other.provider&& format == other.format&& raw == other.raw&& parsed == other.parsed&& offset == other.offset&& preferred == other.preferred&& updated == other.updated;
+        
+            }
+
+class IndexAudio  {
+                final String title;
+final String artist;
+final String album;
+final String? albumArtist;
+final int track;
+final BigInt duration;
+final int? bitrate;
+final int? sampleRate;
+final String path;
+final BigInt modified;
+final BigInt created;
+final String? by;
+
+                const IndexAudio({required this.title ,required this.artist ,required this.album ,this.albumArtist ,required this.track ,required this.duration ,this.bitrate ,this.sampleRate ,required this.path ,required this.modified ,required this.created ,this.by ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => title.hashCode^artist.hashCode^album.hashCode^albumArtist.hashCode^track.hashCode^duration.hashCode^bitrate.hashCode^sampleRate.hashCode^path.hashCode^modified.hashCode^created.hashCode^by.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is IndexAudio &&
+                runtimeType == other.runtimeType
+                && title == other.title&& artist == other.artist&& album == other.album&& albumArtist == other.albumArtist&& track == other.track&& duration == other.duration&& bitrate == other.bitrate&& sampleRate == other.sampleRate&& path == other.path&& modified == other.modified&& created == other.created&& by == other.by;
+        
+            }
+
+/// CUE 虚拟音轨在音乐文件中的位置
+class IndexCueTrack  {
+                /// 虚拟音轨的路径，即 [IndexAudio::path]
+final String path;
+/// 音乐文件路径
+final String source;
+final int track;
+/// ms
+final BigInt start;
+/// ms，None 表示到文件结尾
+final BigInt? end;
+
+                const IndexCueTrack({required this.path ,required this.source ,required this.track ,required this.start ,this.end ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => path.hashCode^source.hashCode^track.hashCode^start.hashCode^end.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is IndexCueTrack &&
+                runtimeType == other.runtimeType
+                && path == other.path&& source == other.source&& track == other.track&& start == other.start&& end == other.end;
+        
+            }
+
+class IndexFolder  {
+                final String path;
+final BigInt modified;
+final BigInt latest;
+final List<IndexAudio> audios;
+
+                const IndexFolder({required this.path ,required this.modified ,required this.latest ,required this.audios ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => path.hashCode^modified.hashCode^latest.hashCode^audios.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is IndexFolder &&
+                runtimeType == other.runtimeType
+                && path == other.path&& modified == other.modified&& latest == other.latest&& audios == other.audios;
+        
+            }
+
+enum LyricSource {
+                    /// 音乐文件的内嵌歌词
+embedded,
+/// 外挂歌词文件
+sidecar,
+/// 在线歌词，provider 为来源名称
+online,
+/// 用户编辑过的歌词
+userEdit,
+                    ;
+                    
+                }
+            
\ No newline at end of file
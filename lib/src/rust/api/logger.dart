@@ -6,8 +6,12 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `log_to_dart`
 
-/// initialize a stream to pass log events to dart/flutter
-Stream<String> initRustLogger() =>
-    RustLib.instance.api.crateApiLoggerInitRustLogger();
+            // These functions are ignored (category: IgnoreBecauseExplicitAttribute): `log_to_dart`
+
+
+            /// initialize a stream to pass log events to dart/flutter
+Stream<String>  initRustLogger() => RustLib.instance.api.crateApiLoggerInitRustLogger();
+
+            
+            
\ No newline at end of file
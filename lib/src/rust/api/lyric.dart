@@ -0,0 +1,258 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'tag_reader.dart';
+import 'text_convert.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `_apply_offset`, `_convert_words`, `_fill_word_ends`, `_load_and_cache_lyric`, `_map_times`, `_merge_translation`, `_parse_lyric_text`, `_parse_tag`, `_parse_timestamp`, `_sorted_lyric_candidates`, `as_str`, `from_json_value`, `from_str`, `from_synced_text`, `new`, `to_json_value`, `to_synced_text`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            /// for Flutter
+/// 自动识别 QRC, TTML, KRC, SRT, 逐字 LRC 和 LRC。没有歌词行时返回 None
+Future<Lyric?>  parseLyric({required String text }) => RustLib.instance.api.crateApiLyricParseLyric(text: text);
+
+/// for Flutter
+/// 解析 LRC 和逐字 LRC。相同时间的多行中，逐字时间最多的一行为原文，其他行合并为翻译
+Future<Lyric?>  parseLrc({required String text }) => RustLib.instance.api.crateApiLyricParseLrc(text: text);
+
+/// for Flutter
+/// 解析已经解密的 KRC 文本，[language:xxx] 中的翻译会放到 translation
+Future<Lyric?>  parseKrc({required String text }) => RustLib.instance.api.crateApiLyricParseKrc(text: text);
+
+/// for Flutter
+/// 解析已经解密的 QRC 文本（可以带有 QrcInfos XML 外壳）。
+/// translation: 翻译的 LRC 文本，按时间对应到最接近的行
+Future<Lyric?>  parseQrc({required String text , String? translation }) => RustLib.instance.api.crateApiLyricParseQrc(text: text, translation: translation);
+
+/// for Flutter
+/// 解密酷狗 .krc 文件，返回 KRC 文本
+Future<String>  decryptKrc({required List<int> data }) => RustLib.instance.api.crateApiLyricDecryptKrc(data: data);
+
+/// for Flutter
+/// 解密 QQ 音乐本地 .qrc 文件或者网络接口返回的 hex 文本，返回 QRC 文本
+Future<String>  decryptQrc({required List<int> data }) => RustLib.instance.api.crateApiLyricDecryptQrc(data: data);
+
+/// for Flutter
+/// 解析 SRT 字幕。每个字幕块的第一行为原文，其他行合并为翻译
+Future<Lyric?>  parseSrt({required String text }) => RustLib.instance.api.crateApiLyricParseSrt(text: text);
+
+/// for Flutter
+/// 解析 TTML（包括 Apple Music 的逐字歌词）。支持对唱（ttm:agent）、和声（x-bg）和翻译
+Future<Lyric?>  parseTtml({required String text }) => RustLib.instance.api.crateApiLyricParseTtml(text: text);
+
+/// for Flutter
+/// 读取并解析 [tag_reader::get_lyric_candidates] 返回的歌词，按扩展名选择格式
+Future<Lyric?>  parseLyricCandidate({required String path , required LyricCandidate candidate }) => RustLib.instance.api.crateApiLyricParseLyricCandidate(path: path, candidate: candidate);
+
+/// for Flutter
+/// 读取并解析歌词。优先使用 .krc, .qrc 逐字歌词，其次按 [tag_reader::get_lyric_candidates] 的顺序
+Future<Lyric?>  getParsedLyricFromPath({required String path }) => RustLib.instance.api.crateApiLyricGetParsedLyricFromPath(path: path);
+
+/// for Flutter
+/// 优先使用 [library_db::get_preferred_lyric] 缓存的歌词，没有缓存时和 [get_parsed_lyric_from_path] 相同并保存到缓存。
+/// 应用缓存歌词的时间偏移和 [library_db::set_lyric_offset] 保存的时间偏移
+Future<Lyric?>  getParsedLyricForPlayback({required String indexPath , required String path }) => RustLib.instance.api.crateApiLyricGetParsedLyricForPlayback(indexPath: indexPath, path: path);
+
+            class Lyric  {
+                final LyricFormat format;
+final List<LyricTag> tags;
+/// 按 start 升序排列。offset 标签已经应用到时间上
+final List<LyricLine> lines;
+final List<LyricAgent> agents;
+
+                const Lyric({required this.format ,required this.tags ,required this.lines ,required this.agents ,});
+
+                /// for Flutter
+/// 所有时间加上 offset ms（正数表示歌词延后，和 LRC 的 [offset:] 相反），小于 0 的时间变为 0
+ Lyric  shifted({required PlatformInt64 offset })=>RustLib.instance.api.crateApiLyricLyricShifted(that: this, offset: offset);
+
+
+/// for Flutter
+/// 线性伸缩：把 from_a, from_b 分别对应到 to_a, to_b，其他时间按比例变换。
+/// 用于和音频的速度不一致（如 PAL 加速）的歌词。from_a == from_b 或顺序被反转时返回 None
+ Lyric?  stretched({required int fromA , required int toA , required int fromB , required int toB })=>RustLib.instance.api.crateApiLyricLyricStretched(that: this, fromA: fromA, toA: toA, fromB: fromB, toB: toB);
+
+
+/// for Flutter
+/// 标签名不区分大小写
+ String?  tag({required String key })=>RustLib.instance.api.crateApiLyricLyricTag(that: this, key: key);
+
+
+/// for Flutter
+/// 导出为 LRC。有逐字时间时写成逐字 LRC，和声写在 [bg:...] 行，翻译写在相同时间的下一行
+ String  toLrc()=>RustLib.instance.api.crateApiLyricLyricToLrc(that: this, );
+
+
+/// for Flutter
+/// 导出为 Apple Music 风格的 TTML
+ String  toTtml()=>RustLib.instance.api.crateApiLyricLyricToTtml(that: this, );
+
+
+/// for Flutter
+/// 简繁转换原文（包括逐字歌词、和声）和翻译
+ Lyric  withChineseVariant({required ChineseVariant variant })=>RustLib.instance.api.crateApiLyricLyricWithChineseVariant(that: this, variant: variant);
+
+
+/// for Flutter
+/// 为有汉字或假名的行生成拼音或罗马字，放在 romanization。
+/// Auto 时整首歌词有假名则为罗马字，否则为拼音。罗马字时跳过含有汉字的行
+ Lyric  withRomanization({required RomanizationKind kind })=>RustLib.instance.api.crateApiLyricLyricWithRomanization(that: this, kind: kind);
+
+
+/// for Flutter
+/// 把 translation 的每一行作为翻译放到开始时间最接近（相差不超过 tolerance ms）的行
+ Lyric  withTranslation({required Lyric translation , required int tolerance })=>RustLib.instance.api.crateApiLyricLyricWithTranslation(that: this, translation: translation, tolerance: tolerance);
+
+
+                
+
+                
+        @override
+        int get hashCode => format.hashCode^tags.hashCode^lines.hashCode^agents.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Lyric &&
+                runtimeType == other.runtimeType
+                && format == other.format&& tags == other.tags&& lines == other.lines&& agents == other.agents;
+        
+            }
+
+/// TTML 中的 ttm:agent
+class LyricAgent  {
+                /// 如 v1, v2
+final String id;
+/// person, group, other
+final String kind;
+final String? name;
+
+                const LyricAgent({required this.id ,required this.kind ,this.name ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^kind.hashCode^name.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LyricAgent &&
+                runtimeType == other.runtimeType
+                && id == other.id&& kind == other.kind&& name == other.name;
+        
+            }
+
+enum LyricFormat {
+                    lrc,
+/// 带有 `<mm:ss.xx>` 逐字时间的 LRC
+enhancedLrc,
+krc,
+qrc,
+srt,
+/// TTML，包括 Apple Music 的逐字歌词（itunes:timing="Word"）
+ttml,
+                    ;
+                    
+                }
+
+class LyricLine  {
+                /// ms
+final int start;
+/// ms
+final int end;
+final String text;
+/// 没有逐字时间时为空
+final List<LyricWord> words;
+final String? translation;
+/// 演唱者，对应 [LyricAgent::id]。用于对唱
+final String? agent;
+/// 和声（背景人声）的逐字时间，没有时为空
+final List<LyricWord> background;
+/// 拼音或罗马字，见 [Lyric::with_romanization]
+final String? romanization;
+
+                const LyricLine({required this.start ,required this.end ,required this.text ,required this.words ,this.translation ,this.agent ,required this.background ,this.romanization ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => start.hashCode^end.hashCode^text.hashCode^words.hashCode^translation.hashCode^agent.hashCode^background.hashCode^romanization.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LyricLine &&
+                runtimeType == other.runtimeType
+                && start == other.start&& end == other.end&& text == other.text&& words == other.words&& translation == other.translation&& agent == other.agent&& background == other.background&& romanization == other.romanization;
+        
+            }
+
+/// [ar:xxx], [ti:xxx], [offset:xxx] 等标签
+class LyricTag  {
+                final String key;
+final String value;
+
+                const LyricTag({required this.key ,required this.value ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => key.hashCode^value.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LyricTag &&
+                runtimeType == other.runtimeType
+                && key == other.key&& value == other.value;
+        
+            }
+
+class LyricWord  {
+                /// ms
+final int start;
+/// ms
+final int end;
+final String text;
+
+                const LyricWord({required this.start ,required this.end ,required this.text ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => start.hashCode^end.hashCode^text.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LyricWord &&
+                runtimeType == other.runtimeType
+                && start == other.start&& end == other.end&& text == other.text;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,122 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `_artist_coverage`, `_artist_score`, `_closing_bracket`, `_duration_score`, `_find_root`, `_fold`, `_score_normalized`, `_similarity`, `_strip_brackets`, `_strip_feat`, `_strip_punctuation`, `_strip_version_suffix`, `_title_score`, `new`, `score`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `NormalizedTrack`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+
+
+            /// for Flutter
+/// 用于比较的标题：全角转半角，去掉重音、feat.、括号和版本后缀，小写，片假名转平假名，繁体转简体
+String  normalizeTitle({required String title }) => RustLib.instance.api.crateApiMusicMatcherNormalizeTitle(title: title);
+
+/// for Flutter
+/// 拆分并规范化艺术家，去掉重复的
+List<String>  normalizeArtists({required String artist }) => RustLib.instance.api.crateApiMusicMatcherNormalizeArtists(artist: artist);
+
+/// for Flutter
+MatchScore  scoreMatch({required MatchTrack query , required MatchTrack candidate }) => RustLib.instance.api.crateApiMusicMatcherScoreMatch(query: query, candidate: candidate);
+
+/// for Flutter
+/// 按 total 从高到低排列，相同时保持原来的顺序
+List<RankedMatch>  rankMatches({required MatchTrack query , required List<MatchTrack> candidates }) => RustLib.instance.api.crateApiMusicMatcherRankMatches(query: query, candidates: candidates);
+
+/// for Flutter
+/// 找出可能重复的歌曲，返回每组在 tracks 中的位置（至少两首）。
+/// 只比较规范化后标题相同的歌曲，total 不低于 threshold 时视为重复
+Future<List<Uint32List>>  findDuplicates({required List<MatchTrack> tracks , required double threshold }) => RustLib.instance.api.crateApiMusicMatcherFindDuplicates(tracks: tracks, threshold: threshold);
+
+/// for Flutter
+/// 在 index_path 的音乐库中查找重复的歌曲，返回每组的路径。见 [find_duplicates]
+Future<List<List<String>>>  findDuplicateAudios({required String indexPath , required double threshold }) => RustLib.instance.api.crateApiMusicMatcherFindDuplicateAudios(indexPath: indexPath, threshold: threshold);
+
+            class MatchScore  {
+                /// 0~1，已知的各项按权重平均
+final double total;
+final double title;
+/// 任一方没有艺术家时为 None
+final double? artist;
+/// 任一方没有时长时为 None
+final double? duration;
+/// 给人看的说明，如 "title: exact match after normalization"
+final List<String> reasons;
+
+                const MatchScore({required this.total ,required this.title ,this.artist ,this.duration ,required this.reasons ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => total.hashCode^title.hashCode^artist.hashCode^duration.hashCode^reasons.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MatchScore &&
+                runtimeType == other.runtimeType
+                && total == other.total&& title == other.title&& artist == other.artist&& duration == other.duration&& reasons == other.reasons;
+        
+            }
+
+/// 用于匹配的歌曲信息
+class MatchTrack  {
+                final String title;
+/// 多个艺术家可以用 、/ , & ; feat. 等分隔
+final String artist;
+/// s，未知时为 None
+final double? duration;
+
+                const MatchTrack({required this.title ,required this.artist ,this.duration ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => title.hashCode^artist.hashCode^duration.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MatchTrack &&
+                runtimeType == other.runtimeType
+                && title == other.title&& artist == other.artist&& duration == other.duration;
+        
+            }
+
+class RankedMatch  {
+                /// 在 candidates 中的位置
+final int index;
+final MatchScore score;
+
+                const RankedMatch({required this.index ,required this.score ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => index.hashCode^score.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RankedMatch &&
+                runtimeType == other.runtimeType
+                && index == other.index&& score == other.score;
+        
+            }
+            
\ No newline at end of file
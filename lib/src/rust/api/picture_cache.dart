@@ -0,0 +1,33 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `_memory_cache`, `_now_secs`, `_source_from_string`, `_source_to_string`, `blob_id`, `clear`, `evict`, `get_by_blob`, `get`, `get`, `invalidate_non_embedded`, `invalidate_non_embedded`, `invalidate`, `invalidate`, `link`, `new`, `open`, `put`, `put`, `touch_blob`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `DiskCache`, `PictureCacheKey`
+
+
+            /// for Flutter
+/// 在 index_path/thumbnails.sqlite 启用缩略图的磁盘缓存。
+/// byte_budget: 缓存上限（bytes），为 0 时使用默认值 256 MiB。超出时按最近访问时间淘汰。
+void  initPictureCache({required String indexPath , required BigInt byteBudget }) => RustLib.instance.api.crateApiPictureCacheInitPictureCache(indexPath: indexPath, byteBudget: byteBudget);
+
+/// for Flutter
+/// 清空内存和磁盘中的缩略图缓存
+Future<void>  clearPictureCache() => RustLib.instance.api.crateApiPictureCacheClearPictureCache();
+
+/// for Flutter
+/// 在后台为 index_path 索引中的所有音乐生成 width x height 的缩略图，已有缓存的会被跳过。
+/// 立即返回；已经在预热时不会重复开始。扫描或更新索引后调用。
+void  prewarmPictureCache({required String indexPath , required int width , required int height }) => RustLib.instance.api.crateApiPictureCachePrewarmPictureCache(indexPath: indexPath, width: width, height: height);
+
+/// for Flutter
+/// 停止 [prewarm_picture_cache]
+void  cancelPictureCachePrewarm() => RustLib.instance.api.crateApiPictureCacheCancelPictureCachePrewarm();
+
+            
+            
\ No newline at end of file
@@ -5,48 +5,202 @@
 
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'smtc_flutter.freezed.dart';
 
-// These functions are ignored because they are not marked as `pub`: `_init_controls`, `_new`, `_ras_ref_from_pic_data`, `_update_display`, `_update_state`, `_update_time_properties`
-
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SMTCFlutter>>
-abstract class SmtcFlutter implements RustOpaqueInterface {
-  /// Apis for Flutter
-  Future<void> close();
-
-  /// Apis for Flutter
-  factory SmtcFlutter() =>
-      RustLib.instance.api.crateApiSmtcFlutterSmtcFlutterNew();
-
-  /// Apis for Flutter
-  Stream<SMTCControlEvent> subscribeToControlEvents();
-
-  /// Apis for Flutter
-  Future<void> updateDisplay(
-      {required String title,
-      required String artist,
-      required String album,
-      required int duration,
-      required String path});
-
-  /// Apis for Flutter
-  Future<void> updateState({required SMTCState state});
-
-  /// progress, duration: ms
-  /// Apis for Flutter
-  Future<void> updateTimeProperties({required int progress});
-}
-
-enum SMTCControlEvent {
-  play,
-  pause,
-  previous,
-  next,
-  unknown,
-  ;
-}
+            // These functions are ignored because they are not marked as `pub`: `_forward_events`, `_lock`, `_resolve_artwork`, `_run`, `close`, `new`, `reinitialize`, `subscribe`, `update_display`, `update_position`, `update_rate`, `update_repeat`, `update_shuffle`, `update_state`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `SMTCSession`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `from`, `from`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SMTCFlutter>>
+                abstract class SmtcFlutter implements RustOpaqueInterface {
+                    /// Apis for Flutter
+ Future<void>  close();
+
+
+/// 创建失败时不会 panic，status 为 Unavailable
+/// Apis for Flutter
+factory SmtcFlutter()=>RustLib.instance.api.crateApiSmtcFlutterSmtcFlutterNew();
+
+
+/// 关闭当前的媒体控件并重新创建，恢复事件订阅、播放状态、显示内容、随机播放、循环模式和时间线
+/// Apis for Flutter
+ Future<void>  reinitialize();
+
+
+/// Apis for Flutter
+ SMTCStatus  status();
+
+
+/// Apis for Flutter
+ Stream<SMTCControlEvent>  subscribeToControlEvents();
+
+
+/// Apis for Flutter
+static SmtcFlutter  tryNew()=>RustLib.instance.api.crateApiSmtcFlutterSmtcFlutterTryNew();
+
+
+/// 封面使用 256x256 的缩略图。需要更多信息时使用 [SMTCFlutter::update_metadata]
+/// Apis for Flutter
+ Future<void>  updateDisplay({required String title , required String artist , required String album , required int duration , required String path });
+
+
+/// Apis for Flutter
+ Future<void>  updateMetadata({required SMTCMetadata metadata , required SMTCArtwork artwork });
+
+
+/// 同步播放器的播放速度
+/// Apis for Flutter
+ Future<void>  updatePlaybackRate({required double rate });
+
+
+/// 同步播放器的循环模式
+/// Apis for Flutter
+ Future<void>  updateRepeatMode({required SMTCRepeatMode mode });
+
+
+/// 同步播放器的随机播放状态
+/// Apis for Flutter
+ Future<void>  updateShuffle({required bool enabled });
+
+
+/// Apis for Flutter
+ Future<void>  updateState({required SMTCState state });
+
+
+/// progress: ms。播放时每秒最多发送一次，跳转时立即发送
+/// Apis for Flutter
+ Future<void>  updateTimeProperties({required int progress });
+
+
+
+                    
+                }
+                
+
+@freezed
+                sealed class SMTCArtwork with _$SMTCArtwork  {
+                    const SMTCArtwork._();
+
+                     const factory SMTCArtwork.none() = SMTCArtwork_None;
+ /// 已经在内存中的图片，例如正在显示的封面
+const factory SMTCArtwork.bytes({   required Uint8List data , }) = SMTCArtwork_Bytes;
+ /// 图片文件
+const factory SMTCArtwork.file({   required String path , }) = SMTCArtwork_File;
+ /// 音乐文件的缩略图，参数和 get_picture_from_path 相同。
+/// 命中缩略图缓存时不会重新读取音乐文件
+const factory SMTCArtwork.thumbnail({   required String path ,  required int width ,  required int height , }) = SMTCArtwork_Thumbnail;
+
+                    
+
+                    
+                }
+
+@freezed
+                sealed class SMTCControlEvent with _$SMTCControlEvent  {
+                    const SMTCControlEvent._();
+
+                     const factory SMTCControlEvent.play() = SMTCControlEvent_Play;
+ const factory SMTCControlEvent.pause() = SMTCControlEvent_Pause;
+ const factory SMTCControlEvent.previous() = SMTCControlEvent_Previous;
+ const factory SMTCControlEvent.next() = SMTCControlEvent_Next;
+ const factory SMTCControlEvent.unknown() = SMTCControlEvent_Unknown;
+ const factory SMTCControlEvent.stop() = SMTCControlEvent_Stop;
+ const factory SMTCControlEvent.fastForward() = SMTCControlEvent_FastForward;
+ const factory SMTCControlEvent.rewind() = SMTCControlEvent_Rewind;
+ /// 跳转到指定位置，ms
+const factory SMTCControlEvent.seek({   required BigInt position , }) = SMTCControlEvent_Seek;
+ /// 相对当前位置跳转，ms。只有 Linux 会发出
+const factory SMTCControlEvent.seekBy({   required PlatformInt64 offset , }) = SMTCControlEvent_SeekBy;
+ const factory SMTCControlEvent.shuffle({   required bool enabled , }) = SMTCControlEvent_Shuffle;
+ const factory SMTCControlEvent.repeat({   required SMTCRepeatMode mode , }) = SMTCControlEvent_Repeat;
+ const factory SMTCControlEvent.rate({   required double rate , }) = SMTCControlEvent_Rate;
+ /// 0.0 ~ 1.0。只有 Linux 会发出
+const factory SMTCControlEvent.volume({   required double volume , }) = SMTCControlEvent_Volume;
+
+                    
+
+                    
+                }
+
+@freezed
+                sealed class SMTCError with _$SMTCError implements FrbException {
+                    const SMTCError._();
+
+                     /// 创建媒体控件失败
+const factory SMTCError.init({   required String message , }) = SMTCError_Init;
+ /// 恢复订阅或显示内容失败
+const factory SMTCError.restore({   required String message , }) = SMTCError_Restore;
+
+                    
+
+                    
+                }
+
+/// 媒体控件上显示的信息
+class SMTCMetadata  {
+                final String title;
+final String artist;
+final String album;
+final String? albumArtist;
+final int? trackNumber;
+final String? genre;
+/// ms
+final int duration;
+/// 音乐文件的路径
+final String path;
+
+                const SMTCMetadata({required this.title ,required this.artist ,required this.album ,this.albumArtist ,this.trackNumber ,this.genre ,required this.duration ,required this.path ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => title.hashCode^artist.hashCode^album.hashCode^albumArtist.hashCode^trackNumber.hashCode^genre.hashCode^duration.hashCode^path.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SMTCMetadata &&
+                runtimeType == other.runtimeType
+                && title == other.title&& artist == other.artist&& album == other.album&& albumArtist == other.albumArtist&& trackNumber == other.trackNumber&& genre == other.genre&& duration == other.duration&& path == other.path;
+        
+            }
+
+enum SMTCRepeatMode {
+                    none,
+track,
+list,
+                    ;
+                    
+                }
 
 enum SMTCState {
-  paused,
-  playing,
-  ;
-}
+                    paused,
+playing,
+                    ;
+                    
+                }
+
+@freezed
+                sealed class SMTCStatus with _$SMTCStatus  {
+                    const SMTCStatus._();
+
+                     const factory SMTCStatus.ready() = SMTCStatus_Ready;
+ /// 创建失败，没有可用的媒体控件
+const factory SMTCStatus.unavailable({   required String message , }) = SMTCStatus_Unavailable;
+ /// 最近一次操作失败，媒体控件可能已经失效。可以调用 reinitialize
+const factory SMTCStatus.failed({   required String message , }) = SMTCStatus_Failed;
+
+                    
+
+                    
+                }
+            
\ No newline at end of file
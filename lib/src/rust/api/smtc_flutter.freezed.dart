@@ -0,0 +1,1252 @@
+// dart format width=80
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'smtc_flutter.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$SMTCArtwork {
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SMTCArtwork);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'SMTCArtwork()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class SMTCArtwork_None extends SMTCArtwork {
+  const SMTCArtwork_None(): super._();
+  
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SMTCArtwork_None);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'SMTCArtwork.none()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class SMTCArtwork_Bytes extends SMTCArtwork {
+  const SMTCArtwork_Bytes({required this.data}): super._();
+  
+
+ final  Uint8List data;
+
+/// Create a copy of SMTCArtwork
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SMTCArtwork_BytesCopyWith<SMTCArtwork_Bytes> get copyWith => _$SMTCArtwork_BytesCopyWithImpl<SMTCArtwork_Bytes>(this, _$identity);
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SMTCArtwork_Bytes&&const DeepCollectionEquality().equals(other.data, data));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(data));
+
+@override
+String toString() {
+  return 'SMTCArtwork.bytes(data: $data)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SMTCArtwork_BytesCopyWith<$Res>  {
+  factory $SMTCArtwork_BytesCopyWith(SMTCArtwork_Bytes value, $Res Function(SMTCArtwork_Bytes) _then) = _$SMTCArtwork_BytesCopyWithImpl;
+@useResult
+$Res call({
+ Uint8List data
+});
+
+
+
+
+}
+/// @nodoc
+class _$SMTCArtwork_BytesCopyWithImpl<$Res>
+    implements $SMTCArtwork_BytesCopyWith<$Res> {
+  _$SMTCArtwork_BytesCopyWithImpl(this._self, this._then);
+
+  final SMTCArtwork_Bytes _self;
+  final $Res Function(SMTCArtwork_Bytes) _then;
+
+/// Create a copy of SMTCArtwork
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? data = null,}) {
+  return _then(SMTCArtwork_Bytes(
+data: null == data ? _self.data : data // ignore: cast_nullable_to_non_nullable
+as Uint8List,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SMTCArtwork_File extends SMTCArtwork {
+  const SMTCArtwork_File({required this.path}): super._();
+  
+
+ final  String path;
+
+/// Create a copy of SMTCArtwork
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SMTCArtwork_FileCopyWith<SMTCArtwork_File> get copyWith => _$SMTCArtwork_FileCopyWithImpl<SMTCArtwork_File>(this, _$identity);
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SMTCArtwork_File&&(identical(other.path, path) || other.path == path));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,path);
+
+@override
+String toString() {
+  return 'SMTCArtwork.file(path: $path)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SMTCArtwork_FileCopyWith<$Res>  {
+  factory $SMTCArtwork_FileCopyWith(SMTCArtwork_File value, $Res Function(SMTCArtwork_File) _then) = _$SMTCArtwork_FileCopyWithImpl;
+@useResult
+$Res call({
+ String path
+});
+
+
+
+
+}
+/// @nodoc
+class _$SMTCArtwork_FileCopyWithImpl<$Res>
+    implements $SMTCArtwork_FileCopyWith<$Res> {
+  _$SMTCArtwork_FileCopyWithImpl(this._self, this._then);
+
+  final SMTCArtwork_File _self;
+  final $Res Function(SMTCArtwork_File) _then;
+
+/// Create a copy of SMTCArtwork
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? path = null,}) {
+  return _then(SMTCArtwork_File(
+path: null == path ? _self.path : path // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SMTCArtwork_Thumbnail extends SMTCArtwork {
+  const SMTCArtwork_Thumbnail({required this.path, required this.width, required this.height}): super._();
+  
+
+ final  String path;
+ final  int width;
+ final  int height;
+
+/// Create a copy of SMTCArtwork
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SMTCArtwork_ThumbnailCopyWith<SMTCArtwork_Thumbnail> get copyWith => _$SMTCArtwork_ThumbnailCopyWithImpl<SMTCArtwork_Thumbnail>(this, _$identity);
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SMTCArtwork_Thumbnail&&(identical(other.path, path) || other.path == path)&&(identical(other.width, width) || other.width == width)&&(identical(other.height, height) || other.height == height));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,path,width,height);
+
+@override
+String toString() {
+  return 'SMTCArtwork.thumbnail(path: $path, width: $width, height: $height)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SMTCArtwork_ThumbnailCopyWith<$Res>  {
+  factory $SMTCArtwork_ThumbnailCopyWith(SMTCArtwork_Thumbnail value, $Res Function(SMTCArtwork_Thumbnail) _then) = _$SMTCArtwork_ThumbnailCopyWithImpl;
+@useResult
+$Res call({
+ String path, int width, int height
+});
+
+
+
+
+}
+/// @nodoc
+class _$SMTCArtwork_ThumbnailCopyWithImpl<$Res>
+    implements $SMTCArtwork_ThumbnailCopyWith<$Res> {
+  _$SMTCArtwork_ThumbnailCopyWithImpl(this._self, this._then);
+
+  final SMTCArtwork_Thumbnail _self;
+  final $Res Function(SMTCArtwork_Thumbnail) _then;
+
+/// Create a copy of SMTCArtwork
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? path = null,Object? width = null,Object? height = null,}) {
+  return _then(SMTCArtwork_Thumbnail(
+path: null == path ? _self.path : path // ignore: cast_nullable_to_non_nullable
+as String,
+width: null == width ? _self.width : width // ignore: cast_nullable_to_non_nullable
+as int,
+height: null == height ? _self.height : height // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$SMTCControlEvent {
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SMTCControlEvent);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'SMTCControlEvent()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class SMTCControlEvent_Play extends SMTCControlEvent {
+  const SMTCControlEvent_Play(): super._();
+  
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SMTCControlEvent_Play);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'SMTCControlEvent.play()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class SMTCControlEvent_Pause extends SMTCControlEvent {
+  const SMTCControlEvent_Pause(): super._();
+  
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SMTCControlEvent_Pause);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'SMTCControlEvent.pause()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class SMTCControlEvent_Previous extends SMTCControlEvent {
+  const SMTCControlEvent_Previous(): super._();
+  
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SMTCControlEvent_Previous);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'SMTCControlEvent.previous()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class SMTCControlEvent_Next extends SMTCControlEvent {
+  const SMTCControlEvent_Next(): super._();
+  
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SMTCControlEvent_Next);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'SMTCControlEvent.next()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class SMTCControlEvent_Unknown extends SMTCControlEvent {
+  const SMTCControlEvent_Unknown(): super._();
+  
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SMTCControlEvent_Unknown);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'SMTCControlEvent.unknown()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class SMTCControlEvent_Stop extends SMTCControlEvent {
+  const SMTCControlEvent_Stop(): super._();
+  
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SMTCControlEvent_Stop);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'SMTCControlEvent.stop()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class SMTCControlEvent_FastForward extends SMTCControlEvent {
+  const SMTCControlEvent_FastForward(): super._();
+  
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SMTCControlEvent_FastForward);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'SMTCControlEvent.fastForward()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class SMTCControlEvent_Rewind extends SMTCControlEvent {
+  const SMTCControlEvent_Rewind(): super._();
+  
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SMTCControlEvent_Rewind);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'SMTCControlEvent.rewind()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class SMTCControlEvent_Seek extends SMTCControlEvent {
+  const SMTCControlEvent_Seek({required this.position}): super._();
+  
+
+ final  BigInt position;
+
+/// Create a copy of SMTCControlEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SMTCControlEvent_SeekCopyWith<SMTCControlEvent_Seek> get copyWith => _$SMTCControlEvent_SeekCopyWithImpl<SMTCControlEvent_Seek>(this, _$identity);
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SMTCControlEvent_Seek&&(identical(other.position, position) || other.position == position));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,position);
+
+@override
+String toString() {
+  return 'SMTCControlEvent.seek(position: $position)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SMTCControlEvent_SeekCopyWith<$Res>  {
+  factory $SMTCControlEvent_SeekCopyWith(SMTCControlEvent_Seek value, $Res Function(SMTCControlEvent_Seek) _then) = _$SMTCControlEvent_SeekCopyWithImpl;
+@useResult
+$Res call({
+ BigInt position
+});
+
+
+
+
+}
+/// @nodoc
+class _$SMTCControlEvent_SeekCopyWithImpl<$Res>
+    implements $SMTCControlEvent_SeekCopyWith<$Res> {
+  _$SMTCControlEvent_SeekCopyWithImpl(this._self, this._then);
+
+  final SMTCControlEvent_Seek _self;
+  final $Res Function(SMTCControlEvent_Seek) _then;
+
+/// Create a copy of SMTCControlEvent
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? position = null,}) {
+  return _then(SMTCControlEvent_Seek(
+position: null == position ? _self.position : position // ignore: cast_nullable_to_non_nullable
+as BigInt,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SMTCControlEvent_SeekBy extends SMTCControlEvent {
+  const SMTCControlEvent_SeekBy({required this.offset}): super._();
+  
+
+ final  PlatformInt64 offset;
+
+/// Create a copy of SMTCControlEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SMTCControlEvent_SeekByCopyWith<SMTCControlEvent_SeekBy> get copyWith => _$SMTCControlEvent_SeekByCopyWithImpl<SMTCControlEvent_SeekBy>(this, _$identity);
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SMTCControlEvent_SeekBy&&(identical(other.offset, offset) || other.offset == offset));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,offset);
+
+@override
+String toString() {
+  return 'SMTCControlEvent.seekBy(offset: $offset)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SMTCControlEvent_SeekByCopyWith<$Res>  {
+  factory $SMTCControlEvent_SeekByCopyWith(SMTCControlEvent_SeekBy value, $Res Function(SMTCControlEvent_SeekBy) _then) = _$SMTCControlEvent_SeekByCopyWithImpl;
+@useResult
+$Res call({
+ PlatformInt64 offset
+});
+
+
+
+
+}
+/// @nodoc
+class _$SMTCControlEvent_SeekByCopyWithImpl<$Res>
+    implements $SMTCControlEvent_SeekByCopyWith<$Res> {
+  _$SMTCControlEvent_SeekByCopyWithImpl(this._self, this._then);
+
+  final SMTCControlEvent_SeekBy _self;
+  final $Res Function(SMTCControlEvent_SeekBy) _then;
+
+/// Create a copy of SMTCControlEvent
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? offset = null,}) {
+  return _then(SMTCControlEvent_SeekBy(
+offset: null == offset ? _self.offset : offset // ignore: cast_nullable_to_non_nullable
+as PlatformInt64,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SMTCControlEvent_Shuffle extends SMTCControlEvent {
+  const SMTCControlEvent_Shuffle({required this.enabled}): super._();
+  
+
+ final  bool enabled;
+
+/// Create a copy of SMTCControlEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SMTCControlEvent_ShuffleCopyWith<SMTCControlEvent_Shuffle> get copyWith => _$SMTCControlEvent_ShuffleCopyWithImpl<SMTCControlEvent_Shuffle>(this, _$identity);
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SMTCControlEvent_Shuffle&&(identical(other.enabled, enabled) || other.enabled == enabled));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,enabled);
+
+@override
+String toString() {
+  return 'SMTCControlEvent.shuffle(enabled: $enabled)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SMTCControlEvent_ShuffleCopyWith<$Res>  {
+  factory $SMTCControlEvent_ShuffleCopyWith(SMTCControlEvent_Shuffle value, $Res Function(SMTCControlEvent_Shuffle) _then) = _$SMTCControlEvent_ShuffleCopyWithImpl;
+@useResult
+$Res call({
+ bool enabled
+});
+
+
+
+
+}
+/// @nodoc
+class _$SMTCControlEvent_ShuffleCopyWithImpl<$Res>
+    implements $SMTCControlEvent_ShuffleCopyWith<$Res> {
+  _$SMTCControlEvent_ShuffleCopyWithImpl(this._self, this._then);
+
+  final SMTCControlEvent_Shuffle _self;
+  final $Res Function(SMTCControlEvent_Shuffle) _then;
+
+/// Create a copy of SMTCControlEvent
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? enabled = null,}) {
+  return _then(SMTCControlEvent_Shuffle(
+enabled: null == enabled ? _self.enabled : enabled // ignore: cast_nullable_to_non_nullable
+as bool,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SMTCControlEvent_Repeat extends SMTCControlEvent {
+  const SMTCControlEvent_Repeat({required this.mode}): super._();
+  
+
+ final  SMTCRepeatMode mode;
+
+/// Create a copy of SMTCControlEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SMTCControlEvent_RepeatCopyWith<SMTCControlEvent_Repeat> get copyWith => _$SMTCControlEvent_RepeatCopyWithImpl<SMTCControlEvent_Repeat>(this, _$identity);
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SMTCControlEvent_Repeat&&(identical(other.mode, mode) || other.mode == mode));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,mode);
+
+@override
+String toString() {
+  return 'SMTCControlEvent.repeat(mode: $mode)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SMTCControlEvent_RepeatCopyWith<$Res>  {
+  factory $SMTCControlEvent_RepeatCopyWith(SMTCControlEvent_Repeat value, $Res Function(SMTCControlEvent_Repeat) _then) = _$SMTCControlEvent_RepeatCopyWithImpl;
+@useResult
+$Res call({
+ SMTCRepeatMode mode
+});
+
+
+
+
+}
+/// @nodoc
+class _$SMTCControlEvent_RepeatCopyWithImpl<$Res>
+    implements $SMTCControlEvent_RepeatCopyWith<$Res> {
+  _$SMTCControlEvent_RepeatCopyWithImpl(this._self, this._then);
+
+  final SMTCControlEvent_Repeat _self;
+  final $Res Function(SMTCControlEvent_Repeat) _then;
+
+/// Create a copy of SMTCControlEvent
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? mode = null,}) {
+  return _then(SMTCControlEvent_Repeat(
+mode: null == mode ? _self.mode : mode // ignore: cast_nullable_to_non_nullable
+as SMTCRepeatMode,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SMTCControlEvent_Rate extends SMTCControlEvent {
+  const SMTCControlEvent_Rate({required this.rate}): super._();
+  
+
+ final  double rate;
+
+/// Create a copy of SMTCControlEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SMTCControlEvent_RateCopyWith<SMTCControlEvent_Rate> get copyWith => _$SMTCControlEvent_RateCopyWithImpl<SMTCControlEvent_Rate>(this, _$identity);
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SMTCControlEvent_Rate&&(identical(other.rate, rate) || other.rate == rate));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,rate);
+
+@override
+String toString() {
+  return 'SMTCControlEvent.rate(rate: $rate)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SMTCControlEvent_RateCopyWith<$Res>  {
+  factory $SMTCControlEvent_RateCopyWith(SMTCControlEvent_Rate value, $Res Function(SMTCControlEvent_Rate) _then) = _$SMTCControlEvent_RateCopyWithImpl;
+@useResult
+$Res call({
+ double rate
+});
+
+
+
+
+}
+/// @nodoc
+class _$SMTCControlEvent_RateCopyWithImpl<$Res>
+    implements $SMTCControlEvent_RateCopyWith<$Res> {
+  _$SMTCControlEvent_RateCopyWithImpl(this._self, this._then);
+
+  final SMTCControlEvent_Rate _self;
+  final $Res Function(SMTCControlEvent_Rate) _then;
+
+/// Create a copy of SMTCControlEvent
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? rate = null,}) {
+  return _then(SMTCControlEvent_Rate(
+rate: null == rate ? _self.rate : rate // ignore: cast_nullable_to_non_nullable
+as double,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SMTCControlEvent_Volume extends SMTCControlEvent {
+  const SMTCControlEvent_Volume({required this.volume}): super._();
+  
+
+ final  double volume;
+
+/// Create a copy of SMTCControlEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SMTCControlEvent_VolumeCopyWith<SMTCControlEvent_Volume> get copyWith => _$SMTCControlEvent_VolumeCopyWithImpl<SMTCControlEvent_Volume>(this, _$identity);
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SMTCControlEvent_Volume&&(identical(other.volume, volume) || other.volume == volume));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,volume);
+
+@override
+String toString() {
+  return 'SMTCControlEvent.volume(volume: $volume)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SMTCControlEvent_VolumeCopyWith<$Res>  {
+  factory $SMTCControlEvent_VolumeCopyWith(SMTCControlEvent_Volume value, $Res Function(SMTCControlEvent_Volume) _then) = _$SMTCControlEvent_VolumeCopyWithImpl;
+@useResult
+$Res call({
+ double volume
+});
+
+
+
+
+}
+/// @nodoc
+class _$SMTCControlEvent_VolumeCopyWithImpl<$Res>
+    implements $SMTCControlEvent_VolumeCopyWith<$Res> {
+  _$SMTCControlEvent_VolumeCopyWithImpl(this._self, this._then);
+
+  final SMTCControlEvent_Volume _self;
+  final $Res Function(SMTCControlEvent_Volume) _then;
+
+/// Create a copy of SMTCControlEvent
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? volume = null,}) {
+  return _then(SMTCControlEvent_Volume(
+volume: null == volume ? _self.volume : volume // ignore: cast_nullable_to_non_nullable
+as double,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$SMTCError {
+
+ String get message;
+/// Create a copy of SMTCError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SMTCErrorCopyWith<SMTCError> get copyWith => _$SMTCErrorCopyWithImpl<SMTCError>(this as SMTCError, _$identity);
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SMTCError&&(identical(other.message, message) || other.message == message));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,message);
+
+@override
+String toString() {
+  return 'SMTCError(message: $message)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SMTCErrorCopyWith<$Res>  {
+  factory $SMTCErrorCopyWith(SMTCError value, $Res Function(SMTCError) _then) = _$SMTCErrorCopyWithImpl;
+@useResult
+$Res call({
+ String message
+});
+
+
+
+
+}
+/// @nodoc
+class _$SMTCErrorCopyWithImpl<$Res>
+    implements $SMTCErrorCopyWith<$Res> {
+  _$SMTCErrorCopyWithImpl(this._self, this._then);
+
+  final SMTCError _self;
+  final $Res Function(SMTCError) _then;
+
+/// Create a copy of SMTCError
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? message = null,}) {
+  return _then(_self.copyWith(
+message: null == message ? _self.message : message // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SMTCError_Init extends SMTCError {
+  const SMTCError_Init({required this.message}): super._();
+  
+
+@override final  String message;
+
+/// Create a copy of SMTCError
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SMTCError_InitCopyWith<SMTCError_Init> get copyWith => _$SMTCError_InitCopyWithImpl<SMTCError_Init>(this, _$identity);
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SMTCError_Init&&(identical(other.message, message) || other.message == message));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,message);
+
+@override
+String toString() {
+  return 'SMTCError.init(message: $message)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SMTCError_InitCopyWith<$Res>  implements $SMTCErrorCopyWith<$Res> {
+  factory $SMTCError_InitCopyWith(SMTCError_Init value, $Res Function(SMTCError_Init) _then) = _$SMTCError_InitCopyWithImpl;
+@override @useResult
+$Res call({
+ String message
+});
+
+
+
+
+}
+/// @nodoc
+class _$SMTCError_InitCopyWithImpl<$Res>
+    implements $SMTCError_InitCopyWith<$Res> {
+  _$SMTCError_InitCopyWithImpl(this._self, this._then);
+
+  final SMTCError_Init _self;
+  final $Res Function(SMTCError_Init) _then;
+
+/// Create a copy of SMTCError
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? message = null,}) {
+  return _then(SMTCError_Init(
+message: null == message ? _self.message : message // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SMTCError_Restore extends SMTCError {
+  const SMTCError_Restore({required this.message}): super._();
+  
+
+@override final  String message;
+
+/// Create a copy of SMTCError
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SMTCError_RestoreCopyWith<SMTCError_Restore> get copyWith => _$SMTCError_RestoreCopyWithImpl<SMTCError_Restore>(this, _$identity);
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SMTCError_Restore&&(identical(other.message, message) || other.message == message));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,message);
+
+@override
+String toString() {
+  return 'SMTCError.restore(message: $message)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SMTCError_RestoreCopyWith<$Res>  implements $SMTCErrorCopyWith<$Res> {
+  factory $SMTCError_RestoreCopyWith(SMTCError_Restore value, $Res Function(SMTCError_Restore) _then) = _$SMTCError_RestoreCopyWithImpl;
+@override @useResult
+$Res call({
+ String message
+});
+
+
+
+
+}
+/// @nodoc
+class _$SMTCError_RestoreCopyWithImpl<$Res>
+    implements $SMTCError_RestoreCopyWith<$Res> {
+  _$SMTCError_RestoreCopyWithImpl(this._self, this._then);
+
+  final SMTCError_Restore _self;
+  final $Res Function(SMTCError_Restore) _then;
+
+/// Create a copy of SMTCError
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? message = null,}) {
+  return _then(SMTCError_Restore(
+message: null == message ? _self.message : message // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$SMTCStatus {
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SMTCStatus);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'SMTCStatus()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class SMTCStatus_Ready extends SMTCStatus {
+  const SMTCStatus_Ready(): super._();
+  
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SMTCStatus_Ready);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'SMTCStatus.ready()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class SMTCStatus_Unavailable extends SMTCStatus {
+  const SMTCStatus_Unavailable({required this.message}): super._();
+  
+
+ final  String message;
+
+/// Create a copy of SMTCStatus
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SMTCStatus_UnavailableCopyWith<SMTCStatus_Unavailable> get copyWith => _$SMTCStatus_UnavailableCopyWithImpl<SMTCStatus_Unavailable>(this, _$identity);
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SMTCStatus_Unavailable&&(identical(other.message, message) || other.message == message));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,message);
+
+@override
+String toString() {
+  return 'SMTCStatus.unavailable(message: $message)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SMTCStatus_UnavailableCopyWith<$Res>  {
+  factory $SMTCStatus_UnavailableCopyWith(SMTCStatus_Unavailable value, $Res Function(SMTCStatus_Unavailable) _then) = _$SMTCStatus_UnavailableCopyWithImpl;
+@useResult
+$Res call({
+ String message
+});
+
+
+
+
+}
+/// @nodoc
+class _$SMTCStatus_UnavailableCopyWithImpl<$Res>
+    implements $SMTCStatus_UnavailableCopyWith<$Res> {
+  _$SMTCStatus_UnavailableCopyWithImpl(this._self, this._then);
+
+  final SMTCStatus_Unavailable _self;
+  final $Res Function(SMTCStatus_Unavailable) _then;
+
+/// Create a copy of SMTCStatus
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? message = null,}) {
+  return _then(SMTCStatus_Unavailable(
+message: null == message ? _self.message : message // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SMTCStatus_Failed extends SMTCStatus {
+  const SMTCStatus_Failed({required this.message}): super._();
+  
+
+ final  String message;
+
+/// Create a copy of SMTCStatus
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SMTCStatus_FailedCopyWith<SMTCStatus_Failed> get copyWith => _$SMTCStatus_FailedCopyWithImpl<SMTCStatus_Failed>(this, _$identity);
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SMTCStatus_Failed&&(identical(other.message, message) || other.message == message));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,message);
+
+@override
+String toString() {
+  return 'SMTCStatus.failed(message: $message)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SMTCStatus_FailedCopyWith<$Res>  {
+  factory $SMTCStatus_FailedCopyWith(SMTCStatus_Failed value, $Res Function(SMTCStatus_Failed) _then) = _$SMTCStatus_FailedCopyWithImpl;
+@useResult
+$Res call({
+ String message
+});
+
+
+
+
+}
+/// @nodoc
+class _$SMTCStatus_FailedCopyWithImpl<$Res>
+    implements $SMTCStatus_FailedCopyWith<$Res> {
+  _$SMTCStatus_FailedCopyWithImpl(this._self, this._then);
+
+  final SMTCStatus_Failed _self;
+  final $Res Function(SMTCStatus_Failed) _then;
+
+/// Create a copy of SMTCStatus
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? message = null,}) {
+  return _then(SMTCStatus_Failed(
+message: null == message ? _self.message : message // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+// dart format on
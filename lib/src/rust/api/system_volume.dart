@@ -5,15 +5,103 @@
 
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'system_volume.freezed.dart';
 
-Stream<double> systemVolumeInit() =>
-    RustLib.instance.api.crateApiSystemVolumeSystemVolumeInit();
+            // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`
 
-void systemVolumeSet({required double val}) =>
-    RustLib.instance.api.crateApiSystemVolumeSystemVolumeSet(val: val);
 
-double systemVolumeGet() =>
-    RustLib.instance.api.crateApiSystemVolumeSystemVolumeGet();
+            Stream<double>  systemVolumeInit() => RustLib.instance.api.crateApiSystemVolumeSystemVolumeInit();
 
-void systemVolumeDispose() =>
-    RustLib.instance.api.crateApiSystemVolumeSystemVolumeDispose();
+Future<void>  systemVolumeSet({required double val }) => RustLib.instance.api.crateApiSystemVolumeSystemVolumeSet(val: val);
+
+Future<double>  systemVolumeGet() => RustLib.instance.api.crateApiSystemVolumeSystemVolumeGet();
+
+Future<void>  systemVolumeDispose() => RustLib.instance.api.crateApiSystemVolumeSystemVolumeDispose();
+
+/// 列出所有输出设备，包括未启用和未插入的
+Future<List<OutputDevice>>  outputDeviceList() => RustLib.instance.api.crateApiSystemVolumeOutputDeviceList();
+
+/// 0.0 ~ 1.0
+Future<double>  outputDeviceVolumeGet({required String id }) => RustLib.instance.api.crateApiSystemVolumeOutputDeviceVolumeGet(id: id);
+
+Future<void>  outputDeviceVolumeSet({required String id , required double val }) => RustLib.instance.api.crateApiSystemVolumeOutputDeviceVolumeSet(id: id, val: val);
+
+Future<bool>  outputDeviceMuteGet({required String id }) => RustLib.instance.api.crateApiSystemVolumeOutputDeviceMuteGet(id: id);
+
+Future<void>  outputDeviceMuteSet({required String id , required bool mute }) => RustLib.instance.api.crateApiSystemVolumeOutputDeviceMuteSet(id: id, mute: mute);
+
+/// 监听输出设备的添加、移除和默认设备变化。再次调用时替换之前的 sink
+Stream<OutputDeviceEvent>  outputDeviceEventsInit() => RustLib.instance.api.crateApiSystemVolumeOutputDeviceEventsInit();
+
+Future<void>  outputDeviceEventsDispose() => RustLib.instance.api.crateApiSystemVolumeOutputDeviceEventsDispose();
+
+            /// 音频输出设备
+class OutputDevice  {
+                /// Windows 上是 endpoint id，Linux 上是 PulseAudio 的 sink name
+final String id;
+final String name;
+final OutputFormFactor formFactor;
+/// 是否为默认输出设备
+final bool isDefault;
+final OutputDeviceState state;
+
+                const OutputDevice({required this.id ,required this.name ,required this.formFactor ,required this.isDefault ,required this.state ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^name.hashCode^formFactor.hashCode^isDefault.hashCode^state.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is OutputDevice &&
+                runtimeType == other.runtimeType
+                && id == other.id&& name == other.name&& formFactor == other.formFactor&& isDefault == other.isDefault&& state == other.state;
+        
+            }
+
+@freezed
+                sealed class OutputDeviceEvent with _$OutputDeviceEvent  {
+                    const OutputDeviceEvent._();
+
+                     const factory OutputDeviceEvent.added({   required String id , }) = OutputDeviceEvent_Added;
+ const factory OutputDeviceEvent.removed({   required String id , }) = OutputDeviceEvent_Removed;
+ /// 没有默认输出设备时 id 为 None
+const factory OutputDeviceEvent.defaultChanged({   String? id , }) = OutputDeviceEvent_DefaultChanged;
+ /// 只有 Windows 会发出
+const factory OutputDeviceEvent.stateChanged({   required String id ,  required OutputDeviceState state , }) = OutputDeviceEvent_StateChanged;
+
+                    
+
+                    
+                }
+
+enum OutputDeviceState {
+                    active,
+disabled,
+notPresent,
+/// 插孔上没有插入设备
+unplugged,
+                    ;
+                    
+                }
+
+enum OutputFormFactor {
+                    speakers,
+headphones,
+headset,
+lineOut,
+spdif,
+hdmi,
+network,
+unknown,
+                    ;
+                    
+                }
+            
\ No newline at end of file
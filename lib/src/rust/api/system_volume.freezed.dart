@@ -0,0 +1,301 @@
+// dart format width=80
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'system_volume.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$OutputDeviceEvent {
+
+ String? get id;
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is OutputDeviceEvent&&(identical(other.id, id) || other.id == id));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,id);
+
+@override
+String toString() {
+  return 'OutputDeviceEvent(id: $id)';
+}
+
+
+}
+
+/// @nodoc
+
+
+class OutputDeviceEvent_Added extends OutputDeviceEvent {
+  const OutputDeviceEvent_Added({required this.id}): super._();
+  
+
+@override final  String id;
+
+/// Create a copy of OutputDeviceEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$OutputDeviceEvent_AddedCopyWith<OutputDeviceEvent_Added> get copyWith => _$OutputDeviceEvent_AddedCopyWithImpl<OutputDeviceEvent_Added>(this, _$identity);
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is OutputDeviceEvent_Added&&(identical(other.id, id) || other.id == id));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,id);
+
+@override
+String toString() {
+  return 'OutputDeviceEvent.added(id: $id)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $OutputDeviceEvent_AddedCopyWith<$Res>  {
+  factory $OutputDeviceEvent_AddedCopyWith(OutputDeviceEvent_Added value, $Res Function(OutputDeviceEvent_Added) _then) = _$OutputDeviceEvent_AddedCopyWithImpl;
+@useResult
+$Res call({
+ String id
+});
+
+
+
+
+}
+/// @nodoc
+class _$OutputDeviceEvent_AddedCopyWithImpl<$Res>
+    implements $OutputDeviceEvent_AddedCopyWith<$Res> {
+  _$OutputDeviceEvent_AddedCopyWithImpl(this._self, this._then);
+
+  final OutputDeviceEvent_Added _self;
+  final $Res Function(OutputDeviceEvent_Added) _then;
+
+/// Create a copy of OutputDeviceEvent
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? id = null,}) {
+  return _then(OutputDeviceEvent_Added(
+id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class OutputDeviceEvent_Removed extends OutputDeviceEvent {
+  const OutputDeviceEvent_Removed({required this.id}): super._();
+  
+
+@override final  String id;
+
+/// Create a copy of OutputDeviceEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$OutputDeviceEvent_RemovedCopyWith<OutputDeviceEvent_Removed> get copyWith => _$OutputDeviceEvent_RemovedCopyWithImpl<OutputDeviceEvent_Removed>(this, _$identity);
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is OutputDeviceEvent_Removed&&(identical(other.id, id) || other.id == id));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,id);
+
+@override
+String toString() {
+  return 'OutputDeviceEvent.removed(id: $id)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $OutputDeviceEvent_RemovedCopyWith<$Res>  {
+  factory $OutputDeviceEvent_RemovedCopyWith(OutputDeviceEvent_Removed value, $Res Function(OutputDeviceEvent_Removed) _then) = _$OutputDeviceEvent_RemovedCopyWithImpl;
+@useResult
+$Res call({
+ String id
+});
+
+
+
+
+}
+/// @nodoc
+class _$OutputDeviceEvent_RemovedCopyWithImpl<$Res>
+    implements $OutputDeviceEvent_RemovedCopyWith<$Res> {
+  _$OutputDeviceEvent_RemovedCopyWithImpl(this._self, this._then);
+
+  final OutputDeviceEvent_Removed _self;
+  final $Res Function(OutputDeviceEvent_Removed) _then;
+
+/// Create a copy of OutputDeviceEvent
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? id = null,}) {
+  return _then(OutputDeviceEvent_Removed(
+id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class OutputDeviceEvent_DefaultChanged extends OutputDeviceEvent {
+  const OutputDeviceEvent_DefaultChanged({this.id}): super._();
+  
+
+@override final  String? id;
+
+/// Create a copy of OutputDeviceEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$OutputDeviceEvent_DefaultChangedCopyWith<OutputDeviceEvent_DefaultChanged> get copyWith => _$OutputDeviceEvent_DefaultChangedCopyWithImpl<OutputDeviceEvent_DefaultChanged>(this, _$identity);
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is OutputDeviceEvent_DefaultChanged&&(identical(other.id, id) || other.id == id));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,id);
+
+@override
+String toString() {
+  return 'OutputDeviceEvent.defaultChanged(id: $id)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $OutputDeviceEvent_DefaultChangedCopyWith<$Res>  {
+  factory $OutputDeviceEvent_DefaultChangedCopyWith(OutputDeviceEvent_DefaultChanged value, $Res Function(OutputDeviceEvent_DefaultChanged) _then) = _$OutputDeviceEvent_DefaultChangedCopyWithImpl;
+@useResult
+$Res call({
+ String? id
+});
+
+
+
+
+}
+/// @nodoc
+class _$OutputDeviceEvent_DefaultChangedCopyWithImpl<$Res>
+    implements $OutputDeviceEvent_DefaultChangedCopyWith<$Res> {
+  _$OutputDeviceEvent_DefaultChangedCopyWithImpl(this._self, this._then);
+
+  final OutputDeviceEvent_DefaultChanged _self;
+  final $Res Function(OutputDeviceEvent_DefaultChanged) _then;
+
+/// Create a copy of OutputDeviceEvent
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? id = freezed,}) {
+  return _then(OutputDeviceEvent_DefaultChanged(
+id: freezed == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
+as String?,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class OutputDeviceEvent_StateChanged extends OutputDeviceEvent {
+  const OutputDeviceEvent_StateChanged({required this.id, required this.state}): super._();
+  
+
+@override final  String id;
+ final  OutputDeviceState state;
+
+/// Create a copy of OutputDeviceEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$OutputDeviceEvent_StateChangedCopyWith<OutputDeviceEvent_StateChanged> get copyWith => _$OutputDeviceEvent_StateChangedCopyWithImpl<OutputDeviceEvent_StateChanged>(this, _$identity);
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is OutputDeviceEvent_StateChanged&&(identical(other.id, id) || other.id == id)&&(identical(other.state, state) || other.state == state));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,id,state);
+
+@override
+String toString() {
+  return 'OutputDeviceEvent.stateChanged(id: $id, state: $state)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $OutputDeviceEvent_StateChangedCopyWith<$Res>  {
+  factory $OutputDeviceEvent_StateChangedCopyWith(OutputDeviceEvent_StateChanged value, $Res Function(OutputDeviceEvent_StateChanged) _then) = _$OutputDeviceEvent_StateChangedCopyWithImpl;
+@useResult
+$Res call({
+ String id, OutputDeviceState state
+});
+
+
+
+
+}
+/// @nodoc
+class _$OutputDeviceEvent_StateChangedCopyWithImpl<$Res>
+    implements $OutputDeviceEvent_StateChangedCopyWith<$Res> {
+  _$OutputDeviceEvent_StateChangedCopyWithImpl(this._self, this._then);
+
+  final OutputDeviceEvent_StateChanged _self;
+  final $Res Function(OutputDeviceEvent_StateChanged) _then;
+
+/// Create a copy of OutputDeviceEvent
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? id = null,Object? state = null,}) {
+  return _then(OutputDeviceEvent_StateChanged(
+id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
+as String,
+state: null == state ? _self.state : state // ignore: cast_nullable_to_non_nullable
+as OutputDeviceState,
+  ));
+}
+
+
+}
+
+// dart format on
@@ -4,78 +4,309 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'lyric.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'tag_reader.freezed.dart';
 
-// These functions are ignored because they are not marked as `pub`: `_get_lyric_from_lofty`, `_get_lyric_from_lrc_file`, `_get_picture_by_lofty`, `_get_picture_by_windows`, `_picture_cache_key`, `_update_index_below_1_1_0`, `new_with_path`, `read_by_lofty`, `read_by_win_music_properties`, `read_from_folder_recursively`, `read_from_folder`, `read_from_path`, `to_json_value`, `to_json_value`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `AudioFolder`, `Audio`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`, `fmt`
+            // These functions are ignored because they are not marked as `pub`: `_apply_cue_sheets`, `_average_bitrate`, `_cue_sheets_in_folder`, `_cue_track_path`, `_cue_track`, `_encode_picture`, `_expand_cue_tracks`, `_find_folder_cover`, `_find_lyric_candidates`, `_find_lyric_files`, `_fit_picture`, `_folder_cover_names`, `_get_artist_title`, `_get_cue_sheet_from_tag`, `_get_lyric_from_lofty`, `_get_picture_by_lofty`, `_get_picture_with_source`, `_global_lyric_names`, `_id3v2_lang_code`, `_id3v2_language`, `_id3v2_latin1_keys`, `_id3v2_lyrics`, `_indexed_audio_modified`, `_indexed_file_path`, `_latin1_items_of_file`, `_latin1_items`, `_lowercase_names`, `_lyric_search_options`, `_modified_secs`, `_normalize_lyric_name`, `_probe_file_type`, `_read_id3v2_bytes`, `_read_id3v2`, `_read_lyric_file`, `_repair_id3_text`, `_tag_for_write`, `_tag_lyrics`, `_update_index_below_1_1_0`, `_uses_id3v2_lyrics`, `_write_id3v2_lyrics`, `cache_variant`, `cue_source_path`, `new_with_path`, `physical_size`, `read_by_lofty`, `read_by_system_properties`, `read_from_folder_recursively`, `read_from_folder`, `read_from_path`, `read_natively`, `to_filter_type`, `to_json_value`, `to_json_value`, `with_size`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `AudioCue`, `AudioFolder`, `Audio`, `Latin1Items`, `LyricSearchOptions`, `NativeTags`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
 
-/// for Flutter
-Future<String> readAudioExtraMetadata({required String path}) =>
-    RustLib.instance.api.crateApiTagReaderReadAudioExtraMetadata(path: path);
 
-/// for Flutter
-/// 如果无法通过 Lofty 获取则通过 Windows 获取
-Future<Uint8List?> getPictureFromPath(
-        {required String path, required int width, required int height}) =>
-    RustLib.instance.api.crateApiTagReaderGetPictureFromPath(
-        path: path, width: width, height: height);
+            /// for Flutter
+Future<String>  readAudioExtraMetadata({required String path }) => RustLib.instance.api.crateApiTagReaderReadAudioExtraMetadata(path: path);
+
+/// for Flutter  
+/// 设置外置封面的文件名（不含扩展名，不区分大小写）及查找顺序。
+/// 以 `*` 结尾的名称按前缀匹配，有多个匹配时使用最大的文件；
+/// 单独的 `*` 表示文件夹里唯一的一张图片。
+/// 传入空列表时恢复默认：cover, folder, front, AlbumArt*, *
+void  setFolderCoverNames({required List<String> names }) => RustLib.instance.api.crateApiTagReaderSetFolderCoverNames(names: names);
+
+/// for Flutter  
+/// 如果无法通过 Lofty 获取则查找外置封面，再不能的话使用系统生成的缩略图
+/// （Windows 缩略图或 freedesktop 缩略图缓存）
+Future<Uint8List?>  getPictureFromPath({required String path , required int width , required int height }) => RustLib.instance.api.crateApiTagReaderGetPictureFromPath(path: path, width: width, height: height);
+
+/// for Flutter  
+/// 和 [get_picture_from_path] 相同，同时返回图片的来源
+Future<PictureWithSource?>  getPictureWithSourceFromPath({required String path , required int width , required int height }) => RustLib.instance.api.crateApiTagReaderGetPictureWithSourceFromPath(path: path, width: width, height: height);
+
+/// for Flutter  
+/// 按 options 缩放、裁剪并编码图片。无法解码时返回原图。
+Future<PictureWithSource?>  getPictureWithOptions({required String path , required PictureOptions options }) => RustLib.instance.api.crateApiTagReaderGetPictureWithOptions(path: path, options: options);
+
+/// for Flutter  
+/// 设置外挂歌词的查找方式，都不区分大小写。
+/// extensions: 扩展名及优先顺序，传入空列表时恢复默认：lrc, txt, krc, qrc, ttml, srt
+/// subfolders: 音乐文件所在文件夹下的歌词文件夹，传入空列表时恢复默认：Lyrics
+/// global_folder: 全局歌词文件夹，按“歌手 - 标题”或相同文件名匹配
+void  setLyricSearchOptions({required List<String> extensions , required List<String> subfolders , String? globalFolder }) => RustLib.instance.api.crateApiTagReaderSetLyricSearchOptions(extensions: extensions, subfolders: subfolders, globalFolder: globalFolder);
+
+/// for Flutter  
+/// 列出音乐文件的所有歌词：内嵌歌词、相同文件夹、歌词子文件夹、全局歌词文件夹，
+/// 按这个顺序及 [set_lyric_search_options] 设置的扩展名顺序排列
+Future<List<LyricCandidate>>  getLyricCandidates({required String path }) => RustLib.instance.api.crateApiTagReaderGetLyricCandidates(path: path);
+
+/// for Flutter  
+/// 读取 [get_lyric_candidates] 返回的歌词的文本
+Future<String?>  readLyricCandidate({required String path , required LyricCandidate candidate }) => RustLib.instance.api.crateApiTagReaderReadLyricCandidate(path: path, candidate: candidate);
 
 /// for Flutter
+/// 读取外挂的 .krc（酷狗）, .qrc（QQ 音乐）歌词并解析为逐字歌词，查找方式和 [get_lyric_candidates] 相同
+Future<Lyric?>  getEncryptedLyricFromPath({required String path }) => RustLib.instance.api.crateApiTagReaderGetEncryptedLyricFromPath(path: path);
+
+/// for Flutter   
 /// 只支持读取 ID3V2, VorbisComment, Mp4Ilst 存储的内嵌歌词
-/// 以及相同目录相同文件名的 .lrc 外挂歌词（utf-8 or utf-16）
-Future<String?> getLyricFromPath({required String path}) =>
-    RustLib.instance.api.crateApiTagReaderGetLyricFromPath(path: path);
+/// 以及 .lrc, .txt 外挂歌词（查找方式和 [get_lyric_candidates] 相同；
+/// 自动识别 UTF-8, UTF-16, GB18030, Big5, Shift-JIS, EUC-KR）
+Future<String?>  getLyricFromPath({required String path }) => RustLib.instance.api.crateApiTagReaderGetLyricFromPath(path: path);
 
 /// for Flutter
 /// 写入歌词到音频文件标签（ID3/VorbisComment/MP4 等），使用 Lofty 的 `ItemKey::Lyrics` 映射
-Future<void> writeLyricToPath({required String path, required String lyric}) =>
-    RustLib.instance.api
-        .crateApiTagReaderWriteLyricToPath(path: path, lyric: lyric);
+Future<void>  writeLyricToPath({required String path , required String lyric }) => RustLib.instance.api.crateApiTagReaderWriteLyricToPath(path: path, lyric: lyric);
 
 /// for Flutter
-/// 扫描给定路径下所有子文件夹（包括自己）的音乐文件并把索引保存在 index_path/index.json。
-Stream<IndexActionState> buildIndexFromFoldersRecursively(
-        {required List<String> folders, required String indexPath}) =>
-    RustLib.instance.api.crateApiTagReaderBuildIndexFromFoldersRecursively(
-        folders: folders, indexPath: indexPath);
+/// 列出标签中的所有歌词：ID3v2 的所有 USLT, SYLT 帧，
+/// VorbisComment 的所有 LYRICS, UNSYNCEDLYRICS，其他标签的歌词项
+Future<List<EmbeddedLyric>>  getEmbeddedLyricsFromPath({required String path }) => RustLib.instance.api.crateApiTagReaderGetEmbeddedLyricsFromPath(path: path);
 
 /// for Flutter
-/// 读取 index_path/index.json，检查更新。不可能重新读取被修改的文件夹下所有的音乐标签，这样太耗时。
+/// 用 lyrics 替换标签中的所有歌词。
+/// ID3v2：Unsynced 写为 USLT，Synced（LRC 文本）写为毫秒时间的 SYLT，每一项一个帧；
+/// VorbisComment：Synced 写为 LYRICS，Unsynced 写为 UNSYNCEDLYRICS，不支持语言和描述；
+/// 其他标签全部写为歌词项
+Future<void>  writeLyricsToPath({required String path , required List<EmbeddedLyric> lyrics }) => RustLib.instance.api.crateApiTagReaderWriteLyricsToPath(path: path, lyrics: lyrics);
+
+/// for Flutter  
+/// 扫描给定路径下所有子文件夹（包括自己）的音乐文件并把索引保存在 index_path/index.json。
+Stream<IndexActionState>  buildIndexFromFoldersRecursively({required List<String> folders , required String indexPath }) => RustLib.instance.api.crateApiTagReaderBuildIndexFromFoldersRecursively(folders: folders, indexPath: indexPath);
+
+/// for Flutter   
+/// 读取 index_path/index.json，检查更新。不可能重新读取被修改的文件夹下所有的音乐标签，这样太耗时。  
 ///
 /// [LOWEST_VERSION] 指定可以继承的 index 的最低版本。
 /// 如果 index version < [LOWEST_VERSION] 或者是 index 根本没有 version 再或者格式不符合要求，就转到
 /// [_update_index_below_1_1_0] 更新 index；
 /// 如果 index version >= [LOWEST_VERSION] 则进行更新。
 ///
-/// 如果文件夹不存在，删除记录。
+/// 如果文件夹不存在，删除记录。  
 /// 如果文件夹被修改（再次读取到的 modified > 记录的 modified），就更新它。没有则跳过它
 /// 1. 遍历该文件夹索引，判断文件是否存在，不存在则删除记录
 /// 2. 遍历该文件夹索引，如果文件被修改（再次读取到的 modified > 记录的 modified），重新读取标签；没有则跳过它
 /// 3. 遍历该文件夹，添加新增（读取到的 created > 记录的 latest）的音乐文件
-Stream<IndexActionState> updateIndex({required String indexPath}) =>
-    RustLib.instance.api.crateApiTagReaderUpdateIndex(indexPath: indexPath);
-
-class IndexActionState {
-  /// completed / total
-  final double progress;
-
-  /// describe action state
-  final String message;
-
-  const IndexActionState({
-    required this.progress,
-    required this.message,
-  });
-
-  @override
-  int get hashCode => progress.hashCode ^ message.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is IndexActionState &&
-          runtimeType == other.runtimeType &&
-          progress == other.progress &&
-          message == other.message;
-}
+Stream<IndexActionState>  updateIndex({required String indexPath }) => RustLib.instance.api.crateApiTagReaderUpdateIndex(indexPath: indexPath);
+
+            /// 内嵌在标签中的一份歌词
+class EmbeddedLyric  {
+                final EmbeddedLyricKind kind;
+/// ISO 639-2 语言代码，如 eng, chi, jpn。只有 ID3v2 支持，未知时为 None
+final String? language;
+/// ID3v2 帧的描述，用于区分同一语言的多份歌词（如原文和翻译）
+final String description;
+/// Synced 时为 LRC 文本
+final String text;
+
+                const EmbeddedLyric({required this.kind ,this.language ,required this.description ,required this.text ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => kind.hashCode^language.hashCode^description.hashCode^text.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EmbeddedLyric &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& language == other.language&& description == other.description&& text == other.text;
+        
+            }
+
+enum EmbeddedLyricKind {
+                    /// ID3v2 USLT, VorbisComment UNSYNCEDLYRICS, 以及没有时间的 LYRICS, MP4 ©lyr
+unsynced,
+/// ID3v2 SYLT, 以及 LRC 格式的 LYRICS, MP4 ©lyr
+synced,
+                    ;
+                    
+                }
+
+class IndexActionState  {
+                /// completed / total
+final double progress;
+/// describe action state
+final String message;
+
+                const IndexActionState({required this.progress ,required this.message ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => progress.hashCode^message.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is IndexActionState &&
+                runtimeType == other.runtimeType
+                && progress == other.progress&& message == other.message;
+        
+            }
+
+class LyricCandidate  {
+                final LyricCandidateSource source;
+/// 内嵌歌词为 None
+final String? path;
+/// 小写的扩展名，内嵌歌词为 None
+final String? extension_;
+
+                const LyricCandidate({required this.source ,this.path ,this.extension_ ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => source.hashCode^path.hashCode^extension_.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LyricCandidate &&
+                runtimeType == other.runtimeType
+                && source == other.source&& path == other.path&& extension_ == other.extension_;
+        
+            }
+
+/// 歌词的来源
+enum LyricCandidateSource {
+                    /// 音乐文件的内嵌歌词
+embedded,
+/// 相同文件夹、相同文件名的外挂歌词
+sameFolder,
+/// 歌词子文件夹（如 Lyrics/）中相同文件名的外挂歌词
+subfolder,
+/// 全局歌词文件夹中“歌手 - 标题”或相同文件名的外挂歌词
+globalFolder,
+                    ;
+                    
+                }
+
+/// 缩放时使用的采样方式，从快到慢
+enum PictureFilter {
+                    nearest,
+triangle,
+catmullRom,
+gaussian,
+lanczos3,
+                    ;
+                    
+                }
+
+/// 图片和目标尺寸比例不同时的处理方式
+enum PictureFit {
+                    /// 完整显示在 width x height 内，保持原比例
+contain,
+/// 填满 width x height，居中裁掉多余的部分
+cover,
+/// 居中裁剪为正方形，边长为 min(width, height)
+cropSquare,
+                    ;
+                    
+                }
+
+/// 缩略图的编码格式
+enum PictureFormat {
+                    png,
+jpeg,
+webP,
+                    ;
+                    
+                }
+
+class PictureOptions  {
+                /// 逻辑像素
+final int width;
+/// 逻辑像素
+final int height;
+/// 实际输出的像素为 width * device_pixel_ratio
+final double devicePixelRatio;
+final PictureFormat format;
+/// 1~100，只对 Jpeg 和 WebP 生效
+final int quality;
+final PictureFilter filter;
+final PictureFit fit;
+
+                const PictureOptions({required this.width ,required this.height ,required this.devicePixelRatio ,required this.format ,required this.quality ,required this.filter ,required this.fit ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => width.hashCode^height.hashCode^devicePixelRatio.hashCode^format.hashCode^quality.hashCode^filter.hashCode^fit.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PictureOptions &&
+                runtimeType == other.runtimeType
+                && width == other.width&& height == other.height&& devicePixelRatio == other.devicePixelRatio&& format == other.format&& quality == other.quality&& filter == other.filter&& fit == other.fit;
+        
+            }
+
+@freezed
+                sealed class PictureSource with _$PictureSource  {
+                    const PictureSource._();
+
+                     /// 音乐文件的内嵌图片
+const factory PictureSource.embedded() = PictureSource_Embedded;
+ /// 同一文件夹下的外置封面
+const factory PictureSource.folder({   required String path , }) = PictureSource_Folder;
+ /// 系统生成的缩略图（Windows 缩略图或 freedesktop 缩略图缓存）
+const factory PictureSource.system() = PictureSource_System;
+
+                    
+
+                    
+                }
+
+class PictureWithSource  {
+                final Uint8List data;
+final PictureSource source;
+
+                const PictureWithSource({required this.data ,required this.source ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => data.hashCode^source.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PictureWithSource &&
+                runtimeType == other.runtimeType
+                && data == other.data&& source == other.source;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,154 @@
+// dart format width=80
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'tag_reader.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$PictureSource {
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is PictureSource);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'PictureSource()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class PictureSource_Embedded extends PictureSource {
+  const PictureSource_Embedded(): super._();
+  
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is PictureSource_Embedded);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'PictureSource.embedded()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class PictureSource_Folder extends PictureSource {
+  const PictureSource_Folder({required this.path}): super._();
+  
+
+ final  String path;
+
+/// Create a copy of PictureSource
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$PictureSource_FolderCopyWith<PictureSource_Folder> get copyWith => _$PictureSource_FolderCopyWithImpl<PictureSource_Folder>(this, _$identity);
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is PictureSource_Folder&&(identical(other.path, path) || other.path == path));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,path);
+
+@override
+String toString() {
+  return 'PictureSource.folder(path: $path)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $PictureSource_FolderCopyWith<$Res>  {
+  factory $PictureSource_FolderCopyWith(PictureSource_Folder value, $Res Function(PictureSource_Folder) _then) = _$PictureSource_FolderCopyWithImpl;
+@useResult
+$Res call({
+ String path
+});
+
+
+
+
+}
+/// @nodoc
+class _$PictureSource_FolderCopyWithImpl<$Res>
+    implements $PictureSource_FolderCopyWith<$Res> {
+  _$PictureSource_FolderCopyWithImpl(this._self, this._then);
+
+  final PictureSource_Folder _self;
+  final $Res Function(PictureSource_Folder) _then;
+
+/// Create a copy of PictureSource
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? path = null,}) {
+  return _then(PictureSource_Folder(
+path: null == path ? _self.path : path // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class PictureSource_System extends PictureSource {
+  const PictureSource_System(): super._();
+  
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is PictureSource_System);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'PictureSource.system()';
+}
+
+
+}
+
+// dart format on
@@ -0,0 +1,48 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `_contains_kanji`, `_is_kana`, `_to_ascii_punctuation`, `_to_pinyin`, `_to_romaji`, `contains_kana`, `needs_romanization`, `resolve_romanization`, `to_zhconv_variant`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`
+
+
+            /// for Flutter
+String  convertChinese({required String text , required ChineseVariant variant }) => RustLib.instance.api.crateApiTextConvertConvertChinese(text: text, variant: variant);
+
+/// for Flutter
+/// 不是汉字和假名的文字保持不变。罗马字时没有读音词典，含有汉字的文本原样返回，
+/// 否则会得到罗马字和汉字混在一起的结果
+String  romanize({required String text , required RomanizationKind kind }) => RustLib.instance.api.crateApiTextConvertRomanize(text: text, kind: kind);
+
+            /// 简繁转换的目标。字表和词表（MediaWiki, OpenCC）编译在程序中
+enum ChineseVariant {
+                    /// 简体（不转换地区用词）
+simplified,
+/// 大陆简体，包括地区用词
+mainland,
+/// 繁体（不转换地区用词）
+traditional,
+/// 台湾正体，包括地区用词
+taiwan,
+/// 香港繁体，包括地区用词
+hongKong,
+                    ;
+                    
+                }
+
+enum RomanizationKind {
+                    /// 有假名时为罗马字，否则为拼音
+auto,
+/// 带声调的拼音
+pinyin,
+/// 罗马字。汉字的读音来自 IPADIC 词典，没有词典时含有汉字的文本不转换
+romaji,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,58 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `_char_score`, `_decode_with`, `_detect_bomless_utf16`, `_is_in_rows`, `_score`, `decode_bytes`, `encoding`, `repair_latin1`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`
+
+
+            /// for Flutter
+/// 识别文本编码（UTF-8, UTF-16 LE/BE, GB18030, Big5, Shift-JIS, EUC-KR）并解码
+Future<DecodedText>  decodeText({required List<int> bytes }) => RustLib.instance.api.crateApiTextEncodingDecodeText(bytes: bytes);
+
+            class DecodedText  {
+                final String text;
+final TextEncoding encoding;
+final bool hasBom;
+/// 0.0 ~ 1.0。BOM 和合法的多字节 UTF-8 接近 1.0
+final double confidence;
+
+                const DecodedText({required this.text ,required this.encoding ,required this.hasBom ,required this.confidence ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => text.hashCode^encoding.hashCode^hasBom.hashCode^confidence.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DecodedText &&
+                runtimeType == other.runtimeType
+                && text == other.text&& encoding == other.encoding&& hasBom == other.hasBom&& confidence == other.confidence;
+        
+            }
+
+enum TextEncoding {
+                    utf8,
+utf16Le,
+utf16Be,
+gb18030,
+big5,
+shiftJis,
+eucKr,
+/// 无法识别时的回退，Latin-1 的超集
+windows1252,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -3,1764 +3,4659 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/album_palette.dart';
+import 'api/embedded_picture.dart';
 import 'api/installed_font.dart';
 import 'api/library_db.dart';
 import 'api/logger.dart';
+import 'api/lyric.dart';
+import 'api/music_matcher.dart';
+import 'api/picture_cache.dart';
 import 'api/smtc_flutter.dart';
 import 'api/system_theme.dart';
 import 'api/system_volume.dart';
 import 'api/tag_reader.dart';
+import 'api/text_convert.dart';
+import 'api/text_encoding.dart';
 import 'api/utils.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
-import 'frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
-
-  RustLib._();
-
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-    bool forceSameCodegenVersion = true,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-      forceSameCodegenVersion: forceSameCodegenVersion,
-    );
-  }
 
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({
-    required RustLibApi api,
-  }) {
-    instance.initMockImpl(
-      api: api,
-    );
-  }
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
 
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
+                  RustLib._();
 
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                    bool forceSameCodegenVersion = true,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                      forceSameCodegenVersion: forceSameCodegenVersion,
+                    );
+                  }
 
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
 
-  @override
-  Future<void> executeRustInitializers() async {}
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
 
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
 
-  @override
-  String get codegenVersion => '2.11.1';
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
 
-  @override
-  int get rustContentHash => 1846291561;
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    
+                  }
 
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-    stem: 'rust_lib_coriander_player',
-    ioDirectory: 'rust/target/release/',
-    webPrefix: 'pkg/',
-  );
-}
-
-abstract class RustLibApi extends BaseApi {
-  Future<void> crateApiSmtcFlutterSmtcFlutterClose({required SmtcFlutter that});
-
-  SmtcFlutter crateApiSmtcFlutterSmtcFlutterNew();
-
-  Stream<SMTCControlEvent>
-      crateApiSmtcFlutterSmtcFlutterSubscribeToControlEvents(
-          {required SmtcFlutter that});
-
-  Future<void> crateApiSmtcFlutterSmtcFlutterUpdateDisplay(
-      {required SmtcFlutter that,
-      required String title,
-      required String artist,
-      required String album,
-      required int duration,
-      required String path});
-
-  Future<void> crateApiSmtcFlutterSmtcFlutterUpdateState(
-      {required SmtcFlutter that, required SMTCState state});
-
-  Future<void> crateApiSmtcFlutterSmtcFlutterUpdateTimeProperties(
-      {required SmtcFlutter that, required int progress});
-
-  Stream<IndexActionState> crateApiTagReaderBuildIndexFromFoldersRecursively(
-      {required List<String> folders, required String indexPath});
-
-  Future<List<InstalledFont>?> crateApiInstalledFontGetInstalledFonts();
-
-  Future<String?> crateApiTagReaderGetLyricFromPath({required String path});
-
-  Future<Uint8List?> crateApiTagReaderGetPictureFromPath(
-      {required String path, required int width, required int height});
-
-  Stream<String> crateApiLoggerInitRustLogger();
-
-  Future<bool> crateApiUtilsLaunchInBrowser({required String uri});
-
-  Future<void> crateApiLibraryDbMigrateIndexJsonToSqlite(
-      {required String indexPath});
-
-  Future<String?> crateApiUtilsPickSingleFolder();
-
-  Future<String> crateApiTagReaderReadAudioExtraMetadata(
-      {required String path});
-
-  Future<List<IndexFolder>> crateApiLibraryDbReadIndexFromSqlite(
-      {required String indexPath});
-
-  Future<bool> crateApiUtilsShowInExplorer({required String path});
-
-  SystemTheme crateApiSystemThemeSystemThemeGetSystemTheme();
-
-  void crateApiSystemVolumeSystemVolumeDispose();
-
-  double crateApiSystemVolumeSystemVolumeGet();
-
-  Stream<double> crateApiSystemVolumeSystemVolumeInit();
-
-  void crateApiSystemVolumeSystemVolumeSet({required double val});
-
-  Stream<IndexActionState> crateApiTagReaderUpdateIndex(
-      {required String indexPath});
-
-  Future<void> crateApiTagReaderWriteLyricToPath(
-      {required String path, required String lyric});
-
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_SmtcFlutter;
-
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_SmtcFlutter;
-
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SmtcFlutterPtr;
-}
-
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  @override
-  Future<void> crateApiSmtcFlutterSmtcFlutterClose(
-      {required SmtcFlutter that}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-            that, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 1, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiSmtcFlutterSmtcFlutterCloseConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiSmtcFlutterSmtcFlutterCloseConstMeta =>
-      const TaskConstMeta(
-        debugName: "SmtcFlutter_close",
-        argNames: ["that"],
-      );
-
-  @override
-  SmtcFlutter crateApiSmtcFlutterSmtcFlutterNew() {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiSmtcFlutterSmtcFlutterNewConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiSmtcFlutterSmtcFlutterNewConstMeta =>
-      const TaskConstMeta(
-        debugName: "SmtcFlutter_new",
-        argNames: [],
-      );
-
-  @override
-  Stream<SMTCControlEvent>
-      crateApiSmtcFlutterSmtcFlutterSubscribeToControlEvents(
-          {required SmtcFlutter that}) {
-    final sink = RustStreamSink<SMTCControlEvent>();
-    unawaited(handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-            that, serializer);
-        sse_encode_StreamSink_smtc_control_event_Sse(sink, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 3, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta:
-          kCrateApiSmtcFlutterSmtcFlutterSubscribeToControlEventsConstMeta,
-      argValues: [that, sink],
-      apiImpl: this,
-    )));
-    return sink.stream;
-  }
-
-  TaskConstMeta
-      get kCrateApiSmtcFlutterSmtcFlutterSubscribeToControlEventsConstMeta =>
-          const TaskConstMeta(
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
+
+                  @override
+                  String get codegenVersion => '2.11.1';
+
+                  @override
+                  int get rustContentHash => -1684905261;
+
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'rust_lib_coriander_player',
+                    ioDirectory: 'rust/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
+
+                abstract class RustLibApi extends BaseApi {
+                  Future<void> crateApiSmtcFlutterSmtcFlutterClose({required SmtcFlutter that });
+
+SmtcFlutter crateApiSmtcFlutterSmtcFlutterNew();
+
+Future<void> crateApiSmtcFlutterSmtcFlutterReinitialize({required SmtcFlutter that });
+
+SMTCStatus crateApiSmtcFlutterSmtcFlutterStatus({required SmtcFlutter that });
+
+Stream<SMTCControlEvent> crateApiSmtcFlutterSmtcFlutterSubscribeToControlEvents({required SmtcFlutter that });
+
+SmtcFlutter crateApiSmtcFlutterSmtcFlutterTryNew();
+
+Future<void> crateApiSmtcFlutterSmtcFlutterUpdateDisplay({required SmtcFlutter that , required String title , required String artist , required String album , required int duration , required String path });
+
+Future<void> crateApiSmtcFlutterSmtcFlutterUpdateMetadata({required SmtcFlutter that , required SMTCMetadata metadata , required SMTCArtwork artwork });
+
+Future<void> crateApiSmtcFlutterSmtcFlutterUpdatePlaybackRate({required SmtcFlutter that , required double rate });
+
+Future<void> crateApiSmtcFlutterSmtcFlutterUpdateRepeatMode({required SmtcFlutter that , required SMTCRepeatMode mode });
+
+Future<void> crateApiSmtcFlutterSmtcFlutterUpdateShuffle({required SmtcFlutter that , required bool enabled });
+
+Future<void> crateApiSmtcFlutterSmtcFlutterUpdateState({required SmtcFlutter that , required SMTCState state });
+
+Future<void> crateApiSmtcFlutterSmtcFlutterUpdateTimeProperties({required SmtcFlutter that , required int progress });
+
+Stream<IndexActionState> crateApiTagReaderBuildIndexFromFoldersRecursively({required List<String> folders , required String indexPath });
+
+void crateApiPictureCacheCancelPictureCachePrewarm();
+
+Future<void> crateApiPictureCacheClearPictureCache();
+
+String crateApiTextConvertConvertChinese({required String text , required ChineseVariant variant });
+
+Future<DecodedText> crateApiTextEncodingDecodeText({required List<int> bytes });
+
+Future<String> crateApiLyricDecryptKrc({required List<int> data });
+
+Future<String> crateApiLyricDecryptQrc({required List<int> data });
+
+Future<String> crateApiEmbeddedPictureExtractEmbeddedPicture({required String path , required int index , required String outputPath });
+
+Future<AlbumPalette?> crateApiAlbumPaletteExtractPalette({required String indexPath , required String path });
+
+Future<List<List<String>>> crateApiMusicMatcherFindDuplicateAudios({required String indexPath , required double threshold });
+
+Future<List<Uint32List>> crateApiMusicMatcherFindDuplicates({required List<MatchTrack> tracks , required double threshold });
+
+Future<List<CachedLyric>> crateApiLibraryDbGetCachedLyrics({required String indexPath , required String path });
+
+Future<IndexCueTrack?> crateApiLibraryDbGetCueTrack({required String indexPath , required String path });
+
+Future<List<EmbeddedLyric>> crateApiTagReaderGetEmbeddedLyricsFromPath({required String path });
+
+Future<Lyric?> crateApiTagReaderGetEncryptedLyricFromPath({required String path });
+
+Future<List<InstalledFont>?> crateApiInstalledFontGetInstalledFonts();
+
+Future<List<LyricCandidate>> crateApiTagReaderGetLyricCandidates({required String path });
+
+Future<String?> crateApiTagReaderGetLyricFromPath({required String path });
+
+Future<PlatformInt64> crateApiLibraryDbGetLyricOffset({required String indexPath , required String path });
+
+Future<Lyric?> crateApiLyricGetParsedLyricForPlayback({required String indexPath , required String path });
+
+Future<Lyric?> crateApiLyricGetParsedLyricFromPath({required String path });
+
+Future<Uint8List?> crateApiTagReaderGetPictureFromPath({required String path , required int width , required int height });
+
+Future<PictureWithSource?> crateApiTagReaderGetPictureWithOptions({required String path , required PictureOptions options });
+
+Future<PictureWithSource?> crateApiTagReaderGetPictureWithSourceFromPath({required String path , required int width , required int height });
+
+Future<CachedLyric?> crateApiLibraryDbGetPreferredLyric({required String indexPath , required String path });
+
+void crateApiPictureCacheInitPictureCache({required String indexPath , required BigInt byteBudget });
+
+Stream<String> crateApiLoggerInitRustLogger();
+
+Future<bool> crateApiUtilsLaunchInBrowser({required String uri });
+
+Future<List<EmbeddedPicture>> crateApiEmbeddedPictureListEmbeddedPictures({required String path });
+
+Lyric crateApiLyricLyricShifted({required Lyric that , required PlatformInt64 offset });
+
+Lyric? crateApiLyricLyricStretched({required Lyric that , required int fromA , required int toA , required int fromB , required int toB });
+
+String? crateApiLyricLyricTag({required Lyric that , required String key });
+
+String crateApiLyricLyricToLrc({required Lyric that });
+
+String crateApiLyricLyricToTtml({required Lyric that });
+
+Lyric crateApiLyricLyricWithChineseVariant({required Lyric that , required ChineseVariant variant });
+
+Lyric crateApiLyricLyricWithRomanization({required Lyric that , required RomanizationKind kind });
+
+Lyric crateApiLyricLyricWithTranslation({required Lyric that , required Lyric translation , required int tolerance });
+
+Future<void> crateApiLibraryDbMigrateIndexJsonToSqlite({required String indexPath });
+
+List<String> crateApiMusicMatcherNormalizeArtists({required String artist });
+
+String crateApiMusicMatcherNormalizeTitle({required String title });
+
+Future<void> crateApiSystemVolumeOutputDeviceEventsDispose();
+
+Stream<OutputDeviceEvent> crateApiSystemVolumeOutputDeviceEventsInit();
+
+Future<List<OutputDevice>> crateApiSystemVolumeOutputDeviceList();
+
+Future<bool> crateApiSystemVolumeOutputDeviceMuteGet({required String id });
+
+Future<void> crateApiSystemVolumeOutputDeviceMuteSet({required String id , required bool mute });
+
+Future<double> crateApiSystemVolumeOutputDeviceVolumeGet({required String id });
+
+Future<void> crateApiSystemVolumeOutputDeviceVolumeSet({required String id , required double val });
+
+Future<Lyric?> crateApiLyricParseKrc({required String text });
+
+Future<Lyric?> crateApiLyricParseLrc({required String text });
+
+Future<Lyric?> crateApiLyricParseLyric({required String text });
+
+Future<Lyric?> crateApiLyricParseLyricCandidate({required String path , required LyricCandidate candidate });
+
+Future<Lyric?> crateApiLyricParseQrc({required String text , String? translation });
+
+Future<Lyric?> crateApiLyricParseSrt({required String text });
+
+Future<Lyric?> crateApiLyricParseTtml({required String text });
+
+Future<String?> crateApiUtilsPickSingleFolder();
+
+void crateApiPictureCachePrewarmPictureCache({required String indexPath , required int width , required int height });
+
+List<RankedMatch> crateApiMusicMatcherRankMatches({required MatchTrack query , required List<MatchTrack> candidates });
+
+Future<String> crateApiTagReaderReadAudioExtraMetadata({required String path });
+
+Future<List<IndexFolder>> crateApiLibraryDbReadIndexFromSqlite({required String indexPath });
+
+Future<String?> crateApiTagReaderReadLyricCandidate({required String path , required LyricCandidate candidate });
+
+Future<void> crateApiLibraryDbRemoveCachedLyric({required String indexPath , required String path , required String hash });
+
+Future<int> crateApiEmbeddedPictureRemoveEmbeddedPictures({required String path , EmbeddedPictureType? pictureType });
+
+String crateApiTextConvertRomanize({required String text , required RomanizationKind kind });
+
+Future<CachedLyric> crateApiLibraryDbSaveLyric({required String indexPath , required String path , required LyricSource source , String? provider , required String raw , required bool preferred });
+
+MatchScore crateApiMusicMatcherScoreMatch({required MatchTrack query , required MatchTrack candidate });
+
+Future<void> crateApiLibraryDbSetCachedLyricOffset({required String indexPath , required String path , required String hash , required PlatformInt64 offset });
+
+void crateApiTagReaderSetFolderCoverNames({required List<String> names });
+
+Future<void> crateApiEmbeddedPictureSetFrontCover({required String path , required List<int> data , int? maxSize , int? jpegQuality });
+
+Future<void> crateApiLibraryDbSetLyricOffset({required String indexPath , required String path , required PlatformInt64 offset });
+
+void crateApiTagReaderSetLyricSearchOptions({required List<String> extensions , required List<String> subfolders , String? globalFolder });
+
+Future<void> crateApiLibraryDbSetPreferredLyric({required String indexPath , required String path , required String hash });
+
+Future<bool> crateApiUtilsShowInExplorer({required String path });
+
+SystemTheme crateApiSystemThemeSystemThemeGetSystemTheme();
+
+Future<void> crateApiSystemVolumeSystemVolumeDispose();
+
+Future<double> crateApiSystemVolumeSystemVolumeGet();
+
+Stream<double> crateApiSystemVolumeSystemVolumeInit();
+
+Future<void> crateApiSystemVolumeSystemVolumeSet({required double val });
+
+Stream<IndexActionState> crateApiTagReaderUpdateIndex({required String indexPath });
+
+Future<void> crateApiTagReaderWriteLyricToPath({required String path , required String lyric });
+
+Future<void> crateApiTagReaderWriteLyricsToPath({required String path , required List<EmbeddedLyric> lyrics });
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_SmtcFlutter;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_SmtcFlutter;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SmtcFlutterPtr;
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override Future<void> crateApiSmtcFlutterSmtcFlutterClose({required SmtcFlutter that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSmtcFlutterSmtcFlutterCloseConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSmtcFlutterSmtcFlutterCloseConstMeta => const TaskConstMeta(
+            debugName: "SmtcFlutter_close",
+            argNames: ["that"],
+        );
+        
+
+@override SmtcFlutter crateApiSmtcFlutterSmtcFlutterNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSmtcFlutterSmtcFlutterNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSmtcFlutterSmtcFlutterNewConstMeta => const TaskConstMeta(
+            debugName: "SmtcFlutter_new",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiSmtcFlutterSmtcFlutterReinitialize({required SmtcFlutter that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_smtc_error,
+        )
+        ,
+            constMeta: kCrateApiSmtcFlutterSmtcFlutterReinitializeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSmtcFlutterSmtcFlutterReinitializeConstMeta => const TaskConstMeta(
+            debugName: "SmtcFlutter_reinitialize",
+            argNames: ["that"],
+        );
+        
+
+@override SMTCStatus crateApiSmtcFlutterSmtcFlutterStatus({required SmtcFlutter that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_smtc_status,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSmtcFlutterSmtcFlutterStatusConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSmtcFlutterSmtcFlutterStatusConstMeta => const TaskConstMeta(
+            debugName: "SmtcFlutter_status",
+            argNames: ["that"],
+        );
+        
+
+@override Stream<SMTCControlEvent> crateApiSmtcFlutterSmtcFlutterSubscribeToControlEvents({required SmtcFlutter that })  { 
+            final sink = RustStreamSink<SMTCControlEvent>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(that, serializer);
+sse_encode_StreamSink_smtc_control_event_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSmtcFlutterSmtcFlutterSubscribeToControlEventsConstMeta,
+            argValues: [that, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiSmtcFlutterSmtcFlutterSubscribeToControlEventsConstMeta => const TaskConstMeta(
             debugName: "SmtcFlutter_subscribe_to_control_events",
             argNames: ["that", "sink"],
-          );
-
-  @override
-  Future<void> crateApiSmtcFlutterSmtcFlutterUpdateDisplay(
-      {required SmtcFlutter that,
-      required String title,
-      required String artist,
-      required String album,
-      required int duration,
-      required String path}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-            that, serializer);
-        sse_encode_String(title, serializer);
-        sse_encode_String(artist, serializer);
-        sse_encode_String(album, serializer);
-        sse_encode_u_32(duration, serializer);
-        sse_encode_String(path, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 4, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiSmtcFlutterSmtcFlutterUpdateDisplayConstMeta,
-      argValues: [that, title, artist, album, duration, path],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiSmtcFlutterSmtcFlutterUpdateDisplayConstMeta =>
-      const TaskConstMeta(
-        debugName: "SmtcFlutter_update_display",
-        argNames: ["that", "title", "artist", "album", "duration", "path"],
-      );
-
-  @override
-  Future<void> crateApiSmtcFlutterSmtcFlutterUpdateState(
-      {required SmtcFlutter that, required SMTCState state}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-            that, serializer);
-        sse_encode_smtc_state(state, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 5, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiSmtcFlutterSmtcFlutterUpdateStateConstMeta,
-      argValues: [that, state],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiSmtcFlutterSmtcFlutterUpdateStateConstMeta =>
-      const TaskConstMeta(
-        debugName: "SmtcFlutter_update_state",
-        argNames: ["that", "state"],
-      );
-
-  @override
-  Future<void> crateApiSmtcFlutterSmtcFlutterUpdateTimeProperties(
-      {required SmtcFlutter that, required int progress}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-            that, serializer);
-        sse_encode_u_32(progress, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 6, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiSmtcFlutterSmtcFlutterUpdateTimePropertiesConstMeta,
-      argValues: [that, progress],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiSmtcFlutterSmtcFlutterUpdateTimePropertiesConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override SmtcFlutter crateApiSmtcFlutterSmtcFlutterTryNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter,
+          decodeErrorData: sse_decode_smtc_error,
+        )
+        ,
+            constMeta: kCrateApiSmtcFlutterSmtcFlutterTryNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSmtcFlutterSmtcFlutterTryNewConstMeta => const TaskConstMeta(
+            debugName: "SmtcFlutter_try_new",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiSmtcFlutterSmtcFlutterUpdateDisplay({required SmtcFlutter that , required String title , required String artist , required String album , required int duration , required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(that, serializer);
+sse_encode_String(title, serializer);
+sse_encode_String(artist, serializer);
+sse_encode_String(album, serializer);
+sse_encode_u_32(duration, serializer);
+sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSmtcFlutterSmtcFlutterUpdateDisplayConstMeta,
+            argValues: [that, title, artist, album, duration, path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSmtcFlutterSmtcFlutterUpdateDisplayConstMeta => const TaskConstMeta(
+            debugName: "SmtcFlutter_update_display",
+            argNames: ["that", "title", "artist", "album", "duration", "path"],
+        );
+        
+
+@override Future<void> crateApiSmtcFlutterSmtcFlutterUpdateMetadata({required SmtcFlutter that , required SMTCMetadata metadata , required SMTCArtwork artwork })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(that, serializer);
+sse_encode_box_autoadd_smtc_metadata(metadata, serializer);
+sse_encode_box_autoadd_smtc_artwork(artwork, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSmtcFlutterSmtcFlutterUpdateMetadataConstMeta,
+            argValues: [that, metadata, artwork],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSmtcFlutterSmtcFlutterUpdateMetadataConstMeta => const TaskConstMeta(
+            debugName: "SmtcFlutter_update_metadata",
+            argNames: ["that", "metadata", "artwork"],
+        );
+        
+
+@override Future<void> crateApiSmtcFlutterSmtcFlutterUpdatePlaybackRate({required SmtcFlutter that , required double rate })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(that, serializer);
+sse_encode_f_64(rate, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSmtcFlutterSmtcFlutterUpdatePlaybackRateConstMeta,
+            argValues: [that, rate],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSmtcFlutterSmtcFlutterUpdatePlaybackRateConstMeta => const TaskConstMeta(
+            debugName: "SmtcFlutter_update_playback_rate",
+            argNames: ["that", "rate"],
+        );
+        
+
+@override Future<void> crateApiSmtcFlutterSmtcFlutterUpdateRepeatMode({required SmtcFlutter that , required SMTCRepeatMode mode })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(that, serializer);
+sse_encode_smtc_repeat_mode(mode, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSmtcFlutterSmtcFlutterUpdateRepeatModeConstMeta,
+            argValues: [that, mode],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSmtcFlutterSmtcFlutterUpdateRepeatModeConstMeta => const TaskConstMeta(
+            debugName: "SmtcFlutter_update_repeat_mode",
+            argNames: ["that", "mode"],
+        );
+        
+
+@override Future<void> crateApiSmtcFlutterSmtcFlutterUpdateShuffle({required SmtcFlutter that , required bool enabled })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(that, serializer);
+sse_encode_bool(enabled, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSmtcFlutterSmtcFlutterUpdateShuffleConstMeta,
+            argValues: [that, enabled],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSmtcFlutterSmtcFlutterUpdateShuffleConstMeta => const TaskConstMeta(
+            debugName: "SmtcFlutter_update_shuffle",
+            argNames: ["that", "enabled"],
+        );
+        
+
+@override Future<void> crateApiSmtcFlutterSmtcFlutterUpdateState({required SmtcFlutter that , required SMTCState state })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(that, serializer);
+sse_encode_smtc_state(state, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSmtcFlutterSmtcFlutterUpdateStateConstMeta,
+            argValues: [that, state],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSmtcFlutterSmtcFlutterUpdateStateConstMeta => const TaskConstMeta(
+            debugName: "SmtcFlutter_update_state",
+            argNames: ["that", "state"],
+        );
+        
+
+@override Future<void> crateApiSmtcFlutterSmtcFlutterUpdateTimeProperties({required SmtcFlutter that , required int progress })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(that, serializer);
+sse_encode_u_32(progress, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSmtcFlutterSmtcFlutterUpdateTimePropertiesConstMeta,
+            argValues: [that, progress],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSmtcFlutterSmtcFlutterUpdateTimePropertiesConstMeta => const TaskConstMeta(
             debugName: "SmtcFlutter_update_time_properties",
             argNames: ["that", "progress"],
-          );
-
-  @override
-  Stream<IndexActionState> crateApiTagReaderBuildIndexFromFoldersRecursively(
-      {required List<String> folders, required String indexPath}) {
-    final sink = RustStreamSink<IndexActionState>();
-    unawaited(handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_String(folders, serializer);
-        sse_encode_String(indexPath, serializer);
-        sse_encode_StreamSink_index_action_state_Sse(sink, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 7, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiTagReaderBuildIndexFromFoldersRecursivelyConstMeta,
-      argValues: [folders, indexPath, sink],
-      apiImpl: this,
-    )));
-    return sink.stream;
-  }
-
-  TaskConstMeta
-      get kCrateApiTagReaderBuildIndexFromFoldersRecursivelyConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override Stream<IndexActionState> crateApiTagReaderBuildIndexFromFoldersRecursively({required List<String> folders , required String indexPath })  { 
+            final sink = RustStreamSink<IndexActionState>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(folders, serializer);
+sse_encode_String(indexPath, serializer);
+sse_encode_StreamSink_index_action_state_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTagReaderBuildIndexFromFoldersRecursivelyConstMeta,
+            argValues: [folders, indexPath, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiTagReaderBuildIndexFromFoldersRecursivelyConstMeta => const TaskConstMeta(
             debugName: "build_index_from_folders_recursively",
             argNames: ["folders", "indexPath", "sink"],
-          );
-
-  @override
-  Future<List<InstalledFont>?> crateApiInstalledFontGetInstalledFonts() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 8, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_list_installed_font,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiInstalledFontGetInstalledFontsConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiInstalledFontGetInstalledFontsConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_installed_fonts",
-        argNames: [],
-      );
-
-  @override
-  Future<String?> crateApiTagReaderGetLyricFromPath({required String path}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(path, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 9, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiTagReaderGetLyricFromPathConstMeta,
-      argValues: [path],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiTagReaderGetLyricFromPathConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_lyric_from_path",
-        argNames: ["path"],
-      );
-
-  @override
-  Future<Uint8List?> crateApiTagReaderGetPictureFromPath(
-      {required String path, required int width, required int height}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(path, serializer);
-        sse_encode_u_32(width, serializer);
-        sse_encode_u_32(height, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 10, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_list_prim_u_8_strict,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiTagReaderGetPictureFromPathConstMeta,
-      argValues: [path, width, height],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiTagReaderGetPictureFromPathConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_picture_from_path",
-        argNames: ["path", "width", "height"],
-      );
-
-  @override
-  Stream<String> crateApiLoggerInitRustLogger() {
-    final sink = RustStreamSink<String>();
-    unawaited(handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_StreamSink_String_Sse(sink, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 11, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiLoggerInitRustLoggerConstMeta,
-      argValues: [sink],
-      apiImpl: this,
-    )));
-    return sink.stream;
-  }
-
-  TaskConstMeta get kCrateApiLoggerInitRustLoggerConstMeta =>
-      const TaskConstMeta(
-        debugName: "init_rust_logger",
-        argNames: ["sink"],
-      );
-
-  @override
-  Future<bool> crateApiUtilsLaunchInBrowser({required String uri}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(uri, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 12, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiUtilsLaunchInBrowserConstMeta,
-      argValues: [uri],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiUtilsLaunchInBrowserConstMeta =>
-      const TaskConstMeta(
-        debugName: "launch_in_browser",
-        argNames: ["uri"],
-      );
-
-  @override
-  Future<void> crateApiLibraryDbMigrateIndexJsonToSqlite(
-      {required String indexPath}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(indexPath, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 13, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiLibraryDbMigrateIndexJsonToSqliteConstMeta,
-      argValues: [indexPath],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiLibraryDbMigrateIndexJsonToSqliteConstMeta =>
-      const TaskConstMeta(
-        debugName: "migrate_index_json_to_sqlite",
-        argNames: ["indexPath"],
-      );
-
-  @override
-  Future<String?> crateApiUtilsPickSingleFolder() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 14, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiUtilsPickSingleFolderConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiUtilsPickSingleFolderConstMeta =>
-      const TaskConstMeta(
-        debugName: "pick_single_folder",
-        argNames: [],
-      );
-
-  @override
-  Future<String> crateApiTagReaderReadAudioExtraMetadata(
-      {required String path}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(path, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 15, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiTagReaderReadAudioExtraMetadataConstMeta,
-      argValues: [path],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiTagReaderReadAudioExtraMetadataConstMeta =>
-      const TaskConstMeta(
-        debugName: "read_audio_extra_metadata",
-        argNames: ["path"],
-      );
-
-  @override
-  Future<List<IndexFolder>> crateApiLibraryDbReadIndexFromSqlite(
-      {required String indexPath}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(indexPath, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 16, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_index_folder,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiLibraryDbReadIndexFromSqliteConstMeta,
-      argValues: [indexPath],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiLibraryDbReadIndexFromSqliteConstMeta =>
-      const TaskConstMeta(
-        debugName: "read_index_from_sqlite",
-        argNames: ["indexPath"],
-      );
-
-  @override
-  Future<bool> crateApiUtilsShowInExplorer({required String path}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(path, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 17, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiUtilsShowInExplorerConstMeta,
-      argValues: [path],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiUtilsShowInExplorerConstMeta =>
-      const TaskConstMeta(
-        debugName: "show_in_explorer",
-        argNames: ["path"],
-      );
-
-  @override
-  SystemTheme crateApiSystemThemeSystemThemeGetSystemTheme() {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_system_theme,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiSystemThemeSystemThemeGetSystemThemeConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiSystemThemeSystemThemeGetSystemThemeConstMeta =>
-      const TaskConstMeta(
-        debugName: "system_theme_get_system_theme",
-        argNames: [],
-      );
-
-  @override
-  void crateApiSystemVolumeSystemVolumeDispose() {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiSystemVolumeSystemVolumeDisposeConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiSystemVolumeSystemVolumeDisposeConstMeta =>
-      const TaskConstMeta(
-        debugName: "system_volume_dispose",
-        argNames: [],
-      );
-
-  @override
-  double crateApiSystemVolumeSystemVolumeGet() {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_f_64,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiSystemVolumeSystemVolumeGetConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiSystemVolumeSystemVolumeGetConstMeta =>
-      const TaskConstMeta(
-        debugName: "system_volume_get",
-        argNames: [],
-      );
-
-  @override
-  Stream<double> crateApiSystemVolumeSystemVolumeInit() {
-    final sink = RustStreamSink<double>();
-    handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_StreamSink_f_64_Sse(sink, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_f_64,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiSystemVolumeSystemVolumeInitConstMeta,
-      argValues: [sink],
-      apiImpl: this,
-    ));
-    return sink.stream;
-  }
-
-  TaskConstMeta get kCrateApiSystemVolumeSystemVolumeInitConstMeta =>
-      const TaskConstMeta(
-        debugName: "system_volume_init",
-        argNames: ["sink"],
-      );
-
-  @override
-  void crateApiSystemVolumeSystemVolumeSet({required double val}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_f_64(val, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiSystemVolumeSystemVolumeSetConstMeta,
-      argValues: [val],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiSystemVolumeSystemVolumeSetConstMeta =>
-      const TaskConstMeta(
-        debugName: "system_volume_set",
-        argNames: ["val"],
-      );
-
-  @override
-  Stream<IndexActionState> crateApiTagReaderUpdateIndex(
-      {required String indexPath}) {
-    final sink = RustStreamSink<IndexActionState>();
-    unawaited(handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(indexPath, serializer);
-        sse_encode_StreamSink_index_action_state_Sse(sink, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 23, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiTagReaderUpdateIndexConstMeta,
-      argValues: [indexPath, sink],
-      apiImpl: this,
-    )));
-    return sink.stream;
-  }
-
-  TaskConstMeta get kCrateApiTagReaderUpdateIndexConstMeta =>
-      const TaskConstMeta(
-        debugName: "update_index",
-        argNames: ["indexPath", "sink"],
-      );
-
-  @override
-  Future<void> crateApiTagReaderWriteLyricToPath(
-      {required String path, required String lyric}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(path, serializer);
-        sse_encode_String(lyric, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 24, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiTagReaderWriteLyricToPathConstMeta,
-      argValues: [path, lyric],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiTagReaderWriteLyricToPathConstMeta =>
-      const TaskConstMeta(
-        debugName: "write_lyric_to_path",
-        argNames: ["path", "lyric"],
-      );
-
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_SmtcFlutter => wire
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter;
-
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_SmtcFlutter => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter;
-
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
-  }
-
-  @protected
-  SmtcFlutter
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return SmtcFlutterImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  SmtcFlutter
-      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return SmtcFlutterImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  SmtcFlutter
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return SmtcFlutterImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
-  }
-
-  @protected
-  RustStreamSink<double> dco_decode_StreamSink_f_64_Sse(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
-  }
-
-  @protected
-  RustStreamSink<IndexActionState> dco_decode_StreamSink_index_action_state_Sse(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
-  }
-
-  @protected
-  RustStreamSink<SMTCControlEvent> dco_decode_StreamSink_smtc_control_event_Sse(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
-  }
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
-
-  @protected
-  int dco_decode_box_autoadd_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  double dco_decode_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
-  }
-
-  @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  IndexActionState dco_decode_index_action_state(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return IndexActionState(
-      progress: dco_decode_f_64(arr[0]),
-      message: dco_decode_String(arr[1]),
-    );
-  }
-
-  @protected
-  IndexAudio dco_decode_index_audio(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 12)
-      throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
-    return IndexAudio(
-      title: dco_decode_String(arr[0]),
-      artist: dco_decode_String(arr[1]),
-      album: dco_decode_String(arr[2]),
-      albumArtist: dco_decode_opt_String(arr[3]),
-      track: dco_decode_u_32(arr[4]),
-      duration: dco_decode_u_64(arr[5]),
-      bitrate: dco_decode_opt_box_autoadd_u_32(arr[6]),
-      sampleRate: dco_decode_opt_box_autoadd_u_32(arr[7]),
-      path: dco_decode_String(arr[8]),
-      modified: dco_decode_u_64(arr[9]),
-      created: dco_decode_u_64(arr[10]),
-      by: dco_decode_opt_String(arr[11]),
-    );
-  }
-
-  @protected
-  IndexFolder dco_decode_index_folder(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return IndexFolder(
-      path: dco_decode_String(arr[0]),
-      modified: dco_decode_u_64(arr[1]),
-      latest: dco_decode_u_64(arr[2]),
-      audios: dco_decode_list_index_audio(arr[3]),
-    );
-  }
-
-  @protected
-  InstalledFont dco_decode_installed_font(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return InstalledFont(
-      path: dco_decode_String(arr[0]),
-      fullName: dco_decode_String(arr[1]),
-    );
-  }
-
-  @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
-  }
-
-  @protected
-  List<IndexAudio> dco_decode_list_index_audio(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_index_audio).toList();
-  }
-
-  @protected
-  List<IndexFolder> dco_decode_list_index_folder(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_index_folder).toList();
-  }
-
-  @protected
-  List<InstalledFont> dco_decode_list_installed_font(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_installed_font).toList();
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
-  }
-
-  @protected
-  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
-  }
-
-  @protected
-  List<InstalledFont>? dco_decode_opt_list_installed_font(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_list_installed_font(raw);
-  }
-
-  @protected
-  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_list_prim_u_8_strict(raw);
-  }
-
-  @protected
-  (int, int, int, int) dco_decode_record_u_8_u_8_u_8_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4) {
-      throw Exception('Expected 4 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_u_8(arr[0]),
-      dco_decode_u_8(arr[1]),
-      dco_decode_u_8(arr[2]),
-      dco_decode_u_8(arr[3]),
-    );
-  }
-
-  @protected
-  SMTCControlEvent dco_decode_smtc_control_event(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return SMTCControlEvent.values[raw as int];
-  }
-
-  @protected
-  SMTCState dco_decode_smtc_state(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return SMTCState.values[raw as int];
-  }
-
-  @protected
-  SystemTheme dco_decode_system_theme(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return SystemTheme(
-      fore: dco_decode_record_u_8_u_8_u_8_u_8(arr[0]),
-      accent: dco_decode_record_u_8_u_8_u_8_u_8(arr[1]),
-    );
-  }
-
-  @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
-  }
-
-  @protected
-  SmtcFlutter
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return SmtcFlutterImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  SmtcFlutter
-      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return SmtcFlutterImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  SmtcFlutter
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return SmtcFlutterImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  RustStreamSink<String> sse_decode_StreamSink_String_Sse(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
-  }
-
-  @protected
-  RustStreamSink<double> sse_decode_StreamSink_f_64_Sse(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
-  }
-
-  @protected
-  RustStreamSink<IndexActionState> sse_decode_StreamSink_index_action_state_Sse(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
-  }
-
-  @protected
-  RustStreamSink<SMTCControlEvent> sse_decode_StreamSink_smtc_control_event_Sse(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_u_32(deserializer));
-  }
-
-  @protected
-  double sse_decode_f_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat64();
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  IndexActionState sse_decode_index_action_state(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_progress = sse_decode_f_64(deserializer);
-    var var_message = sse_decode_String(deserializer);
-    return IndexActionState(progress: var_progress, message: var_message);
-  }
-
-  @protected
-  IndexAudio sse_decode_index_audio(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_title = sse_decode_String(deserializer);
-    var var_artist = sse_decode_String(deserializer);
-    var var_album = sse_decode_String(deserializer);
-    var var_albumArtist = sse_decode_opt_String(deserializer);
-    var var_track = sse_decode_u_32(deserializer);
-    var var_duration = sse_decode_u_64(deserializer);
-    var var_bitrate = sse_decode_opt_box_autoadd_u_32(deserializer);
-    var var_sampleRate = sse_decode_opt_box_autoadd_u_32(deserializer);
-    var var_path = sse_decode_String(deserializer);
-    var var_modified = sse_decode_u_64(deserializer);
-    var var_created = sse_decode_u_64(deserializer);
-    var var_by = sse_decode_opt_String(deserializer);
-    return IndexAudio(
-        title: var_title,
-        artist: var_artist,
-        album: var_album,
-        albumArtist: var_albumArtist,
-        track: var_track,
-        duration: var_duration,
-        bitrate: var_bitrate,
-        sampleRate: var_sampleRate,
-        path: var_path,
-        modified: var_modified,
-        created: var_created,
-        by: var_by);
-  }
-
-  @protected
-  IndexFolder sse_decode_index_folder(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_path = sse_decode_String(deserializer);
-    var var_modified = sse_decode_u_64(deserializer);
-    var var_latest = sse_decode_u_64(deserializer);
-    var var_audios = sse_decode_list_index_audio(deserializer);
-    return IndexFolder(
-        path: var_path,
-        modified: var_modified,
-        latest: var_latest,
-        audios: var_audios);
-  }
-
-  @protected
-  InstalledFont sse_decode_installed_font(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_path = sse_decode_String(deserializer);
-    var var_fullName = sse_decode_String(deserializer);
-    return InstalledFont(path: var_path, fullName: var_fullName);
-  }
-
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<IndexAudio> sse_decode_list_index_audio(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <IndexAudio>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_index_audio(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<IndexFolder> sse_decode_list_index_folder(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <IndexFolder>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_index_folder(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<InstalledFont> sse_decode_list_installed_font(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <InstalledFont>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_installed_font(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_32(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  List<InstalledFont>? sse_decode_opt_list_installed_font(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_installed_font(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_prim_u_8_strict(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  (int, int, int, int) sse_decode_record_u_8_u_8_u_8_u_8(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_u_8(deserializer);
-    var var_field1 = sse_decode_u_8(deserializer);
-    var var_field2 = sse_decode_u_8(deserializer);
-    var var_field3 = sse_decode_u_8(deserializer);
-    return (var_field0, var_field1, var_field2, var_field3);
-  }
-
-  @protected
-  SMTCControlEvent sse_decode_smtc_control_event(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return SMTCControlEvent.values[inner];
-  }
-
-  @protected
-  SMTCState sse_decode_smtc_state(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return SMTCState.values[inner];
-  }
-
-  @protected
-  SystemTheme sse_decode_system_theme(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_fore = sse_decode_record_u_8_u_8_u_8_u_8(deserializer);
-    var var_accent = sse_decode_record_u_8_u_8_u_8_u_8(deserializer);
-    return SystemTheme(fore: var_fore, accent: var_accent);
-  }
-
-  @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
-  }
-
-  @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  void sse_encode_AnyhowException(
-      AnyhowException self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-          SmtcFlutter self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as SmtcFlutterImpl).frbInternalSseEncode(move: true), serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-          SmtcFlutter self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as SmtcFlutterImpl).frbInternalSseEncode(move: false),
-        serializer);
-  }
-
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-          SmtcFlutter self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as SmtcFlutterImpl).frbInternalSseEncode(move: null), serializer);
-  }
-
-  @protected
-  void sse_encode_StreamSink_String_Sse(
-      RustStreamSink<String> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-        self.setupAndSerialize(
-            codec: SseCodec(
+        );
+        
+
+@override void crateApiPictureCacheCancelPictureCachePrewarm()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPictureCacheCancelPictureCachePrewarmConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPictureCacheCancelPictureCachePrewarmConstMeta => const TaskConstMeta(
+            debugName: "cancel_picture_cache_prewarm",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiPictureCacheClearPictureCache()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPictureCacheClearPictureCacheConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPictureCacheClearPictureCacheConstMeta => const TaskConstMeta(
+            debugName: "clear_picture_cache",
+            argNames: [],
+        );
+        
+
+@override String crateApiTextConvertConvertChinese({required String text , required ChineseVariant variant })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(text, serializer);
+sse_encode_chinese_variant(variant, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTextConvertConvertChineseConstMeta,
+            argValues: [text, variant],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTextConvertConvertChineseConstMeta => const TaskConstMeta(
+            debugName: "convert_chinese",
+            argNames: ["text", "variant"],
+        );
+        
+
+@override Future<DecodedText> crateApiTextEncodingDecodeText({required List<int> bytes })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(bytes, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_decoded_text,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTextEncodingDecodeTextConstMeta,
+            argValues: [bytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTextEncodingDecodeTextConstMeta => const TaskConstMeta(
+            debugName: "decode_text",
+            argNames: ["bytes"],
+        );
+        
+
+@override Future<String> crateApiLyricDecryptKrc({required List<int> data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiLyricDecryptKrcConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLyricDecryptKrcConstMeta => const TaskConstMeta(
+            debugName: "decrypt_krc",
+            argNames: ["data"],
+        );
+        
+
+@override Future<String> crateApiLyricDecryptQrc({required List<int> data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiLyricDecryptQrcConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLyricDecryptQrcConstMeta => const TaskConstMeta(
+            debugName: "decrypt_qrc",
+            argNames: ["data"],
+        );
+        
+
+@override Future<String> crateApiEmbeddedPictureExtractEmbeddedPicture({required String path , required int index , required String outputPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_u_32(index, serializer);
+sse_encode_String(outputPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        )),
-        serializer);
-  }
-
-  @protected
-  void sse_encode_StreamSink_f_64_Sse(
-      RustStreamSink<double> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-        self.setupAndSerialize(
-            codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiEmbeddedPictureExtractEmbeddedPictureConstMeta,
+            argValues: [path, index, outputPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEmbeddedPictureExtractEmbeddedPictureConstMeta => const TaskConstMeta(
+            debugName: "extract_embedded_picture",
+            argNames: ["path", "index", "outputPath"],
+        );
+        
+
+@override Future<AlbumPalette?> crateApiAlbumPaletteExtractPalette({required String indexPath , required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(indexPath, serializer);
+sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_album_palette,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAlbumPaletteExtractPaletteConstMeta,
+            argValues: [indexPath, path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAlbumPaletteExtractPaletteConstMeta => const TaskConstMeta(
+            debugName: "extract_palette",
+            argNames: ["indexPath", "path"],
+        );
+        
+
+@override Future<List<List<String>>> crateApiMusicMatcherFindDuplicateAudios({required String indexPath , required double threshold })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(indexPath, serializer);
+sse_encode_f_64(threshold, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_list_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiMusicMatcherFindDuplicateAudiosConstMeta,
+            argValues: [indexPath, threshold],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMusicMatcherFindDuplicateAudiosConstMeta => const TaskConstMeta(
+            debugName: "find_duplicate_audios",
+            argNames: ["indexPath", "threshold"],
+        );
+        
+
+@override Future<List<Uint32List>> crateApiMusicMatcherFindDuplicates({required List<MatchTrack> tracks , required double threshold })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_match_track(tracks, serializer);
+sse_encode_f_64(threshold, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_list_prim_u_32_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMusicMatcherFindDuplicatesConstMeta,
+            argValues: [tracks, threshold],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMusicMatcherFindDuplicatesConstMeta => const TaskConstMeta(
+            debugName: "find_duplicates",
+            argNames: ["tracks", "threshold"],
+        );
+        
+
+@override Future<List<CachedLyric>> crateApiLibraryDbGetCachedLyrics({required String indexPath , required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(indexPath, serializer);
+sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_cached_lyric,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiLibraryDbGetCachedLyricsConstMeta,
+            argValues: [indexPath, path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLibraryDbGetCachedLyricsConstMeta => const TaskConstMeta(
+            debugName: "get_cached_lyrics",
+            argNames: ["indexPath", "path"],
+        );
+        
+
+@override Future<IndexCueTrack?> crateApiLibraryDbGetCueTrack({required String indexPath , required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(indexPath, serializer);
+sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_index_cue_track,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiLibraryDbGetCueTrackConstMeta,
+            argValues: [indexPath, path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLibraryDbGetCueTrackConstMeta => const TaskConstMeta(
+            debugName: "get_cue_track",
+            argNames: ["indexPath", "path"],
+        );
+        
+
+@override Future<List<EmbeddedLyric>> crateApiTagReaderGetEmbeddedLyricsFromPath({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_embedded_lyric,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTagReaderGetEmbeddedLyricsFromPathConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTagReaderGetEmbeddedLyricsFromPathConstMeta => const TaskConstMeta(
+            debugName: "get_embedded_lyrics_from_path",
+            argNames: ["path"],
+        );
+        
+
+@override Future<Lyric?> crateApiTagReaderGetEncryptedLyricFromPath({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_lyric,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTagReaderGetEncryptedLyricFromPathConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTagReaderGetEncryptedLyricFromPathConstMeta => const TaskConstMeta(
+            debugName: "get_encrypted_lyric_from_path",
+            argNames: ["path"],
+        );
+        
+
+@override Future<List<InstalledFont>?> crateApiInstalledFontGetInstalledFonts()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_list_installed_font,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiInstalledFontGetInstalledFontsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInstalledFontGetInstalledFontsConstMeta => const TaskConstMeta(
+            debugName: "get_installed_fonts",
+            argNames: [],
+        );
+        
+
+@override Future<List<LyricCandidate>> crateApiTagReaderGetLyricCandidates({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_lyric_candidate,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTagReaderGetLyricCandidatesConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTagReaderGetLyricCandidatesConstMeta => const TaskConstMeta(
+            debugName: "get_lyric_candidates",
+            argNames: ["path"],
+        );
+        
+
+@override Future<String?> crateApiTagReaderGetLyricFromPath({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTagReaderGetLyricFromPathConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTagReaderGetLyricFromPathConstMeta => const TaskConstMeta(
+            debugName: "get_lyric_from_path",
+            argNames: ["path"],
+        );
+        
+
+@override Future<PlatformInt64> crateApiLibraryDbGetLyricOffset({required String indexPath , required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(indexPath, serializer);
+sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiLibraryDbGetLyricOffsetConstMeta,
+            argValues: [indexPath, path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLibraryDbGetLyricOffsetConstMeta => const TaskConstMeta(
+            debugName: "get_lyric_offset",
+            argNames: ["indexPath", "path"],
+        );
+        
+
+@override Future<Lyric?> crateApiLyricGetParsedLyricForPlayback({required String indexPath , required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(indexPath, serializer);
+sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_lyric,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLyricGetParsedLyricForPlaybackConstMeta,
+            argValues: [indexPath, path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLyricGetParsedLyricForPlaybackConstMeta => const TaskConstMeta(
+            debugName: "get_parsed_lyric_for_playback",
+            argNames: ["indexPath", "path"],
+        );
+        
+
+@override Future<Lyric?> crateApiLyricGetParsedLyricFromPath({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_lyric,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLyricGetParsedLyricFromPathConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLyricGetParsedLyricFromPathConstMeta => const TaskConstMeta(
+            debugName: "get_parsed_lyric_from_path",
+            argNames: ["path"],
+        );
+        
+
+@override Future<Uint8List?> crateApiTagReaderGetPictureFromPath({required String path , required int width , required int height })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_u_32(width, serializer);
+sse_encode_u_32(height, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTagReaderGetPictureFromPathConstMeta,
+            argValues: [path, width, height],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTagReaderGetPictureFromPathConstMeta => const TaskConstMeta(
+            debugName: "get_picture_from_path",
+            argNames: ["path", "width", "height"],
+        );
+        
+
+@override Future<PictureWithSource?> crateApiTagReaderGetPictureWithOptions({required String path , required PictureOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_box_autoadd_picture_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_picture_with_source,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTagReaderGetPictureWithOptionsConstMeta,
+            argValues: [path, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTagReaderGetPictureWithOptionsConstMeta => const TaskConstMeta(
+            debugName: "get_picture_with_options",
+            argNames: ["path", "options"],
+        );
+        
+
+@override Future<PictureWithSource?> crateApiTagReaderGetPictureWithSourceFromPath({required String path , required int width , required int height })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_u_32(width, serializer);
+sse_encode_u_32(height, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_picture_with_source,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTagReaderGetPictureWithSourceFromPathConstMeta,
+            argValues: [path, width, height],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTagReaderGetPictureWithSourceFromPathConstMeta => const TaskConstMeta(
+            debugName: "get_picture_with_source_from_path",
+            argNames: ["path", "width", "height"],
+        );
+        
+
+@override Future<CachedLyric?> crateApiLibraryDbGetPreferredLyric({required String indexPath , required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(indexPath, serializer);
+sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_cached_lyric,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiLibraryDbGetPreferredLyricConstMeta,
+            argValues: [indexPath, path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLibraryDbGetPreferredLyricConstMeta => const TaskConstMeta(
+            debugName: "get_preferred_lyric",
+            argNames: ["indexPath", "path"],
+        );
+        
+
+@override void crateApiPictureCacheInitPictureCache({required String indexPath , required BigInt byteBudget })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(indexPath, serializer);
+sse_encode_u_64(byteBudget, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPictureCacheInitPictureCacheConstMeta,
+            argValues: [indexPath, byteBudget],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPictureCacheInitPictureCacheConstMeta => const TaskConstMeta(
+            debugName: "init_picture_cache",
+            argNames: ["indexPath", "byteBudget"],
+        );
+        
+
+@override Stream<String> crateApiLoggerInitRustLogger()  { 
+            final sink = RustStreamSink<String>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLoggerInitRustLoggerConstMeta,
+            argValues: [sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiLoggerInitRustLoggerConstMeta => const TaskConstMeta(
+            debugName: "init_rust_logger",
+            argNames: ["sink"],
+        );
+        
+
+@override Future<bool> crateApiUtilsLaunchInBrowser({required String uri })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(uri, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiUtilsLaunchInBrowserConstMeta,
+            argValues: [uri],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUtilsLaunchInBrowserConstMeta => const TaskConstMeta(
+            debugName: "launch_in_browser",
+            argNames: ["uri"],
+        );
+        
+
+@override Future<List<EmbeddedPicture>> crateApiEmbeddedPictureListEmbeddedPictures({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_embedded_picture,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiEmbeddedPictureListEmbeddedPicturesConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEmbeddedPictureListEmbeddedPicturesConstMeta => const TaskConstMeta(
+            debugName: "list_embedded_pictures",
+            argNames: ["path"],
+        );
+        
+
+@override Lyric crateApiLyricLyricShifted({required Lyric that , required PlatformInt64 offset })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_lyric(that, serializer);
+sse_encode_i_64(offset, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_lyric,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLyricLyricShiftedConstMeta,
+            argValues: [that, offset],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLyricLyricShiftedConstMeta => const TaskConstMeta(
+            debugName: "lyric_shifted",
+            argNames: ["that", "offset"],
+        );
+        
+
+@override Lyric? crateApiLyricLyricStretched({required Lyric that , required int fromA , required int toA , required int fromB , required int toB })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_lyric(that, serializer);
+sse_encode_u_32(fromA, serializer);
+sse_encode_u_32(toA, serializer);
+sse_encode_u_32(fromB, serializer);
+sse_encode_u_32(toB, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_lyric,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLyricLyricStretchedConstMeta,
+            argValues: [that, fromA, toA, fromB, toB],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLyricLyricStretchedConstMeta => const TaskConstMeta(
+            debugName: "lyric_stretched",
+            argNames: ["that", "fromA", "toA", "fromB", "toB"],
+        );
+        
+
+@override String? crateApiLyricLyricTag({required Lyric that , required String key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_lyric(that, serializer);
+sse_encode_String(key, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLyricLyricTagConstMeta,
+            argValues: [that, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLyricLyricTagConstMeta => const TaskConstMeta(
+            debugName: "lyric_tag",
+            argNames: ["that", "key"],
+        );
+        
+
+@override String crateApiLyricLyricToLrc({required Lyric that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_lyric(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLyricLyricToLrcConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLyricLyricToLrcConstMeta => const TaskConstMeta(
+            debugName: "lyric_to_lrc",
+            argNames: ["that"],
+        );
+        
+
+@override String crateApiLyricLyricToTtml({required Lyric that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_lyric(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLyricLyricToTtmlConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLyricLyricToTtmlConstMeta => const TaskConstMeta(
+            debugName: "lyric_to_ttml",
+            argNames: ["that"],
+        );
+        
+
+@override Lyric crateApiLyricLyricWithChineseVariant({required Lyric that , required ChineseVariant variant })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_lyric(that, serializer);
+sse_encode_chinese_variant(variant, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_lyric,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLyricLyricWithChineseVariantConstMeta,
+            argValues: [that, variant],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLyricLyricWithChineseVariantConstMeta => const TaskConstMeta(
+            debugName: "lyric_with_chinese_variant",
+            argNames: ["that", "variant"],
+        );
+        
+
+@override Lyric crateApiLyricLyricWithRomanization({required Lyric that , required RomanizationKind kind })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_lyric(that, serializer);
+sse_encode_romanization_kind(kind, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_lyric,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLyricLyricWithRomanizationConstMeta,
+            argValues: [that, kind],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLyricLyricWithRomanizationConstMeta => const TaskConstMeta(
+            debugName: "lyric_with_romanization",
+            argNames: ["that", "kind"],
+        );
+        
+
+@override Lyric crateApiLyricLyricWithTranslation({required Lyric that , required Lyric translation , required int tolerance })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_lyric(that, serializer);
+sse_encode_box_autoadd_lyric(translation, serializer);
+sse_encode_u_32(tolerance, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_lyric,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLyricLyricWithTranslationConstMeta,
+            argValues: [that, translation, tolerance],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLyricLyricWithTranslationConstMeta => const TaskConstMeta(
+            debugName: "lyric_with_translation",
+            argNames: ["that", "translation", "tolerance"],
+        );
+        
+
+@override Future<void> crateApiLibraryDbMigrateIndexJsonToSqlite({required String indexPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(indexPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiLibraryDbMigrateIndexJsonToSqliteConstMeta,
+            argValues: [indexPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLibraryDbMigrateIndexJsonToSqliteConstMeta => const TaskConstMeta(
+            debugName: "migrate_index_json_to_sqlite",
+            argNames: ["indexPath"],
+        );
+        
+
+@override List<String> crateApiMusicMatcherNormalizeArtists({required String artist })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(artist, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMusicMatcherNormalizeArtistsConstMeta,
+            argValues: [artist],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMusicMatcherNormalizeArtistsConstMeta => const TaskConstMeta(
+            debugName: "normalize_artists",
+            argNames: ["artist"],
+        );
+        
+
+@override String crateApiMusicMatcherNormalizeTitle({required String title })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(title, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMusicMatcherNormalizeTitleConstMeta,
+            argValues: [title],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMusicMatcherNormalizeTitleConstMeta => const TaskConstMeta(
+            debugName: "normalize_title",
+            argNames: ["title"],
+        );
+        
+
+@override Future<void> crateApiSystemVolumeOutputDeviceEventsDispose()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSystemVolumeOutputDeviceEventsDisposeConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSystemVolumeOutputDeviceEventsDisposeConstMeta => const TaskConstMeta(
+            debugName: "output_device_events_dispose",
+            argNames: [],
+        );
+        
+
+@override Stream<OutputDeviceEvent> crateApiSystemVolumeOutputDeviceEventsInit()  { 
+            final sink = RustStreamSink<OutputDeviceEvent>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_StreamSink_output_device_event_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSystemVolumeOutputDeviceEventsInitConstMeta,
+            argValues: [sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiSystemVolumeOutputDeviceEventsInitConstMeta => const TaskConstMeta(
+            debugName: "output_device_events_init",
+            argNames: ["sink"],
+        );
+        
+
+@override Future<List<OutputDevice>> crateApiSystemVolumeOutputDeviceList()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_output_device,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSystemVolumeOutputDeviceListConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSystemVolumeOutputDeviceListConstMeta => const TaskConstMeta(
+            debugName: "output_device_list",
+            argNames: [],
+        );
+        
+
+@override Future<bool> crateApiSystemVolumeOutputDeviceMuteGet({required String id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSystemVolumeOutputDeviceMuteGetConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSystemVolumeOutputDeviceMuteGetConstMeta => const TaskConstMeta(
+            debugName: "output_device_mute_get",
+            argNames: ["id"],
+        );
+        
+
+@override Future<void> crateApiSystemVolumeOutputDeviceMuteSet({required String id , required bool mute })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+sse_encode_bool(mute, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSystemVolumeOutputDeviceMuteSetConstMeta,
+            argValues: [id, mute],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSystemVolumeOutputDeviceMuteSetConstMeta => const TaskConstMeta(
+            debugName: "output_device_mute_set",
+            argNames: ["id", "mute"],
+        );
+        
+
+@override Future<double> crateApiSystemVolumeOutputDeviceVolumeGet({required String id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_f_64,
           decodeErrorData: sse_decode_AnyhowException,
-        )),
-        serializer);
-  }
-
-  @protected
-  void sse_encode_StreamSink_index_action_state_Sse(
-      RustStreamSink<IndexActionState> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-        self.setupAndSerialize(
-            codec: SseCodec(
-          decodeSuccessData: sse_decode_index_action_state,
+        )
+        ,
+            constMeta: kCrateApiSystemVolumeOutputDeviceVolumeGetConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSystemVolumeOutputDeviceVolumeGetConstMeta => const TaskConstMeta(
+            debugName: "output_device_volume_get",
+            argNames: ["id"],
+        );
+        
+
+@override Future<void> crateApiSystemVolumeOutputDeviceVolumeSet({required String id , required double val })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+sse_encode_f_64(val, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
-        )),
-        serializer);
-  }
-
-  @protected
-  void sse_encode_StreamSink_smtc_control_event_Sse(
-      RustStreamSink<SMTCControlEvent> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-        self.setupAndSerialize(
-            codec: SseCodec(
-          decodeSuccessData: sse_decode_smtc_control_event,
+        )
+        ,
+            constMeta: kCrateApiSystemVolumeOutputDeviceVolumeSetConstMeta,
+            argValues: [id, val],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSystemVolumeOutputDeviceVolumeSetConstMeta => const TaskConstMeta(
+            debugName: "output_device_volume_set",
+            argNames: ["id", "val"],
+        );
+        
+
+@override Future<Lyric?> crateApiLyricParseKrc({required String text })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(text, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_lyric,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLyricParseKrcConstMeta,
+            argValues: [text],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLyricParseKrcConstMeta => const TaskConstMeta(
+            debugName: "parse_krc",
+            argNames: ["text"],
+        );
+        
+
+@override Future<Lyric?> crateApiLyricParseLrc({required String text })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(text, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_lyric,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLyricParseLrcConstMeta,
+            argValues: [text],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLyricParseLrcConstMeta => const TaskConstMeta(
+            debugName: "parse_lrc",
+            argNames: ["text"],
+        );
+        
+
+@override Future<Lyric?> crateApiLyricParseLyric({required String text })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(text, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_lyric,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLyricParseLyricConstMeta,
+            argValues: [text],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLyricParseLyricConstMeta => const TaskConstMeta(
+            debugName: "parse_lyric",
+            argNames: ["text"],
+        );
+        
+
+@override Future<Lyric?> crateApiLyricParseLyricCandidate({required String path , required LyricCandidate candidate })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_box_autoadd_lyric_candidate(candidate, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_lyric,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLyricParseLyricCandidateConstMeta,
+            argValues: [path, candidate],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLyricParseLyricCandidateConstMeta => const TaskConstMeta(
+            debugName: "parse_lyric_candidate",
+            argNames: ["path", "candidate"],
+        );
+        
+
+@override Future<Lyric?> crateApiLyricParseQrc({required String text , String? translation })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(text, serializer);
+sse_encode_opt_String(translation, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_lyric,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLyricParseQrcConstMeta,
+            argValues: [text, translation],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLyricParseQrcConstMeta => const TaskConstMeta(
+            debugName: "parse_qrc",
+            argNames: ["text", "translation"],
+        );
+        
+
+@override Future<Lyric?> crateApiLyricParseSrt({required String text })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(text, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_lyric,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLyricParseSrtConstMeta,
+            argValues: [text],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLyricParseSrtConstMeta => const TaskConstMeta(
+            debugName: "parse_srt",
+            argNames: ["text"],
+        );
+        
+
+@override Future<Lyric?> crateApiLyricParseTtml({required String text })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(text, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_lyric,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLyricParseTtmlConstMeta,
+            argValues: [text],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLyricParseTtmlConstMeta => const TaskConstMeta(
+            debugName: "parse_ttml",
+            argNames: ["text"],
+        );
+        
+
+@override Future<String?> crateApiUtilsPickSingleFolder()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiUtilsPickSingleFolderConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUtilsPickSingleFolderConstMeta => const TaskConstMeta(
+            debugName: "pick_single_folder",
+            argNames: [],
+        );
+        
+
+@override void crateApiPictureCachePrewarmPictureCache({required String indexPath , required int width , required int height })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(indexPath, serializer);
+sse_encode_u_32(width, serializer);
+sse_encode_u_32(height, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPictureCachePrewarmPictureCacheConstMeta,
+            argValues: [indexPath, width, height],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPictureCachePrewarmPictureCacheConstMeta => const TaskConstMeta(
+            debugName: "prewarm_picture_cache",
+            argNames: ["indexPath", "width", "height"],
+        );
+        
+
+@override List<RankedMatch> crateApiMusicMatcherRankMatches({required MatchTrack query , required List<MatchTrack> candidates })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_match_track(query, serializer);
+sse_encode_list_match_track(candidates, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_ranked_match,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMusicMatcherRankMatchesConstMeta,
+            argValues: [query, candidates],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMusicMatcherRankMatchesConstMeta => const TaskConstMeta(
+            debugName: "rank_matches",
+            argNames: ["query", "candidates"],
+        );
+        
+
+@override Future<String> crateApiTagReaderReadAudioExtraMetadata({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTagReaderReadAudioExtraMetadataConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTagReaderReadAudioExtraMetadataConstMeta => const TaskConstMeta(
+            debugName: "read_audio_extra_metadata",
+            argNames: ["path"],
+        );
+        
+
+@override Future<List<IndexFolder>> crateApiLibraryDbReadIndexFromSqlite({required String indexPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(indexPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_index_folder,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiLibraryDbReadIndexFromSqliteConstMeta,
+            argValues: [indexPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLibraryDbReadIndexFromSqliteConstMeta => const TaskConstMeta(
+            debugName: "read_index_from_sqlite",
+            argNames: ["indexPath"],
+        );
+        
+
+@override Future<String?> crateApiTagReaderReadLyricCandidate({required String path , required LyricCandidate candidate })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_box_autoadd_lyric_candidate(candidate, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTagReaderReadLyricCandidateConstMeta,
+            argValues: [path, candidate],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTagReaderReadLyricCandidateConstMeta => const TaskConstMeta(
+            debugName: "read_lyric_candidate",
+            argNames: ["path", "candidate"],
+        );
+        
+
+@override Future<void> crateApiLibraryDbRemoveCachedLyric({required String indexPath , required String path , required String hash })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(indexPath, serializer);
+sse_encode_String(path, serializer);
+sse_encode_String(hash, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiLibraryDbRemoveCachedLyricConstMeta,
+            argValues: [indexPath, path, hash],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLibraryDbRemoveCachedLyricConstMeta => const TaskConstMeta(
+            debugName: "remove_cached_lyric",
+            argNames: ["indexPath", "path", "hash"],
+        );
+        
+
+@override Future<int> crateApiEmbeddedPictureRemoveEmbeddedPictures({required String path , EmbeddedPictureType? pictureType })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_opt_box_autoadd_embedded_picture_type(pictureType, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiEmbeddedPictureRemoveEmbeddedPicturesConstMeta,
+            argValues: [path, pictureType],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEmbeddedPictureRemoveEmbeddedPicturesConstMeta => const TaskConstMeta(
+            debugName: "remove_embedded_pictures",
+            argNames: ["path", "pictureType"],
+        );
+        
+
+@override String crateApiTextConvertRomanize({required String text , required RomanizationKind kind })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(text, serializer);
+sse_encode_romanization_kind(kind, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTextConvertRomanizeConstMeta,
+            argValues: [text, kind],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTextConvertRomanizeConstMeta => const TaskConstMeta(
+            debugName: "romanize",
+            argNames: ["text", "kind"],
+        );
+        
+
+@override Future<CachedLyric> crateApiLibraryDbSaveLyric({required String indexPath , required String path , required LyricSource source , String? provider , required String raw , required bool preferred })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(indexPath, serializer);
+sse_encode_String(path, serializer);
+sse_encode_lyric_source(source, serializer);
+sse_encode_opt_String(provider, serializer);
+sse_encode_String(raw, serializer);
+sse_encode_bool(preferred, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_cached_lyric,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiLibraryDbSaveLyricConstMeta,
+            argValues: [indexPath, path, source, provider, raw, preferred],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLibraryDbSaveLyricConstMeta => const TaskConstMeta(
+            debugName: "save_lyric",
+            argNames: ["indexPath", "path", "source", "provider", "raw", "preferred"],
+        );
+        
+
+@override MatchScore crateApiMusicMatcherScoreMatch({required MatchTrack query , required MatchTrack candidate })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_match_track(query, serializer);
+sse_encode_box_autoadd_match_track(candidate, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_match_score,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMusicMatcherScoreMatchConstMeta,
+            argValues: [query, candidate],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMusicMatcherScoreMatchConstMeta => const TaskConstMeta(
+            debugName: "score_match",
+            argNames: ["query", "candidate"],
+        );
+        
+
+@override Future<void> crateApiLibraryDbSetCachedLyricOffset({required String indexPath , required String path , required String hash , required PlatformInt64 offset })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(indexPath, serializer);
+sse_encode_String(path, serializer);
+sse_encode_String(hash, serializer);
+sse_encode_i_64(offset, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiLibraryDbSetCachedLyricOffsetConstMeta,
+            argValues: [indexPath, path, hash, offset],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLibraryDbSetCachedLyricOffsetConstMeta => const TaskConstMeta(
+            debugName: "set_cached_lyric_offset",
+            argNames: ["indexPath", "path", "hash", "offset"],
+        );
+        
+
+@override void crateApiTagReaderSetFolderCoverNames({required List<String> names })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(names, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTagReaderSetFolderCoverNamesConstMeta,
+            argValues: [names],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTagReaderSetFolderCoverNamesConstMeta => const TaskConstMeta(
+            debugName: "set_folder_cover_names",
+            argNames: ["names"],
+        );
+        
+
+@override Future<void> crateApiEmbeddedPictureSetFrontCover({required String path , required List<int> data , int? maxSize , int? jpegQuality })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_opt_box_autoadd_u_32(maxSize, serializer);
+sse_encode_opt_box_autoadd_u_8(jpegQuality, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiEmbeddedPictureSetFrontCoverConstMeta,
+            argValues: [path, data, maxSize, jpegQuality],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEmbeddedPictureSetFrontCoverConstMeta => const TaskConstMeta(
+            debugName: "set_front_cover",
+            argNames: ["path", "data", "maxSize", "jpegQuality"],
+        );
+        
+
+@override Future<void> crateApiLibraryDbSetLyricOffset({required String indexPath , required String path , required PlatformInt64 offset })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(indexPath, serializer);
+sse_encode_String(path, serializer);
+sse_encode_i_64(offset, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiLibraryDbSetLyricOffsetConstMeta,
+            argValues: [indexPath, path, offset],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLibraryDbSetLyricOffsetConstMeta => const TaskConstMeta(
+            debugName: "set_lyric_offset",
+            argNames: ["indexPath", "path", "offset"],
+        );
+        
+
+@override void crateApiTagReaderSetLyricSearchOptions({required List<String> extensions , required List<String> subfolders , String? globalFolder })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(extensions, serializer);
+sse_encode_list_String(subfolders, serializer);
+sse_encode_opt_String(globalFolder, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTagReaderSetLyricSearchOptionsConstMeta,
+            argValues: [extensions, subfolders, globalFolder],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTagReaderSetLyricSearchOptionsConstMeta => const TaskConstMeta(
+            debugName: "set_lyric_search_options",
+            argNames: ["extensions", "subfolders", "globalFolder"],
+        );
+        
+
+@override Future<void> crateApiLibraryDbSetPreferredLyric({required String indexPath , required String path , required String hash })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(indexPath, serializer);
+sse_encode_String(path, serializer);
+sse_encode_String(hash, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiLibraryDbSetPreferredLyricConstMeta,
+            argValues: [indexPath, path, hash],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLibraryDbSetPreferredLyricConstMeta => const TaskConstMeta(
+            debugName: "set_preferred_lyric",
+            argNames: ["indexPath", "path", "hash"],
+        );
+        
+
+@override Future<bool> crateApiUtilsShowInExplorer({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiUtilsShowInExplorerConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUtilsShowInExplorerConstMeta => const TaskConstMeta(
+            debugName: "show_in_explorer",
+            argNames: ["path"],
+        );
+        
+
+@override SystemTheme crateApiSystemThemeSystemThemeGetSystemTheme()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_system_theme,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSystemThemeSystemThemeGetSystemThemeConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSystemThemeSystemThemeGetSystemThemeConstMeta => const TaskConstMeta(
+            debugName: "system_theme_get_system_theme",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiSystemVolumeSystemVolumeDispose()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 87, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSystemVolumeSystemVolumeDisposeConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSystemVolumeSystemVolumeDisposeConstMeta => const TaskConstMeta(
+            debugName: "system_volume_dispose",
+            argNames: [],
+        );
+        
+
+@override Future<double> crateApiSystemVolumeSystemVolumeGet()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_f_64,
           decodeErrorData: sse_decode_AnyhowException,
-        )),
-        serializer);
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self, serializer);
-  }
-
-  @protected
-  void sse_encode_f_64(double self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat64(self);
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-
-  @protected
-  void sse_encode_index_action_state(
-      IndexActionState self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_64(self.progress, serializer);
-    sse_encode_String(self.message, serializer);
-  }
-
-  @protected
-  void sse_encode_index_audio(IndexAudio self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.title, serializer);
-    sse_encode_String(self.artist, serializer);
-    sse_encode_String(self.album, serializer);
-    sse_encode_opt_String(self.albumArtist, serializer);
-    sse_encode_u_32(self.track, serializer);
-    sse_encode_u_64(self.duration, serializer);
-    sse_encode_opt_box_autoadd_u_32(self.bitrate, serializer);
-    sse_encode_opt_box_autoadd_u_32(self.sampleRate, serializer);
-    sse_encode_String(self.path, serializer);
-    sse_encode_u_64(self.modified, serializer);
-    sse_encode_u_64(self.created, serializer);
-    sse_encode_opt_String(self.by, serializer);
-  }
-
-  @protected
-  void sse_encode_index_folder(IndexFolder self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.path, serializer);
-    sse_encode_u_64(self.modified, serializer);
-    sse_encode_u_64(self.latest, serializer);
-    sse_encode_list_index_audio(self.audios, serializer);
-  }
-
-  @protected
-  void sse_encode_installed_font(InstalledFont self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.path, serializer);
-    sse_encode_String(self.fullName, serializer);
-  }
-
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_index_audio(
-      List<IndexAudio> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_index_audio(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_index_folder(
-      List<IndexFolder> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_index_folder(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_installed_font(
-      List<InstalledFont> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_installed_font(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_u_32(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_list_installed_font(
-      List<InstalledFont>? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_installed_font(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_list_prim_u_8_strict(
-      Uint8List? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_prim_u_8_strict(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_record_u_8_u_8_u_8_u_8(
-      (int, int, int, int) self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_8(self.$1, serializer);
-    sse_encode_u_8(self.$2, serializer);
-    sse_encode_u_8(self.$3, serializer);
-    sse_encode_u_8(self.$4, serializer);
-  }
-
-  @protected
-  void sse_encode_smtc_control_event(
-      SMTCControlEvent self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_smtc_state(SMTCState self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_system_theme(SystemTheme self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_record_u_8_u_8_u_8_u_8(self.fore, serializer);
-    sse_encode_record_u_8_u_8_u_8_u_8(self.accent, serializer);
-  }
-
-  @protected
-  void sse_encode_u_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint32(self);
-  }
-
-  @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-}
-
-@sealed
-class SmtcFlutterImpl extends RustOpaque implements SmtcFlutter {
-  // Not to be used by end users
-  SmtcFlutterImpl.frbInternalDcoDecode(List<dynamic> wire)
-      : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  SmtcFlutterImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_SmtcFlutter,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_SmtcFlutter,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_SmtcFlutterPtr,
-  );
-
-  /// Apis for Flutter
-  Future<void> close() =>
-      RustLib.instance.api.crateApiSmtcFlutterSmtcFlutterClose(
-        that: this,
-      );
-
-  /// Apis for Flutter
-  Stream<SMTCControlEvent> subscribeToControlEvents() => RustLib.instance.api
-          .crateApiSmtcFlutterSmtcFlutterSubscribeToControlEvents(
-        that: this,
-      );
-
-  /// Apis for Flutter
-  Future<void> updateDisplay(
-          {required String title,
-          required String artist,
-          required String album,
-          required int duration,
-          required String path}) =>
-      RustLib.instance.api.crateApiSmtcFlutterSmtcFlutterUpdateDisplay(
-          that: this,
-          title: title,
-          artist: artist,
-          album: album,
-          duration: duration,
-          path: path);
-
-  /// Apis for Flutter
-  Future<void> updateState({required SMTCState state}) => RustLib.instance.api
-      .crateApiSmtcFlutterSmtcFlutterUpdateState(that: this, state: state);
-
-  /// progress, duration: ms
-  /// Apis for Flutter
-  Future<void> updateTimeProperties({required int progress}) =>
-      RustLib.instance.api.crateApiSmtcFlutterSmtcFlutterUpdateTimeProperties(
-          that: this, progress: progress);
-}
+        )
+        ,
+            constMeta: kCrateApiSystemVolumeSystemVolumeGetConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSystemVolumeSystemVolumeGetConstMeta => const TaskConstMeta(
+            debugName: "system_volume_get",
+            argNames: [],
+        );
+        
+
+@override Stream<double> crateApiSystemVolumeSystemVolumeInit()  { 
+            final sink = RustStreamSink<double>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_StreamSink_f_64_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_f_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSystemVolumeSystemVolumeInitConstMeta,
+            argValues: [sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiSystemVolumeSystemVolumeInitConstMeta => const TaskConstMeta(
+            debugName: "system_volume_init",
+            argNames: ["sink"],
+        );
+        
+
+@override Future<void> crateApiSystemVolumeSystemVolumeSet({required double val })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_f_64(val, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 90, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSystemVolumeSystemVolumeSetConstMeta,
+            argValues: [val],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSystemVolumeSystemVolumeSetConstMeta => const TaskConstMeta(
+            debugName: "system_volume_set",
+            argNames: ["val"],
+        );
+        
+
+@override Stream<IndexActionState> crateApiTagReaderUpdateIndex({required String indexPath })  { 
+            final sink = RustStreamSink<IndexActionState>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(indexPath, serializer);
+sse_encode_StreamSink_index_action_state_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTagReaderUpdateIndexConstMeta,
+            argValues: [indexPath, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiTagReaderUpdateIndexConstMeta => const TaskConstMeta(
+            debugName: "update_index",
+            argNames: ["indexPath", "sink"],
+        );
+        
+
+@override Future<void> crateApiTagReaderWriteLyricToPath({required String path , required String lyric })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_String(lyric, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 92, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiTagReaderWriteLyricToPathConstMeta,
+            argValues: [path, lyric],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTagReaderWriteLyricToPathConstMeta => const TaskConstMeta(
+            debugName: "write_lyric_to_path",
+            argNames: ["path", "lyric"],
+        );
+        
+
+@override Future<void> crateApiTagReaderWriteLyricsToPath({required String path , required List<EmbeddedLyric> lyrics })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_list_embedded_lyric(lyrics, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 93, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiTagReaderWriteLyricsToPathConstMeta,
+            argValues: [path, lyrics],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTagReaderWriteLyricsToPathConstMeta => const TaskConstMeta(
+            debugName: "write_lyrics_to_path",
+            argNames: ["path", "lyrics"],
+        );
+        
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_SmtcFlutter => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_SmtcFlutter => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter;
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnyhowException(raw as String); }
+
+@protected SmtcFlutter dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SmtcFlutterImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected SmtcFlutter dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SmtcFlutterImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected SmtcFlutter dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SmtcFlutterImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<double> dco_decode_StreamSink_f_64_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<IndexActionState> dco_decode_StreamSink_index_action_state_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<OutputDeviceEvent> dco_decode_StreamSink_output_device_event_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<SMTCControlEvent> dco_decode_StreamSink_smtc_control_event_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected AlbumPalette dco_decode_album_palette(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return AlbumPalette(dominant: dco_decode_palette_swatch(arr[0]),
+vibrant: dco_decode_palette_swatch(arr[1]),
+lightVibrant: dco_decode_palette_swatch(arr[2]),
+darkVibrant: dco_decode_palette_swatch(arr[3]),
+muted: dco_decode_palette_swatch(arr[4]),
+lightMuted: dco_decode_palette_swatch(arr[5]),
+darkMuted: dco_decode_palette_swatch(arr[6]),); }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected AlbumPalette dco_decode_box_autoadd_album_palette(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_album_palette(raw); }
+
+@protected CachedLyric dco_decode_box_autoadd_cached_lyric(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_cached_lyric(raw); }
+
+@protected EmbeddedPictureType dco_decode_box_autoadd_embedded_picture_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_embedded_picture_type(raw); }
+
+@protected double dco_decode_box_autoadd_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected IndexCueTrack dco_decode_box_autoadd_index_cue_track(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_index_cue_track(raw); }
+
+@protected Lyric dco_decode_box_autoadd_lyric(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_lyric(raw); }
+
+@protected LyricCandidate dco_decode_box_autoadd_lyric_candidate(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_lyric_candidate(raw); }
+
+@protected LyricFormat dco_decode_box_autoadd_lyric_format(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_lyric_format(raw); }
+
+@protected MatchTrack dco_decode_box_autoadd_match_track(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_match_track(raw); }
+
+@protected PictureOptions dco_decode_box_autoadd_picture_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_picture_options(raw); }
+
+@protected PictureWithSource dco_decode_box_autoadd_picture_with_source(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_picture_with_source(raw); }
+
+@protected SMTCArtwork dco_decode_box_autoadd_smtc_artwork(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_smtc_artwork(raw); }
+
+@protected SMTCMetadata dco_decode_box_autoadd_smtc_metadata(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_smtc_metadata(raw); }
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_u_64(raw); }
+
+@protected int dco_decode_box_autoadd_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected CachedLyric dco_decode_cached_lyric(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return CachedLyric(path: dco_decode_String(arr[0]),
+hash: dco_decode_String(arr[1]),
+source: dco_decode_lyric_source(arr[2]),
+provider: dco_decode_opt_String(arr[3]),
+format: dco_decode_opt_box_autoadd_lyric_format(arr[4]),
+raw: dco_decode_String(arr[5]),
+parsed: dco_decode_opt_box_autoadd_lyric(arr[6]),
+offset: dco_decode_i_64(arr[7]),
+preferred: dco_decode_bool(arr[8]),
+updated: dco_decode_u_64(arr[9]),); }
+
+@protected ChineseVariant dco_decode_chinese_variant(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ChineseVariant.values[raw as int]; }
+
+@protected DecodedText dco_decode_decoded_text(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return DecodedText(text: dco_decode_String(arr[0]),
+encoding: dco_decode_text_encoding(arr[1]),
+hasBom: dco_decode_bool(arr[2]),
+confidence: dco_decode_f_32(arr[3]),); }
+
+@protected EmbeddedLyric dco_decode_embedded_lyric(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return EmbeddedLyric(kind: dco_decode_embedded_lyric_kind(arr[0]),
+language: dco_decode_opt_String(arr[1]),
+description: dco_decode_String(arr[2]),
+text: dco_decode_String(arr[3]),); }
+
+@protected EmbeddedLyricKind dco_decode_embedded_lyric_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return EmbeddedLyricKind.values[raw as int]; }
+
+@protected EmbeddedPicture dco_decode_embedded_picture(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return EmbeddedPicture(index: dco_decode_u_32(arr[0]),
+pictureType: dco_decode_embedded_picture_type(arr[1]),
+mimeType: dco_decode_opt_String(arr[2]),
+description: dco_decode_opt_String(arr[3]),
+width: dco_decode_opt_box_autoadd_u_32(arr[4]),
+height: dco_decode_opt_box_autoadd_u_32(arr[5]),
+size: dco_decode_u_64(arr[6]),); }
+
+@protected EmbeddedPictureType dco_decode_embedded_picture_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return EmbeddedPictureType_Other();
+case 1: return EmbeddedPictureType_Icon();
+case 2: return EmbeddedPictureType_OtherIcon();
+case 3: return EmbeddedPictureType_CoverFront();
+case 4: return EmbeddedPictureType_CoverBack();
+case 5: return EmbeddedPictureType_Leaflet();
+case 6: return EmbeddedPictureType_Media();
+case 7: return EmbeddedPictureType_LeadArtist();
+case 8: return EmbeddedPictureType_Artist();
+case 9: return EmbeddedPictureType_Conductor();
+case 10: return EmbeddedPictureType_Band();
+case 11: return EmbeddedPictureType_Composer();
+case 12: return EmbeddedPictureType_Lyricist();
+case 13: return EmbeddedPictureType_RecordingLocation();
+case 14: return EmbeddedPictureType_DuringRecording();
+case 15: return EmbeddedPictureType_DuringPerformance();
+case 16: return EmbeddedPictureType_ScreenCapture();
+case 17: return EmbeddedPictureType_BrightFish();
+case 18: return EmbeddedPictureType_Illustration();
+case 19: return EmbeddedPictureType_BandLogo();
+case 20: return EmbeddedPictureType_PublisherLogo();
+case 21: return EmbeddedPictureType_Undefined(raw: dco_decode_u_8(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected double dco_decode_f_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected double dco_decode_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64(raw); }
+
+@protected IndexActionState dco_decode_index_action_state(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return IndexActionState(progress: dco_decode_f_64(arr[0]),
+message: dco_decode_String(arr[1]),); }
+
+@protected IndexAudio dco_decode_index_audio(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 12) throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+                return IndexAudio(title: dco_decode_String(arr[0]),
+artist: dco_decode_String(arr[1]),
+album: dco_decode_String(arr[2]),
+albumArtist: dco_decode_opt_String(arr[3]),
+track: dco_decode_u_32(arr[4]),
+duration: dco_decode_u_64(arr[5]),
+bitrate: dco_decode_opt_box_autoadd_u_32(arr[6]),
+sampleRate: dco_decode_opt_box_autoadd_u_32(arr[7]),
+path: dco_decode_String(arr[8]),
+modified: dco_decode_u_64(arr[9]),
+created: dco_decode_u_64(arr[10]),
+by: dco_decode_opt_String(arr[11]),); }
+
+@protected IndexCueTrack dco_decode_index_cue_track(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return IndexCueTrack(path: dco_decode_String(arr[0]),
+source: dco_decode_String(arr[1]),
+track: dco_decode_u_32(arr[2]),
+start: dco_decode_u_64(arr[3]),
+end: dco_decode_opt_box_autoadd_u_64(arr[4]),); }
+
+@protected IndexFolder dco_decode_index_folder(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return IndexFolder(path: dco_decode_String(arr[0]),
+modified: dco_decode_u_64(arr[1]),
+latest: dco_decode_u_64(arr[2]),
+audios: dco_decode_list_index_audio(arr[3]),); }
+
+@protected InstalledFont dco_decode_installed_font(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return InstalledFont(path: dco_decode_String(arr[0]),
+fullName: dco_decode_String(arr[1]),); }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<CachedLyric> dco_decode_list_cached_lyric(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_cached_lyric).toList(); }
+
+@protected List<EmbeddedLyric> dco_decode_list_embedded_lyric(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_embedded_lyric).toList(); }
+
+@protected List<EmbeddedPicture> dco_decode_list_embedded_picture(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_embedded_picture).toList(); }
+
+@protected List<IndexAudio> dco_decode_list_index_audio(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_index_audio).toList(); }
+
+@protected List<IndexFolder> dco_decode_list_index_folder(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_index_folder).toList(); }
+
+@protected List<InstalledFont> dco_decode_list_installed_font(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_installed_font).toList(); }
+
+@protected List<List<String>> dco_decode_list_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_list_String).toList(); }
+
+@protected List<Uint32List> dco_decode_list_list_prim_u_32_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_list_prim_u_32_strict).toList(); }
+
+@protected List<LyricAgent> dco_decode_list_lyric_agent(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_lyric_agent).toList(); }
+
+@protected List<LyricCandidate> dco_decode_list_lyric_candidate(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_lyric_candidate).toList(); }
+
+@protected List<LyricLine> dco_decode_list_lyric_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_lyric_line).toList(); }
+
+@protected List<LyricTag> dco_decode_list_lyric_tag(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_lyric_tag).toList(); }
+
+@protected List<LyricWord> dco_decode_list_lyric_word(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_lyric_word).toList(); }
+
+@protected List<MatchTrack> dco_decode_list_match_track(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_match_track).toList(); }
+
+@protected List<OutputDevice> dco_decode_list_output_device(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_output_device).toList(); }
+
+@protected Uint32List dco_decode_list_prim_u_32_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint32List; }
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<int>; }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<RankedMatch> dco_decode_list_ranked_match(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_ranked_match).toList(); }
+
+@protected Lyric dco_decode_lyric(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return Lyric(format: dco_decode_lyric_format(arr[0]),
+tags: dco_decode_list_lyric_tag(arr[1]),
+lines: dco_decode_list_lyric_line(arr[2]),
+agents: dco_decode_list_lyric_agent(arr[3]),); }
+
+@protected LyricAgent dco_decode_lyric_agent(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return LyricAgent(id: dco_decode_String(arr[0]),
+kind: dco_decode_String(arr[1]),
+name: dco_decode_opt_String(arr[2]),); }
+
+@protected LyricCandidate dco_decode_lyric_candidate(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return LyricCandidate(source: dco_decode_lyric_candidate_source(arr[0]),
+path: dco_decode_opt_String(arr[1]),
+extension_: dco_decode_opt_String(arr[2]),); }
+
+@protected LyricCandidateSource dco_decode_lyric_candidate_source(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LyricCandidateSource.values[raw as int]; }
+
+@protected LyricFormat dco_decode_lyric_format(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LyricFormat.values[raw as int]; }
+
+@protected LyricLine dco_decode_lyric_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return LyricLine(start: dco_decode_u_32(arr[0]),
+end: dco_decode_u_32(arr[1]),
+text: dco_decode_String(arr[2]),
+words: dco_decode_list_lyric_word(arr[3]),
+translation: dco_decode_opt_String(arr[4]),
+agent: dco_decode_opt_String(arr[5]),
+background: dco_decode_list_lyric_word(arr[6]),
+romanization: dco_decode_opt_String(arr[7]),); }
+
+@protected LyricSource dco_decode_lyric_source(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LyricSource.values[raw as int]; }
+
+@protected LyricTag dco_decode_lyric_tag(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return LyricTag(key: dco_decode_String(arr[0]),
+value: dco_decode_String(arr[1]),); }
+
+@protected LyricWord dco_decode_lyric_word(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return LyricWord(start: dco_decode_u_32(arr[0]),
+end: dco_decode_u_32(arr[1]),
+text: dco_decode_String(arr[2]),); }
+
+@protected MatchScore dco_decode_match_score(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return MatchScore(total: dco_decode_f_64(arr[0]),
+title: dco_decode_f_64(arr[1]),
+artist: dco_decode_opt_box_autoadd_f_64(arr[2]),
+duration: dco_decode_opt_box_autoadd_f_64(arr[3]),
+reasons: dco_decode_list_String(arr[4]),); }
+
+@protected MatchTrack dco_decode_match_track(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return MatchTrack(title: dco_decode_String(arr[0]),
+artist: dco_decode_String(arr[1]),
+duration: dco_decode_opt_box_autoadd_f_64(arr[2]),); }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected AlbumPalette? dco_decode_opt_box_autoadd_album_palette(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_album_palette(raw); }
+
+@protected CachedLyric? dco_decode_opt_box_autoadd_cached_lyric(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_cached_lyric(raw); }
+
+@protected EmbeddedPictureType? dco_decode_opt_box_autoadd_embedded_picture_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_embedded_picture_type(raw); }
+
+@protected double? dco_decode_opt_box_autoadd_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_f_64(raw); }
+
+@protected IndexCueTrack? dco_decode_opt_box_autoadd_index_cue_track(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_index_cue_track(raw); }
+
+@protected Lyric? dco_decode_opt_box_autoadd_lyric(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_lyric(raw); }
+
+@protected LyricFormat? dco_decode_opt_box_autoadd_lyric_format(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_lyric_format(raw); }
+
+@protected PictureWithSource? dco_decode_opt_box_autoadd_picture_with_source(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_picture_with_source(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_32(raw); }
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_64(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_8(raw); }
+
+@protected List<InstalledFont>? dco_decode_opt_list_installed_font(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_installed_font(raw); }
+
+@protected Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_prim_u_8_strict(raw); }
+
+@protected OutputDevice dco_decode_output_device(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return OutputDevice(id: dco_decode_String(arr[0]),
+name: dco_decode_String(arr[1]),
+formFactor: dco_decode_output_form_factor(arr[2]),
+isDefault: dco_decode_bool(arr[3]),
+state: dco_decode_output_device_state(arr[4]),); }
+
+@protected OutputDeviceEvent dco_decode_output_device_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return OutputDeviceEvent_Added(id: dco_decode_String(raw[1]),);
+case 1: return OutputDeviceEvent_Removed(id: dco_decode_String(raw[1]),);
+case 2: return OutputDeviceEvent_DefaultChanged(id: dco_decode_opt_String(raw[1]),);
+case 3: return OutputDeviceEvent_StateChanged(id: dco_decode_String(raw[1]),state: dco_decode_output_device_state(raw[2]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected OutputDeviceState dco_decode_output_device_state(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return OutputDeviceState.values[raw as int]; }
+
+@protected OutputFormFactor dco_decode_output_form_factor(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return OutputFormFactor.values[raw as int]; }
+
+@protected PaletteSwatch dco_decode_palette_swatch(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return PaletteSwatch(color: dco_decode_record_u_8_u_8_u_8_u_8(arr[0]),
+onColor: dco_decode_record_u_8_u_8_u_8_u_8(arr[1]),
+population: dco_decode_f_64(arr[2]),); }
+
+@protected PictureFilter dco_decode_picture_filter(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PictureFilter.values[raw as int]; }
+
+@protected PictureFit dco_decode_picture_fit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PictureFit.values[raw as int]; }
+
+@protected PictureFormat dco_decode_picture_format(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PictureFormat.values[raw as int]; }
+
+@protected PictureOptions dco_decode_picture_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return PictureOptions(width: dco_decode_u_32(arr[0]),
+height: dco_decode_u_32(arr[1]),
+devicePixelRatio: dco_decode_f_64(arr[2]),
+format: dco_decode_picture_format(arr[3]),
+quality: dco_decode_u_8(arr[4]),
+filter: dco_decode_picture_filter(arr[5]),
+fit: dco_decode_picture_fit(arr[6]),); }
+
+@protected PictureSource dco_decode_picture_source(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return PictureSource_Embedded();
+case 1: return PictureSource_Folder(path: dco_decode_String(raw[1]),);
+case 2: return PictureSource_System();
+                default: throw Exception("unreachable");
+            } }
+
+@protected PictureWithSource dco_decode_picture_with_source(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return PictureWithSource(data: dco_decode_list_prim_u_8_strict(arr[0]),
+source: dco_decode_picture_source(arr[1]),); }
+
+@protected RankedMatch dco_decode_ranked_match(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return RankedMatch(index: dco_decode_u_32(arr[0]),
+score: dco_decode_match_score(arr[1]),); }
+
+@protected (int,int,int,int) dco_decode_record_u_8_u_8_u_8_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 4) {
+                throw Exception('Expected 4 elements, got ${arr.length}');
+            }
+            return (dco_decode_u_8(arr[0]),dco_decode_u_8(arr[1]),dco_decode_u_8(arr[2]),dco_decode_u_8(arr[3]),); }
+
+@protected RomanizationKind dco_decode_romanization_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RomanizationKind.values[raw as int]; }
+
+@protected SMTCArtwork dco_decode_smtc_artwork(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return SMTCArtwork_None();
+case 1: return SMTCArtwork_Bytes(data: dco_decode_list_prim_u_8_strict(raw[1]),);
+case 2: return SMTCArtwork_File(path: dco_decode_String(raw[1]),);
+case 3: return SMTCArtwork_Thumbnail(path: dco_decode_String(raw[1]),width: dco_decode_u_32(raw[2]),height: dco_decode_u_32(raw[3]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected SMTCControlEvent dco_decode_smtc_control_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return SMTCControlEvent_Play();
+case 1: return SMTCControlEvent_Pause();
+case 2: return SMTCControlEvent_Previous();
+case 3: return SMTCControlEvent_Next();
+case 4: return SMTCControlEvent_Unknown();
+case 5: return SMTCControlEvent_Stop();
+case 6: return SMTCControlEvent_FastForward();
+case 7: return SMTCControlEvent_Rewind();
+case 8: return SMTCControlEvent_Seek(position: dco_decode_u_64(raw[1]),);
+case 9: return SMTCControlEvent_SeekBy(offset: dco_decode_i_64(raw[1]),);
+case 10: return SMTCControlEvent_Shuffle(enabled: dco_decode_bool(raw[1]),);
+case 11: return SMTCControlEvent_Repeat(mode: dco_decode_smtc_repeat_mode(raw[1]),);
+case 12: return SMTCControlEvent_Rate(rate: dco_decode_f_64(raw[1]),);
+case 13: return SMTCControlEvent_Volume(volume: dco_decode_f_64(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected SMTCError dco_decode_smtc_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return SMTCError_Init(message: dco_decode_String(raw[1]),);
+case 1: return SMTCError_Restore(message: dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected SMTCMetadata dco_decode_smtc_metadata(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return SMTCMetadata(title: dco_decode_String(arr[0]),
+artist: dco_decode_String(arr[1]),
+album: dco_decode_String(arr[2]),
+albumArtist: dco_decode_opt_String(arr[3]),
+trackNumber: dco_decode_opt_box_autoadd_u_32(arr[4]),
+genre: dco_decode_opt_String(arr[5]),
+duration: dco_decode_u_32(arr[6]),
+path: dco_decode_String(arr[7]),); }
+
+@protected SMTCRepeatMode dco_decode_smtc_repeat_mode(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SMTCRepeatMode.values[raw as int]; }
+
+@protected SMTCState dco_decode_smtc_state(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SMTCState.values[raw as int]; }
+
+@protected SMTCStatus dco_decode_smtc_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return SMTCStatus_Ready();
+case 1: return SMTCStatus_Unavailable(message: dco_decode_String(raw[1]),);
+case 2: return SMTCStatus_Failed(message: dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected SystemTheme dco_decode_system_theme(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return SystemTheme(fore: dco_decode_record_u_8_u_8_u_8_u_8(arr[0]),
+accent: dco_decode_record_u_8_u_8_u_8_u_8(arr[1]),); }
+
+@protected TextEncoding dco_decode_text_encoding(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TextEncoding.values[raw as int]; }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected BigInt dco_decode_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return AnyhowException(inner); }
+
+@protected SmtcFlutter sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SmtcFlutterImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected SmtcFlutter sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SmtcFlutterImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected SmtcFlutter sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SmtcFlutterImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected RustStreamSink<String> sse_decode_StreamSink_String_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<double> sse_decode_StreamSink_f_64_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<IndexActionState> sse_decode_StreamSink_index_action_state_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<OutputDeviceEvent> sse_decode_StreamSink_output_device_event_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<SMTCControlEvent> sse_decode_StreamSink_smtc_control_event_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected AlbumPalette sse_decode_album_palette(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_dominant = sse_decode_palette_swatch(deserializer);
+var var_vibrant = sse_decode_palette_swatch(deserializer);
+var var_lightVibrant = sse_decode_palette_swatch(deserializer);
+var var_darkVibrant = sse_decode_palette_swatch(deserializer);
+var var_muted = sse_decode_palette_swatch(deserializer);
+var var_lightMuted = sse_decode_palette_swatch(deserializer);
+var var_darkMuted = sse_decode_palette_swatch(deserializer);
+return AlbumPalette(dominant: var_dominant, vibrant: var_vibrant, lightVibrant: var_lightVibrant, darkVibrant: var_darkVibrant, muted: var_muted, lightMuted: var_lightMuted, darkMuted: var_darkMuted); }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected AlbumPalette sse_decode_box_autoadd_album_palette(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_album_palette(deserializer)); }
+
+@protected CachedLyric sse_decode_box_autoadd_cached_lyric(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_cached_lyric(deserializer)); }
+
+@protected EmbeddedPictureType sse_decode_box_autoadd_embedded_picture_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_embedded_picture_type(deserializer)); }
+
+@protected double sse_decode_box_autoadd_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_f_64(deserializer)); }
+
+@protected IndexCueTrack sse_decode_box_autoadd_index_cue_track(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_index_cue_track(deserializer)); }
+
+@protected Lyric sse_decode_box_autoadd_lyric(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_lyric(deserializer)); }
+
+@protected LyricCandidate sse_decode_box_autoadd_lyric_candidate(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_lyric_candidate(deserializer)); }
+
+@protected LyricFormat sse_decode_box_autoadd_lyric_format(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_lyric_format(deserializer)); }
+
+@protected MatchTrack sse_decode_box_autoadd_match_track(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_match_track(deserializer)); }
+
+@protected PictureOptions sse_decode_box_autoadd_picture_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_picture_options(deserializer)); }
+
+@protected PictureWithSource sse_decode_box_autoadd_picture_with_source(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_picture_with_source(deserializer)); }
+
+@protected SMTCArtwork sse_decode_box_autoadd_smtc_artwork(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_smtc_artwork(deserializer)); }
+
+@protected SMTCMetadata sse_decode_box_autoadd_smtc_metadata(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_smtc_metadata(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_32(deserializer)); }
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_64(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_8(deserializer)); }
+
+@protected CachedLyric sse_decode_cached_lyric(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_path = sse_decode_String(deserializer);
+var var_hash = sse_decode_String(deserializer);
+var var_source = sse_decode_lyric_source(deserializer);
+var var_provider = sse_decode_opt_String(deserializer);
+var var_format = sse_decode_opt_box_autoadd_lyric_format(deserializer);
+var var_raw = sse_decode_String(deserializer);
+var var_parsed = sse_decode_opt_box_autoadd_lyric(deserializer);
+var var_offset = sse_decode_i_64(deserializer);
+var var_preferred = sse_decode_bool(deserializer);
+var var_updated = sse_decode_u_64(deserializer);
+return CachedLyric(path: var_path, hash: var_hash, source: var_source, provider: var_provider, format: var_format, raw: var_raw, parsed: var_parsed, offset: var_offset, preferred: var_preferred, updated: var_updated); }
+
+@protected ChineseVariant sse_decode_chinese_variant(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ChineseVariant.values[inner]; }
+
+@protected DecodedText sse_decode_decoded_text(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_text = sse_decode_String(deserializer);
+var var_encoding = sse_decode_text_encoding(deserializer);
+var var_hasBom = sse_decode_bool(deserializer);
+var var_confidence = sse_decode_f_32(deserializer);
+return DecodedText(text: var_text, encoding: var_encoding, hasBom: var_hasBom, confidence: var_confidence); }
+
+@protected EmbeddedLyric sse_decode_embedded_lyric(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kind = sse_decode_embedded_lyric_kind(deserializer);
+var var_language = sse_decode_opt_String(deserializer);
+var var_description = sse_decode_String(deserializer);
+var var_text = sse_decode_String(deserializer);
+return EmbeddedLyric(kind: var_kind, language: var_language, description: var_description, text: var_text); }
+
+@protected EmbeddedLyricKind sse_decode_embedded_lyric_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return EmbeddedLyricKind.values[inner]; }
+
+@protected EmbeddedPicture sse_decode_embedded_picture(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_index = sse_decode_u_32(deserializer);
+var var_pictureType = sse_decode_embedded_picture_type(deserializer);
+var var_mimeType = sse_decode_opt_String(deserializer);
+var var_description = sse_decode_opt_String(deserializer);
+var var_width = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_height = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_size = sse_decode_u_64(deserializer);
+return EmbeddedPicture(index: var_index, pictureType: var_pictureType, mimeType: var_mimeType, description: var_description, width: var_width, height: var_height, size: var_size); }
+
+@protected EmbeddedPictureType sse_decode_embedded_picture_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return EmbeddedPictureType_Other();case 1: return EmbeddedPictureType_Icon();case 2: return EmbeddedPictureType_OtherIcon();case 3: return EmbeddedPictureType_CoverFront();case 4: return EmbeddedPictureType_CoverBack();case 5: return EmbeddedPictureType_Leaflet();case 6: return EmbeddedPictureType_Media();case 7: return EmbeddedPictureType_LeadArtist();case 8: return EmbeddedPictureType_Artist();case 9: return EmbeddedPictureType_Conductor();case 10: return EmbeddedPictureType_Band();case 11: return EmbeddedPictureType_Composer();case 12: return EmbeddedPictureType_Lyricist();case 13: return EmbeddedPictureType_RecordingLocation();case 14: return EmbeddedPictureType_DuringRecording();case 15: return EmbeddedPictureType_DuringPerformance();case 16: return EmbeddedPictureType_ScreenCapture();case 17: return EmbeddedPictureType_BrightFish();case 18: return EmbeddedPictureType_Illustration();case 19: return EmbeddedPictureType_BandLogo();case 20: return EmbeddedPictureType_PublisherLogo();case 21: var var_raw = sse_decode_u_8(deserializer);
+return EmbeddedPictureType_Undefined(raw: var_raw); default: throw UnimplementedError(''); }
+             }
+
+@protected double sse_decode_f_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat32(); }
+
+@protected double sse_decode_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat64(); }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getPlatformInt64(); }
+
+@protected IndexActionState sse_decode_index_action_state(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_progress = sse_decode_f_64(deserializer);
+var var_message = sse_decode_String(deserializer);
+return IndexActionState(progress: var_progress, message: var_message); }
+
+@protected IndexAudio sse_decode_index_audio(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_title = sse_decode_String(deserializer);
+var var_artist = sse_decode_String(deserializer);
+var var_album = sse_decode_String(deserializer);
+var var_albumArtist = sse_decode_opt_String(deserializer);
+var var_track = sse_decode_u_32(deserializer);
+var var_duration = sse_decode_u_64(deserializer);
+var var_bitrate = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_sampleRate = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_path = sse_decode_String(deserializer);
+var var_modified = sse_decode_u_64(deserializer);
+var var_created = sse_decode_u_64(deserializer);
+var var_by = sse_decode_opt_String(deserializer);
+return IndexAudio(title: var_title, artist: var_artist, album: var_album, albumArtist: var_albumArtist, track: var_track, duration: var_duration, bitrate: var_bitrate, sampleRate: var_sampleRate, path: var_path, modified: var_modified, created: var_created, by: var_by); }
+
+@protected IndexCueTrack sse_decode_index_cue_track(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_path = sse_decode_String(deserializer);
+var var_source = sse_decode_String(deserializer);
+var var_track = sse_decode_u_32(deserializer);
+var var_start = sse_decode_u_64(deserializer);
+var var_end = sse_decode_opt_box_autoadd_u_64(deserializer);
+return IndexCueTrack(path: var_path, source: var_source, track: var_track, start: var_start, end: var_end); }
+
+@protected IndexFolder sse_decode_index_folder(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_path = sse_decode_String(deserializer);
+var var_modified = sse_decode_u_64(deserializer);
+var var_latest = sse_decode_u_64(deserializer);
+var var_audios = sse_decode_list_index_audio(deserializer);
+return IndexFolder(path: var_path, modified: var_modified, latest: var_latest, audios: var_audios); }
+
+@protected InstalledFont sse_decode_installed_font(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_path = sse_decode_String(deserializer);
+var var_fullName = sse_decode_String(deserializer);
+return InstalledFont(path: var_path, fullName: var_fullName); }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<CachedLyric> sse_decode_list_cached_lyric(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <CachedLyric>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_cached_lyric(deserializer)); }
+        return ans_;
+         }
+
+@protected List<EmbeddedLyric> sse_decode_list_embedded_lyric(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <EmbeddedLyric>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_embedded_lyric(deserializer)); }
+        return ans_;
+         }
+
+@protected List<EmbeddedPicture> sse_decode_list_embedded_picture(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <EmbeddedPicture>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_embedded_picture(deserializer)); }
+        return ans_;
+         }
+
+@protected List<IndexAudio> sse_decode_list_index_audio(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <IndexAudio>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_index_audio(deserializer)); }
+        return ans_;
+         }
+
+@protected List<IndexFolder> sse_decode_list_index_folder(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <IndexFolder>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_index_folder(deserializer)); }
+        return ans_;
+         }
+
+@protected List<InstalledFont> sse_decode_list_installed_font(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <InstalledFont>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_installed_font(deserializer)); }
+        return ans_;
+         }
+
+@protected List<List<String>> sse_decode_list_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <List<String>>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_list_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Uint32List> sse_decode_list_list_prim_u_32_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Uint32List>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_list_prim_u_32_strict(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LyricAgent> sse_decode_list_lyric_agent(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LyricAgent>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_lyric_agent(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LyricCandidate> sse_decode_list_lyric_candidate(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LyricCandidate>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_lyric_candidate(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LyricLine> sse_decode_list_lyric_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LyricLine>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_lyric_line(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LyricTag> sse_decode_list_lyric_tag(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LyricTag>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_lyric_tag(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LyricWord> sse_decode_list_lyric_word(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LyricWord>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_lyric_word(deserializer)); }
+        return ans_;
+         }
+
+@protected List<MatchTrack> sse_decode_list_match_track(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <MatchTrack>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_match_track(deserializer)); }
+        return ans_;
+         }
+
+@protected List<OutputDevice> sse_decode_list_output_device(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <OutputDevice>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_output_device(deserializer)); }
+        return ans_;
+         }
+
+@protected Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint32List(len_); }
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<RankedMatch> sse_decode_list_ranked_match(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <RankedMatch>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_ranked_match(deserializer)); }
+        return ans_;
+         }
+
+@protected Lyric sse_decode_lyric(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_format = sse_decode_lyric_format(deserializer);
+var var_tags = sse_decode_list_lyric_tag(deserializer);
+var var_lines = sse_decode_list_lyric_line(deserializer);
+var var_agents = sse_decode_list_lyric_agent(deserializer);
+return Lyric(format: var_format, tags: var_tags, lines: var_lines, agents: var_agents); }
+
+@protected LyricAgent sse_decode_lyric_agent(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_kind = sse_decode_String(deserializer);
+var var_name = sse_decode_opt_String(deserializer);
+return LyricAgent(id: var_id, kind: var_kind, name: var_name); }
+
+@protected LyricCandidate sse_decode_lyric_candidate(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_source = sse_decode_lyric_candidate_source(deserializer);
+var var_path = sse_decode_opt_String(deserializer);
+var var_extension_ = sse_decode_opt_String(deserializer);
+return LyricCandidate(source: var_source, path: var_path, extension_: var_extension_); }
+
+@protected LyricCandidateSource sse_decode_lyric_candidate_source(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return LyricCandidateSource.values[inner]; }
+
+@protected LyricFormat sse_decode_lyric_format(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return LyricFormat.values[inner]; }
+
+@protected LyricLine sse_decode_lyric_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_start = sse_decode_u_32(deserializer);
+var var_end = sse_decode_u_32(deserializer);
+var var_text = sse_decode_String(deserializer);
+var var_words = sse_decode_list_lyric_word(deserializer);
+var var_translation = sse_decode_opt_String(deserializer);
+var var_agent = sse_decode_opt_String(deserializer);
+var var_background = sse_decode_list_lyric_word(deserializer);
+var var_romanization = sse_decode_opt_String(deserializer);
+return LyricLine(start: var_start, end: var_end, text: var_text, words: var_words, translation: var_translation, agent: var_agent, background: var_background, romanization: var_romanization); }
+
+@protected LyricSource sse_decode_lyric_source(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return LyricSource.values[inner]; }
+
+@protected LyricTag sse_decode_lyric_tag(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_key = sse_decode_String(deserializer);
+var var_value = sse_decode_String(deserializer);
+return LyricTag(key: var_key, value: var_value); }
+
+@protected LyricWord sse_decode_lyric_word(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_start = sse_decode_u_32(deserializer);
+var var_end = sse_decode_u_32(deserializer);
+var var_text = sse_decode_String(deserializer);
+return LyricWord(start: var_start, end: var_end, text: var_text); }
+
+@protected MatchScore sse_decode_match_score(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_total = sse_decode_f_64(deserializer);
+var var_title = sse_decode_f_64(deserializer);
+var var_artist = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_duration = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_reasons = sse_decode_list_String(deserializer);
+return MatchScore(total: var_total, title: var_title, artist: var_artist, duration: var_duration, reasons: var_reasons); }
+
+@protected MatchTrack sse_decode_match_track(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_title = sse_decode_String(deserializer);
+var var_artist = sse_decode_String(deserializer);
+var var_duration = sse_decode_opt_box_autoadd_f_64(deserializer);
+return MatchTrack(title: var_title, artist: var_artist, duration: var_duration); }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected AlbumPalette? sse_decode_opt_box_autoadd_album_palette(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_album_palette(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected CachedLyric? sse_decode_opt_box_autoadd_cached_lyric(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_cached_lyric(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected EmbeddedPictureType? sse_decode_opt_box_autoadd_embedded_picture_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_embedded_picture_type(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_f_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected IndexCueTrack? sse_decode_opt_box_autoadd_index_cue_track(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_index_cue_track(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Lyric? sse_decode_opt_box_autoadd_lyric(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_lyric(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected LyricFormat? sse_decode_opt_box_autoadd_lyric_format(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_lyric_format(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PictureWithSource? sse_decode_opt_box_autoadd_picture_with_source(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_picture_with_source(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_8(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected List<InstalledFont>? sse_decode_opt_list_installed_font(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_installed_font(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_prim_u_8_strict(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected OutputDevice sse_decode_output_device(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_formFactor = sse_decode_output_form_factor(deserializer);
+var var_isDefault = sse_decode_bool(deserializer);
+var var_state = sse_decode_output_device_state(deserializer);
+return OutputDevice(id: var_id, name: var_name, formFactor: var_formFactor, isDefault: var_isDefault, state: var_state); }
+
+@protected OutputDeviceEvent sse_decode_output_device_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_id = sse_decode_String(deserializer);
+return OutputDeviceEvent_Added(id: var_id);case 1: var var_id = sse_decode_String(deserializer);
+return OutputDeviceEvent_Removed(id: var_id);case 2: var var_id = sse_decode_opt_String(deserializer);
+return OutputDeviceEvent_DefaultChanged(id: var_id);case 3: var var_id = sse_decode_String(deserializer);
+var var_state = sse_decode_output_device_state(deserializer);
+return OutputDeviceEvent_StateChanged(id: var_id, state: var_state); default: throw UnimplementedError(''); }
+             }
+
+@protected OutputDeviceState sse_decode_output_device_state(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return OutputDeviceState.values[inner]; }
+
+@protected OutputFormFactor sse_decode_output_form_factor(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return OutputFormFactor.values[inner]; }
+
+@protected PaletteSwatch sse_decode_palette_swatch(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_color = sse_decode_record_u_8_u_8_u_8_u_8(deserializer);
+var var_onColor = sse_decode_record_u_8_u_8_u_8_u_8(deserializer);
+var var_population = sse_decode_f_64(deserializer);
+return PaletteSwatch(color: var_color, onColor: var_onColor, population: var_population); }
+
+@protected PictureFilter sse_decode_picture_filter(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return PictureFilter.values[inner]; }
+
+@protected PictureFit sse_decode_picture_fit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return PictureFit.values[inner]; }
+
+@protected PictureFormat sse_decode_picture_format(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return PictureFormat.values[inner]; }
+
+@protected PictureOptions sse_decode_picture_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_width = sse_decode_u_32(deserializer);
+var var_height = sse_decode_u_32(deserializer);
+var var_devicePixelRatio = sse_decode_f_64(deserializer);
+var var_format = sse_decode_picture_format(deserializer);
+var var_quality = sse_decode_u_8(deserializer);
+var var_filter = sse_decode_picture_filter(deserializer);
+var var_fit = sse_decode_picture_fit(deserializer);
+return PictureOptions(width: var_width, height: var_height, devicePixelRatio: var_devicePixelRatio, format: var_format, quality: var_quality, filter: var_filter, fit: var_fit); }
+
+@protected PictureSource sse_decode_picture_source(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return PictureSource_Embedded();case 1: var var_path = sse_decode_String(deserializer);
+return PictureSource_Folder(path: var_path);case 2: return PictureSource_System(); default: throw UnimplementedError(''); }
+             }
+
+@protected PictureWithSource sse_decode_picture_with_source(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+var var_source = sse_decode_picture_source(deserializer);
+return PictureWithSource(data: var_data, source: var_source); }
+
+@protected RankedMatch sse_decode_ranked_match(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_index = sse_decode_u_32(deserializer);
+var var_score = sse_decode_match_score(deserializer);
+return RankedMatch(index: var_index, score: var_score); }
+
+@protected (int,int,int,int) sse_decode_record_u_8_u_8_u_8_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_u_8(deserializer);
+var var_field1 = sse_decode_u_8(deserializer);
+var var_field2 = sse_decode_u_8(deserializer);
+var var_field3 = sse_decode_u_8(deserializer);
+return (var_field0, var_field1, var_field2, var_field3); }
+
+@protected RomanizationKind sse_decode_romanization_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return RomanizationKind.values[inner]; }
+
+@protected SMTCArtwork sse_decode_smtc_artwork(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return SMTCArtwork_None();case 1: var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+return SMTCArtwork_Bytes(data: var_data);case 2: var var_path = sse_decode_String(deserializer);
+return SMTCArtwork_File(path: var_path);case 3: var var_path = sse_decode_String(deserializer);
+var var_width = sse_decode_u_32(deserializer);
+var var_height = sse_decode_u_32(deserializer);
+return SMTCArtwork_Thumbnail(path: var_path, width: var_width, height: var_height); default: throw UnimplementedError(''); }
+             }
+
+@protected SMTCControlEvent sse_decode_smtc_control_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return SMTCControlEvent_Play();case 1: return SMTCControlEvent_Pause();case 2: return SMTCControlEvent_Previous();case 3: return SMTCControlEvent_Next();case 4: return SMTCControlEvent_Unknown();case 5: return SMTCControlEvent_Stop();case 6: return SMTCControlEvent_FastForward();case 7: return SMTCControlEvent_Rewind();case 8: var var_position = sse_decode_u_64(deserializer);
+return SMTCControlEvent_Seek(position: var_position);case 9: var var_offset = sse_decode_i_64(deserializer);
+return SMTCControlEvent_SeekBy(offset: var_offset);case 10: var var_enabled = sse_decode_bool(deserializer);
+return SMTCControlEvent_Shuffle(enabled: var_enabled);case 11: var var_mode = sse_decode_smtc_repeat_mode(deserializer);
+return SMTCControlEvent_Repeat(mode: var_mode);case 12: var var_rate = sse_decode_f_64(deserializer);
+return SMTCControlEvent_Rate(rate: var_rate);case 13: var var_volume = sse_decode_f_64(deserializer);
+return SMTCControlEvent_Volume(volume: var_volume); default: throw UnimplementedError(''); }
+             }
+
+@protected SMTCError sse_decode_smtc_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_message = sse_decode_String(deserializer);
+return SMTCError_Init(message: var_message);case 1: var var_message = sse_decode_String(deserializer);
+return SMTCError_Restore(message: var_message); default: throw UnimplementedError(''); }
+             }
+
+@protected SMTCMetadata sse_decode_smtc_metadata(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_title = sse_decode_String(deserializer);
+var var_artist = sse_decode_String(deserializer);
+var var_album = sse_decode_String(deserializer);
+var var_albumArtist = sse_decode_opt_String(deserializer);
+var var_trackNumber = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_genre = sse_decode_opt_String(deserializer);
+var var_duration = sse_decode_u_32(deserializer);
+var var_path = sse_decode_String(deserializer);
+return SMTCMetadata(title: var_title, artist: var_artist, album: var_album, albumArtist: var_albumArtist, trackNumber: var_trackNumber, genre: var_genre, duration: var_duration, path: var_path); }
+
+@protected SMTCRepeatMode sse_decode_smtc_repeat_mode(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return SMTCRepeatMode.values[inner]; }
+
+@protected SMTCState sse_decode_smtc_state(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return SMTCState.values[inner]; }
+
+@protected SMTCStatus sse_decode_smtc_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return SMTCStatus_Ready();case 1: var var_message = sse_decode_String(deserializer);
+return SMTCStatus_Unavailable(message: var_message);case 2: var var_message = sse_decode_String(deserializer);
+return SMTCStatus_Failed(message: var_message); default: throw UnimplementedError(''); }
+             }
+
+@protected SystemTheme sse_decode_system_theme(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_fore = sse_decode_record_u_8_u_8_u_8_u_8(deserializer);
+var var_accent = sse_decode_record_u_8_u_8_u_8_u_8(deserializer);
+return SystemTheme(fore: var_fore, accent: var_accent); }
+
+@protected TextEncoding sse_decode_text_encoding(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return TextEncoding.values[inner]; }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.message, serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(SmtcFlutter self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SmtcFlutterImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(SmtcFlutter self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SmtcFlutterImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(SmtcFlutter self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SmtcFlutterImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_StreamSink_String_Sse(RustStreamSink<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_String,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_f_64_Sse(RustStreamSink<double> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_f_64,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_index_action_state_Sse(RustStreamSink<IndexActionState> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_index_action_state,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_output_device_event_Sse(RustStreamSink<OutputDeviceEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_output_device_event,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_smtc_control_event_Sse(RustStreamSink<SMTCControlEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_smtc_control_event,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_album_palette(AlbumPalette self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_palette_swatch(self.dominant, serializer);
+sse_encode_palette_swatch(self.vibrant, serializer);
+sse_encode_palette_swatch(self.lightVibrant, serializer);
+sse_encode_palette_swatch(self.darkVibrant, serializer);
+sse_encode_palette_swatch(self.muted, serializer);
+sse_encode_palette_swatch(self.lightMuted, serializer);
+sse_encode_palette_swatch(self.darkMuted, serializer);
+ }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_album_palette(AlbumPalette self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_album_palette(self, serializer); }
+
+@protected void sse_encode_box_autoadd_cached_lyric(CachedLyric self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_cached_lyric(self, serializer); }
+
+@protected void sse_encode_box_autoadd_embedded_picture_type(EmbeddedPictureType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_embedded_picture_type(self, serializer); }
+
+@protected void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_index_cue_track(IndexCueTrack self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_index_cue_track(self, serializer); }
+
+@protected void sse_encode_box_autoadd_lyric(Lyric self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_lyric(self, serializer); }
+
+@protected void sse_encode_box_autoadd_lyric_candidate(LyricCandidate self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_lyric_candidate(self, serializer); }
+
+@protected void sse_encode_box_autoadd_lyric_format(LyricFormat self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_lyric_format(self, serializer); }
+
+@protected void sse_encode_box_autoadd_match_track(MatchTrack self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_match_track(self, serializer); }
+
+@protected void sse_encode_box_autoadd_picture_options(PictureOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_picture_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_picture_with_source(PictureWithSource self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_picture_with_source(self, serializer); }
+
+@protected void sse_encode_box_autoadd_smtc_artwork(SMTCArtwork self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_smtc_artwork(self, serializer); }
+
+@protected void sse_encode_box_autoadd_smtc_metadata(SMTCMetadata self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_smtc_metadata(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_8(self, serializer); }
+
+@protected void sse_encode_cached_lyric(CachedLyric self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.path, serializer);
+sse_encode_String(self.hash, serializer);
+sse_encode_lyric_source(self.source, serializer);
+sse_encode_opt_String(self.provider, serializer);
+sse_encode_opt_box_autoadd_lyric_format(self.format, serializer);
+sse_encode_String(self.raw, serializer);
+sse_encode_opt_box_autoadd_lyric(self.parsed, serializer);
+sse_encode_i_64(self.offset, serializer);
+sse_encode_bool(self.preferred, serializer);
+sse_encode_u_64(self.updated, serializer);
+ }
+
+@protected void sse_encode_chinese_variant(ChineseVariant self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_decoded_text(DecodedText self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.text, serializer);
+sse_encode_text_encoding(self.encoding, serializer);
+sse_encode_bool(self.hasBom, serializer);
+sse_encode_f_32(self.confidence, serializer);
+ }
+
+@protected void sse_encode_embedded_lyric(EmbeddedLyric self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_embedded_lyric_kind(self.kind, serializer);
+sse_encode_opt_String(self.language, serializer);
+sse_encode_String(self.description, serializer);
+sse_encode_String(self.text, serializer);
+ }
+
+@protected void sse_encode_embedded_lyric_kind(EmbeddedLyricKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_embedded_picture(EmbeddedPicture self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.index, serializer);
+sse_encode_embedded_picture_type(self.pictureType, serializer);
+sse_encode_opt_String(self.mimeType, serializer);
+sse_encode_opt_String(self.description, serializer);
+sse_encode_opt_box_autoadd_u_32(self.width, serializer);
+sse_encode_opt_box_autoadd_u_32(self.height, serializer);
+sse_encode_u_64(self.size, serializer);
+ }
+
+@protected void sse_encode_embedded_picture_type(EmbeddedPictureType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case EmbeddedPictureType_Other(): sse_encode_i_32(0, serializer); case EmbeddedPictureType_Icon(): sse_encode_i_32(1, serializer); case EmbeddedPictureType_OtherIcon(): sse_encode_i_32(2, serializer); case EmbeddedPictureType_CoverFront(): sse_encode_i_32(3, serializer); case EmbeddedPictureType_CoverBack(): sse_encode_i_32(4, serializer); case EmbeddedPictureType_Leaflet(): sse_encode_i_32(5, serializer); case EmbeddedPictureType_Media(): sse_encode_i_32(6, serializer); case EmbeddedPictureType_LeadArtist(): sse_encode_i_32(7, serializer); case EmbeddedPictureType_Artist(): sse_encode_i_32(8, serializer); case EmbeddedPictureType_Conductor(): sse_encode_i_32(9, serializer); case EmbeddedPictureType_Band(): sse_encode_i_32(10, serializer); case EmbeddedPictureType_Composer(): sse_encode_i_32(11, serializer); case EmbeddedPictureType_Lyricist(): sse_encode_i_32(12, serializer); case EmbeddedPictureType_RecordingLocation(): sse_encode_i_32(13, serializer); case EmbeddedPictureType_DuringRecording(): sse_encode_i_32(14, serializer); case EmbeddedPictureType_DuringPerformance(): sse_encode_i_32(15, serializer); case EmbeddedPictureType_ScreenCapture(): sse_encode_i_32(16, serializer); case EmbeddedPictureType_BrightFish(): sse_encode_i_32(17, serializer); case EmbeddedPictureType_Illustration(): sse_encode_i_32(18, serializer); case EmbeddedPictureType_BandLogo(): sse_encode_i_32(19, serializer); case EmbeddedPictureType_PublisherLogo(): sse_encode_i_32(20, serializer); case EmbeddedPictureType_Undefined(raw: final raw): sse_encode_i_32(21, serializer); sse_encode_u_8(raw, serializer);
+  } }
+
+@protected void sse_encode_f_32(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat32(self); }
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat64(self); }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putPlatformInt64(self); }
+
+@protected void sse_encode_index_action_state(IndexActionState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self.progress, serializer);
+sse_encode_String(self.message, serializer);
+ }
+
+@protected void sse_encode_index_audio(IndexAudio self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.title, serializer);
+sse_encode_String(self.artist, serializer);
+sse_encode_String(self.album, serializer);
+sse_encode_opt_String(self.albumArtist, serializer);
+sse_encode_u_32(self.track, serializer);
+sse_encode_u_64(self.duration, serializer);
+sse_encode_opt_box_autoadd_u_32(self.bitrate, serializer);
+sse_encode_opt_box_autoadd_u_32(self.sampleRate, serializer);
+sse_encode_String(self.path, serializer);
+sse_encode_u_64(self.modified, serializer);
+sse_encode_u_64(self.created, serializer);
+sse_encode_opt_String(self.by, serializer);
+ }
+
+@protected void sse_encode_index_cue_track(IndexCueTrack self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.path, serializer);
+sse_encode_String(self.source, serializer);
+sse_encode_u_32(self.track, serializer);
+sse_encode_u_64(self.start, serializer);
+sse_encode_opt_box_autoadd_u_64(self.end, serializer);
+ }
+
+@protected void sse_encode_index_folder(IndexFolder self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.path, serializer);
+sse_encode_u_64(self.modified, serializer);
+sse_encode_u_64(self.latest, serializer);
+sse_encode_list_index_audio(self.audios, serializer);
+ }
+
+@protected void sse_encode_installed_font(InstalledFont self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.path, serializer);
+sse_encode_String(self.fullName, serializer);
+ }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_cached_lyric(List<CachedLyric> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_cached_lyric(item, serializer); } }
+
+@protected void sse_encode_list_embedded_lyric(List<EmbeddedLyric> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_embedded_lyric(item, serializer); } }
+
+@protected void sse_encode_list_embedded_picture(List<EmbeddedPicture> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_embedded_picture(item, serializer); } }
+
+@protected void sse_encode_list_index_audio(List<IndexAudio> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_index_audio(item, serializer); } }
+
+@protected void sse_encode_list_index_folder(List<IndexFolder> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_index_folder(item, serializer); } }
+
+@protected void sse_encode_list_installed_font(List<InstalledFont> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_installed_font(item, serializer); } }
+
+@protected void sse_encode_list_list_String(List<List<String>> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_list_String(item, serializer); } }
+
+@protected void sse_encode_list_list_prim_u_32_strict(List<Uint32List> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_list_prim_u_32_strict(item, serializer); } }
+
+@protected void sse_encode_list_lyric_agent(List<LyricAgent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_lyric_agent(item, serializer); } }
+
+@protected void sse_encode_list_lyric_candidate(List<LyricCandidate> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_lyric_candidate(item, serializer); } }
+
+@protected void sse_encode_list_lyric_line(List<LyricLine> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_lyric_line(item, serializer); } }
+
+@protected void sse_encode_list_lyric_tag(List<LyricTag> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_lyric_tag(item, serializer); } }
+
+@protected void sse_encode_list_lyric_word(List<LyricWord> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_lyric_word(item, serializer); } }
+
+@protected void sse_encode_list_match_track(List<MatchTrack> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_match_track(item, serializer); } }
+
+@protected void sse_encode_list_output_device(List<OutputDevice> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_output_device(item, serializer); } }
+
+@protected void sse_encode_list_prim_u_32_strict(Uint32List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint32List(self); }
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self is Uint8List ? self : Uint8List.fromList(self)); }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_ranked_match(List<RankedMatch> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_ranked_match(item, serializer); } }
+
+@protected void sse_encode_lyric(Lyric self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_lyric_format(self.format, serializer);
+sse_encode_list_lyric_tag(self.tags, serializer);
+sse_encode_list_lyric_line(self.lines, serializer);
+sse_encode_list_lyric_agent(self.agents, serializer);
+ }
+
+@protected void sse_encode_lyric_agent(LyricAgent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.kind, serializer);
+sse_encode_opt_String(self.name, serializer);
+ }
+
+@protected void sse_encode_lyric_candidate(LyricCandidate self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_lyric_candidate_source(self.source, serializer);
+sse_encode_opt_String(self.path, serializer);
+sse_encode_opt_String(self.extension_, serializer);
+ }
+
+@protected void sse_encode_lyric_candidate_source(LyricCandidateSource self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_lyric_format(LyricFormat self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_lyric_line(LyricLine self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.start, serializer);
+sse_encode_u_32(self.end, serializer);
+sse_encode_String(self.text, serializer);
+sse_encode_list_lyric_word(self.words, serializer);
+sse_encode_opt_String(self.translation, serializer);
+sse_encode_opt_String(self.agent, serializer);
+sse_encode_list_lyric_word(self.background, serializer);
+sse_encode_opt_String(self.romanization, serializer);
+ }
+
+@protected void sse_encode_lyric_source(LyricSource self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_lyric_tag(LyricTag self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.key, serializer);
+sse_encode_String(self.value, serializer);
+ }
+
+@protected void sse_encode_lyric_word(LyricWord self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.start, serializer);
+sse_encode_u_32(self.end, serializer);
+sse_encode_String(self.text, serializer);
+ }
+
+@protected void sse_encode_match_score(MatchScore self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self.total, serializer);
+sse_encode_f_64(self.title, serializer);
+sse_encode_opt_box_autoadd_f_64(self.artist, serializer);
+sse_encode_opt_box_autoadd_f_64(self.duration, serializer);
+sse_encode_list_String(self.reasons, serializer);
+ }
+
+@protected void sse_encode_match_track(MatchTrack self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.title, serializer);
+sse_encode_String(self.artist, serializer);
+sse_encode_opt_box_autoadd_f_64(self.duration, serializer);
+ }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_album_palette(AlbumPalette? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_album_palette(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_cached_lyric(CachedLyric? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_cached_lyric(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_embedded_picture_type(EmbeddedPictureType? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_embedded_picture_type(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_f_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_index_cue_track(IndexCueTrack? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_index_cue_track(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_lyric(Lyric? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_lyric(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_lyric_format(LyricFormat? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_lyric_format(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_picture_with_source(PictureWithSource? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_picture_with_source(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_8(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_installed_font(List<InstalledFont>? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_installed_font(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_prim_u_8_strict(Uint8List? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_prim_u_8_strict(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_output_device(OutputDevice self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_output_form_factor(self.formFactor, serializer);
+sse_encode_bool(self.isDefault, serializer);
+sse_encode_output_device_state(self.state, serializer);
+ }
+
+@protected void sse_encode_output_device_event(OutputDeviceEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case OutputDeviceEvent_Added(id: final id): sse_encode_i_32(0, serializer); sse_encode_String(id, serializer);
+case OutputDeviceEvent_Removed(id: final id): sse_encode_i_32(1, serializer); sse_encode_String(id, serializer);
+case OutputDeviceEvent_DefaultChanged(id: final id): sse_encode_i_32(2, serializer); sse_encode_opt_String(id, serializer);
+case OutputDeviceEvent_StateChanged(id: final id,state: final state): sse_encode_i_32(3, serializer); sse_encode_String(id, serializer);
+sse_encode_output_device_state(state, serializer);
+  } }
+
+@protected void sse_encode_output_device_state(OutputDeviceState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_output_form_factor(OutputFormFactor self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_palette_swatch(PaletteSwatch self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_record_u_8_u_8_u_8_u_8(self.color, serializer);
+sse_encode_record_u_8_u_8_u_8_u_8(self.onColor, serializer);
+sse_encode_f_64(self.population, serializer);
+ }
+
+@protected void sse_encode_picture_filter(PictureFilter self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_picture_fit(PictureFit self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_picture_format(PictureFormat self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_picture_options(PictureOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.width, serializer);
+sse_encode_u_32(self.height, serializer);
+sse_encode_f_64(self.devicePixelRatio, serializer);
+sse_encode_picture_format(self.format, serializer);
+sse_encode_u_8(self.quality, serializer);
+sse_encode_picture_filter(self.filter, serializer);
+sse_encode_picture_fit(self.fit, serializer);
+ }
+
+@protected void sse_encode_picture_source(PictureSource self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case PictureSource_Embedded(): sse_encode_i_32(0, serializer); case PictureSource_Folder(path: final path): sse_encode_i_32(1, serializer); sse_encode_String(path, serializer);
+case PictureSource_System(): sse_encode_i_32(2, serializer);   } }
+
+@protected void sse_encode_picture_with_source(PictureWithSource self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(self.data, serializer);
+sse_encode_picture_source(self.source, serializer);
+ }
+
+@protected void sse_encode_ranked_match(RankedMatch self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.index, serializer);
+sse_encode_match_score(self.score, serializer);
+ }
+
+@protected void sse_encode_record_u_8_u_8_u_8_u_8((int,int,int,int) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_8(self.$1, serializer);
+sse_encode_u_8(self.$2, serializer);
+sse_encode_u_8(self.$3, serializer);
+sse_encode_u_8(self.$4, serializer);
+ }
+
+@protected void sse_encode_romanization_kind(RomanizationKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_smtc_artwork(SMTCArtwork self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case SMTCArtwork_None(): sse_encode_i_32(0, serializer); case SMTCArtwork_Bytes(data: final data): sse_encode_i_32(1, serializer); sse_encode_list_prim_u_8_strict(data, serializer);
+case SMTCArtwork_File(path: final path): sse_encode_i_32(2, serializer); sse_encode_String(path, serializer);
+case SMTCArtwork_Thumbnail(path: final path,width: final width,height: final height): sse_encode_i_32(3, serializer); sse_encode_String(path, serializer);
+sse_encode_u_32(width, serializer);
+sse_encode_u_32(height, serializer);
+  } }
+
+@protected void sse_encode_smtc_control_event(SMTCControlEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case SMTCControlEvent_Play(): sse_encode_i_32(0, serializer); case SMTCControlEvent_Pause(): sse_encode_i_32(1, serializer); case SMTCControlEvent_Previous(): sse_encode_i_32(2, serializer); case SMTCControlEvent_Next(): sse_encode_i_32(3, serializer); case SMTCControlEvent_Unknown(): sse_encode_i_32(4, serializer); case SMTCControlEvent_Stop(): sse_encode_i_32(5, serializer); case SMTCControlEvent_FastForward(): sse_encode_i_32(6, serializer); case SMTCControlEvent_Rewind(): sse_encode_i_32(7, serializer); case SMTCControlEvent_Seek(position: final position): sse_encode_i_32(8, serializer); sse_encode_u_64(position, serializer);
+case SMTCControlEvent_SeekBy(offset: final offset): sse_encode_i_32(9, serializer); sse_encode_i_64(offset, serializer);
+case SMTCControlEvent_Shuffle(enabled: final enabled): sse_encode_i_32(10, serializer); sse_encode_bool(enabled, serializer);
+case SMTCControlEvent_Repeat(mode: final mode): sse_encode_i_32(11, serializer); sse_encode_smtc_repeat_mode(mode, serializer);
+case SMTCControlEvent_Rate(rate: final rate): sse_encode_i_32(12, serializer); sse_encode_f_64(rate, serializer);
+case SMTCControlEvent_Volume(volume: final volume): sse_encode_i_32(13, serializer); sse_encode_f_64(volume, serializer);
+  } }
+
+@protected void sse_encode_smtc_error(SMTCError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case SMTCError_Init(message: final message): sse_encode_i_32(0, serializer); sse_encode_String(message, serializer);
+case SMTCError_Restore(message: final message): sse_encode_i_32(1, serializer); sse_encode_String(message, serializer);
+  } }
+
+@protected void sse_encode_smtc_metadata(SMTCMetadata self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.title, serializer);
+sse_encode_String(self.artist, serializer);
+sse_encode_String(self.album, serializer);
+sse_encode_opt_String(self.albumArtist, serializer);
+sse_encode_opt_box_autoadd_u_32(self.trackNumber, serializer);
+sse_encode_opt_String(self.genre, serializer);
+sse_encode_u_32(self.duration, serializer);
+sse_encode_String(self.path, serializer);
+ }
+
+@protected void sse_encode_smtc_repeat_mode(SMTCRepeatMode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_smtc_state(SMTCState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_smtc_status(SMTCStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case SMTCStatus_Ready(): sse_encode_i_32(0, serializer); case SMTCStatus_Unavailable(message: final message): sse_encode_i_32(1, serializer); sse_encode_String(message, serializer);
+case SMTCStatus_Failed(message: final message): sse_encode_i_32(2, serializer); sse_encode_String(message, serializer);
+  } }
+
+@protected void sse_encode_system_theme(SystemTheme self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_record_u_8_u_8_u_8_u_8(self.fore, serializer);
+sse_encode_record_u_8_u_8_u_8_u_8(self.accent, serializer);
+ }
+
+@protected void sse_encode_text_encoding(TextEncoding self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+                }
+                
+
+            @sealed class SmtcFlutterImpl extends RustOpaque implements SmtcFlutter {
+                // Not to be used by end users
+                SmtcFlutterImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                SmtcFlutterImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_SmtcFlutter,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_SmtcFlutter,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_SmtcFlutterPtr,
+                );
+
+                /// Apis for Flutter
+ Future<void>  close()=>RustLib.instance.api.crateApiSmtcFlutterSmtcFlutterClose(that: this, );
+
+
+/// 关闭当前的媒体控件并重新创建，恢复事件订阅、播放状态、显示内容、随机播放、循环模式和时间线
+/// Apis for Flutter
+ Future<void>  reinitialize()=>RustLib.instance.api.crateApiSmtcFlutterSmtcFlutterReinitialize(that: this, );
+
+
+/// Apis for Flutter
+ SMTCStatus  status()=>RustLib.instance.api.crateApiSmtcFlutterSmtcFlutterStatus(that: this, );
+
+
+/// Apis for Flutter
+ Stream<SMTCControlEvent>  subscribeToControlEvents()=>RustLib.instance.api.crateApiSmtcFlutterSmtcFlutterSubscribeToControlEvents(that: this, );
+
+
+/// 封面使用 256x256 的缩略图。需要更多信息时使用 [SMTCFlutter::update_metadata]
+/// Apis for Flutter
+ Future<void>  updateDisplay({required String title , required String artist , required String album , required int duration , required String path })=>RustLib.instance.api.crateApiSmtcFlutterSmtcFlutterUpdateDisplay(that: this, title: title, artist: artist, album: album, duration: duration, path: path);
+
+
+/// Apis for Flutter
+ Future<void>  updateMetadata({required SMTCMetadata metadata , required SMTCArtwork artwork })=>RustLib.instance.api.crateApiSmtcFlutterSmtcFlutterUpdateMetadata(that: this, metadata: metadata, artwork: artwork);
+
+
+/// 同步播放器的播放速度
+/// Apis for Flutter
+ Future<void>  updatePlaybackRate({required double rate })=>RustLib.instance.api.crateApiSmtcFlutterSmtcFlutterUpdatePlaybackRate(that: this, rate: rate);
+
+
+/// 同步播放器的循环模式
+/// Apis for Flutter
+ Future<void>  updateRepeatMode({required SMTCRepeatMode mode })=>RustLib.instance.api.crateApiSmtcFlutterSmtcFlutterUpdateRepeatMode(that: this, mode: mode);
+
+
+/// 同步播放器的随机播放状态
+/// Apis for Flutter
+ Future<void>  updateShuffle({required bool enabled })=>RustLib.instance.api.crateApiSmtcFlutterSmtcFlutterUpdateShuffle(that: this, enabled: enabled);
+
+
+/// Apis for Flutter
+ Future<void>  updateState({required SMTCState state })=>RustLib.instance.api.crateApiSmtcFlutterSmtcFlutterUpdateState(that: this, state: state);
+
+
+/// progress: ms。播放时每秒最多发送一次，跳转时立即发送
+/// Apis for Flutter
+ Future<void>  updateTimeProperties({required int progress })=>RustLib.instance.api.crateApiSmtcFlutterSmtcFlutterUpdateTimeProperties(that: this, progress: progress);
+
+
+            }
\ No newline at end of file
@@ -3,13 +3,20 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/album_palette.dart';
+import 'api/embedded_picture.dart';
 import 'api/installed_font.dart';
 import 'api/library_db.dart';
 import 'api/logger.dart';
+import 'api/lyric.dart';
+import 'api/music_matcher.dart';
+import 'api/picture_cache.dart';
 import 'api/smtc_flutter.dart';
 import 'api/system_theme.dart';
 import 'api/system_volume.dart';
 import 'api/tag_reader.dart';
+import 'api/text_convert.dart';
+import 'api/text_encoding.dart';
 import 'api/utils.dart';
 import 'dart:async';
 import 'dart:convert';
@@ -17,418 +24,764 @@ import 'dart:ffi' as ffi;
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_io.dart';
 
-abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
-  RustLibApiImplPlatform({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
 
-  CrossPlatformFinalizerArg
-      get rust_arc_decrement_strong_count_SmtcFlutterPtr => wire
-          ._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutterPtr;
 
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw);
 
-  @protected
-  SmtcFlutter
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-          dynamic raw);
+                abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+                  RustLibApiImplPlatform({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
 
-  @protected
-  SmtcFlutter
-      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-          dynamic raw);
+                  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SmtcFlutterPtr => wire._rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutterPtr;
 
-  @protected
-  SmtcFlutter
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-          dynamic raw);
 
-  @protected
-  RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw);
 
-  @protected
-  RustStreamSink<double> dco_decode_StreamSink_f_64_Sse(dynamic raw);
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw);
 
-  @protected
-  RustStreamSink<IndexActionState> dco_decode_StreamSink_index_action_state_Sse(
-      dynamic raw);
+@protected SmtcFlutter dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(dynamic raw);
 
-  @protected
-  RustStreamSink<SMTCControlEvent> dco_decode_StreamSink_smtc_control_event_Sse(
-      dynamic raw);
+@protected SmtcFlutter dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(dynamic raw);
 
-  @protected
-  String dco_decode_String(dynamic raw);
+@protected SmtcFlutter dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(dynamic raw);
 
-  @protected
-  bool dco_decode_bool(dynamic raw);
+@protected RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw);
 
-  @protected
-  int dco_decode_box_autoadd_u_32(dynamic raw);
+@protected RustStreamSink<double> dco_decode_StreamSink_f_64_Sse(dynamic raw);
 
-  @protected
-  double dco_decode_f_64(dynamic raw);
+@protected RustStreamSink<IndexActionState> dco_decode_StreamSink_index_action_state_Sse(dynamic raw);
 
-  @protected
-  int dco_decode_i_32(dynamic raw);
+@protected RustStreamSink<OutputDeviceEvent> dco_decode_StreamSink_output_device_event_Sse(dynamic raw);
 
-  @protected
-  IndexActionState dco_decode_index_action_state(dynamic raw);
+@protected RustStreamSink<SMTCControlEvent> dco_decode_StreamSink_smtc_control_event_Sse(dynamic raw);
 
-  @protected
-  IndexAudio dco_decode_index_audio(dynamic raw);
+@protected String dco_decode_String(dynamic raw);
 
-  @protected
-  IndexFolder dco_decode_index_folder(dynamic raw);
+@protected AlbumPalette dco_decode_album_palette(dynamic raw);
 
-  @protected
-  InstalledFont dco_decode_installed_font(dynamic raw);
+@protected bool dco_decode_bool(dynamic raw);
 
-  @protected
-  List<String> dco_decode_list_String(dynamic raw);
+@protected AlbumPalette dco_decode_box_autoadd_album_palette(dynamic raw);
 
-  @protected
-  List<IndexAudio> dco_decode_list_index_audio(dynamic raw);
+@protected CachedLyric dco_decode_box_autoadd_cached_lyric(dynamic raw);
 
-  @protected
-  List<IndexFolder> dco_decode_list_index_folder(dynamic raw);
+@protected EmbeddedPictureType dco_decode_box_autoadd_embedded_picture_type(dynamic raw);
 
-  @protected
-  List<InstalledFont> dco_decode_list_installed_font(dynamic raw);
+@protected double dco_decode_box_autoadd_f_64(dynamic raw);
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+@protected IndexCueTrack dco_decode_box_autoadd_index_cue_track(dynamic raw);
 
-  @protected
-  String? dco_decode_opt_String(dynamic raw);
+@protected Lyric dco_decode_box_autoadd_lyric(dynamic raw);
 
-  @protected
-  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+@protected LyricCandidate dco_decode_box_autoadd_lyric_candidate(dynamic raw);
 
-  @protected
-  List<InstalledFont>? dco_decode_opt_list_installed_font(dynamic raw);
+@protected LyricFormat dco_decode_box_autoadd_lyric_format(dynamic raw);
 
-  @protected
-  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
+@protected MatchTrack dco_decode_box_autoadd_match_track(dynamic raw);
 
-  @protected
-  (int, int, int, int) dco_decode_record_u_8_u_8_u_8_u_8(dynamic raw);
+@protected PictureOptions dco_decode_box_autoadd_picture_options(dynamic raw);
 
-  @protected
-  SMTCControlEvent dco_decode_smtc_control_event(dynamic raw);
+@protected PictureWithSource dco_decode_box_autoadd_picture_with_source(dynamic raw);
 
-  @protected
-  SMTCState dco_decode_smtc_state(dynamic raw);
+@protected SMTCArtwork dco_decode_box_autoadd_smtc_artwork(dynamic raw);
 
-  @protected
-  SystemTheme dco_decode_system_theme(dynamic raw);
+@protected SMTCMetadata dco_decode_box_autoadd_smtc_metadata(dynamic raw);
 
-  @protected
-  int dco_decode_u_32(dynamic raw);
+@protected int dco_decode_box_autoadd_u_32(dynamic raw);
 
-  @protected
-  BigInt dco_decode_u_64(dynamic raw);
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
-  @protected
-  int dco_decode_u_8(dynamic raw);
+@protected int dco_decode_box_autoadd_u_8(dynamic raw);
 
-  @protected
-  void dco_decode_unit(dynamic raw);
+@protected CachedLyric dco_decode_cached_lyric(dynamic raw);
 
-  @protected
-  BigInt dco_decode_usize(dynamic raw);
+@protected ChineseVariant dco_decode_chinese_variant(dynamic raw);
 
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+@protected DecodedText dco_decode_decoded_text(dynamic raw);
 
-  @protected
-  SmtcFlutter
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-          SseDeserializer deserializer);
+@protected EmbeddedLyric dco_decode_embedded_lyric(dynamic raw);
 
-  @protected
-  SmtcFlutter
-      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-          SseDeserializer deserializer);
+@protected EmbeddedLyricKind dco_decode_embedded_lyric_kind(dynamic raw);
 
-  @protected
-  SmtcFlutter
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-          SseDeserializer deserializer);
+@protected EmbeddedPicture dco_decode_embedded_picture(dynamic raw);
 
-  @protected
-  RustStreamSink<String> sse_decode_StreamSink_String_Sse(
-      SseDeserializer deserializer);
+@protected EmbeddedPictureType dco_decode_embedded_picture_type(dynamic raw);
 
-  @protected
-  RustStreamSink<double> sse_decode_StreamSink_f_64_Sse(
-      SseDeserializer deserializer);
+@protected double dco_decode_f_32(dynamic raw);
 
-  @protected
-  RustStreamSink<IndexActionState> sse_decode_StreamSink_index_action_state_Sse(
-      SseDeserializer deserializer);
+@protected double dco_decode_f_64(dynamic raw);
 
-  @protected
-  RustStreamSink<SMTCControlEvent> sse_decode_StreamSink_smtc_control_event_Sse(
-      SseDeserializer deserializer);
+@protected int dco_decode_i_32(dynamic raw);
 
-  @protected
-  String sse_decode_String(SseDeserializer deserializer);
+@protected PlatformInt64 dco_decode_i_64(dynamic raw);
 
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+@protected IndexActionState dco_decode_index_action_state(dynamic raw);
 
-  @protected
-  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+@protected IndexAudio dco_decode_index_audio(dynamic raw);
 
-  @protected
-  double sse_decode_f_64(SseDeserializer deserializer);
+@protected IndexCueTrack dco_decode_index_cue_track(dynamic raw);
 
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+@protected IndexFolder dco_decode_index_folder(dynamic raw);
 
-  @protected
-  IndexActionState sse_decode_index_action_state(SseDeserializer deserializer);
+@protected InstalledFont dco_decode_installed_font(dynamic raw);
 
-  @protected
-  IndexAudio sse_decode_index_audio(SseDeserializer deserializer);
+@protected List<String> dco_decode_list_String(dynamic raw);
 
-  @protected
-  IndexFolder sse_decode_index_folder(SseDeserializer deserializer);
+@protected List<CachedLyric> dco_decode_list_cached_lyric(dynamic raw);
 
-  @protected
-  InstalledFont sse_decode_installed_font(SseDeserializer deserializer);
+@protected List<EmbeddedLyric> dco_decode_list_embedded_lyric(dynamic raw);
 
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
+@protected List<EmbeddedPicture> dco_decode_list_embedded_picture(dynamic raw);
 
-  @protected
-  List<IndexAudio> sse_decode_list_index_audio(SseDeserializer deserializer);
+@protected List<IndexAudio> dco_decode_list_index_audio(dynamic raw);
 
-  @protected
-  List<IndexFolder> sse_decode_list_index_folder(SseDeserializer deserializer);
+@protected List<IndexFolder> dco_decode_list_index_folder(dynamic raw);
 
-  @protected
-  List<InstalledFont> sse_decode_list_installed_font(
-      SseDeserializer deserializer);
+@protected List<InstalledFont> dco_decode_list_installed_font(dynamic raw);
 
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+@protected List<List<String>> dco_decode_list_list_String(dynamic raw);
 
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+@protected List<Uint32List> dco_decode_list_list_prim_u_32_strict(dynamic raw);
 
-  @protected
-  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+@protected List<LyricAgent> dco_decode_list_lyric_agent(dynamic raw);
 
-  @protected
-  List<InstalledFont>? sse_decode_opt_list_installed_font(
-      SseDeserializer deserializer);
+@protected List<LyricCandidate> dco_decode_list_lyric_candidate(dynamic raw);
 
-  @protected
-  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+@protected List<LyricLine> dco_decode_list_lyric_line(dynamic raw);
 
-  @protected
-  (int, int, int, int) sse_decode_record_u_8_u_8_u_8_u_8(
-      SseDeserializer deserializer);
+@protected List<LyricTag> dco_decode_list_lyric_tag(dynamic raw);
 
-  @protected
-  SMTCControlEvent sse_decode_smtc_control_event(SseDeserializer deserializer);
+@protected List<LyricWord> dco_decode_list_lyric_word(dynamic raw);
 
-  @protected
-  SMTCState sse_decode_smtc_state(SseDeserializer deserializer);
+@protected List<MatchTrack> dco_decode_list_match_track(dynamic raw);
 
-  @protected
-  SystemTheme sse_decode_system_theme(SseDeserializer deserializer);
+@protected List<OutputDevice> dco_decode_list_output_device(dynamic raw);
 
-  @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+@protected Uint32List dco_decode_list_prim_u_32_strict(dynamic raw);
 
-  @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer);
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
 
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+@protected List<RankedMatch> dco_decode_list_ranked_match(dynamic raw);
 
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
+@protected Lyric dco_decode_lyric(dynamic raw);
 
-  @protected
-  void sse_encode_AnyhowException(
-      AnyhowException self, SseSerializer serializer);
+@protected LyricAgent dco_decode_lyric_agent(dynamic raw);
 
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-          SmtcFlutter self, SseSerializer serializer);
+@protected LyricCandidate dco_decode_lyric_candidate(dynamic raw);
 
-  @protected
-  void
-      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-          SmtcFlutter self, SseSerializer serializer);
+@protected LyricCandidateSource dco_decode_lyric_candidate_source(dynamic raw);
 
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-          SmtcFlutter self, SseSerializer serializer);
+@protected LyricFormat dco_decode_lyric_format(dynamic raw);
 
-  @protected
-  void sse_encode_StreamSink_String_Sse(
-      RustStreamSink<String> self, SseSerializer serializer);
+@protected LyricLine dco_decode_lyric_line(dynamic raw);
 
-  @protected
-  void sse_encode_StreamSink_f_64_Sse(
-      RustStreamSink<double> self, SseSerializer serializer);
+@protected LyricSource dco_decode_lyric_source(dynamic raw);
 
-  @protected
-  void sse_encode_StreamSink_index_action_state_Sse(
-      RustStreamSink<IndexActionState> self, SseSerializer serializer);
+@protected LyricTag dco_decode_lyric_tag(dynamic raw);
 
-  @protected
-  void sse_encode_StreamSink_smtc_control_event_Sse(
-      RustStreamSink<SMTCControlEvent> self, SseSerializer serializer);
+@protected LyricWord dco_decode_lyric_word(dynamic raw);
 
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+@protected MatchScore dco_decode_match_score(dynamic raw);
 
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+@protected MatchTrack dco_decode_match_track(dynamic raw);
 
-  @protected
-  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+@protected String? dco_decode_opt_String(dynamic raw);
 
-  @protected
-  void sse_encode_f_64(double self, SseSerializer serializer);
+@protected AlbumPalette? dco_decode_opt_box_autoadd_album_palette(dynamic raw);
 
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+@protected CachedLyric? dco_decode_opt_box_autoadd_cached_lyric(dynamic raw);
 
-  @protected
-  void sse_encode_index_action_state(
-      IndexActionState self, SseSerializer serializer);
+@protected EmbeddedPictureType? dco_decode_opt_box_autoadd_embedded_picture_type(dynamic raw);
 
-  @protected
-  void sse_encode_index_audio(IndexAudio self, SseSerializer serializer);
+@protected double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
 
-  @protected
-  void sse_encode_index_folder(IndexFolder self, SseSerializer serializer);
+@protected IndexCueTrack? dco_decode_opt_box_autoadd_index_cue_track(dynamic raw);
 
-  @protected
-  void sse_encode_installed_font(InstalledFont self, SseSerializer serializer);
+@protected Lyric? dco_decode_opt_box_autoadd_lyric(dynamic raw);
 
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+@protected LyricFormat? dco_decode_opt_box_autoadd_lyric_format(dynamic raw);
 
-  @protected
-  void sse_encode_list_index_audio(
-      List<IndexAudio> self, SseSerializer serializer);
+@protected PictureWithSource? dco_decode_opt_box_autoadd_picture_with_source(dynamic raw);
 
-  @protected
-  void sse_encode_list_index_folder(
-      List<IndexFolder> self, SseSerializer serializer);
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
 
-  @protected
-  void sse_encode_list_installed_font(
-      List<InstalledFont> self, SseSerializer serializer);
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
 
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer);
+@protected int? dco_decode_opt_box_autoadd_u_8(dynamic raw);
 
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+@protected List<InstalledFont>? dco_decode_opt_list_installed_font(dynamic raw);
 
-  @protected
-  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+@protected Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
 
-  @protected
-  void sse_encode_opt_list_installed_font(
-      List<InstalledFont>? self, SseSerializer serializer);
+@protected OutputDevice dco_decode_output_device(dynamic raw);
 
-  @protected
-  void sse_encode_opt_list_prim_u_8_strict(
-      Uint8List? self, SseSerializer serializer);
+@protected OutputDeviceEvent dco_decode_output_device_event(dynamic raw);
 
-  @protected
-  void sse_encode_record_u_8_u_8_u_8_u_8(
-      (int, int, int, int) self, SseSerializer serializer);
+@protected OutputDeviceState dco_decode_output_device_state(dynamic raw);
 
-  @protected
-  void sse_encode_smtc_control_event(
-      SMTCControlEvent self, SseSerializer serializer);
+@protected OutputFormFactor dco_decode_output_form_factor(dynamic raw);
 
-  @protected
-  void sse_encode_smtc_state(SMTCState self, SseSerializer serializer);
+@protected PaletteSwatch dco_decode_palette_swatch(dynamic raw);
 
-  @protected
-  void sse_encode_system_theme(SystemTheme self, SseSerializer serializer);
+@protected PictureFilter dco_decode_picture_filter(dynamic raw);
 
-  @protected
-  void sse_encode_u_32(int self, SseSerializer serializer);
+@protected PictureFit dco_decode_picture_fit(dynamic raw);
 
-  @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+@protected PictureFormat dco_decode_picture_format(dynamic raw);
 
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+@protected PictureOptions dco_decode_picture_options(dynamic raw);
 
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
+@protected PictureSource dco_decode_picture_source(dynamic raw);
+
+@protected PictureWithSource dco_decode_picture_with_source(dynamic raw);
+
+@protected RankedMatch dco_decode_ranked_match(dynamic raw);
+
+@protected (int,int,int,int) dco_decode_record_u_8_u_8_u_8_u_8(dynamic raw);
+
+@protected RomanizationKind dco_decode_romanization_kind(dynamic raw);
+
+@protected SMTCArtwork dco_decode_smtc_artwork(dynamic raw);
+
+@protected SMTCControlEvent dco_decode_smtc_control_event(dynamic raw);
+
+@protected SMTCError dco_decode_smtc_error(dynamic raw);
+
+@protected SMTCMetadata dco_decode_smtc_metadata(dynamic raw);
+
+@protected SMTCRepeatMode dco_decode_smtc_repeat_mode(dynamic raw);
+
+@protected SMTCState dco_decode_smtc_state(dynamic raw);
+
+@protected SMTCStatus dco_decode_smtc_status(dynamic raw);
+
+@protected SystemTheme dco_decode_system_theme(dynamic raw);
+
+@protected TextEncoding dco_decode_text_encoding(dynamic raw);
+
+@protected int dco_decode_u_32(dynamic raw);
+
+@protected BigInt dco_decode_u_64(dynamic raw);
+
+@protected int dco_decode_u_8(dynamic raw);
+
+@protected void dco_decode_unit(dynamic raw);
+
+@protected BigInt dco_decode_usize(dynamic raw);
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+@protected SmtcFlutter sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(SseDeserializer deserializer);
+
+@protected SmtcFlutter sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(SseDeserializer deserializer);
+
+@protected SmtcFlutter sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(SseDeserializer deserializer);
+
+@protected RustStreamSink<String> sse_decode_StreamSink_String_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<double> sse_decode_StreamSink_f_64_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<IndexActionState> sse_decode_StreamSink_index_action_state_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<OutputDeviceEvent> sse_decode_StreamSink_output_device_event_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<SMTCControlEvent> sse_decode_StreamSink_smtc_control_event_Sse(SseDeserializer deserializer);
+
+@protected String sse_decode_String(SseDeserializer deserializer);
+
+@protected AlbumPalette sse_decode_album_palette(SseDeserializer deserializer);
+
+@protected bool sse_decode_bool(SseDeserializer deserializer);
+
+@protected AlbumPalette sse_decode_box_autoadd_album_palette(SseDeserializer deserializer);
+
+@protected CachedLyric sse_decode_box_autoadd_cached_lyric(SseDeserializer deserializer);
+
+@protected EmbeddedPictureType sse_decode_box_autoadd_embedded_picture_type(SseDeserializer deserializer);
+
+@protected double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+
+@protected IndexCueTrack sse_decode_box_autoadd_index_cue_track(SseDeserializer deserializer);
+
+@protected Lyric sse_decode_box_autoadd_lyric(SseDeserializer deserializer);
+
+@protected LyricCandidate sse_decode_box_autoadd_lyric_candidate(SseDeserializer deserializer);
+
+@protected LyricFormat sse_decode_box_autoadd_lyric_format(SseDeserializer deserializer);
+
+@protected MatchTrack sse_decode_box_autoadd_match_track(SseDeserializer deserializer);
+
+@protected PictureOptions sse_decode_box_autoadd_picture_options(SseDeserializer deserializer);
+
+@protected PictureWithSource sse_decode_box_autoadd_picture_with_source(SseDeserializer deserializer);
+
+@protected SMTCArtwork sse_decode_box_autoadd_smtc_artwork(SseDeserializer deserializer);
+
+@protected SMTCMetadata sse_decode_box_autoadd_smtc_metadata(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_8(SseDeserializer deserializer);
+
+@protected CachedLyric sse_decode_cached_lyric(SseDeserializer deserializer);
+
+@protected ChineseVariant sse_decode_chinese_variant(SseDeserializer deserializer);
+
+@protected DecodedText sse_decode_decoded_text(SseDeserializer deserializer);
+
+@protected EmbeddedLyric sse_decode_embedded_lyric(SseDeserializer deserializer);
+
+@protected EmbeddedLyricKind sse_decode_embedded_lyric_kind(SseDeserializer deserializer);
+
+@protected EmbeddedPicture sse_decode_embedded_picture(SseDeserializer deserializer);
+
+@protected EmbeddedPictureType sse_decode_embedded_picture_type(SseDeserializer deserializer);
+
+@protected double sse_decode_f_32(SseDeserializer deserializer);
+
+@protected double sse_decode_f_64(SseDeserializer deserializer);
+
+@protected int sse_decode_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+@protected IndexActionState sse_decode_index_action_state(SseDeserializer deserializer);
+
+@protected IndexAudio sse_decode_index_audio(SseDeserializer deserializer);
+
+@protected IndexCueTrack sse_decode_index_cue_track(SseDeserializer deserializer);
+
+@protected IndexFolder sse_decode_index_folder(SseDeserializer deserializer);
+
+@protected InstalledFont sse_decode_installed_font(SseDeserializer deserializer);
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+@protected List<CachedLyric> sse_decode_list_cached_lyric(SseDeserializer deserializer);
+
+@protected List<EmbeddedLyric> sse_decode_list_embedded_lyric(SseDeserializer deserializer);
+
+@protected List<EmbeddedPicture> sse_decode_list_embedded_picture(SseDeserializer deserializer);
+
+@protected List<IndexAudio> sse_decode_list_index_audio(SseDeserializer deserializer);
+
+@protected List<IndexFolder> sse_decode_list_index_folder(SseDeserializer deserializer);
+
+@protected List<InstalledFont> sse_decode_list_installed_font(SseDeserializer deserializer);
+
+@protected List<List<String>> sse_decode_list_list_String(SseDeserializer deserializer);
+
+@protected List<Uint32List> sse_decode_list_list_prim_u_32_strict(SseDeserializer deserializer);
+
+@protected List<LyricAgent> sse_decode_list_lyric_agent(SseDeserializer deserializer);
+
+@protected List<LyricCandidate> sse_decode_list_lyric_candidate(SseDeserializer deserializer);
+
+@protected List<LyricLine> sse_decode_list_lyric_line(SseDeserializer deserializer);
+
+@protected List<LyricTag> sse_decode_list_lyric_tag(SseDeserializer deserializer);
+
+@protected List<LyricWord> sse_decode_list_lyric_word(SseDeserializer deserializer);
+
+@protected List<MatchTrack> sse_decode_list_match_track(SseDeserializer deserializer);
+
+@protected List<OutputDevice> sse_decode_list_output_device(SseDeserializer deserializer);
+
+@protected Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer);
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<RankedMatch> sse_decode_list_ranked_match(SseDeserializer deserializer);
+
+@protected Lyric sse_decode_lyric(SseDeserializer deserializer);
+
+@protected LyricAgent sse_decode_lyric_agent(SseDeserializer deserializer);
+
+@protected LyricCandidate sse_decode_lyric_candidate(SseDeserializer deserializer);
+
+@protected LyricCandidateSource sse_decode_lyric_candidate_source(SseDeserializer deserializer);
+
+@protected LyricFormat sse_decode_lyric_format(SseDeserializer deserializer);
+
+@protected LyricLine sse_decode_lyric_line(SseDeserializer deserializer);
+
+@protected LyricSource sse_decode_lyric_source(SseDeserializer deserializer);
+
+@protected LyricTag sse_decode_lyric_tag(SseDeserializer deserializer);
+
+@protected LyricWord sse_decode_lyric_word(SseDeserializer deserializer);
+
+@protected MatchScore sse_decode_match_score(SseDeserializer deserializer);
+
+@protected MatchTrack sse_decode_match_track(SseDeserializer deserializer);
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer);
+
+@protected AlbumPalette? sse_decode_opt_box_autoadd_album_palette(SseDeserializer deserializer);
+
+@protected CachedLyric? sse_decode_opt_box_autoadd_cached_lyric(SseDeserializer deserializer);
+
+@protected EmbeddedPictureType? sse_decode_opt_box_autoadd_embedded_picture_type(SseDeserializer deserializer);
+
+@protected double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+@protected IndexCueTrack? sse_decode_opt_box_autoadd_index_cue_track(SseDeserializer deserializer);
+
+@protected Lyric? sse_decode_opt_box_autoadd_lyric(SseDeserializer deserializer);
+
+@protected LyricFormat? sse_decode_opt_box_autoadd_lyric_format(SseDeserializer deserializer);
+
+@protected PictureWithSource? sse_decode_opt_box_autoadd_picture_with_source(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer);
+
+@protected List<InstalledFont>? sse_decode_opt_list_installed_font(SseDeserializer deserializer);
+
+@protected Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected OutputDevice sse_decode_output_device(SseDeserializer deserializer);
+
+@protected OutputDeviceEvent sse_decode_output_device_event(SseDeserializer deserializer);
+
+@protected OutputDeviceState sse_decode_output_device_state(SseDeserializer deserializer);
+
+@protected OutputFormFactor sse_decode_output_form_factor(SseDeserializer deserializer);
+
+@protected PaletteSwatch sse_decode_palette_swatch(SseDeserializer deserializer);
+
+@protected PictureFilter sse_decode_picture_filter(SseDeserializer deserializer);
+
+@protected PictureFit sse_decode_picture_fit(SseDeserializer deserializer);
+
+@protected PictureFormat sse_decode_picture_format(SseDeserializer deserializer);
+
+@protected PictureOptions sse_decode_picture_options(SseDeserializer deserializer);
+
+@protected PictureSource sse_decode_picture_source(SseDeserializer deserializer);
+
+@protected PictureWithSource sse_decode_picture_with_source(SseDeserializer deserializer);
+
+@protected RankedMatch sse_decode_ranked_match(SseDeserializer deserializer);
+
+@protected (int,int,int,int) sse_decode_record_u_8_u_8_u_8_u_8(SseDeserializer deserializer);
+
+@protected RomanizationKind sse_decode_romanization_kind(SseDeserializer deserializer);
+
+@protected SMTCArtwork sse_decode_smtc_artwork(SseDeserializer deserializer);
+
+@protected SMTCControlEvent sse_decode_smtc_control_event(SseDeserializer deserializer);
+
+@protected SMTCError sse_decode_smtc_error(SseDeserializer deserializer);
+
+@protected SMTCMetadata sse_decode_smtc_metadata(SseDeserializer deserializer);
+
+@protected SMTCRepeatMode sse_decode_smtc_repeat_mode(SseDeserializer deserializer);
+
+@protected SMTCState sse_decode_smtc_state(SseDeserializer deserializer);
+
+@protected SMTCStatus sse_decode_smtc_status(SseDeserializer deserializer);
+
+@protected SystemTheme sse_decode_system_theme(SseDeserializer deserializer);
+
+@protected TextEncoding sse_decode_text_encoding(SseDeserializer deserializer);
+
+@protected int sse_decode_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_u_8(SseDeserializer deserializer);
+
+@protected void sse_decode_unit(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer);
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(SmtcFlutter self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(SmtcFlutter self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(SmtcFlutter self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_String_Sse(RustStreamSink<String> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_f_64_Sse(RustStreamSink<double> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_index_action_state_Sse(RustStreamSink<IndexActionState> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_output_device_event_Sse(RustStreamSink<OutputDeviceEvent> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_smtc_control_event_Sse(RustStreamSink<SMTCControlEvent> self, SseSerializer serializer);
+
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_album_palette(AlbumPalette self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_album_palette(AlbumPalette self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_cached_lyric(CachedLyric self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_embedded_picture_type(EmbeddedPictureType self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_index_cue_track(IndexCueTrack self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_lyric(Lyric self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_lyric_candidate(LyricCandidate self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_lyric_format(LyricFormat self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_match_track(MatchTrack self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_picture_options(PictureOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_picture_with_source(PictureWithSource self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_smtc_artwork(SMTCArtwork self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_smtc_metadata(SMTCMetadata self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_cached_lyric(CachedLyric self, SseSerializer serializer);
+
+@protected void sse_encode_chinese_variant(ChineseVariant self, SseSerializer serializer);
+
+@protected void sse_encode_decoded_text(DecodedText self, SseSerializer serializer);
+
+@protected void sse_encode_embedded_lyric(EmbeddedLyric self, SseSerializer serializer);
+
+@protected void sse_encode_embedded_lyric_kind(EmbeddedLyricKind self, SseSerializer serializer);
+
+@protected void sse_encode_embedded_picture(EmbeddedPicture self, SseSerializer serializer);
+
+@protected void sse_encode_embedded_picture_type(EmbeddedPictureType self, SseSerializer serializer);
+
+@protected void sse_encode_f_32(double self, SseSerializer serializer);
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_index_action_state(IndexActionState self, SseSerializer serializer);
+
+@protected void sse_encode_index_audio(IndexAudio self, SseSerializer serializer);
+
+@protected void sse_encode_index_cue_track(IndexCueTrack self, SseSerializer serializer);
+
+@protected void sse_encode_index_folder(IndexFolder self, SseSerializer serializer);
+
+@protected void sse_encode_installed_font(InstalledFont self, SseSerializer serializer);
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+@protected void sse_encode_list_cached_lyric(List<CachedLyric> self, SseSerializer serializer);
+
+@protected void sse_encode_list_embedded_lyric(List<EmbeddedLyric> self, SseSerializer serializer);
+
+@protected void sse_encode_list_embedded_picture(List<EmbeddedPicture> self, SseSerializer serializer);
+
+@protected void sse_encode_list_index_audio(List<IndexAudio> self, SseSerializer serializer);
+
+@protected void sse_encode_list_index_folder(List<IndexFolder> self, SseSerializer serializer);
+
+@protected void sse_encode_list_installed_font(List<InstalledFont> self, SseSerializer serializer);
+
+@protected void sse_encode_list_list_String(List<List<String>> self, SseSerializer serializer);
+
+@protected void sse_encode_list_list_prim_u_32_strict(List<Uint32List> self, SseSerializer serializer);
+
+@protected void sse_encode_list_lyric_agent(List<LyricAgent> self, SseSerializer serializer);
+
+@protected void sse_encode_list_lyric_candidate(List<LyricCandidate> self, SseSerializer serializer);
+
+@protected void sse_encode_list_lyric_line(List<LyricLine> self, SseSerializer serializer);
+
+@protected void sse_encode_list_lyric_tag(List<LyricTag> self, SseSerializer serializer);
+
+@protected void sse_encode_list_lyric_word(List<LyricWord> self, SseSerializer serializer);
+
+@protected void sse_encode_list_match_track(List<MatchTrack> self, SseSerializer serializer);
+
+@protected void sse_encode_list_output_device(List<OutputDevice> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_32_strict(Uint32List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+
+@protected void sse_encode_list_ranked_match(List<RankedMatch> self, SseSerializer serializer);
+
+@protected void sse_encode_lyric(Lyric self, SseSerializer serializer);
+
+@protected void sse_encode_lyric_agent(LyricAgent self, SseSerializer serializer);
+
+@protected void sse_encode_lyric_candidate(LyricCandidate self, SseSerializer serializer);
+
+@protected void sse_encode_lyric_candidate_source(LyricCandidateSource self, SseSerializer serializer);
+
+@protected void sse_encode_lyric_format(LyricFormat self, SseSerializer serializer);
+
+@protected void sse_encode_lyric_line(LyricLine self, SseSerializer serializer);
+
+@protected void sse_encode_lyric_source(LyricSource self, SseSerializer serializer);
+
+@protected void sse_encode_lyric_tag(LyricTag self, SseSerializer serializer);
+
+@protected void sse_encode_lyric_word(LyricWord self, SseSerializer serializer);
+
+@protected void sse_encode_match_score(MatchScore self, SseSerializer serializer);
+
+@protected void sse_encode_match_track(MatchTrack self, SseSerializer serializer);
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_album_palette(AlbumPalette? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_cached_lyric(CachedLyric? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_embedded_picture_type(EmbeddedPictureType? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_index_cue_track(IndexCueTrack? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_lyric(Lyric? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_lyric_format(LyricFormat? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_picture_with_source(PictureWithSource? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_installed_font(List<InstalledFont>? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_prim_u_8_strict(Uint8List? self, SseSerializer serializer);
+
+@protected void sse_encode_output_device(OutputDevice self, SseSerializer serializer);
+
+@protected void sse_encode_output_device_event(OutputDeviceEvent self, SseSerializer serializer);
+
+@protected void sse_encode_output_device_state(OutputDeviceState self, SseSerializer serializer);
+
+@protected void sse_encode_output_form_factor(OutputFormFactor self, SseSerializer serializer);
+
+@protected void sse_encode_palette_swatch(PaletteSwatch self, SseSerializer serializer);
+
+@protected void sse_encode_picture_filter(PictureFilter self, SseSerializer serializer);
+
+@protected void sse_encode_picture_fit(PictureFit self, SseSerializer serializer);
+
+@protected void sse_encode_picture_format(PictureFormat self, SseSerializer serializer);
+
+@protected void sse_encode_picture_options(PictureOptions self, SseSerializer serializer);
+
+@protected void sse_encode_picture_source(PictureSource self, SseSerializer serializer);
+
+@protected void sse_encode_picture_with_source(PictureWithSource self, SseSerializer serializer);
+
+@protected void sse_encode_ranked_match(RankedMatch self, SseSerializer serializer);
+
+@protected void sse_encode_record_u_8_u_8_u_8_u_8((int,int,int,int) self, SseSerializer serializer);
+
+@protected void sse_encode_romanization_kind(RomanizationKind self, SseSerializer serializer);
+
+@protected void sse_encode_smtc_artwork(SMTCArtwork self, SseSerializer serializer);
+
+@protected void sse_encode_smtc_control_event(SMTCControlEvent self, SseSerializer serializer);
+
+@protected void sse_encode_smtc_error(SMTCError self, SseSerializer serializer);
+
+@protected void sse_encode_smtc_metadata(SMTCMetadata self, SseSerializer serializer);
+
+@protected void sse_encode_smtc_repeat_mode(SMTCRepeatMode self, SseSerializer serializer);
+
+@protected void sse_encode_smtc_state(SMTCState self, SseSerializer serializer);
+
+@protected void sse_encode_smtc_status(SMTCStatus self, SseSerializer serializer);
+
+@protected void sse_encode_system_theme(SystemTheme self, SseSerializer serializer);
+
+@protected void sse_encode_text_encoding(TextEncoding self, SseSerializer serializer);
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_unit(void self, SseSerializer serializer);
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer);
+                }
+                
 
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer);
-}
 
 // Section: wire_class
 
-class RustLibWire implements BaseWire {
-  factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
-      RustLibWire(lib.ffiDynamicLibrary);
-
-  /// Holds the symbol lookup function.
-  final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
-      _lookup;
-
-  /// The symbols are looked up in [dynamicLibrary].
-  RustLibWire(ffi.DynamicLibrary dynamicLibrary)
-      : _lookup = dynamicLibrary.lookup;
-
-  void
-      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutterPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_coriander_player_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter');
-  late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter =
-      _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutterPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-
-  void
-      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-    ffi.Pointer<ffi.Void> ptr,
-  ) {
-    return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
-      ptr,
-    );
-  }
-
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutterPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'frbgen_coriander_player_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter');
-  late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter =
-      _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutterPtr
-          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
-}
+
+        class RustLibWire implements BaseWire {
+
+            factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
+              RustLibWire(lib.ffiDynamicLibrary);
+        
+            /// Holds the symbol lookup function.
+            final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
+                _lookup;
+  
+            /// The symbols are looked up in [dynamicLibrary].
+            RustLibWire(ffi.DynamicLibrary dynamicLibrary)
+                : _lookup = dynamicLibrary.lookup;
+
+            
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutterPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_coriander_player_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter');
+            late final _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter = _rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutterPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+            void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
+              ffi.Pointer<ffi.Void> ptr,
+            ) {
+              return _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter(
+                ptr,
+              );
+            }
+
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutterPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>('frbgen_coriander_player_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter');
+            late final _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutter = _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSMTCFlutterPtr.asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+            
+        }
+        
\ No newline at end of file
@@ -0,0 +1,460 @@
+use std::path::Path;
+
+use xxhash_rust::xxh3::xxh3_128;
+
+use super::library_db;
+use super::logger::log_to_dart;
+use super::tag_reader::{self, PictureOptions};
+
+/// 参与计算的缩略图尺寸
+const SAMPLE_SIZE: u32 = 64;
+const CLUSTER_COUNT: usize = 8;
+const KMEANS_ITERATIONS: usize = 12;
+
+/// WCAG AA 正文对比度
+const MIN_CONTRAST: f64 = 4.5;
+
+#[derive(Clone, Copy, Debug)]
+pub struct PaletteSwatch {
+    /// a, r, g, b
+    pub color: (u8, u8, u8, u8),
+    /// 放在 color 上的文字颜色，对比度不低于 4.5。a, r, g, b
+    pub on_color: (u8, u8, u8, u8),
+    /// 在图片中的占比，0~1。由其他颜色推算出来时为 0
+    pub population: f64,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct AlbumPalette {
+    /// 占比最大的颜色
+    pub dominant: PaletteSwatch,
+    pub vibrant: PaletteSwatch,
+    pub light_vibrant: PaletteSwatch,
+    pub dark_vibrant: PaletteSwatch,
+    pub muted: PaletteSwatch,
+    pub light_muted: PaletteSwatch,
+    pub dark_muted: PaletteSwatch,
+}
+
+/// 各分量 0~1
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Hsl {
+    h: f64,
+    s: f64,
+    l: f64,
+}
+
+fn _rgb_to_hsl(rgb: [f64; 3]) -> Hsl {
+    let [r, g, b] = rgb.map(|v| v / 255.0);
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let l = (max + min) / 2.0;
+    let d = max - min;
+    if d < f64::EPSILON {
+        return Hsl { h: 0.0, s: 0.0, l };
+    }
+
+    let s = d / (1.0 - (2.0 * l - 1.0).abs());
+    let h = if max == r {
+        ((g - b) / d).rem_euclid(6.0)
+    } else if max == g {
+        (b - r) / d + 2.0
+    } else {
+        (r - g) / d + 4.0
+    } / 6.0;
+
+    Hsl {
+        h,
+        s: s.clamp(0.0, 1.0),
+        l,
+    }
+}
+
+fn _hsl_to_rgb(hsl: Hsl) -> [f64; 3] {
+    let c = (1.0 - (2.0 * hsl.l - 1.0).abs()) * hsl.s;
+    let h = hsl.h.rem_euclid(1.0) * 6.0;
+    let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+    let (r, g, b) = match h as u32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    let m = hsl.l - c / 2.0;
+    [r, g, b].map(|v| ((v + m) * 255.0).clamp(0.0, 255.0))
+}
+
+/// WCAG 相对亮度
+fn _relative_luminance(rgb: [f64; 3]) -> f64 {
+    let [r, g, b] = rgb.map(|v| {
+        let v = v / 255.0;
+        if v <= 0.03928 {
+            v / 12.92
+        } else {
+            ((v + 0.055) / 1.055).powf(2.4)
+        }
+    });
+    0.2126 * r + 0.7152 * g + 0.0722 * b
+}
+
+fn _contrast(a: [f64; 3], b: [f64; 3]) -> f64 {
+    let (la, lb) = (_relative_luminance(a), _relative_luminance(b));
+    (la.max(lb) + 0.05) / (la.min(lb) + 0.05)
+}
+
+fn _argb(rgb: [f64; 3]) -> (u8, u8, u8, u8) {
+    let [r, g, b] = rgb.map(|v| v.round().clamp(0.0, 255.0) as u8);
+    (255, r, g, b)
+}
+
+/// 和背景同色相的浅色或深色文字。达不到 [MIN_CONTRAST] 时使用纯白或纯黑。
+fn _on_color(background: [f64; 3]) -> [f64; 3] {
+    const WHITE: [f64; 3] = [255.0, 255.0, 255.0];
+    const BLACK: [f64; 3] = [0.0, 0.0, 0.0];
+
+    let hsl = _rgb_to_hsl(background);
+    let prefer_light = _contrast(background, WHITE) >= _contrast(background, BLACK);
+    let tinted = _hsl_to_rgb(Hsl {
+        h: hsl.h,
+        s: hsl.s * 0.6,
+        l: if prefer_light { 0.95 } else { 0.12 },
+    });
+    if _contrast(background, tinted) >= MIN_CONTRAST {
+        return tinted;
+    }
+
+    if prefer_light {
+        WHITE
+    } else {
+        BLACK
+    }
+}
+
+fn _swatch(rgb: [f64; 3], population: f64) -> PaletteSwatch {
+    PaletteSwatch {
+        color: _argb(rgb),
+        on_color: _argb(_on_color(rgb)),
+        population,
+    }
+}
+
+struct Cluster {
+    rgb: [f64; 3],
+    /// 0~1
+    population: f64,
+}
+
+fn _distance2(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    (a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)
+}
+
+/// k-means。初始中心按亮度等距选取，结果是确定的。
+fn _kmeans(pixels: &[[f64; 3]], k: usize) -> Vec<Cluster> {
+    if pixels.is_empty() {
+        return vec![];
+    }
+
+    let mut sorted = pixels.to_vec();
+    sorted.sort_by(|a, b| {
+        _relative_luminance(*a)
+            .partial_cmp(&_relative_luminance(*b))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    let k = k.min(sorted.len());
+    let mut centers: Vec<[f64; 3]> = (0..k)
+        .map(|i| sorted[(2 * i + 1) * sorted.len() / (2 * k)])
+        .collect();
+
+    let mut assignments = vec![0usize; pixels.len()];
+    for _ in 0..KMEANS_ITERATIONS {
+        let mut changed = false;
+        for (pixel, assignment) in pixels.iter().zip(assignments.iter_mut()) {
+            let nearest = centers
+                .iter()
+                .enumerate()
+                .min_by(|(_, a), (_, b)| {
+                    _distance2(pixel, a)
+                        .partial_cmp(&_distance2(pixel, b))
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .map(|(i, _)| i)
+                .unwrap_or(0);
+            if *assignment != nearest {
+                *assignment = nearest;
+                changed = true;
+            }
+        }
+
+        let mut sums = vec![[0.0f64; 3]; k];
+        let mut counts = vec![0usize; k];
+        for (pixel, assignment) in pixels.iter().zip(assignments.iter()) {
+            for c in 0..3 {
+                sums[*assignment][c] += pixel[c];
+            }
+            counts[*assignment] += 1;
+        }
+        for i in 0..k {
+            if counts[i] > 0 {
+                centers[i] = sums[i].map(|v| v / counts[i] as f64);
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+
+    let mut counts = vec![0usize; k];
+    for assignment in &assignments {
+        counts[*assignment] += 1;
+    }
+    let mut clusters: Vec<Cluster> = centers
+        .into_iter()
+        .zip(counts)
+        .filter(|(_, count)| *count > 0)
+        .map(|(rgb, count)| Cluster {
+            rgb,
+            population: count as f64 / pixels.len() as f64,
+        })
+        .collect();
+    clusters.sort_by(|a, b| {
+        b.population
+            .partial_cmp(&a.population)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    clusters
+}
+
+/// 参考 Android Palette 的目标颜色
+struct Target {
+    min_lightness: f64,
+    target_lightness: f64,
+    max_lightness: f64,
+    min_saturation: f64,
+    target_saturation: f64,
+    max_saturation: f64,
+}
+
+impl Target {
+    const fn new(lightness: (f64, f64, f64), saturation: (f64, f64, f64)) -> Self {
+        Target {
+            min_lightness: lightness.0,
+            target_lightness: lightness.1,
+            max_lightness: lightness.2,
+            min_saturation: saturation.0,
+            target_saturation: saturation.1,
+            max_saturation: saturation.2,
+        }
+    }
+
+    fn accepts(&self, hsl: &Hsl) -> bool {
+        (self.min_lightness..=self.max_lightness).contains(&hsl.l)
+            && (self.min_saturation..=self.max_saturation).contains(&hsl.s)
+    }
+
+    fn score(&self, hsl: &Hsl, population: f64, max_population: f64) -> f64 {
+        (1.0 - (hsl.s - self.target_saturation).abs()) * 0.24
+            + (1.0 - (hsl.l - self.target_lightness).abs()) * 0.52
+            + population / max_population * 0.24
+    }
+
+    /// 没有合适的颜色时，把主色调整到目标的饱和度和亮度
+    fn derive_from(&self, rgb: [f64; 3]) -> [f64; 3] {
+        let hsl = _rgb_to_hsl(rgb);
+        _hsl_to_rgb(Hsl {
+            h: hsl.h,
+            s: hsl.s.clamp(self.min_saturation, self.max_saturation),
+            l: self.target_lightness,
+        })
+    }
+}
+
+const VIBRANT: Target = Target::new((0.3, 0.5, 0.7), (0.35, 1.0, 1.0));
+const LIGHT_VIBRANT: Target = Target::new((0.55, 0.74, 1.0), (0.35, 1.0, 1.0));
+const DARK_VIBRANT: Target = Target::new((0.0, 0.26, 0.45), (0.35, 1.0, 1.0));
+const MUTED: Target = Target::new((0.3, 0.5, 0.7), (0.0, 0.3, 0.4));
+const LIGHT_MUTED: Target = Target::new((0.55, 0.74, 1.0), (0.0, 0.3, 0.4));
+const DARK_MUTED: Target = Target::new((0.0, 0.26, 0.45), (0.0, 0.3, 0.4));
+
+fn _palette_from_pixels(pixels: &[[f64; 3]]) -> Option<AlbumPalette> {
+    let clusters = _kmeans(pixels, CLUSTER_COUNT);
+    let dominant = clusters.first()?;
+    let max_population = dominant.population;
+    let hsls: Vec<Hsl> = clusters.iter().map(|c| _rgb_to_hsl(c.rgb)).collect();
+
+    // 每个颜色只被选中一次
+    let mut used = vec![false; clusters.len()];
+    let mut pick = |target: &Target| {
+        let best = clusters
+            .iter()
+            .zip(hsls.iter())
+            .enumerate()
+            .filter(|(i, (_, hsl))| !used[*i] && target.accepts(hsl))
+            .max_by(|(_, (a, a_hsl)), (_, (b, b_hsl))| {
+                target
+                    .score(a_hsl, a.population, max_population)
+                    .partial_cmp(&target.score(b_hsl, b.population, max_population))
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .map(|(i, _)| i);
+
+        match best {
+            Some(i) => {
+                used[i] = true;
+                _swatch(clusters[i].rgb, clusters[i].population)
+            }
+            None => _swatch(target.derive_from(dominant.rgb), 0.0),
+        }
+    };
+
+    Some(AlbumPalette {
+        vibrant: pick(&VIBRANT),
+        light_vibrant: pick(&LIGHT_VIBRANT),
+        dark_vibrant: pick(&DARK_VIBRANT),
+        muted: pick(&MUTED),
+        light_muted: pick(&LIGHT_MUTED),
+        dark_muted: pick(&DARK_MUTED),
+        dominant: _swatch(dominant.rgb, dominant.population),
+    })
+}
+
+fn _swatch_to_json(swatch: &PaletteSwatch) -> serde_json::Value {
+    let (a, r, g, b) = swatch.color;
+    let (on_a, on_r, on_g, on_b) = swatch.on_color;
+    serde_json::json!([a, r, g, b, on_a, on_r, on_g, on_b, swatch.population])
+}
+
+fn _swatch_from_json(value: &serde_json::Value) -> Option<PaletteSwatch> {
+    let values = value.as_array()?;
+    let byte = |i: usize| values.get(i)?.as_u64().map(|v| v as u8);
+    Some(PaletteSwatch {
+        color: (byte(0)?, byte(1)?, byte(2)?, byte(3)?),
+        on_color: (byte(4)?, byte(5)?, byte(6)?, byte(7)?),
+        population: values.get(8)?.as_f64()?,
+    })
+}
+
+impl AlbumPalette {
+    fn swatches(&self) -> [&PaletteSwatch; 7] {
+        [
+            &self.dominant,
+            &self.vibrant,
+            &self.light_vibrant,
+            &self.dark_vibrant,
+            &self.muted,
+            &self.light_muted,
+            &self.dark_muted,
+        ]
+    }
+
+    fn to_json_string(self) -> String {
+        serde_json::Value::Array(self.swatches().map(_swatch_to_json).to_vec()).to_string()
+    }
+
+    fn from_json_str(json: &str) -> Option<Self> {
+        let value: serde_json::Value = serde_json::from_str(json).ok()?;
+        let swatches = value
+            .as_array()?
+            .iter()
+            .map(_swatch_from_json)
+            .collect::<Option<Vec<_>>>()?;
+        match swatches[..] {
+            [dominant, vibrant, light_vibrant, dark_vibrant, muted, light_muted, dark_muted] => {
+                Some(AlbumPalette {
+                    dominant,
+                    vibrant,
+                    light_vibrant,
+                    dark_vibrant,
+                    muted,
+                    light_muted,
+                    dark_muted,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// for Flutter
+/// 从封面提取主题色。图片获取方式和 [tag_reader::get_picture_from_path] 相同。
+/// 结果按专辑保存在 index_path 的曲库数据库中，同一专辑的曲目共用一份，封面变化后重新计算。
+/// 没有图片时返回 None。
+pub fn extract_palette(index_path: String, path: String) -> Option<AlbumPalette> {
+    let sample = tag_reader::get_picture_with_options(
+        path.clone(),
+        PictureOptions::with_size(SAMPLE_SIZE, SAMPLE_SIZE),
+    )?;
+    let index_dir = Path::new(&index_path);
+    let picture_hash = format!("{:032x}", xxh3_128(&sample.data));
+
+    match library_db::get_album_palette(index_dir, &path, &picture_hash) {
+        Ok(cached) => {
+            if let Some(palette) = cached.and_then(|json| AlbumPalette::from_json_str(&json)) {
+                return Some(palette);
+            }
+        }
+        Err(err) => log_to_dart(format!("fail to read album palette: {}", err)),
+    }
+
+    let rgba = image::load_from_memory(&sample.data).ok()?.into_rgba8();
+    // 忽略透明像素
+    let pixels: Vec<[f64; 3]> = rgba
+        .pixels()
+        .filter(|p| p.0[3] >= 128)
+        .map(|p| [p.0[0] as f64, p.0[1] as f64, p.0[2] as f64])
+        .collect();
+
+    let palette = _palette_from_pixels(&pixels)?;
+    if let Err(err) =
+        library_db::save_album_palette(index_dir, &path, &picture_hash, &palette.to_json_string())
+    {
+        log_to_dart(format!("fail to save album palette: {}", err));
+    }
+    Some(palette)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn palette_of_two_colors() {
+        let mut pixels = vec![[220.0, 30.0, 40.0]; 300];
+        pixels.extend(vec![[40.0, 50.0, 70.0]; 100]);
+
+        let palette = _palette_from_pixels(&pixels).unwrap();
+        assert_eq!(palette.dominant.color, (255, 220, 30, 40));
+        assert!((palette.dominant.population - 0.75).abs() < 1e-9);
+        assert_eq!(palette.vibrant.color, (255, 220, 30, 40));
+        assert_eq!(palette.dark_muted.color, (255, 40, 50, 70));
+
+        for swatch in palette.swatches() {
+            let (_, r, g, b) = swatch.color;
+            let (_, on_r, on_g, on_b) = swatch.on_color;
+            let contrast = _contrast(
+                [r as f64, g as f64, b as f64],
+                [on_r as f64, on_g as f64, on_b as f64],
+            );
+            assert!(contrast >= MIN_CONTRAST, "{:?}", swatch);
+        }
+
+        let restored = AlbumPalette::from_json_str(&palette.to_json_string()).unwrap();
+        assert_eq!(restored.muted.color, palette.muted.color);
+    }
+
+    #[test]
+    fn hsl_roundtrip() {
+        for rgb in [
+            [255.0, 0.0, 0.0],
+            [12.0, 200.0, 99.0],
+            [128.0, 128.0, 128.0],
+        ] {
+            let back = _hsl_to_rgb(_rgb_to_hsl(rgb));
+            for c in 0..3 {
+                assert!((back[c] - rgb[c]).abs() < 0.5);
+            }
+        }
+    }
+}
@@ -0,0 +1,560 @@
+use std::{fs, io::Cursor, path::PathBuf};
+
+use anyhow::{anyhow, Result};
+use image::{codecs::jpeg::JpegEncoder, imageops, ImageFormat, ImageReader};
+use lofty::config::WriteOptions;
+use lofty::file::TaggedFile;
+use lofty::picture::{MimeType, Picture, PictureType};
+use lofty::prelude::{AudioFile, TaggedFileExt};
+use lofty::tag::{Tag, TagType};
+
+use super::{picture_cache, tag_reader};
+
+const DEFAULT_JPEG_QUALITY: u8 = 90;
+
+/// 内嵌图片类型，对应 ID3v2 APIC 的图片类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmbeddedPictureType {
+    Other,
+    Icon,
+    OtherIcon,
+    CoverFront,
+    CoverBack,
+    Leaflet,
+    Media,
+    LeadArtist,
+    Artist,
+    Conductor,
+    Band,
+    Composer,
+    Lyricist,
+    RecordingLocation,
+    DuringRecording,
+    DuringPerformance,
+    ScreenCapture,
+    BrightFish,
+    Illustration,
+    BandLogo,
+    PublisherLogo,
+    /// 标准之外的类型，raw 为原始的类型字节
+    Undefined {
+        raw: u8,
+    },
+}
+
+impl EmbeddedPictureType {
+    fn from_lofty(pic_type: PictureType) -> Self {
+        match pic_type {
+            PictureType::Other => Self::Other,
+            PictureType::Icon => Self::Icon,
+            PictureType::OtherIcon => Self::OtherIcon,
+            PictureType::CoverFront => Self::CoverFront,
+            PictureType::CoverBack => Self::CoverBack,
+            PictureType::Leaflet => Self::Leaflet,
+            PictureType::Media => Self::Media,
+            PictureType::LeadArtist => Self::LeadArtist,
+            PictureType::Artist => Self::Artist,
+            PictureType::Conductor => Self::Conductor,
+            PictureType::Band => Self::Band,
+            PictureType::Composer => Self::Composer,
+            PictureType::Lyricist => Self::Lyricist,
+            PictureType::RecordingLocation => Self::RecordingLocation,
+            PictureType::DuringRecording => Self::DuringRecording,
+            PictureType::DuringPerformance => Self::DuringPerformance,
+            PictureType::ScreenCapture => Self::ScreenCapture,
+            PictureType::BrightFish => Self::BrightFish,
+            PictureType::Illustration => Self::Illustration,
+            PictureType::BandLogo => Self::BandLogo,
+            PictureType::PublisherLogo => Self::PublisherLogo,
+            _ => Self::Undefined {
+                raw: pic_type.as_u8(),
+            },
+        }
+    }
+
+    fn to_lofty(self) -> PictureType {
+        match self {
+            Self::Other => PictureType::Other,
+            Self::Icon => PictureType::Icon,
+            Self::OtherIcon => PictureType::OtherIcon,
+            Self::CoverFront => PictureType::CoverFront,
+            Self::CoverBack => PictureType::CoverBack,
+            Self::Leaflet => PictureType::Leaflet,
+            Self::Media => PictureType::Media,
+            Self::LeadArtist => PictureType::LeadArtist,
+            Self::Artist => PictureType::Artist,
+            Self::Conductor => PictureType::Conductor,
+            Self::Band => PictureType::Band,
+            Self::Composer => PictureType::Composer,
+            Self::Lyricist => PictureType::Lyricist,
+            Self::RecordingLocation => PictureType::RecordingLocation,
+            Self::DuringRecording => PictureType::DuringRecording,
+            Self::DuringPerformance => PictureType::DuringPerformance,
+            Self::ScreenCapture => PictureType::ScreenCapture,
+            Self::BrightFish => PictureType::BrightFish,
+            Self::Illustration => PictureType::Illustration,
+            Self::BandLogo => PictureType::BandLogo,
+            Self::PublisherLogo => PictureType::PublisherLogo,
+            Self::Undefined { raw } => PictureType::from_u8(raw),
+        }
+    }
+}
+
+pub struct EmbeddedPicture {
+    /// 在标签图片列表中的位置，用于提取
+    pub index: u32,
+    pub picture_type: EmbeddedPictureType,
+    pub mime_type: Option<String>,
+    pub description: Option<String>,
+    /// 无法解码时为 None
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    /// bytes
+    pub size: u64,
+}
+
+/// RIFF INFO, AIFF text chunks 和 ID3v1 都不能存储图片
+fn _tag_type_supports_pictures(tag_type: TagType) -> bool {
+    !matches!(
+        tag_type,
+        TagType::Id3v1 | TagType::RiffInfo | TagType::AiffText
+    )
+}
+
+/// MP4 的 covr 不记录图片类型，lofty 一律读成 Other，实际上都是封面
+fn _pic_type_in(tag_type: TagType, pic: &Picture) -> PictureType {
+    if tag_type == TagType::Mp4Ilst {
+        PictureType::CoverFront
+    } else {
+        pic.pic_type()
+    }
+}
+
+/// 读写图片时使用的标签：优先主标签，其次第一个能存储图片的标签
+fn _picture_tag(tagged_file: &TaggedFile) -> Option<&Tag> {
+    tagged_file
+        .primary_tag()
+        .filter(|tag| _tag_type_supports_pictures(tag.tag_type()))
+        .or_else(|| {
+            tagged_file
+                .tags()
+                .iter()
+                .find(|tag| _tag_type_supports_pictures(tag.tag_type()))
+        })
+}
+
+/// 和 [_picture_tag] 的选择规则一致。没有可用标签时，
+/// 新建一个该文件格式支持的、能存储图片的标签。
+fn _picture_tag_mut(tagged_file: &mut TaggedFile) -> Result<&mut Tag> {
+    let tag_type = match _picture_tag(tagged_file) {
+        Some(tag) => tag.tag_type(),
+        None => {
+            let file_type = tagged_file.file_type();
+            let tag_type = [
+                file_type.primary_tag_type(),
+                TagType::Id3v2,
+                TagType::VorbisComments,
+                TagType::Mp4Ilst,
+                TagType::Ape,
+            ]
+            .into_iter()
+            .find(|tag_type| {
+                _tag_type_supports_pictures(*tag_type) && file_type.supports_tag_type(*tag_type)
+            })
+            .ok_or_else(|| anyhow!("{:?} can not store pictures", file_type))?;
+            tagged_file.insert_tag(Tag::new(tag_type));
+            tag_type
+        }
+    };
+
+    tagged_file
+        .tag_mut(tag_type)
+        .ok_or_else(|| anyhow!("failed to create tag"))
+}
+
+fn _mime_type_of(data: &[u8]) -> Option<MimeType> {
+    match image::guess_format(data).ok()? {
+        ImageFormat::Png => Some(MimeType::Png),
+        ImageFormat::Jpeg => Some(MimeType::Jpeg),
+        ImageFormat::Gif => Some(MimeType::Gif),
+        ImageFormat::Bmp => Some(MimeType::Bmp),
+        ImageFormat::Tiff => Some(MimeType::Tiff),
+        ImageFormat::WebP => Some(MimeType::Unknown("image/webp".to_string())),
+        _ => None,
+    }
+}
+
+fn _extension_of(mime_type: Option<&MimeType>) -> &'static str {
+    match mime_type {
+        Some(MimeType::Png) => "png",
+        Some(MimeType::Gif) => "gif",
+        Some(MimeType::Bmp) => "bmp",
+        Some(MimeType::Tiff) => "tiff",
+        Some(MimeType::Unknown(mime)) if mime == "image/webp" => "webp",
+        _ => "jpg",
+    }
+}
+
+fn _dimensions_of(data: &[u8]) -> Option<(u32, u32)> {
+    ImageReader::new(Cursor::new(data))
+        .with_guessed_format()
+        .ok()?
+        .into_dimensions()
+        .ok()
+}
+
+/// max_size: 最长边的上限，超过时等比缩小；不会放大。
+/// 需要缩小或指定了 jpeg_quality 时重新编码为 JPEG，否则原样返回图片数据。
+fn _prepare_cover(
+    data: Vec<u8>,
+    max_size: Option<u32>,
+    jpeg_quality: Option<u8>,
+) -> Result<(Vec<u8>, MimeType)> {
+    let mime_type = _mime_type_of(&data).ok_or_else(|| anyhow!("unsupported image format"))?;
+    let (width, height) = _dimensions_of(&data).ok_or_else(|| anyhow!("fail to decode image"))?;
+
+    let need_resize = max_size.is_some_and(|max| width.max(height) > max);
+    if !need_resize && jpeg_quality.is_none() {
+        return Ok((data, mime_type));
+    }
+
+    let mut loaded = image::load_from_memory(&data)?;
+    if let Some(max) = max_size.filter(|_| need_resize) {
+        loaded = loaded.resize(max, max, imageops::FilterType::Lanczos3);
+    }
+
+    let mut output = Cursor::new(Vec::new());
+    let encoder = JpegEncoder::new_with_quality(
+        &mut output,
+        jpeg_quality.unwrap_or(DEFAULT_JPEG_QUALITY).clamp(1, 100),
+    );
+    // JPEG 不支持透明通道
+    loaded.into_rgb8().write_with_encoder(encoder)?;
+
+    Ok((output.into_inner(), MimeType::Jpeg))
+}
+
+fn _save(tagged_file: TaggedFile, path: &str) -> Result<()> {
+    tagged_file.save_to_path(path, WriteOptions::default())?;
+    picture_cache::invalidate(path);
+    Ok(())
+}
+
+/// for Flutter
+/// 列出音频文件内嵌的所有图片
+pub fn list_embedded_pictures(path: String) -> Result<Vec<EmbeddedPicture>> {
+    let path = tag_reader::cue_source_path(&path).to_string();
+    let tagged_file = lofty::read_from_path(&path)?;
+    let Some(tag) = _picture_tag(&tagged_file) else {
+        return Ok(vec![]);
+    };
+
+    Ok(tag
+        .pictures()
+        .iter()
+        .enumerate()
+        .map(|(index, pic)| {
+            let dimensions = _dimensions_of(pic.data());
+            EmbeddedPicture {
+                index: index as u32,
+                picture_type: EmbeddedPictureType::from_lofty(_pic_type_in(tag.tag_type(), pic)),
+                mime_type: pic
+                    .mime_type()
+                    .cloned()
+                    .or_else(|| _mime_type_of(pic.data()))
+                    .map(|mime| mime.to_string()),
+                description: pic.description().map(|s| s.to_string()),
+                width: dimensions.map(|d| d.0),
+                height: dimensions.map(|d| d.1),
+                size: pic.data().len() as u64,
+            }
+        })
+        .collect())
+}
+
+/// for Flutter
+/// 插入封面。已有封面时替换它，其他类型的图片保持不变。
+/// 封面会被放在图片列表的第一位。
+///
+/// max_size: 最长边的上限（px），超过时等比缩小。
+/// jpeg_quality: 1~100，指定时重新压缩为 JPEG。缩小后的图片也会编码为 JPEG。
+pub fn set_front_cover(
+    path: String,
+    data: Vec<u8>,
+    max_size: Option<u32>,
+    jpeg_quality: Option<u8>,
+) -> Result<()> {
+    let (data, mime_type) = _prepare_cover(data, max_size, jpeg_quality)?;
+    let path = tag_reader::cue_source_path(&path).to_string();
+
+    let mut tagged_file = lofty::read_from_path(&path)?;
+    let tag = _picture_tag_mut(&mut tagged_file)?;
+    let tag_type = tag.tag_type();
+
+    // 封面放在第一位，其他图片保持原有顺序
+    let others: Vec<Picture> = tag
+        .pictures()
+        .iter()
+        .filter(|pic| _pic_type_in(tag_type, pic) != PictureType::CoverFront)
+        .cloned()
+        .collect();
+    while tag.picture_count() > 0 {
+        tag.remove_picture(0);
+    }
+    tag.push_picture(Picture::new_unchecked(
+        PictureType::CoverFront,
+        Some(mime_type),
+        None,
+        data,
+    ));
+    for pic in others {
+        tag.push_picture(pic);
+    }
+
+    _save(tagged_file, &path)
+}
+
+/// for Flutter
+/// 删除内嵌图片。picture_type 为 None 时删除全部图片。返回删除的数量。
+pub fn remove_embedded_pictures(
+    path: String,
+    picture_type: Option<EmbeddedPictureType>,
+) -> Result<u32> {
+    let path = tag_reader::cue_source_path(&path).to_string();
+    let mut tagged_file = lofty::read_from_path(&path)?;
+
+    let mut removed = 0;
+    let tag_types: Vec<TagType> = tagged_file.tags().iter().map(|t| t.tag_type()).collect();
+    for tag_type in tag_types {
+        let Some(tag) = tagged_file.tag_mut(tag_type) else {
+            continue;
+        };
+        let before = tag.picture_count();
+        for index in (0..tag.pictures().len()).rev() {
+            let matched = picture_type.is_none_or(|pic_type| {
+                _pic_type_in(tag_type, &tag.pictures()[index]) == pic_type.to_lofty()
+            });
+            if matched {
+                tag.remove_picture(index);
+            }
+        }
+        removed += before - tag.picture_count();
+    }
+
+    if removed > 0 {
+        _save(tagged_file, &path)?;
+    }
+    Ok(removed)
+}
+
+/// for Flutter
+/// 把第 index 张内嵌图片写到 output_path。
+/// output_path 没有扩展名时按图片格式补上。返回实际写入的路径。
+pub fn extract_embedded_picture(path: String, index: u32, output_path: String) -> Result<String> {
+    let path = tag_reader::cue_source_path(&path).to_string();
+    let tagged_file = lofty::read_from_path(&path)?;
+    let picture = _picture_tag(&tagged_file)
+        .and_then(|tag| tag.pictures().get(index as usize))
+        .ok_or_else(|| anyhow!("no picture at index {}", index))?;
+
+    let mut output_path = PathBuf::from(output_path);
+    if output_path.extension().is_none() {
+        let mime_type = picture
+            .mime_type()
+            .cloned()
+            .or_else(|| _mime_type_of(picture.data()));
+        output_path.set_extension(_extension_of(mime_type.as_ref()));
+    }
+    if let Some(parent) = output_path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&output_path, picture.data())?;
+
+    Ok(output_path.to_string_lossy().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type WriteFixture = fn(&std::path::Path);
+
+    fn write_silent_wav(path: &std::path::Path) {
+        let data_len: u32 = 44100 * 2;
+        let mut bytes = vec![];
+        bytes.extend_from_slice(b"RIFF");
+        bytes.extend_from_slice(&(36 + data_len).to_le_bytes());
+        bytes.extend_from_slice(b"WAVEfmt ");
+        bytes.extend_from_slice(&16u32.to_le_bytes());
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.extend_from_slice(&44100u32.to_le_bytes());
+        bytes.extend_from_slice(&(44100u32 * 2).to_le_bytes());
+        bytes.extend_from_slice(&2u16.to_le_bytes());
+        bytes.extend_from_slice(&16u16.to_le_bytes());
+        bytes.extend_from_slice(b"data");
+        bytes.extend_from_slice(&data_len.to_le_bytes());
+        bytes.resize(bytes.len() + data_len as usize, 0);
+        fs::write(path, bytes).unwrap();
+    }
+
+    /// 三个静音的 MPEG-1 Layer III 帧
+    fn write_silent_mp3(path: &std::path::Path) {
+        let mut frame = vec![0u8; 417];
+        frame[..4].copy_from_slice(&[0xFF, 0xFB, 0x90, 0x64]);
+        fs::write(path, frame.repeat(3)).unwrap();
+    }
+
+    /// 只有 STREAMINFO 和 PADDING 的 FLAC
+    fn write_empty_flac(path: &std::path::Path) {
+        let mut flac = b"fLaC".to_vec();
+        flac.extend([0x00, 0, 0, 34]);
+        flac.extend([0x10, 0x00, 0x10, 0x00]); // block size 4096
+        flac.extend([0; 6]); // frame size
+        flac.extend([0x0A, 0xC4, 0x42, 0xF0, 0, 0, 0, 0]); // 44100 Hz, stereo, 16 bit
+        flac.extend([0; 16]); // MD5
+        flac.extend([0x81, 0, 0, 16]);
+        flac.extend([0; 16]);
+        fs::write(path, flac).unwrap();
+    }
+
+    fn atom(name: &[u8; 4], children: &[Vec<u8>]) -> Vec<u8> {
+        let content = children.concat();
+        let mut bytes = ((content.len() + 8) as u32).to_be_bytes().to_vec();
+        bytes.extend_from_slice(name);
+        bytes.extend(content);
+        bytes
+    }
+
+    /// ftyp + moov.trak.mdia（mdhd, hdlr soun）+ 空的 mdat，lofty 能识别的最小 M4A
+    fn write_minimal_m4a(path: &std::path::Path) {
+        let ftyp = [b"M4A ".as_slice(), &[0; 4], b"M4A isom"].concat();
+        let mut mdhd = vec![0; 12];
+        mdhd.extend(1000u32.to_be_bytes()); // timescale
+        mdhd.extend([0; 8]); // duration, language, quality
+        let mut hdlr = vec![0; 8];
+        hdlr.extend_from_slice(b"soun");
+        hdlr.extend([0; 13]);
+
+        let mdia = atom(b"mdia", &[atom(b"mdhd", &[mdhd]), atom(b"hdlr", &[hdlr])]);
+        let bytes = [
+            atom(b"ftyp", &[ftyp]),
+            atom(b"moov", &[atom(b"trak", &[mdia])]),
+            atom(b"mdat", &[]),
+        ]
+        .concat();
+        fs::write(path, bytes).unwrap();
+    }
+
+    fn png_bytes(width: u32, height: u32) -> Vec<u8> {
+        let img = image::RgbImage::from_pixel(width, height, image::Rgb([200, 40, 40]));
+        let mut output = Cursor::new(Vec::new());
+        img.write_to(&mut output, ImageFormat::Png).unwrap();
+        output.into_inner()
+    }
+
+    fn test_dir(name: &str) -> PathBuf {
+        let base = std::env::temp_dir().join(format!(
+            "coriander_player_picture_test_{}_{}",
+            name,
+            std::process::id()
+        ));
+        fs::create_dir_all(&base).unwrap();
+        base
+    }
+
+    fn assert_cover_roundtrip(base: &std::path::Path, audio: &std::path::Path) {
+        let audio = audio.to_string_lossy().to_string();
+
+        set_front_cover(audio.clone(), png_bytes(64, 32), None, None).unwrap();
+        set_front_cover(audio.clone(), png_bytes(800, 400), Some(200), None).unwrap();
+
+        let pictures = list_embedded_pictures(audio.clone()).unwrap();
+        assert_eq!(pictures.len(), 1, "{}", audio);
+        assert_eq!(pictures[0].picture_type, EmbeddedPictureType::CoverFront);
+        assert_eq!(pictures[0].mime_type.as_deref(), Some("image/jpeg"));
+        assert_eq!(
+            (pictures[0].width, pictures[0].height),
+            (Some(200), Some(100))
+        );
+
+        let extracted = extract_embedded_picture(
+            audio.clone(),
+            0,
+            base.join("cover").to_string_lossy().to_string(),
+        )
+        .unwrap();
+        assert!(extracted.ends_with("cover.jpg"));
+
+        assert_eq!(
+            remove_embedded_pictures(audio.clone(), Some(EmbeddedPictureType::CoverFront)).unwrap(),
+            1
+        );
+        assert!(list_embedded_pictures(audio).unwrap().is_empty());
+    }
+
+    #[test]
+    fn cover_roundtrip() {
+        let base = test_dir("cover");
+        let writers: [(&str, WriteFixture); 4] = [
+            ("t.wav", write_silent_wav),
+            ("t.mp3", write_silent_mp3),
+            ("t.flac", write_empty_flac),
+            ("t.m4a", write_minimal_m4a),
+        ];
+        for (name, write) in writers {
+            let audio = base.join(name);
+            write(&audio);
+            assert_cover_roundtrip(&base, &audio);
+        }
+
+        let _ = fs::remove_dir_all(base);
+    }
+
+    #[test]
+    fn undefined_picture_type_keeps_raw_byte() {
+        let base = test_dir("undefined");
+        let writers: [(&str, WriteFixture); 2] =
+            [("t.mp3", write_silent_mp3), ("t.flac", write_empty_flac)];
+        for (name, write) in writers {
+            let audio = base.join(name);
+            write(&audio);
+            let audio = audio.to_string_lossy().to_string();
+
+            let mut tagged_file = lofty::read_from_path(&audio).unwrap();
+            _picture_tag_mut(&mut tagged_file)
+                .unwrap()
+                .push_picture(Picture::new_unchecked(
+                    PictureType::Undefined(0x30),
+                    Some(MimeType::Png),
+                    None,
+                    png_bytes(8, 8),
+                ));
+            _save(tagged_file, &audio).unwrap();
+            set_front_cover(audio.clone(), png_bytes(16, 16), None, None).unwrap();
+
+            let undefined = EmbeddedPictureType::Undefined { raw: 0x30 };
+            let types: Vec<_> = list_embedded_pictures(audio.clone())
+                .unwrap()
+                .into_iter()
+                .map(|pic| pic.picture_type)
+                .collect();
+            assert_eq!(
+                types,
+                [EmbeddedPictureType::CoverFront, undefined],
+                "{}",
+                audio
+            );
+
+            assert_eq!(
+                remove_embedded_pictures(audio.clone(), Some(undefined)).unwrap(),
+                1
+            );
+            let pictures = list_embedded_pictures(audio).unwrap();
+            assert_eq!(pictures.len(), 1);
+            assert_eq!(pictures[0].picture_type, EmbeddedPictureType::CoverFront);
+        }
+
+        let _ = fs::remove_dir_all(base);
+    }
+}
@@ -3,6 +3,9 @@ use std::path::{Path, PathBuf};
 
 use anyhow::{anyhow, Result};
 use rusqlite::{params, Connection, OptionalExtension};
+use xxhash_rust::xxh3::xxh3_128;
+
+use super::lyric::{self, Lyric, LyricFormat};
 
 #[derive(Clone)]
 pub struct IndexAudio {
@@ -20,6 +23,20 @@ pub struct IndexAudio {
     pub by: Option<String>,
 }
 
+/// CUE 虚拟音轨在音乐文件中的位置
+#[derive(Clone, Debug, PartialEq)]
+pub struct IndexCueTrack {
+    /// 虚拟音轨的路径，即 [IndexAudio::path]
+    pub path: String,
+    /// 音乐文件路径
+    pub source: String,
+    pub track: u32,
+    /// ms
+    pub start: u64,
+    /// ms，None 表示到文件结尾
+    pub end: Option<u64>,
+}
+
 #[derive(Clone)]
 pub struct IndexFolder {
     pub path: String,
@@ -78,6 +95,41 @@ fn init_schema(conn: &Connection) -> Result<()> {
         CREATE INDEX IF NOT EXISTS idx_audios_title ON audios(title);
         CREATE INDEX IF NOT EXISTS idx_audios_artist ON audios(artist);
         CREATE INDEX IF NOT EXISTS idx_audios_album ON audios(album);
+
+        CREATE TABLE IF NOT EXISTS cue_tracks (
+          path TEXT PRIMARY KEY,
+          source TEXT NOT NULL,
+          track INTEGER NOT NULL,
+          start_ms INTEGER NOT NULL,
+          end_ms INTEGER
+        );
+
+        CREATE TABLE IF NOT EXISTS lyric_offsets (
+          path TEXT PRIMARY KEY,
+          offset_ms INTEGER NOT NULL
+        );
+
+        CREATE TABLE IF NOT EXISTS lyrics (
+          path TEXT NOT NULL,
+          hash TEXT NOT NULL,
+          source TEXT NOT NULL,
+          provider TEXT,
+          format TEXT,
+          raw TEXT NOT NULL,
+          parsed TEXT,
+          offset_ms INTEGER NOT NULL DEFAULT 0,
+          preferred INTEGER NOT NULL DEFAULT 0,
+          updated INTEGER NOT NULL,
+          PRIMARY KEY (path, hash)
+        );
+
+        CREATE TABLE IF NOT EXISTS album_palettes (
+          album TEXT NOT NULL,
+          album_artist TEXT NOT NULL,
+          picture_hash TEXT NOT NULL,
+          palette TEXT NOT NULL,
+          PRIMARY KEY (album, album_artist)
+        );
         "#,
     )?;
     Ok(())
@@ -97,6 +149,7 @@ pub(crate) fn write_index_value_to_sqlite(index_dir: &Path, index: &serde_json::
     let tx = conn.transaction()?;
 
     tx.execute("DELETE FROM audios", [])?;
+    tx.execute("DELETE FROM cue_tracks", [])?;
     tx.execute("DELETE FROM folders", [])?;
     tx.execute("DELETE FROM meta WHERE key = 'version'", [])?;
     tx.execute(
@@ -111,6 +164,9 @@ pub(crate) fn write_index_value_to_sqlite(index_dir: &Path, index: &serde_json::
             "INSERT INTO audios(path, folder_path, title, artist, album, album_artist, track, duration, bitrate, sample_rate, modified, created, by)
              VALUES(?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
         )?;
+        let mut cue_stmt = tx.prepare(
+            "INSERT INTO cue_tracks(path, source, track, start_ms, end_ms) VALUES(?1, ?2, ?3, ?4, ?5)",
+        )?;
 
         for folder in folders {
             let folder_path = folder
@@ -161,6 +217,17 @@ pub(crate) fn write_index_value_to_sqlite(index_dir: &Path, index: &serde_json::
                     created as i64,
                     by,
                 ])?;
+
+                let cue = &audio["cue"];
+                if let Some(source) = cue.get("source").and_then(|v| v.as_str()) {
+                    cue_stmt.execute(params![
+                        path,
+                        source,
+                        cue.get("track").and_then(|v| v.as_u64()).unwrap_or(0) as i64,
+                        cue.get("start").and_then(|v| v.as_u64()).unwrap_or(0) as i64,
+                        cue.get("end").and_then(|v| v.as_u64()).map(|v| v as i64),
+                    ])?;
+                }
             }
         }
     }
@@ -256,6 +323,318 @@ pub fn read_index_from_sqlite(index_path: String) -> Result<Vec<IndexFolder>> {
     Ok(result)
 }
 
+/// 播放 CUE 虚拟音轨时需要的音乐文件路径和范围。不是虚拟音轨时为 None
+pub fn get_cue_track(index_path: String, path: String) -> Result<Option<IndexCueTrack>> {
+    let conn = open_connection(Path::new(&index_path))?;
+    init_schema(&conn)?;
+    let track = conn
+        .query_row(
+            "SELECT path, source, track, start_ms, end_ms FROM cue_tracks WHERE path = ?1",
+            params![path],
+            |row| {
+                let track: i64 = row.get(2)?;
+                let start: i64 = row.get(3)?;
+                let end: Option<i64> = row.get(4)?;
+                Ok(IndexCueTrack {
+                    path: row.get(0)?,
+                    source: row.get(1)?,
+                    track: track as u32,
+                    start: start as u64,
+                    end: end.map(|end| end as u64),
+                })
+            },
+        )
+        .optional()?;
+    Ok(track)
+}
+
+/// 歌词时间偏移（ms），正数表示歌词延后。不随索引重建清除
+pub fn set_lyric_offset(index_path: String, path: String, offset: i64) -> Result<()> {
+    let conn = open_connection(Path::new(&index_path))?;
+    init_schema(&conn)?;
+    if offset == 0 {
+        conn.execute("DELETE FROM lyric_offsets WHERE path = ?1", params![path])?;
+    } else {
+        conn.execute(
+            "INSERT INTO lyric_offsets(path, offset_ms) VALUES(?1, ?2)
+             ON CONFLICT(path) DO UPDATE SET offset_ms = excluded.offset_ms",
+            params![path, offset],
+        )?;
+    }
+    Ok(())
+}
+
+/// 没有保存时为 0
+pub fn get_lyric_offset(index_path: String, path: String) -> Result<i64> {
+    let conn = open_connection(Path::new(&index_path))?;
+    init_schema(&conn)?;
+    let offset: Option<i64> = conn
+        .query_row(
+            "SELECT offset_ms FROM lyric_offsets WHERE path = ?1",
+            params![path],
+            |row| row.get(0),
+        )
+        .optional()?;
+    Ok(offset.unwrap_or(0))
+}
+
+/// 索引中 path 所在专辑的主题色。封面变化（picture_hash 不同）时为 None
+pub(crate) fn get_album_palette(
+    index_dir: &Path,
+    path: &str,
+    picture_hash: &str,
+) -> Result<Option<String>> {
+    let conn = open_connection(index_dir)?;
+    init_schema(&conn)?;
+    Ok(conn
+        .query_row(
+            "SELECT p.palette FROM audios a
+             JOIN album_palettes p
+               ON p.album = a.album AND p.album_artist = COALESCE(a.album_artist, '')
+             WHERE a.path = ?1 AND p.picture_hash = ?2",
+            params![path, picture_hash],
+            |row| row.get(0),
+        )
+        .optional()?)
+}
+
+/// 按专辑保存主题色，同一专辑的曲目共用一份。不随索引重建和缩略图缓存清除。
+/// path 不在索引中时不保存
+pub(crate) fn save_album_palette(
+    index_dir: &Path,
+    path: &str,
+    picture_hash: &str,
+    palette: &str,
+) -> Result<()> {
+    let conn = open_connection(index_dir)?;
+    init_schema(&conn)?;
+    conn.execute(
+        "INSERT INTO album_palettes(album, album_artist, picture_hash, palette)
+         SELECT album, COALESCE(album_artist, ''), ?2, ?3 FROM audios WHERE path = ?1
+         ON CONFLICT(album, album_artist) DO UPDATE SET
+           picture_hash = excluded.picture_hash,
+           palette = excluded.palette",
+        params![path, picture_hash, palette],
+    )?;
+    Ok(())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LyricSource {
+    /// 音乐文件的内嵌歌词
+    Embedded,
+    /// 外挂歌词文件
+    Sidecar,
+    /// 在线歌词，provider 为来源名称
+    Online,
+    /// 用户编辑过的歌词
+    UserEdit,
+}
+
+impl LyricSource {
+    fn as_str(self) -> &'static str {
+        match self {
+            LyricSource::Embedded => "embedded",
+            LyricSource::Sidecar => "sidecar",
+            LyricSource::Online => "online",
+            LyricSource::UserEdit => "user_edit",
+        }
+    }
+
+    fn from_str(source: &str) -> Option<Self> {
+        Some(match source {
+            "embedded" => LyricSource::Embedded,
+            "sidecar" => LyricSource::Sidecar,
+            "online" => LyricSource::Online,
+            "user_edit" => LyricSource::UserEdit,
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct CachedLyric {
+    pub path: String,
+    /// raw 的 xxh3_128
+    pub hash: String,
+    pub source: LyricSource,
+    /// 在线歌词的来源名称，外挂歌词的文件路径
+    pub provider: Option<String>,
+    /// 无法解析时为 None
+    pub format: Option<LyricFormat>,
+    pub raw: String,
+    pub parsed: Option<Lyric>,
+    /// 这条歌词自身的时间偏移（ms），正数表示歌词延后
+    pub offset: i64,
+    pub preferred: bool,
+    /// 保存时间，unix 秒
+    pub updated: u64,
+}
+
+const CACHED_LYRIC_COLUMNS: &str =
+    "path, hash, source, provider, format, raw, parsed, offset_ms, preferred, updated";
+
+fn _lyric_hash(raw: &str) -> String {
+    format!("{:032x}", xxh3_128(raw.as_bytes()))
+}
+
+fn _cached_lyric_from_row(row: &rusqlite::Row) -> rusqlite::Result<CachedLyric> {
+    let source: String = row.get(2)?;
+    let format: Option<String> = row.get(4)?;
+    let parsed: Option<String> = row.get(6)?;
+    let updated: i64 = row.get(9)?;
+    Ok(CachedLyric {
+        path: row.get(0)?,
+        hash: row.get(1)?,
+        source: LyricSource::from_str(&source).unwrap_or(LyricSource::Online),
+        provider: row.get(3)?,
+        format: format.as_deref().and_then(LyricFormat::from_str),
+        raw: row.get(5)?,
+        parsed: parsed
+            .and_then(|parsed| serde_json::from_str(&parsed).ok())
+            .and_then(|value| Lyric::from_json_value(&value)),
+        offset: row.get(7)?,
+        preferred: row.get::<_, i64>(8)? != 0,
+        updated: updated.max(0) as u64,
+    })
+}
+
+/// 保存已经解析的歌词。相同内容只保存一次，再次保存时更新来源；
+/// preferred 为 true 时取消这首歌其他歌词的首选
+pub(crate) fn save_parsed_lyric(
+    index_path: &str,
+    path: &str,
+    source: LyricSource,
+    provider: Option<String>,
+    raw: &str,
+    parsed: Option<&Lyric>,
+    preferred: bool,
+) -> Result<CachedLyric> {
+    let mut conn = open_connection(Path::new(index_path))?;
+    init_schema(&conn)?;
+
+    let hash = _lyric_hash(raw);
+    let updated = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    let tx = conn.transaction()?;
+    if preferred {
+        tx.execute(
+            "UPDATE lyrics SET preferred = 0 WHERE path = ?1",
+            params![path],
+        )?;
+    }
+    tx.execute(
+        "INSERT INTO lyrics(path, hash, source, provider, format, raw, parsed, preferred, updated)
+         VALUES(?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+         ON CONFLICT(path, hash) DO UPDATE SET
+           source = excluded.source,
+           provider = excluded.provider,
+           format = excluded.format,
+           parsed = excluded.parsed,
+           preferred = MAX(preferred, excluded.preferred),
+           updated = excluded.updated",
+        params![
+            path,
+            hash,
+            source.as_str(),
+            provider,
+            parsed.map(|lyric| lyric.format.as_str()),
+            raw,
+            parsed.map(|lyric| lyric.to_json_value().to_string()),
+            preferred as i64,
+            updated as i64,
+        ],
+    )?;
+    let cached = tx.query_row(
+        &format!("SELECT {CACHED_LYRIC_COLUMNS} FROM lyrics WHERE path = ?1 AND hash = ?2"),
+        params![path, hash],
+        _cached_lyric_from_row,
+    )?;
+    tx.commit()?;
+    Ok(cached)
+}
+
+/// 保存歌词到缓存，按内容自动识别格式并解析
+pub fn save_lyric(
+    index_path: String,
+    path: String,
+    source: LyricSource,
+    provider: Option<String>,
+    raw: String,
+    preferred: bool,
+) -> Result<CachedLyric> {
+    let parsed = lyric::parse_lyric(raw.clone());
+    save_parsed_lyric(
+        &index_path,
+        &path,
+        source,
+        provider,
+        &raw,
+        parsed.as_ref(),
+        preferred,
+    )
+}
+
+/// 这首歌缓存的所有歌词，首选的在最前
+pub fn get_cached_lyrics(index_path: String, path: String) -> Result<Vec<CachedLyric>> {
+    let conn = open_connection(Path::new(&index_path))?;
+    init_schema(&conn)?;
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {CACHED_LYRIC_COLUMNS} FROM lyrics WHERE path = ?1
+         ORDER BY preferred DESC,
+           CASE source WHEN 'user_edit' THEN 0 WHEN 'sidecar' THEN 1 WHEN 'embedded' THEN 2 ELSE 3 END,
+           updated DESC"
+    ))?;
+    let rows = stmt.query_map(params![path], _cached_lyric_from_row)?;
+    Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+}
+
+/// 首选歌词。没有设置首选时依次为用户编辑、外挂、内嵌、在线歌词，相同来源取最新的
+pub fn get_preferred_lyric(index_path: String, path: String) -> Result<Option<CachedLyric>> {
+    Ok(get_cached_lyrics(index_path, path)?.into_iter().next())
+}
+
+pub fn set_preferred_lyric(index_path: String, path: String, hash: String) -> Result<()> {
+    let mut conn = open_connection(Path::new(&index_path))?;
+    init_schema(&conn)?;
+    let tx = conn.transaction()?;
+    tx.execute(
+        "UPDATE lyrics SET preferred = (hash = ?2) WHERE path = ?1",
+        params![path, hash],
+    )?;
+    tx.commit()?;
+    Ok(())
+}
+
+/// 和 [set_lyric_offset] 叠加
+pub fn set_cached_lyric_offset(
+    index_path: String,
+    path: String,
+    hash: String,
+    offset: i64,
+) -> Result<()> {
+    let conn = open_connection(Path::new(&index_path))?;
+    init_schema(&conn)?;
+    conn.execute(
+        "UPDATE lyrics SET offset_ms = ?3 WHERE path = ?1 AND hash = ?2",
+        params![path, hash, offset],
+    )?;
+    Ok(())
+}
+
+pub fn remove_cached_lyric(index_path: String, path: String, hash: String) -> Result<()> {
+    let conn = open_connection(Path::new(&index_path))?;
+    init_schema(&conn)?;
+    conn.execute(
+        "DELETE FROM lyrics WHERE path = ?1 AND hash = ?2",
+        params![path, hash],
+    )?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -304,4 +683,151 @@ mod tests {
         assert_eq!(folders[0].audios.len(), 1);
         assert_eq!(folders[0].audios[0].title, "t");
     }
+
+    #[test]
+    fn lyric_offset_survives_reindex() {
+        let base = std::env::temp_dir().join(format!(
+            "coriander_player_lyric_offset_test_{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&base).unwrap();
+        let index_path = base.to_string_lossy().to_string();
+        let audio = "C:\\Music\\t.mp3".to_string();
+
+        assert_eq!(
+            get_lyric_offset(index_path.clone(), audio.clone()).unwrap(),
+            0
+        );
+        set_lyric_offset(index_path.clone(), audio.clone(), -300).unwrap();
+        set_lyric_offset(index_path.clone(), audio.clone(), 250).unwrap();
+
+        let index = serde_json::json!({ "version": 110, "folders": [] });
+        write_index_value_to_sqlite(&base, &index).unwrap();
+        assert_eq!(
+            get_lyric_offset(index_path.clone(), audio.clone()).unwrap(),
+            250
+        );
+
+        set_lyric_offset(index_path.clone(), audio.clone(), 0).unwrap();
+        assert_eq!(get_lyric_offset(index_path, audio).unwrap(), 0);
+
+        let _ = std::fs::remove_dir_all(base);
+    }
+
+    #[test]
+    fn album_palette_is_shared_and_survives_reindex() {
+        let base = std::env::temp_dir().join(format!(
+            "coriander_player_album_palette_test_{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&base).unwrap();
+        let audio = |title: &str| {
+            serde_json::json!({
+                "title": title,
+                "artist": "a",
+                "album": "al",
+                "album_artist": null,
+                "track": 0,
+                "duration": 3,
+                "bitrate": 320,
+                "sample_rate": 44100,
+                "path": format!("/music/{title}.flac"),
+                "modified": 4,
+                "created": 5,
+                "by": "Lofty"
+            })
+        };
+        let index = serde_json::json!({
+            "version": 110,
+            "folders": [{
+                "path": "/music",
+                "modified": 1,
+                "latest": 2,
+                "audios": [audio("a"), audio("b")]
+            }]
+        });
+        write_index_value_to_sqlite(&base, &index).unwrap();
+
+        // 不在索引中的文件不保存
+        save_album_palette(&base, "/other/c.flac", "h1", "{}").unwrap();
+        assert_eq!(
+            get_album_palette(&base, "/other/c.flac", "h1").unwrap(),
+            None
+        );
+
+        save_album_palette(&base, "/music/a.flac", "h1", "{\"a\":1}").unwrap();
+        write_index_value_to_sqlite(&base, &index).unwrap();
+        assert_eq!(
+            get_album_palette(&base, "/music/b.flac", "h1").unwrap(),
+            Some("{\"a\":1}".to_string())
+        );
+        // 封面变化后需要重新计算
+        assert_eq!(
+            get_album_palette(&base, "/music/b.flac", "h2").unwrap(),
+            None
+        );
+
+        let _ = std::fs::remove_dir_all(base);
+    }
+
+    #[test]
+    fn lyric_cache_prefers_user_edit_and_keeps_parsed() {
+        let base = std::env::temp_dir().join(format!(
+            "coriander_player_lyric_cache_test_{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&base);
+        std::fs::create_dir_all(&base).unwrap();
+        let index_path = base.to_string_lossy().to_string();
+        let audio = "C:\\Music\\t.mp3".to_string();
+
+        assert!(get_preferred_lyric(index_path.clone(), audio.clone())
+            .unwrap()
+            .is_none());
+
+        let online = save_lyric(
+            index_path.clone(),
+            audio.clone(),
+            LyricSource::Online,
+            Some("netease".to_string()),
+            "[00:01.00]hello\n[00:02.00]world".to_string(),
+            false,
+        )
+        .unwrap();
+        assert_eq!(online.format, Some(LyricFormat::Lrc));
+        save_lyric(
+            index_path.clone(),
+            audio.clone(),
+            LyricSource::UserEdit,
+            None,
+            "[00:01.50]hello!\n[00:02.00]world".to_string(),
+            false,
+        )
+        .unwrap();
+
+        let preferred = get_preferred_lyric(index_path.clone(), audio.clone())
+            .unwrap()
+            .unwrap();
+        assert_eq!(preferred.source, LyricSource::UserEdit);
+        assert_eq!(preferred.parsed.unwrap().lines[0].start, 1500);
+
+        set_preferred_lyric(index_path.clone(), audio.clone(), online.hash.clone()).unwrap();
+        set_cached_lyric_offset(index_path.clone(), audio.clone(), online.hash.clone(), 200)
+            .unwrap();
+        let index = serde_json::json!({ "version": 110, "folders": [] });
+        write_index_value_to_sqlite(&base, &index).unwrap();
+
+        let preferred = get_preferred_lyric(index_path.clone(), audio.clone())
+            .unwrap()
+            .unwrap();
+        assert!(preferred.preferred);
+        assert_eq!(preferred.provider.as_deref(), Some("netease"));
+        assert_eq!(preferred.offset, 200);
+        assert_eq!(preferred.parsed, online.parsed);
+
+        remove_cached_lyric(index_path.clone(), audio.clone(), online.hash).unwrap();
+        assert_eq!(get_cached_lyrics(index_path, audio).unwrap().len(), 1);
+
+        let _ = std::fs::remove_dir_all(base);
+    }
 }
@@ -0,0 +1,1035 @@
+mod decrypt;
+mod krc;
+mod lrc;
+mod qrc;
+mod srt;
+mod ttml;
+
+use anyhow::Result;
+use flutter_rust_bridge::frb;
+
+use super::library_db::{self, CachedLyric};
+use super::logger::log_to_dart;
+use super::tag_reader::{self, LyricCandidate, LyricCandidateSource};
+use super::text_convert::{self, ChineseVariant, RomanizationKind};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LyricFormat {
+    Lrc,
+    /// 带有 `<mm:ss.xx>` 逐字时间的 LRC
+    EnhancedLrc,
+    Krc,
+    Qrc,
+    Srt,
+    /// TTML，包括 Apple Music 的逐字歌词（itunes:timing="Word"）
+    Ttml,
+}
+
+impl LyricFormat {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            LyricFormat::Lrc => "lrc",
+            LyricFormat::EnhancedLrc => "enhanced_lrc",
+            LyricFormat::Krc => "krc",
+            LyricFormat::Qrc => "qrc",
+            LyricFormat::Srt => "srt",
+            LyricFormat::Ttml => "ttml",
+        }
+    }
+
+    pub(crate) fn from_str(format: &str) -> Option<Self> {
+        Some(match format {
+            "lrc" => LyricFormat::Lrc,
+            "enhanced_lrc" => LyricFormat::EnhancedLrc,
+            "krc" => LyricFormat::Krc,
+            "qrc" => LyricFormat::Qrc,
+            "srt" => LyricFormat::Srt,
+            "ttml" => LyricFormat::Ttml,
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LyricWord {
+    /// ms
+    pub start: u32,
+    /// ms
+    pub end: u32,
+    pub text: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LyricLine {
+    /// ms
+    pub start: u32,
+    /// ms
+    pub end: u32,
+    pub text: String,
+    /// 没有逐字时间时为空
+    pub words: Vec<LyricWord>,
+    pub translation: Option<String>,
+    /// 演唱者，对应 [LyricAgent::id]。用于对唱
+    pub agent: Option<String>,
+    /// 和声（背景人声）的逐字时间，没有时为空
+    pub background: Vec<LyricWord>,
+    /// 拼音或罗马字，见 [Lyric::with_romanization]
+    pub romanization: Option<String>,
+}
+
+/// TTML 中的 ttm:agent
+#[derive(Clone, Debug, PartialEq)]
+pub struct LyricAgent {
+    /// 如 v1, v2
+    pub id: String,
+    /// person, group, other
+    pub kind: String,
+    pub name: Option<String>,
+}
+
+/// [ar:xxx], [ti:xxx], [offset:xxx] 等标签
+#[derive(Clone, Debug, PartialEq)]
+pub struct LyricTag {
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Lyric {
+    pub format: LyricFormat,
+    pub tags: Vec<LyricTag>,
+    /// 按 start 升序排列。offset 标签已经应用到时间上
+    pub lines: Vec<LyricLine>,
+    pub agents: Vec<LyricAgent>,
+}
+
+impl Lyric {
+    /// 按开始时间排序（稳定），并补全未知的结束时间：
+    /// 行的结束时间为最后一个字的结束时间或下一行的开始时间；
+    /// 字的结束时间为下一个字的开始时间或行的结束时间。
+    fn new(format: LyricFormat, tags: Vec<LyricTag>, mut lines: Vec<LyricLine>) -> Option<Self> {
+        if lines.is_empty() {
+            return None;
+        }
+        lines.sort_by_key(|line| line.start);
+
+        let next_starts: Vec<Option<u32>> = lines
+            .iter()
+            .skip(1)
+            .map(|line| Some(line.start))
+            .chain([None])
+            .collect();
+        for (line, next_start) in lines.iter_mut().zip(next_starts) {
+            if line.end <= line.start {
+                line.end = line
+                    .words
+                    .last()
+                    .map(|word| word.end)
+                    .filter(|end| *end > line.start)
+                    .or(next_start)
+                    .unwrap_or(line.start)
+                    .max(line.start);
+            }
+
+            _fill_word_ends(&mut line.words, line.end);
+            _fill_word_ends(&mut line.background, line.end);
+
+            if line.text.is_empty() {
+                line.text = line.words.iter().map(|word| word.text.as_str()).collect();
+            }
+        }
+
+        Some(Lyric {
+            format,
+            tags,
+            lines,
+            agents: vec![],
+        })
+    }
+
+    /// for Flutter
+    /// 标签名不区分大小写
+    #[frb(sync)]
+    pub fn tag(&self, key: String) -> Option<String> {
+        self.tags
+            .iter()
+            .find(|tag| tag.key.eq_ignore_ascii_case(&key))
+            .map(|tag| tag.value.clone())
+    }
+
+    /// for Flutter
+    /// 导出为 LRC。有逐字时间时写成逐字 LRC，和声写在 [bg:...] 行，翻译写在相同时间的下一行
+    #[frb(sync)]
+    pub fn to_lrc(&self) -> String {
+        lrc::write(self)
+    }
+
+    /// for Flutter
+    /// 导出为 Apple Music 风格的 TTML
+    #[frb(sync)]
+    pub fn to_ttml(&self) -> String {
+        ttml::write(self)
+    }
+
+    /// 对所有时间（行、字、和声）应用 map
+    fn _map_times(&self, map: impl Fn(u32) -> u32) -> Lyric {
+        let map_words = |words: &[LyricWord]| -> Vec<LyricWord> {
+            words
+                .iter()
+                .map(|word| LyricWord {
+                    start: map(word.start),
+                    end: map(word.end),
+                    text: word.text.clone(),
+                })
+                .collect()
+        };
+        let lines = self
+            .lines
+            .iter()
+            .map(|line| LyricLine {
+                start: map(line.start),
+                end: map(line.end),
+                words: map_words(&line.words),
+                background: map_words(&line.background),
+                ..line.clone()
+            })
+            .collect();
+        Lyric {
+            lines,
+            ..self.clone()
+        }
+    }
+
+    /// for Flutter
+    /// 所有时间加上 offset ms（正数表示歌词延后，和 LRC 的 [offset:] 相反），小于 0 的时间变为 0
+    #[frb(sync)]
+    pub fn shifted(&self, offset: i64) -> Lyric {
+        self._map_times(|time| _apply_offset(time, offset.saturating_neg()))
+    }
+
+    /// for Flutter
+    /// 线性伸缩：把 from_a, from_b 分别对应到 to_a, to_b，其他时间按比例变换。
+    /// 用于和音频的速度不一致（如 PAL 加速）的歌词。from_a == from_b 或顺序被反转时返回 None
+    #[frb(sync)]
+    pub fn stretched(&self, from_a: u32, to_a: u32, from_b: u32, to_b: u32) -> Option<Lyric> {
+        let scale = (to_b as f64 - to_a as f64) / (from_b as f64 - from_a as f64);
+        if !scale.is_finite() || scale <= 0.0 {
+            return None;
+        }
+        Some(self._map_times(|time| {
+            let time = to_a as f64 + (time as f64 - from_a as f64) * scale;
+            time.round().clamp(0.0, u32::MAX as f64) as u32
+        }))
+    }
+
+    /// for Flutter
+    /// 把 translation 的每一行作为翻译放到开始时间最接近（相差不超过 tolerance ms）的行
+    #[frb(sync)]
+    pub fn with_translation(&self, translation: Lyric, tolerance: u32) -> Lyric {
+        let mut lyric = self.clone();
+        _merge_translation(&mut lyric, &translation, tolerance);
+        lyric
+    }
+
+    /// for Flutter
+    /// 简繁转换原文（包括逐字歌词、和声）和翻译
+    #[frb(sync)]
+    pub fn with_chinese_variant(&self, variant: ChineseVariant) -> Lyric {
+        let convert = |text: &str| text_convert::convert_chinese(text.to_string(), variant);
+        let mut lyric = self.clone();
+        for line in &mut lyric.lines {
+            line.text = convert(&line.text);
+            _convert_words(&mut line.words, convert);
+            _convert_words(&mut line.background, convert);
+            line.translation = line.translation.as_deref().map(convert);
+        }
+        lyric
+    }
+
+    /// for Flutter
+    /// 为有汉字或假名的行生成拼音或罗马字，放在 romanization。
+    /// Auto 时整首歌词有假名则为罗马字，否则为拼音。罗马字时跳过含有汉字的行
+    #[frb(sync)]
+    pub fn with_romanization(&self, kind: RomanizationKind) -> Lyric {
+        let has_kana = self
+            .lines
+            .iter()
+            .any(|line| text_convert::contains_kana(&line.text));
+        let kind = text_convert::resolve_romanization(kind, has_kana);
+
+        let mut lyric = self.clone();
+        for line in &mut lyric.lines {
+            line.romanization = text_convert::needs_romanization(&line.text, kind)
+                .then(|| text_convert::romanize(line.text.clone(), kind));
+        }
+        lyric
+    }
+
+    /// 用于缓存，见 [library_db::save_lyric]。字为 [start, end, text]
+    pub(crate) fn to_json_value(&self) -> serde_json::Value {
+        let words_to_json = |words: &[LyricWord]| -> Vec<serde_json::Value> {
+            words
+                .iter()
+                .map(|word| serde_json::json!([word.start, word.end, word.text]))
+                .collect()
+        };
+        serde_json::json!({
+            "format": self.format.as_str(),
+            "tags": self
+                .tags
+                .iter()
+                .map(|tag| serde_json::json!([tag.key, tag.value]))
+                .collect::<Vec<_>>(),
+            "agents": self
+                .agents
+                .iter()
+                .map(|agent| serde_json::json!({
+                    "id": agent.id,
+                    "kind": agent.kind,
+                    "name": agent.name
+                }))
+                .collect::<Vec<_>>(),
+            "lines": self
+                .lines
+                .iter()
+                .map(|line| serde_json::json!({
+                    "start": line.start,
+                    "end": line.end,
+                    "text": line.text,
+                    "words": words_to_json(&line.words),
+                    "translation": line.translation,
+                    "agent": line.agent,
+                    "background": words_to_json(&line.background),
+                    "romanization": line.romanization
+                }))
+                .collect::<Vec<_>>()
+        })
+    }
+
+    /// [Lyric::to_json_value] 的逆操作。不再次排序和补全时间
+    pub(crate) fn from_json_value(value: &serde_json::Value) -> Option<Lyric> {
+        let string = |value: &serde_json::Value| value.as_str().map(|s| s.to_string());
+        let time = |value: &serde_json::Value| value.as_u64().and_then(|t| u32::try_from(t).ok());
+        let words = |value: &serde_json::Value| -> Option<Vec<LyricWord>> {
+            value
+                .as_array()?
+                .iter()
+                .map(|word| {
+                    Some(LyricWord {
+                        start: time(&word[0])?,
+                        end: time(&word[1])?,
+                        text: string(&word[2])?,
+                    })
+                })
+                .collect()
+        };
+
+        let tags = value["tags"]
+            .as_array()?
+            .iter()
+            .map(|tag| {
+                Some(LyricTag {
+                    key: string(&tag[0])?,
+                    value: string(&tag[1])?,
+                })
+            })
+            .collect::<Option<_>>()?;
+        let agents = value["agents"]
+            .as_array()?
+            .iter()
+            .map(|agent| {
+                Some(LyricAgent {
+                    id: string(&agent["id"])?,
+                    kind: string(&agent["kind"])?,
+                    name: string(&agent["name"]),
+                })
+            })
+            .collect::<Option<_>>()?;
+        let lines = value["lines"]
+            .as_array()?
+            .iter()
+            .map(|line| {
+                Some(LyricLine {
+                    start: time(&line["start"])?,
+                    end: time(&line["end"])?,
+                    text: string(&line["text"])?,
+                    words: words(&line["words"])?,
+                    translation: string(&line["translation"]),
+                    agent: string(&line["agent"]),
+                    background: words(&line["background"])?,
+                    romanization: string(&line["romanization"]),
+                })
+            })
+            .collect::<Option<_>>()?;
+
+        Some(Lyric {
+            format: LyricFormat::from_str(value["format"].as_str()?)?,
+            tags,
+            lines,
+            agents,
+        })
+    }
+
+    /// 转换为 ID3v2 SYLT 帧的内容（时间单位为 ms）。
+    /// 有逐字时间时每个字一项，新的一行以换行开头，行的结束时间不是下一行的开始时间时
+    /// 在行末加上一个空白的项（Lofty 无法读取空字符串）；否则每行一项
+    pub(crate) fn to_synced_text(&self) -> Vec<(u32, String)> {
+        let word_timed = self.lines.iter().any(|line| !line.words.is_empty());
+        let mut content = vec![];
+        for (i, line) in self.lines.iter().enumerate() {
+            let newline = if word_timed && i > 0 { "\n" } else { "" };
+            if word_timed && !line.words.is_empty() {
+                for (j, word) in line.words.iter().enumerate() {
+                    let prefix = if j == 0 { newline } else { "" };
+                    content.push((word.start, format!("{prefix}{}", word.text)));
+                }
+                let next_start = self.lines.get(i + 1).map(|next| next.start);
+                if let Some(last) = line.words.last() {
+                    if next_start != Some(last.end) {
+                        content.push((last.end, " ".to_string()));
+                    }
+                }
+            } else {
+                content.push((line.start, format!("{newline}{}", line.text)));
+            }
+        }
+        content
+    }
+}
+
+/// 从 ID3v2 SYLT 帧的内容（时间单位为 ms）构造歌词，是 [Lyric::to_synced_text] 的逆操作
+pub(crate) fn from_synced_text(content: &[(u32, String)]) -> Option<Lyric> {
+    let starts_line = |text: &str| text.starts_with(['\n', '\r']);
+    let word_timed = content.iter().skip(1).any(|(_, text)| starts_line(text));
+
+    let mut lines: Vec<LyricLine> = vec![];
+    for (i, (time, text)) in content.iter().enumerate() {
+        let new_line = !word_timed || i == 0 || starts_line(text);
+        let text = text.trim_start_matches(['\n', '\r']);
+        if new_line {
+            lines.push(LyricLine {
+                start: *time,
+                end: 0,
+                text: String::new(),
+                words: vec![],
+                translation: None,
+                agent: None,
+                background: vec![],
+                romanization: None,
+            });
+        }
+        let Some(line) = lines.last_mut() else {
+            continue;
+        };
+        if word_timed && text.trim().is_empty() && !new_line {
+            // 行末的空白项是最后一个字的结束时间
+            if let Some(word) = line.words.last_mut() {
+                word.end = *time;
+            }
+        } else if word_timed {
+            line.words.push(LyricWord {
+                start: *time,
+                end: 0,
+                text: text.to_string(),
+            });
+        } else {
+            line.text = text.trim().to_string();
+        }
+    }
+
+    let format = if word_timed {
+        LyricFormat::EnhancedLrc
+    } else {
+        LyricFormat::Lrc
+    };
+    Lyric::new(format, vec![], lines)
+}
+
+/// 字的结束时间为下一个字的开始时间或 line_end
+/// 按整行转换，使词表可以匹配跨字的词；字数改变时逐字转换
+fn _convert_words(words: &mut [LyricWord], convert: impl Fn(&str) -> String) {
+    let text: String = words.iter().map(|word| word.text.as_str()).collect();
+    let converted = convert(&text);
+    if converted.chars().count() != text.chars().count() {
+        for word in words {
+            word.text = convert(&word.text);
+        }
+        return;
+    }
+
+    let mut chars = converted.chars();
+    for word in words {
+        word.text = chars.by_ref().take(word.text.chars().count()).collect();
+    }
+}
+
+fn _fill_word_ends(words: &mut [LyricWord], line_end: u32) {
+    let next_starts: Vec<u32> = words
+        .iter()
+        .skip(1)
+        .map(|word| word.start)
+        .chain([line_end])
+        .collect();
+    for (word, next_start) in words.iter_mut().zip(next_starts) {
+        if word.end <= word.start {
+            word.end = next_start.max(word.start);
+        }
+    }
+}
+
+/// mm:ss, mm:ss.x, mm:ss.xx, mm:ss.xxx, mm:ss:xx, hh:mm:ss.xx -> ms
+fn _parse_timestamp(time: &str) -> Option<u32> {
+    let time = time.trim();
+    let parts: Vec<&str> = time.split(':').collect();
+    let is_digits =
+        |s: &str| !s.is_empty() && s.len() <= 9 && s.bytes().all(|b| b.is_ascii_digit());
+
+    let (hours, minutes, seconds) = match parts[..] {
+        [m, s] => ("0", m, s),
+        // mm:ss:xx 是 mm:ss.xx 的另一种写法
+        [m, s, f] if s.len() == 2 && !s.contains('.') && f.len() <= 3 && is_digits(f) => {
+            return _parse_timestamp(&format!("{m}:{s}.{f}"));
+        }
+        [h, m, s] => (h, m, s),
+        _ => return None,
+    };
+
+    let (seconds, fraction) = match seconds.split_once('.') {
+        Some((s, f)) => (s, f),
+        None => (seconds, ""),
+    };
+    if !is_digits(hours) || !is_digits(minutes) || !is_digits(seconds) {
+        return None;
+    }
+    if !fraction.is_empty() && !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    // 小数部分最多保留到毫秒
+    let fraction_ms = match fraction.len() {
+        0 => 0,
+        1 => fraction.parse::<u64>().ok()? * 100,
+        2 => fraction.parse::<u64>().ok()? * 10,
+        _ => fraction[..3].parse::<u64>().ok()?,
+    };
+    let ms = (hours.parse::<u64>().ok()? * 3600
+        + minutes.parse::<u64>().ok()? * 60
+        + seconds.parse::<u64>().ok()?)
+        * 1000
+        + fraction_ms;
+    u32::try_from(ms).ok()
+}
+
+/// 应用 [offset:xxx]。offset 为正时歌词提前
+fn _apply_offset(time: u32, offset: i64) -> u32 {
+    (time as i64)
+        .saturating_sub(offset)
+        .clamp(0, u32::MAX as i64) as u32
+}
+
+/// [key:value] 形式的标签
+fn _parse_tag(line: &str) -> Option<LyricTag> {
+    let inner = line.trim().strip_prefix('[')?.strip_suffix(']')?;
+    let (key, value) = inner.split_once(':')?;
+    let key = key.trim();
+    if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphabetic() || c == '_') {
+        return None;
+    }
+    Some(LyricTag {
+        key: key.to_string(),
+        value: value.trim().to_string(),
+    })
+}
+
+/// for Flutter
+/// 自动识别 QRC, TTML, KRC, SRT, 逐字 LRC 和 LRC。没有歌词行时返回 None
+pub fn parse_lyric(text: String) -> Option<Lyric> {
+    if qrc::is_qrc(&text) {
+        parse_qrc(text, None)
+    } else if ttml::is_ttml(&text) {
+        parse_ttml(text)
+    } else if krc::is_krc(&text) {
+        parse_krc(text)
+    } else if srt::is_srt(&text) {
+        parse_srt(text)
+    } else {
+        parse_lrc(text)
+    }
+}
+
+/// for Flutter
+/// 解析 LRC 和逐字 LRC。相同时间的多行中，逐字时间最多的一行为原文，其他行合并为翻译
+pub fn parse_lrc(text: String) -> Option<Lyric> {
+    lrc::parse(&text)
+}
+
+/// for Flutter
+/// 解析已经解密的 KRC 文本，[language:xxx] 中的翻译会放到 translation
+pub fn parse_krc(text: String) -> Option<Lyric> {
+    krc::parse(&text)
+}
+
+/// for Flutter
+/// 解析已经解密的 QRC 文本（可以带有 QrcInfos XML 外壳）。
+/// translation: 翻译的 LRC 文本，按时间对应到最接近的行
+pub fn parse_qrc(text: String, translation: Option<String>) -> Option<Lyric> {
+    let mut lyric = qrc::parse(&text)?;
+    if let Some(translation) = translation.and_then(|t| lrc::parse(&t)) {
+        _merge_translation(&mut lyric, &translation, 1000);
+    }
+    Some(lyric)
+}
+
+/// 把 translation 的每一行放到 lyric 中开始时间最接近（相差不超过 tolerance ms）的行
+fn _merge_translation(lyric: &mut Lyric, translation: &Lyric, tolerance: u32) {
+    for trans_line in &translation.lines {
+        if trans_line.text.trim().is_empty() {
+            continue;
+        }
+        let nearest = lyric
+            .lines
+            .iter_mut()
+            .filter(|line| line.start.abs_diff(trans_line.start) <= tolerance)
+            .min_by_key(|line| line.start.abs_diff(trans_line.start));
+        if let Some(line) = nearest {
+            line.translation = Some(trans_line.text.trim().to_string());
+        }
+    }
+}
+
+/// for Flutter
+/// 解密酷狗 .krc 文件，返回 KRC 文本
+pub fn decrypt_krc(data: Vec<u8>) -> Result<String> {
+    decrypt::decrypt_krc(&data)
+}
+
+/// for Flutter
+/// 解密 QQ 音乐本地 .qrc 文件或者网络接口返回的 hex 文本，返回 QRC 文本
+pub fn decrypt_qrc(data: Vec<u8>) -> Result<String> {
+    decrypt::decrypt_qrc(&data)
+}
+
+/// for Flutter
+/// 解析 SRT 字幕。每个字幕块的第一行为原文，其他行合并为翻译
+pub fn parse_srt(text: String) -> Option<Lyric> {
+    srt::parse(&text)
+}
+
+/// for Flutter
+/// 解析 TTML（包括 Apple Music 的逐字歌词）。支持对唱（ttm:agent）、和声（x-bg）和翻译
+pub fn parse_ttml(text: String) -> Option<Lyric> {
+    ttml::parse(&text)
+}
+
+/// for Flutter
+/// 读取并解析 [tag_reader::get_lyric_candidates] 返回的歌词，按扩展名选择格式
+pub fn parse_lyric_candidate(path: String, candidate: LyricCandidate) -> Option<Lyric> {
+    let extension = candidate.extension.clone();
+    let text = tag_reader::read_lyric_candidate(path, candidate)?;
+    _parse_lyric_text(text, extension.as_deref())
+}
+
+fn _parse_lyric_text(text: String, extension: Option<&str>) -> Option<Lyric> {
+    match extension {
+        Some("krc") => parse_krc(text),
+        Some("qrc") => parse_qrc(text, None),
+        Some("srt") => parse_srt(text),
+        Some("ttml") => parse_ttml(text),
+        _ => parse_lyric(text),
+    }
+}
+
+fn _sorted_lyric_candidates(path: &str) -> Vec<LyricCandidate> {
+    let candidates = tag_reader::get_lyric_candidates(path.to_string());
+    let (word_timed, others): (Vec<_>, Vec<_>) = candidates
+        .into_iter()
+        .partition(|candidate| matches!(candidate.extension.as_deref(), Some("krc" | "qrc")));
+    word_timed.into_iter().chain(others).collect()
+}
+
+/// for Flutter
+/// 读取并解析歌词。优先使用 .krc, .qrc 逐字歌词，其次按 [tag_reader::get_lyric_candidates] 的顺序
+pub fn get_parsed_lyric_from_path(path: String) -> Option<Lyric> {
+    _sorted_lyric_candidates(&path)
+        .into_iter()
+        .find_map(|candidate| parse_lyric_candidate(path.clone(), candidate))
+}
+
+/// 按 [get_parsed_lyric_from_path] 的顺序读取歌词并保存到缓存
+fn _load_and_cache_lyric(index_path: &str, path: &str) -> Option<(Lyric, i64)> {
+    for candidate in _sorted_lyric_candidates(path) {
+        let (source, provider) = match candidate.source {
+            LyricCandidateSource::Embedded => (library_db::LyricSource::Embedded, None),
+            _ => (library_db::LyricSource::Sidecar, candidate.path.clone()),
+        };
+        let extension = candidate.extension.clone();
+        let Some(text) = tag_reader::read_lyric_candidate(path.to_string(), candidate) else {
+            continue;
+        };
+        let Some(lyric) = _parse_lyric_text(text.clone(), extension.as_deref()) else {
+            continue;
+        };
+        match library_db::save_parsed_lyric(
+            index_path,
+            path,
+            source,
+            provider,
+            &text,
+            Some(&lyric),
+            false,
+        ) {
+            Ok(cached) => return Some((lyric, cached.offset)),
+            Err(err) => {
+                log_to_dart(format!("failed to cache lyric: {}", err));
+                return Some((lyric, 0));
+            }
+        }
+    }
+    None
+}
+
+/// for Flutter
+/// 优先使用 [library_db::get_preferred_lyric] 缓存的歌词，没有缓存时和 [get_parsed_lyric_from_path] 相同并保存到缓存。
+/// 应用缓存歌词的时间偏移和 [library_db::set_lyric_offset] 保存的时间偏移
+pub fn get_parsed_lyric_for_playback(index_path: String, path: String) -> Option<Lyric> {
+    let cached = match library_db::get_preferred_lyric(index_path.clone(), path.clone()) {
+        Ok(cached) => cached,
+        Err(err) => {
+            log_to_dart(format!("failed to read lyric cache: {}", err));
+            None
+        }
+    };
+    let (lyric, lyric_offset) = match cached {
+        Some(CachedLyric {
+            parsed: Some(lyric),
+            offset,
+            ..
+        }) => (lyric, offset),
+        _ => _load_and_cache_lyric(&index_path, &path)?,
+    };
+
+    let offset = match library_db::get_lyric_offset(index_path, path) {
+        Ok(offset) => offset,
+        Err(err) => {
+            log_to_dart(format!("failed to read lyric offset: {}", err));
+            0
+        }
+    };
+    match lyric_offset.saturating_add(offset) {
+        0 => Some(lyric),
+        offset => Some(lyric.shifted(offset)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn timestamps() {
+        assert_eq!(_parse_timestamp("01:02"), Some(62_000));
+        assert_eq!(_parse_timestamp("01:02.3"), Some(62_300));
+        assert_eq!(_parse_timestamp("01:02.34"), Some(62_340));
+        assert_eq!(_parse_timestamp("01:02.345"), Some(62_345));
+        assert_eq!(_parse_timestamp("01:02.3456"), Some(62_345));
+        assert_eq!(_parse_timestamp("01:02:34"), Some(62_340));
+        assert_eq!(_parse_timestamp("1:01:02.34"), Some(3_662_340));
+        assert_eq!(_parse_timestamp("ar:someone"), None);
+        assert_eq!(_parse_timestamp("-1:00"), None);
+    }
+
+    #[test]
+    fn lrc_with_multiple_timestamps_offset_and_translation() {
+        let lyric = parse_lyric(
+            "[ti:Song]\n[ar:Singer]\n[offset:500]\n\
+             [00:01.00][00:11.00]chorus\n\
+             [00:05.00]verse\n[00:05.00]主歌\n"
+                .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(lyric.format, LyricFormat::Lrc);
+        assert_eq!(lyric.tag("ti".to_string()).as_deref(), Some("Song"));
+        let starts: Vec<u32> = lyric.lines.iter().map(|l| l.start).collect();
+        assert_eq!(starts, vec![500, 4_500, 10_500]);
+        assert_eq!(lyric.lines[0].end, 4_500);
+        assert_eq!(lyric.lines[1].text, "verse");
+        assert_eq!(lyric.lines[1].translation.as_deref(), Some("主歌"));
+        assert_eq!(lyric.lines[2].text, "chorus");
+    }
+
+    #[test]
+    fn enhanced_lrc_words() {
+        let lyric = parse_lyric(
+            "[00:01.00]<00:01.00>Hel<00:01.50>lo <00:02.00>world<00:03.00>\n\
+             [00:01.00]你好世界\n[00:04.00]next"
+                .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(lyric.format, LyricFormat::EnhancedLrc);
+        let line = &lyric.lines[0];
+        assert_eq!(line.text, "Hello world");
+        assert_eq!(line.translation.as_deref(), Some("你好世界"));
+        assert_eq!(line.end, 3_000);
+        let words: Vec<(u32, u32, &str)> = line
+            .words
+            .iter()
+            .map(|w| (w.start, w.end, w.text.as_str()))
+            .collect();
+        assert_eq!(
+            words,
+            vec![
+                (1_000, 1_500, "Hel"),
+                (1_500, 2_000, "lo "),
+                (2_000, 3_000, "world")
+            ]
+        );
+    }
+
+    #[test]
+    fn krc_words_and_translation() {
+        // {"content":[{"lyricContent":[["翻译一"],["翻译二"]],"type":1}],"version":1}
+        let language = "eyJjb250ZW50IjpbeyJseXJpY0NvbnRlbnQiOltbIue/u+ivkeS4gCJdLFsi57+76K+R5LqMIl1dLCJ0eXBlIjoxfV0sInZlcnNpb24iOjF9";
+        let lyric = parse_lyric(format!(
+            "[ar:Singer]\n[language:{language}]\n\
+             [1000,2000]<0,500,0>Hel<500,1500,0>lo\n\
+             [4000,1000]<0,1000,0>World"
+        ))
+        .unwrap();
+
+        assert_eq!(lyric.format, LyricFormat::Krc);
+        assert_eq!(lyric.lines.len(), 2);
+        assert_eq!((lyric.lines[0].start, lyric.lines[0].end), (1_000, 3_000));
+        assert_eq!(lyric.lines[0].text, "Hello");
+        assert_eq!(lyric.lines[0].words[1].start, 1_500);
+        assert_eq!(lyric.lines[0].words[1].end, 3_000);
+        assert_eq!(lyric.lines[0].translation.as_deref(), Some("翻译一"));
+        assert_eq!(lyric.lines[1].translation.as_deref(), Some("翻译二"));
+    }
+
+    #[test]
+    fn qrc_words_in_xml() {
+        let lyric = parse_qrc(
+            r#"<?xml version="1.0" encoding="utf-8"?>
+<QrcInfos><LyricInfo LyricCount="1"><Lyric_1 LyricType="1" LyricContent="[ti:Song]
+[1000,2000]Hel(1000,500)lo (1500,500)(feat)(2000,1000)
+[4000,1000]&quot;World&quot;(4000,1000)
+"/></LyricInfo></QrcInfos>"#
+                .to_string(),
+            Some("[00:01.00]翻译\n[00:04.00]世界".to_string()),
+        )
+        .unwrap();
+
+        assert_eq!(lyric.format, LyricFormat::Qrc);
+        assert_eq!(lyric.tag("ti".to_string()).as_deref(), Some("Song"));
+        assert_eq!(lyric.lines[0].text, "Hello (feat)");
+        assert_eq!(lyric.lines[0].words[2].text, "(feat)");
+        assert_eq!(lyric.lines[0].translation.as_deref(), Some("翻译"));
+        assert_eq!(lyric.lines[1].text, "\"World\"");
+        assert_eq!(lyric.lines[1].translation.as_deref(), Some("世界"));
+    }
+
+    #[test]
+    fn srt_blocks() {
+        let lyric = parse_lyric(
+            "1\r\n00:00:01,000 --> 00:00:03,500\r\n<i>Hello</i>\r\n你好\r\n\r\n\
+             2\r\n00:00:04,000 --> 00:00:05,000 X1:0\r\nWorld\r\n"
+                .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(lyric.format, LyricFormat::Srt);
+        assert_eq!((lyric.lines[0].start, lyric.lines[0].end), (1_000, 3_500));
+        assert_eq!(lyric.lines[0].text, "Hello");
+        assert_eq!(lyric.lines[0].translation.as_deref(), Some("你好"));
+        assert_eq!((lyric.lines[1].start, lyric.lines[1].end), (4_000, 5_000));
+        assert_eq!(lyric.lines[1].translation, None);
+    }
+
+    #[test]
+    fn shift_stretch_and_merge_translation() {
+        let lyric =
+            parse_lrc("[00:01.00]<00:01.00>a<00:01.50>b<00:02.00>\n[00:10.00]c\n".to_string())
+                .unwrap();
+
+        let shifted = lyric.shifted(-1_500);
+        assert_eq!(shifted.lines[0].start, 0);
+        assert_eq!(shifted.lines[0].words[1].start, 0);
+        assert_eq!(shifted.lines[1].start, 8_500);
+
+        // 歌词按 25fps 加速（PAL）制作：1s -> 1.04s
+        let stretched = lyric.stretched(1_000, 1_040, 10_000, 10_400).unwrap();
+        assert_eq!(stretched.lines[0].words[1].start, 1_560);
+        assert_eq!(stretched.lines[1].start, 10_400);
+        assert!(lyric.stretched(1_000, 0, 1_000, 5_000).is_none());
+        assert!(lyric.stretched(1_000, 5_000, 2_000, 0).is_none());
+
+        let translation = parse_lrc("[00:01.20]甲乙\n[00:07.00]丙\n".to_string()).unwrap();
+        let merged = lyric.with_translation(translation, 500);
+        assert_eq!(merged.lines[0].translation.as_deref(), Some("甲乙"));
+        assert_eq!(merged.lines[1].translation, None);
+
+        let reparsed = parse_lrc(merged.shifted(250).to_lrc()).unwrap();
+        assert_eq!(reparsed.lines, merged.shifted(250).lines);
+    }
+
+    #[test]
+    fn chinese_variant_and_romanization() {
+        let lyric = parse_lrc(
+            "[00:01.00]<00:01.00>头<00:01.50>发<00:02.00>\n[00:03.00]软件\n[00:05.00]la la\n"
+                .to_string(),
+        )
+        .unwrap();
+
+        let traditional = lyric.with_chinese_variant(ChineseVariant::Taiwan);
+        assert_eq!(traditional.lines[0].text, "頭髮");
+        let words: Vec<_> = traditional.lines[0]
+            .words
+            .iter()
+            .map(|w| w.text.as_str())
+            .collect();
+        assert_eq!(words, ["頭", "髮"]);
+        assert_eq!(traditional.lines[1].text, "軟體");
+
+        let romanized = lyric.with_romanization(RomanizationKind::Auto);
+        assert_eq!(romanized.lines[0].romanization.as_deref(), Some("tóu fā"));
+        assert_eq!(romanized.lines[2].romanization, None);
+
+        let japanese = parse_lrc("[00:01.00]さくら\n[00:02.00]桜\n".to_string())
+            .unwrap()
+            .with_romanization(RomanizationKind::Auto);
+        assert_eq!(japanese.lines[0].romanization.as_deref(), Some("sakura"));
+        // 没有汉字的读音，含有汉字的行不生成罗马字
+        assert_eq!(japanese.lines[1].romanization, None);
+
+        let again = parse_ttml(romanized.to_ttml()).unwrap();
+        assert_eq!(again.lines[0].romanization, romanized.lines[0].romanization);
+    }
+
+    const TTML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<tt xmlns="http://www.w3.org/ns/ttml" xmlns:ttm="http://www.w3.org/ns/ttml#metadata" xmlns:itunes="http://music.apple.com/lyric-ttml-internal" itunes:timing="Word" xml:lang="en">
+  <head>
+    <metadata>
+      <ttm:agent type="person" xml:id="v1"><ttm:name>A</ttm:name></ttm:agent>
+      <ttm:agent type="person" xml:id="v2"/>
+      <iTunesMetadata xmlns="http://music.apple.com/lyric-ttml-internal">
+        <translations><translation xml:lang="zh"><text for="L2">你好 &amp; 再见</text></translation></translations>
+      </iTunesMetadata>
+    </metadata>
+  </head>
+  <body dur="8.5">
+    <div>
+      <p begin="1.000" end="3.000" itunes:key="L1" ttm:agent="v1"><span begin="1.000" end="1.500">Hello</span> <span begin="1.500" end="2.500">world</span><span ttm:role="x-bg"><span begin="2.500" end="3.000">(ooh)</span></span><span ttm:role="x-translation" xml:lang="zh">你好世界</span></p>
+      <p begin="00:04.000" end="00:06.000" itunes:key="L2" ttm:agent="v2"><span begin="4s" end="5000ms">Bye</span> <span begin="5" end="6">now</span></p>
+    </div>
+  </body>
+</tt>"#;
+
+    #[test]
+    fn ttml_words_agents_and_background() {
+        let lyric = parse_lyric(TTML.to_string()).unwrap();
+        assert_eq!(lyric.format, LyricFormat::Ttml);
+        assert_eq!(lyric.tag("language".to_string()).as_deref(), Some("en"));
+        assert_eq!(lyric.agents.len(), 2);
+        assert_eq!(lyric.agents[0].name.as_deref(), Some("A"));
+
+        let first = &lyric.lines[0];
+        assert_eq!(first.text, "Hello world");
+        assert_eq!(first.words[0].text, "Hello ");
+        assert_eq!((first.words[1].start, first.words[1].end), (1_500, 2_500));
+        assert_eq!(first.agent.as_deref(), Some("v1"));
+        assert_eq!(first.background[0].text, "(ooh)");
+        assert_eq!(first.translation.as_deref(), Some("你好世界"));
+
+        let second = &lyric.lines[1];
+        assert_eq!((second.start, second.end), (4_000, 6_000));
+        assert_eq!(second.words[1].start, 5_000);
+        assert_eq!(second.translation.as_deref(), Some("你好 & 再见"));
+    }
+
+    #[test]
+    fn ttml_export_roundtrip() {
+        let lyric = parse_ttml(TTML.to_string()).unwrap();
+
+        let again = parse_ttml(lyric.to_ttml()).unwrap();
+        assert_eq!(again.lines, lyric.lines);
+        assert_eq!(again.agents, lyric.agents);
+
+        let lrc = parse_lrc(lyric.to_lrc()).unwrap();
+        for (lrc, ttml) in lrc.lines.iter().zip(&lyric.lines) {
+            assert_eq!(lrc.words, ttml.words);
+            assert_eq!(lrc.background, ttml.background);
+            assert_eq!(lrc.agent, ttml.agent);
+            assert_eq!(lrc.translation, ttml.translation);
+        }
+    }
+
+    fn check_invariants(lyric: &Lyric) {
+        for pair in lyric.lines.windows(2) {
+            assert!(pair[0].start <= pair[1].start);
+        }
+        for line in &lyric.lines {
+            assert!(line.end >= line.start);
+            for word in &line.words {
+                assert!(word.end >= word.start);
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn parsers_never_panic(text in "\\PC*") {
+            for lyric in [
+                parse_lyric(text.clone()),
+                parse_lrc(text.clone()),
+                parse_krc(text.clone()),
+                parse_qrc(text.clone(), Some(text.clone())),
+                parse_srt(text.clone()),
+                parse_ttml(text.clone()),
+            ]
+            .into_iter()
+            .flatten()
+            {
+                check_invariants(&lyric);
+            }
+        }
+
+        #[test]
+        fn parsers_never_panic_on_lyric_like_text(
+            lines in prop::collection::vec(
+                "(\\[[0-9:.,]{0,10}\\]|<[0-9:.,]{0,10}>|\\([0-9,]{0,8}\\)|[a-z ]{0,4}|\\[[a-z]{0,6}:[^\\]\n]{0,8}\\]){0,8}",
+                0..12,
+            )
+        ) {
+            let text = lines.join("\n");
+            for lyric in [parse_lrc(text.clone()), parse_krc(text.clone()), parse_qrc(text.clone(), None), parse_srt(text.clone())]
+                .into_iter()
+                .flatten()
+            {
+                check_invariants(&lyric);
+            }
+        }
+
+        #[test]
+        fn lrc_roundtrip(
+            lines in prop::collection::vec((0u32..6_000_000, "[a-zA-Z0-9 ]{1,20}"), 1..30)
+        ) {
+            let text: String = lines
+                .iter()
+                .map(|(ms, text)| {
+                    format!("[{:02}:{:02}.{:02}]{}\n", ms / 60_000, ms / 1000 % 60, ms % 1000 / 10, text.trim())
+                })
+                .collect();
+            let lyric = parse_lrc(text).unwrap();
+            check_invariants(&lyric);
+
+            let mut expected: Vec<u32> = lines.iter().map(|(ms, _)| ms / 10 * 10).collect();
+            expected.sort();
+            expected.dedup();
+            let starts: Vec<u32> = lyric.lines.iter().map(|l| l.start).collect();
+            prop_assert_eq!(starts, expected);
+        }
+    }
+}
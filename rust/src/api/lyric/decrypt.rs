@@ -0,0 +1,332 @@
+//! 酷狗 krc 和 QQ 音乐 qrc 的解密。
+//! krc: "krc1" + XOR + zlib
+//! qrc: 本地文件为 11 字节文件头 + QMC1 XOR + 3DES + zlib；网络接口返回的是 hex 编码的 3DES + zlib
+
+use std::io::Read;
+
+use anyhow::{anyhow, bail, Result};
+use flate2::read::ZlibDecoder;
+
+const KRC_MAGIC: &[u8] = b"krc1";
+const KRC_KEY: [u8; 16] = [
+    64, 71, 97, 119, 94, 50, 116, 71, 81, 54, 49, 45, 206, 210, 110, 105,
+];
+
+const QRC_HEADER_LEN: usize = 11;
+const QRC_3DES_KEY: &[u8; 24] = b"!@#)(*$%123ZXC!@!@#)(NHL";
+const QMC1_KEY: [u8; 128] = [
+    0xc3, 0x4a, 0xd6, 0xca, 0x90, 0x67, 0xf7, 0x52, 0xd8, 0xa1, 0x66, 0x62, 0x9f, 0x5b, 0x09, 0x00,
+    0xc3, 0x5e, 0x95, 0x23, 0x9f, 0x13, 0x11, 0x7e, 0xd8, 0x92, 0x3f, 0xbc, 0x90, 0xbb, 0x74, 0x0e,
+    0xc3, 0x47, 0x74, 0x3d, 0x90, 0xaa, 0x3f, 0x51, 0xd8, 0xf4, 0x11, 0x84, 0x9f, 0xde, 0x95, 0x1d,
+    0xc3, 0xc6, 0x09, 0xd5, 0x9f, 0xfa, 0x66, 0xf9, 0xd8, 0xf0, 0xf7, 0xa0, 0x90, 0xa1, 0xd6, 0xf3,
+    0xc3, 0xf3, 0xd6, 0xa1, 0x90, 0xa0, 0xf7, 0xf0, 0xd8, 0xf9, 0x66, 0xfa, 0x9f, 0xd5, 0x09, 0xc6,
+    0xc3, 0x1d, 0x95, 0xde, 0x9f, 0x84, 0x11, 0xf4, 0xd8, 0x51, 0x3f, 0xaa, 0x90, 0x3d, 0x74, 0x47,
+    0xc3, 0x0e, 0x74, 0xbb, 0x90, 0xbc, 0x3f, 0x92, 0xd8, 0x7e, 0x11, 0x13, 0x9f, 0x23, 0x95, 0x5e,
+    0xc3, 0x00, 0x09, 0x5b, 0x9f, 0x62, 0x66, 0xa1, 0xd8, 0x52, 0xf7, 0x67, 0x90, 0xca, 0xd6, 0x4a,
+];
+
+const IP: [u8; 64] = [
+    58, 50, 42, 34, 26, 18, 10, 2, 60, 52, 44, 36, 28, 20, 12, 4, 62, 54, 46, 38, 30, 22, 14, 6,
+    64, 56, 48, 40, 32, 24, 16, 8, 57, 49, 41, 33, 25, 17, 9, 1, 59, 51, 43, 35, 27, 19, 11, 3, 61,
+    53, 45, 37, 29, 21, 13, 5, 63, 55, 47, 39, 31, 23, 15, 7,
+];
+const FP: [u8; 64] = [
+    40, 8, 48, 16, 56, 24, 64, 32, 39, 7, 47, 15, 55, 23, 63, 31, 38, 6, 46, 14, 54, 22, 62, 30,
+    37, 5, 45, 13, 53, 21, 61, 29, 36, 4, 44, 12, 52, 20, 60, 28, 35, 3, 43, 11, 51, 19, 59, 27,
+    34, 2, 42, 10, 50, 18, 58, 26, 33, 1, 41, 9, 49, 17, 57, 25,
+];
+const E: [u8; 48] = [
+    32, 1, 2, 3, 4, 5, 4, 5, 6, 7, 8, 9, 8, 9, 10, 11, 12, 13, 12, 13, 14, 15, 16, 17, 16, 17, 18,
+    19, 20, 21, 20, 21, 22, 23, 24, 25, 24, 25, 26, 27, 28, 29, 28, 29, 30, 31, 32, 1,
+];
+const P: [u8; 32] = [
+    16, 7, 20, 21, 29, 12, 28, 17, 1, 15, 23, 26, 5, 18, 31, 10, 2, 8, 24, 14, 32, 27, 3, 9, 19,
+    13, 30, 6, 22, 11, 4, 25,
+];
+const PC1: [u8; 56] = [
+    57, 49, 41, 33, 25, 17, 9, 1, 58, 50, 42, 34, 26, 18, 10, 2, 59, 51, 43, 35, 27, 19, 11, 3, 60,
+    52, 44, 36, 63, 55, 47, 39, 31, 23, 15, 7, 62, 54, 46, 38, 30, 22, 14, 6, 61, 53, 45, 37, 29,
+    21, 13, 5, 28, 20, 12, 4,
+];
+const PC2: [u8; 48] = [
+    14, 17, 11, 24, 1, 5, 3, 28, 15, 6, 21, 10, 23, 19, 12, 4, 26, 8, 16, 7, 27, 20, 13, 2, 41, 52,
+    31, 37, 47, 55, 30, 40, 51, 45, 33, 48, 44, 49, 39, 56, 34, 53, 46, 42, 50, 36, 29, 32,
+];
+const SHIFTS: [u32; 16] = [1, 1, 2, 2, 2, 2, 2, 2, 1, 2, 2, 2, 2, 2, 2, 1];
+
+type SBoxes = [[u8; 64]; 8];
+
+const STANDARD_SBOXES: SBoxes = [
+    [
+        14, 4, 13, 1, 2, 15, 11, 8, 3, 10, 6, 12, 5, 9, 0, 7, 0, 15, 7, 4, 14, 2, 13, 1, 10, 6, 12,
+        11, 9, 5, 3, 8, 4, 1, 14, 8, 13, 6, 2, 11, 15, 12, 9, 7, 3, 10, 5, 0, 15, 12, 8, 2, 4, 9,
+        1, 7, 5, 11, 3, 14, 10, 0, 6, 13,
+    ],
+    [
+        15, 1, 8, 14, 6, 11, 3, 4, 9, 7, 2, 13, 12, 0, 5, 10, 3, 13, 4, 7, 15, 2, 8, 14, 12, 0, 1,
+        10, 6, 9, 11, 5, 0, 14, 7, 11, 10, 4, 13, 1, 5, 8, 12, 6, 9, 3, 2, 15, 13, 8, 10, 1, 3, 15,
+        4, 2, 11, 6, 7, 12, 0, 5, 14, 9,
+    ],
+    [
+        10, 0, 9, 14, 6, 3, 15, 5, 1, 13, 12, 7, 11, 4, 2, 8, 13, 7, 0, 9, 3, 4, 6, 10, 2, 8, 5,
+        14, 12, 11, 15, 1, 13, 6, 4, 9, 8, 15, 3, 0, 11, 1, 2, 12, 5, 10, 14, 7, 1, 10, 13, 0, 6,
+        9, 8, 7, 4, 15, 14, 3, 11, 5, 2, 12,
+    ],
+    [
+        7, 13, 14, 3, 0, 6, 9, 10, 1, 2, 8, 5, 11, 12, 4, 15, 13, 8, 11, 5, 6, 15, 0, 3, 4, 7, 2,
+        12, 1, 10, 14, 9, 10, 6, 9, 0, 12, 11, 7, 13, 15, 1, 3, 14, 5, 2, 8, 4, 3, 15, 0, 6, 10, 1,
+        13, 8, 9, 4, 5, 11, 12, 7, 2, 14,
+    ],
+    [
+        2, 12, 4, 1, 7, 10, 11, 6, 8, 5, 3, 15, 13, 0, 14, 9, 14, 11, 2, 12, 4, 7, 13, 1, 5, 0, 15,
+        10, 3, 9, 8, 6, 4, 2, 1, 11, 10, 13, 7, 8, 15, 9, 12, 5, 6, 3, 0, 14, 11, 8, 12, 7, 1, 14,
+        2, 13, 6, 15, 0, 9, 10, 4, 5, 3,
+    ],
+    [
+        12, 1, 10, 15, 9, 2, 6, 8, 0, 13, 3, 4, 14, 7, 5, 11, 10, 15, 4, 2, 7, 12, 9, 5, 6, 1, 13,
+        14, 0, 11, 3, 8, 9, 14, 15, 5, 2, 8, 12, 3, 7, 0, 4, 10, 1, 13, 11, 6, 4, 3, 2, 12, 9, 5,
+        15, 10, 11, 14, 1, 7, 6, 0, 8, 13,
+    ],
+    [
+        4, 11, 2, 14, 15, 0, 8, 13, 3, 12, 9, 7, 5, 10, 6, 1, 13, 0, 11, 7, 4, 9, 1, 10, 14, 3, 5,
+        12, 2, 15, 8, 6, 1, 4, 11, 13, 12, 3, 7, 14, 10, 15, 6, 8, 0, 5, 9, 2, 6, 11, 13, 8, 1, 4,
+        10, 7, 9, 5, 0, 15, 14, 2, 3, 12,
+    ],
+    [
+        13, 2, 8, 4, 6, 15, 11, 1, 10, 9, 3, 14, 5, 0, 12, 7, 1, 15, 13, 8, 10, 3, 7, 4, 12, 5, 6,
+        11, 0, 14, 9, 2, 7, 11, 4, 1, 9, 12, 14, 2, 0, 6, 10, 13, 15, 3, 5, 8, 2, 1, 14, 7, 4, 10,
+        8, 13, 15, 12, 9, 0, 3, 5, 6, 11,
+    ],
+];
+
+/// S 盒和子密钥的 PC2 置换表。PC2 作用于末尾补 0 的 57 位 CD
+struct DesTables {
+    sboxes: SBoxes,
+    pc2: [u8; 48],
+}
+
+/// 只用于测试标准 DES 的向量
+#[cfg(test)]
+const STANDARD_DES: DesTables = DesTables {
+    sboxes: STANDARD_SBOXES,
+    pc2: PC2,
+};
+
+/// QQ 音乐的 DES 实现和标准不同：S2 第 2 行的第 8 个值是 15 而不是 14，
+/// S4 第 4 行的第 6 个值是 10 而不是 1；生成子密钥时从 D 中取的位都错后了 1 位，
+/// D 的最后一位取到的是补上的 0
+const QQ_DES: DesTables = {
+    let mut sboxes = STANDARD_SBOXES;
+    sboxes[1][16 + 7] = 15;
+    sboxes[3][3 * 16 + 5] = 10;
+    let mut pc2 = PC2;
+    let mut i = 24;
+    while i < 48 {
+        pc2[i] += 1;
+        i += 1;
+    }
+    DesTables { sboxes, pc2 }
+};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum DesMode {
+    Encrypt,
+    Decrypt,
+}
+
+/// 按照 1 开始、从最高位数起的置换表重排 in_bits 位的 input
+fn _permute(input: u64, in_bits: u32, table: &[u8]) -> u64 {
+    table.iter().fold(0, |out, &pos| {
+        (out << 1) | ((input >> (in_bits - pos as u32)) & 1)
+    })
+}
+
+struct Des {
+    subkeys: [u64; 16],
+    tables: &'static DesTables,
+}
+
+impl Des {
+    fn new(key: u64, mode: DesMode, tables: &'static DesTables) -> Self {
+        let cd = _permute(key, 64, &PC1);
+        let (mut c, mut d) = (cd >> 28, cd & 0x0fff_ffff);
+        let mut subkeys = [0; 16];
+        for (round, shift) in SHIFTS.iter().enumerate() {
+            c = ((c << shift) | (c >> (28 - shift))) & 0x0fff_ffff;
+            d = ((d << shift) | (d >> (28 - shift))) & 0x0fff_ffff;
+            subkeys[round] = _permute(((c << 28) | d) << 1, 57, &tables.pc2);
+        }
+        if mode == DesMode::Decrypt {
+            subkeys.reverse();
+        }
+        Self { subkeys, tables }
+    }
+
+    fn feistel(&self, right: u64, subkey: u64) -> u64 {
+        let expanded = _permute(right, 32, &E) ^ subkey;
+        let substituted = (0..8).fold(0, |out, i| {
+            let six = (expanded >> (42 - 6 * i)) & 0x3f;
+            let row = ((six & 0x20) >> 4) | (six & 1);
+            let col = (six >> 1) & 0x0f;
+            (out << 4) | self.tables.sboxes[i][(row * 16 + col) as usize] as u64
+        });
+        _permute(substituted, 32, &P)
+    }
+
+    fn crypt(&self, block: u64) -> u64 {
+        let block = _permute(block, 64, &IP);
+        let (mut left, mut right) = (block >> 32, block & 0xffff_ffff);
+        for subkey in self.subkeys {
+            (left, right) = (right, left ^ self.feistel(right, subkey));
+        }
+        _permute((right << 32) | left, 64, &FP)
+    }
+}
+
+/// QQ 音乐的 DES 按 4 字节小端读写 64 位的块和密钥
+fn _qq_load(bytes: &[u8]) -> u64 {
+    let high = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    let low = u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]);
+    ((high as u64) << 32) | low as u64
+}
+
+fn _qq_store(value: u64, bytes: &mut [u8]) {
+    bytes[..4].copy_from_slice(&((value >> 32) as u32).to_le_bytes());
+    bytes[4..8].copy_from_slice(&(value as u32).to_le_bytes());
+}
+
+/// EDE 3DES，ECB 模式
+fn _qq_triple_des(data: &mut [u8], mode: DesMode) -> Result<()> {
+    if !data.len().is_multiple_of(8) {
+        bail!("qrc data length {} is not a multiple of 8", data.len());
+    }
+    let key = |i: usize| _qq_load(&QRC_3DES_KEY[i * 8..i * 8 + 8]);
+    let inverse = match mode {
+        DesMode::Encrypt => DesMode::Decrypt,
+        DesMode::Decrypt => DesMode::Encrypt,
+    };
+    let stages = match mode {
+        DesMode::Encrypt => [(key(0), mode), (key(1), inverse), (key(2), mode)],
+        DesMode::Decrypt => [(key(2), mode), (key(1), inverse), (key(0), mode)],
+    }
+    .map(|(key, mode)| Des::new(key, mode, &QQ_DES));
+
+    for block in data.chunks_exact_mut(8) {
+        let value = stages
+            .iter()
+            .fold(_qq_load(block), |value, des| des.crypt(value));
+        _qq_store(value, block);
+    }
+    Ok(())
+}
+
+fn _qmc1_xor(data: &mut [u8]) {
+    for (i, byte) in data.iter_mut().enumerate() {
+        let i = if i > 0x7fff { i % 0x7fff } else { i };
+        *byte ^= QMC1_KEY[i & 0x7f];
+    }
+}
+
+fn _inflate(data: &[u8]) -> Result<String> {
+    let mut bytes = vec![];
+    ZlibDecoder::new(data).read_to_end(&mut bytes)?;
+    let text = String::from_utf8(bytes)?;
+    Ok(text.strip_prefix('\u{feff}').unwrap_or(&text).to_string())
+}
+
+fn _hex_decode(text: &str) -> Option<Vec<u8>> {
+    let text = text.trim();
+    if text.is_empty() || !text.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..text.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(text.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+pub(super) fn decrypt_krc(data: &[u8]) -> Result<String> {
+    let Some(encrypted) = data.strip_prefix(KRC_MAGIC) else {
+        bail!("not a krc file");
+    };
+    let decrypted: Vec<u8> = encrypted
+        .iter()
+        .enumerate()
+        .map(|(i, byte)| byte ^ KRC_KEY[i % KRC_KEY.len()])
+        .collect();
+    _inflate(&decrypted)
+}
+
+/// 同时支持本地 qrc 文件和网络接口返回的 hex 文本
+pub(super) fn decrypt_qrc(data: &[u8]) -> Result<String> {
+    let mut encrypted = match std::str::from_utf8(data).ok().and_then(_hex_decode) {
+        Some(bytes) => bytes,
+        None => {
+            if data.len() <= QRC_HEADER_LEN {
+                return Err(anyhow!("qrc file is too short"));
+            }
+            let mut bytes = data[QRC_HEADER_LEN..].to_vec();
+            _qmc1_xor(&mut bytes);
+            bytes
+        }
+    };
+    _qq_triple_des(&mut encrypted, DesMode::Decrypt)?;
+    _inflate(&encrypted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn standard_des_vector() {
+        let des = Des::new(0x1334_5779_9BBC_DFF1, DesMode::Encrypt, &STANDARD_DES);
+        assert_eq!(des.crypt(0x0123_4567_89AB_CDEF), 0x85E8_1354_0F0A_B405);
+        let des = Des::new(0x1334_5779_9BBC_DFF1, DesMode::Decrypt, &STANDARD_DES);
+        assert_eq!(des.crypt(0x85E8_1354_0F0A_B405), 0x0123_4567_89AB_CDEF);
+    }
+
+    /// LyricDecoder 中 QQMusicDES 的 C 实现加密 0..64 的结果
+    const QQ_TRIPLE_DES_VECTOR: &str = "ce92cac4ea3c8406cef33d598db37d30\
+        968eac2dcba124a4716fecba354e4cc1434cab9d533a00e2157b9a04ca9dbf39\
+        2b97510549c4e97c5388f50f20931c3f";
+
+    #[test]
+    fn qq_triple_des_vector() {
+        let expected = _hex_decode(QQ_TRIPLE_DES_VECTOR).unwrap();
+        let mut data: Vec<u8> = (0..64).collect();
+        _qq_triple_des(&mut data, DesMode::Encrypt).unwrap();
+        assert_eq!(data, expected);
+        _qq_triple_des(&mut data, DesMode::Decrypt).unwrap();
+        assert_eq!(data, (0..64).collect::<Vec<u8>>());
+    }
+
+    const SAMPLE_KRC: &str = "\u{feff}[ar:Singer]\n[ti:Song]\n\
+        [language:eyJjb250ZW50IjpbeyJseXJpY0NvbnRlbnQiOltbIue/u+ivkeS4gCJdLFsi57+76K+R5LqMIl1dLCJ0eXBlIjoxfV0sInZlcnNpb24iOjF9]\n\
+        [1000,2000]<0,500,0>Hel<500,1500,0>lo\n[4000,1000]<0,1000,0>World\n";
+    const SAMPLE_QRC: &str = "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n\
+        <QrcInfos><LyricInfo LyricCount=\"1\"><Lyric_1 LyricType=\"1\" LyricContent=\"[ti:Song]\n\
+        [1000,2000]Hel(1000,500)lo(1500,1500)\n[4000,1000]World(4000,1000)\n\"/></LyricInfo></QrcInfos>";
+
+    /// fixtures 中的 zlib 数据由 LyricDecoder 的 C 实现加密，并能被它解密成这两段文本。
+    /// 本地 qrc 文件的 QMC1 层和 11 字节文件头不在它的范围内，文件头全部为 0
+    #[test]
+    fn decrypt_fixtures() {
+        let krc = decrypt_krc(include_bytes!("../../../fixtures/lyric/sample.krc")).unwrap();
+        assert_eq!(krc, SAMPLE_KRC.trim_start_matches('\u{feff}'));
+
+        let qrc = decrypt_qrc(include_bytes!("../../../fixtures/lyric/sample.qrc")).unwrap();
+        assert_eq!(qrc, SAMPLE_QRC);
+
+        // 网络接口返回的 hex 形式
+        let hex: String = include_bytes!("../../../fixtures/lyric/sample.qrc")[QRC_HEADER_LEN..]
+            .iter()
+            .enumerate()
+            .map(|(i, byte)| format!("{:02X}", byte ^ QMC1_KEY[i & 0x7f]))
+            .collect();
+        assert_eq!(decrypt_qrc(hex.as_bytes()).unwrap(), qrc);
+
+        assert!(decrypt_krc(b"krc1 not zlib").is_err());
+        assert!(decrypt_qrc(b"short").is_err());
+    }
+}
@@ -0,0 +1,120 @@
+use base64::Engine;
+
+use super::{Lyric, LyricFormat, LyricLine, LyricWord};
+
+/// [start,duration]
+fn _parse_range(range: &str) -> Option<(u32, u32)> {
+    let (start, duration) = range.split_once(',')?;
+    Some((start.trim().parse().ok()?, duration.trim().parse().ok()?))
+}
+
+pub(super) fn is_krc(text: &str) -> bool {
+    text.lines().any(|line| {
+        line.trim_start()
+            .strip_prefix('[')
+            .and_then(|inner| inner.split_once(']'))
+            .is_some_and(|(range, content)| {
+                _parse_range(range).is_some() && content.trim_start().starts_with('<')
+            })
+    })
+}
+
+/// <offset,duration,0>word，offset 相对于行开始时间
+fn _parse_words(content: &str, line_start: u32) -> Vec<LyricWord> {
+    let mut words = vec![];
+    for item in content.split('<').skip(1) {
+        let Some((timing, text)) = item.split_once('>') else {
+            continue;
+        };
+        let mut timing = timing.split(',');
+        let (Some(offset), Some(duration)) = (timing.next(), timing.next()) else {
+            continue;
+        };
+        let (Ok(offset), Ok(duration)) =
+            (offset.trim().parse::<u32>(), duration.trim().parse::<u32>())
+        else {
+            continue;
+        };
+        let start = line_start.saturating_add(offset);
+        words.push(LyricWord {
+            start,
+            end: start.saturating_add(duration),
+            text: text.to_string(),
+        });
+    }
+    words
+}
+
+/// [language:base64] 中 type 为 1 的内容是翻译，每一项对应一行
+fn _parse_translation(language: &str) -> Vec<String> {
+    let Ok(bytes) = base64::engine::general_purpose::STANDARD.decode(language.trim()) else {
+        return vec![];
+    };
+    let Ok(json) = serde_json::from_slice::<serde_json::Value>(&bytes) else {
+        return vec![];
+    };
+    let Some(content) = json["content"].as_array() else {
+        return vec![];
+    };
+
+    content
+        .iter()
+        .filter(|item| item["type"].as_i64() == Some(1))
+        .filter_map(|item| item["lyricContent"].as_array())
+        .flatten()
+        .map(|line| match line {
+            serde_json::Value::Array(parts) => parts
+                .iter()
+                .filter_map(|part| part.as_str())
+                .collect::<Vec<_>>()
+                .join(""),
+            serde_json::Value::String(line) => line.clone(),
+            _ => String::new(),
+        })
+        .collect()
+}
+
+pub(super) fn parse(text: &str) -> Option<Lyric> {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+
+    let mut tags = vec![];
+    let mut translation = vec![];
+    let mut lines = vec![];
+    for line in text.lines() {
+        let range_and_content = line
+            .trim_start()
+            .strip_prefix('[')
+            .and_then(|inner| inner.split_once(']'))
+            .and_then(|(range, content)| Some((_parse_range(range)?, content)));
+        let Some(((start, duration), content)) = range_and_content else {
+            if let Some(tag) = super::_parse_tag(line) {
+                if tag.key == "language" {
+                    translation = _parse_translation(&tag.value);
+                }
+                tags.push(tag);
+            }
+            continue;
+        };
+
+        let words = _parse_words(content, start);
+        lines.push(LyricLine {
+            start,
+            end: start.saturating_add(duration),
+            text: words.iter().map(|word| word.text.as_str()).collect(),
+            words,
+            translation: None,
+            agent: None,
+            background: vec![],
+            romanization: None,
+        });
+    }
+
+    // 翻译按行的顺序对应
+    for (line, translation) in lines.iter_mut().zip(translation) {
+        if !translation.trim().is_empty() {
+            line.translation = Some(translation);
+        }
+    }
+
+    Lyric::new(LyricFormat::Krc, tags, lines)
+}
@@ -0,0 +1,277 @@
+use super::{Lyric, LyricFormat, LyricLine, LyricWord};
+
+/// 行首的一个或多个 [mm:ss.xx]，返回时间和剩余内容
+fn _split_time_tags(line: &str) -> (Vec<u32>, &str) {
+    let mut times = vec![];
+    let mut rest = line.trim_start();
+    while let Some(inner) = rest.strip_prefix('[') {
+        let Some(right) = inner.find(']') else {
+            break;
+        };
+        let Some(time) = super::_parse_timestamp(&inner[..right]) else {
+            break;
+        };
+        times.push(time);
+        rest = inner[right + 1..].trim_start();
+    }
+    (times, rest)
+}
+
+/// 逐字 LRC 的 <mm:ss.xx>word<mm:ss.xx>word<mm:ss.xx>。
+/// 最后一个不带文字的时间是最后一个字的结束时间
+fn _parse_words(content: &str, line_start: u32, offset: i64) -> Vec<LyricWord> {
+    let mut words: Vec<LyricWord> = vec![];
+    let mut current_start = None;
+    let mut text = String::new();
+    let mut rest = content;
+
+    loop {
+        let tag = rest.find('<').and_then(|left| {
+            let right = left + rest[left..].find('>')?;
+            let time = super::_parse_timestamp(&rest[left + 1..right])?;
+            Some((left, right, time))
+        });
+        let Some((left, right, time)) = tag else {
+            // 不是时间的 '<' 当作普通文字
+            match rest.find('<') {
+                Some(left) => {
+                    text.push_str(&rest[..=left]);
+                    rest = &rest[left + 1..];
+                    continue;
+                }
+                None => {
+                    text.push_str(rest);
+                    break;
+                }
+            }
+        };
+
+        text.push_str(&rest[..left]);
+        let time = super::_apply_offset(time, offset);
+        if !text.is_empty() {
+            words.push(LyricWord {
+                start: current_start.unwrap_or(line_start),
+                end: time,
+                text: std::mem::take(&mut text),
+            });
+        } else if let Some(last) = words.last_mut() {
+            // 连续两个时间，前一个字在第一个时间结束
+            if last.end <= last.start {
+                last.end = time;
+            }
+        }
+        current_start = Some(time);
+        rest = &rest[right + 1..];
+    }
+
+    if !text.is_empty() && current_start.is_some() {
+        words.push(LyricWord {
+            start: current_start.unwrap_or(line_start),
+            end: 0,
+            text,
+        });
+    }
+    // end 不早于 start 的由 Lyric::new 补全
+    for word in words.iter_mut() {
+        if word.end < word.start {
+            word.end = 0;
+        }
+    }
+    words
+}
+
+/// 相同时间的行：逐字时间最多（其次是非空）的为原文，其他合并为翻译。
+/// lines 需要已经按时间排序
+fn _merge_same_start(lines: Vec<LyricLine>) -> Vec<LyricLine> {
+    let mut groups: Vec<Vec<LyricLine>> = vec![];
+    for line in lines {
+        match groups.last_mut() {
+            Some(group) if group[0].start == line.start => group.push(line),
+            _ => groups.push(vec![line]),
+        }
+    }
+
+    groups
+        .into_iter()
+        .map(|mut group| {
+            let mut primary = 0;
+            for (i, line) in group.iter().enumerate() {
+                let key = |l: &LyricLine| (l.words.len(), !l.text.trim().is_empty());
+                if key(line) > key(&group[primary]) {
+                    primary = i;
+                }
+            }
+            let mut line = group.remove(primary);
+            let translation: Vec<&str> = group
+                .iter()
+                .map(|l| l.text.trim())
+                .filter(|t| !t.is_empty())
+                .collect();
+            if !translation.is_empty() {
+                line.translation = Some(translation.join("\n"));
+            }
+            if line.background.is_empty() {
+                if let Some(other) = group.iter_mut().find(|l| !l.background.is_empty()) {
+                    line.background = std::mem::take(&mut other.background);
+                }
+            }
+            line
+        })
+        .collect()
+}
+
+/// 对唱歌词的 "v1:" 前缀
+fn _split_agent(content: &str) -> (Option<String>, &str) {
+    let trimmed = content.trim_start();
+    if let Some((agent, rest)) = trimmed.split_once(':') {
+        let is_agent = agent
+            .strip_prefix('v')
+            .is_some_and(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()));
+        if is_agent {
+            return (Some(agent.to_string()), rest.trim_start());
+        }
+    }
+    (None, content)
+}
+
+struct RawLine<'a> {
+    time: u32,
+    content: &'a str,
+    /// [bg:<mm:ss.xx>word...] 和声行
+    background: Option<String>,
+}
+
+pub(super) fn parse(text: &str) -> Option<Lyric> {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+
+    let mut tags = vec![];
+    let mut offset = 0;
+    let mut raw_lines: Vec<RawLine> = vec![];
+    // 上一个带时间的行在 raw_lines 中的范围，和声行属于这些行
+    let mut last_timed = 0..0;
+    for line in text.lines() {
+        let (times, content) = _split_time_tags(line);
+        if times.is_empty() {
+            if let Some(tag) = super::_parse_tag(line) {
+                if tag.key.eq_ignore_ascii_case("bg") {
+                    for raw_line in &mut raw_lines[last_timed.clone()] {
+                        raw_line.background = Some(tag.value.clone());
+                    }
+                    continue;
+                }
+                if tag.key.eq_ignore_ascii_case("offset") {
+                    offset = tag.value.parse::<i64>().unwrap_or(0);
+                }
+                tags.push(tag);
+            }
+            continue;
+        }
+        last_timed = raw_lines.len()..raw_lines.len() + times.len();
+        for time in times {
+            raw_lines.push(RawLine {
+                time,
+                content,
+                background: None,
+            });
+        }
+    }
+
+    let mut enhanced = false;
+    let mut lines: Vec<LyricLine> = raw_lines
+        .into_iter()
+        .map(|raw_line| {
+            let start = super::_apply_offset(raw_line.time, offset);
+            let (agent, content) = _split_agent(raw_line.content);
+            let words = _parse_words(content, start, offset);
+            enhanced |= !words.is_empty();
+            LyricLine {
+                start,
+                end: 0,
+                text: if words.is_empty() {
+                    content.trim().to_string()
+                } else {
+                    words.iter().map(|word| word.text.as_str()).collect()
+                },
+                words,
+                translation: None,
+                agent,
+                background: raw_line
+                    .background
+                    .map(|background| _parse_words(&background, start, offset))
+                    .unwrap_or_default(),
+                romanization: None,
+            }
+        })
+        .collect();
+
+    lines.sort_by_key(|line| line.start);
+    let format = if enhanced {
+        LyricFormat::EnhancedLrc
+    } else {
+        LyricFormat::Lrc
+    };
+    Lyric::new(format, tags, _merge_same_start(lines))
+}
+
+/// mm:ss.xxx
+fn _format_timestamp(ms: u32) -> String {
+    format!("{:02}:{:02}.{:03}", ms / 60_000, ms / 1000 % 60, ms % 1000)
+}
+
+/// <开始>字<开始>字<结束>。和下一个字之间有间隔时插入结束时间
+fn _format_words(words: &[LyricWord]) -> String {
+    let mut result = String::new();
+    for (i, word) in words.iter().enumerate() {
+        result.push_str(&format!("<{}>{}", _format_timestamp(word.start), word.text));
+        let next_start = words.get(i + 1).map(|next| next.start);
+        if next_start != Some(word.end) {
+            result.push_str(&format!("<{}>", _format_timestamp(word.end)));
+        }
+    }
+    result
+}
+
+/// 写成 LRC。有逐字时间时写成逐字 LRC，时间精确到毫秒；
+/// 有多个演唱者时加上 "v1:" 前缀，和声写成紧跟的 [bg:...] 行，翻译写成相同时间的下一行
+pub(super) fn write(lyric: &Lyric) -> String {
+    let mut result = String::new();
+    for tag in &lyric.tags {
+        // offset 已经应用到时间上
+        if !tag.key.eq_ignore_ascii_case("offset") {
+            result.push_str(&format!("[{}:{}]\n", tag.key, tag.value));
+        }
+    }
+
+    let mut agents: Vec<&str> = lyric
+        .lines
+        .iter()
+        .filter_map(|line| line.agent.as_deref())
+        .collect();
+    agents.sort();
+    agents.dedup();
+    let write_agent = agents.len() > 1;
+
+    for line in &lyric.lines {
+        let time = _format_timestamp(line.start);
+        result.push_str(&format!("[{time}]"));
+        if let (true, Some(agent)) = (write_agent, &line.agent) {
+            result.push_str(&format!("{agent}: "));
+        }
+        if line.words.is_empty() {
+            result.push_str(&line.text);
+        } else {
+            result.push_str(&_format_words(&line.words));
+        }
+        result.push('\n');
+
+        if !line.background.is_empty() {
+            result.push_str(&format!("[bg:{}]\n", _format_words(&line.background)));
+        }
+        if let Some(translation) = &line.translation {
+            for translation in translation.lines() {
+                result.push_str(&format!("[{time}]{translation}\n"));
+            }
+        }
+    }
+    result
+}
@@ -0,0 +1,128 @@
+use super::{Lyric, LyricFormat, LyricLine, LyricWord};
+
+/// [start,duration]
+fn _parse_range(range: &str) -> Option<(u32, u32)> {
+    let (start, duration) = range.split_once(',')?;
+    Some((start.trim().parse().ok()?, duration.trim().parse().ok()?))
+}
+
+/// 行内容中 (start,duration) 的位置：(左括号, 右括号, start, duration)
+fn _find_timings(content: &str) -> Vec<(usize, usize, u32, u32)> {
+    let mut timings = vec![];
+    let mut from = 0;
+    while let Some(left) = content[from..].find('(').map(|i| from + i) {
+        let timing = content[left + 1..]
+            .find(')')
+            .map(|i| left + 1 + i)
+            .and_then(|right| Some((right, _parse_range(&content[left + 1..right])?)));
+        match timing {
+            Some((right, (start, duration))) => {
+                timings.push((left, right, start, duration));
+                from = right + 1;
+            }
+            None => from = left + 1,
+        }
+    }
+    timings
+}
+
+fn _split_line(line: &str) -> Option<((u32, u32), &str)> {
+    let (range, content) = line.trim_start().strip_prefix('[')?.split_once(']')?;
+    Some((_parse_range(range)?, content))
+}
+
+pub(super) fn is_qrc(text: &str) -> bool {
+    text.contains("LyricContent=")
+        || text.lines().any(|line| {
+            _split_line(line).is_some_and(|(_, content)| !_find_timings(content).is_empty())
+        })
+}
+
+fn _unescape_xml(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        result.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let entity = rest[1..].find(';').map(|i| &rest[1..1 + i]);
+        let unescaped = entity.and_then(|entity| match entity {
+            "quot" => Some('"'),
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "apos" => Some('\''),
+            _ => {
+                let code = match entity.strip_prefix("#x").or(entity.strip_prefix("#X")) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                    None => entity.strip_prefix('#')?.parse().ok(),
+                };
+                code.and_then(char::from_u32)
+            }
+        });
+        match (entity, unescaped) {
+            (Some(entity), Some(c)) => {
+                result.push(c);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                result.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+/// QQ 音乐的 qrc 解密后是 XML，歌词在 LyricContent 属性中
+fn _unwrap_xml(text: &str) -> String {
+    const ATTR: &str = "LyricContent=\"";
+    match text.find(ATTR) {
+        Some(begin) => {
+            let content = &text[begin + ATTR.len()..];
+            let end = content.find('"').unwrap_or(content.len());
+            _unescape_xml(&content[..end])
+        }
+        None => text.to_string(),
+    }
+}
+
+pub(super) fn parse(text: &str) -> Option<Lyric> {
+    let text = _unwrap_xml(text.strip_prefix('\u{feff}').unwrap_or(text));
+
+    let mut tags = vec![];
+    let mut lines = vec![];
+    for line in text.lines() {
+        let Some(((start, duration), content)) = _split_line(line) else {
+            if let Some(tag) = super::_parse_tag(line) {
+                tags.push(tag);
+            }
+            continue;
+        };
+
+        // 两个时间之间的文字属于后一个时间，文字中可以有不是时间的括号
+        let mut words = vec![];
+        let mut word_begin = 0;
+        for (left, right, word_start, word_duration) in _find_timings(content) {
+            words.push(LyricWord {
+                start: word_start,
+                end: word_start.saturating_add(word_duration),
+                text: content[word_begin..left].to_string(),
+            });
+            word_begin = right + 1;
+        }
+
+        lines.push(LyricLine {
+            start,
+            end: start.saturating_add(duration),
+            text: words.iter().map(|word| word.text.as_str()).collect(),
+            words,
+            translation: None,
+            agent: None,
+            background: vec![],
+            romanization: None,
+        });
+    }
+
+    Lyric::new(LyricFormat::Qrc, tags, lines)
+}
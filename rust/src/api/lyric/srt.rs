@@ -0,0 +1,84 @@
+use super::{Lyric, LyricFormat, LyricLine};
+
+/// hh:mm:ss,mmm
+fn _parse_srt_timestamp(time: &str) -> Option<u32> {
+    let time = time.trim().replace(',', ".");
+    if time.contains('.') {
+        super::_parse_timestamp(&time)
+    } else {
+        super::_parse_timestamp(&format!("{time}.000"))
+    }
+}
+
+/// 去掉 <i>, <font color=...> 等格式标签
+fn _strip_tags(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(left) = rest.find('<') {
+        match rest[left..].find('>') {
+            Some(right) => {
+                result.push_str(&rest[..left]);
+                rest = &rest[left + right + 1..];
+            }
+            None => break,
+        }
+    }
+    result.push_str(rest);
+    result.trim().to_string()
+}
+
+pub(super) fn is_srt(text: &str) -> bool {
+    text.lines().any(|line| {
+        line.split_once("-->").is_some_and(|(start, end)| {
+            _parse_srt_timestamp(start).is_some()
+                && end
+                    .split_whitespace()
+                    .next()
+                    .and_then(_parse_srt_timestamp)
+                    .is_some()
+        })
+    })
+}
+
+pub(super) fn parse(text: &str) -> Option<Lyric> {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+
+    let mut lines = vec![];
+    let mut block: Vec<&str> = vec![];
+    for line in text.lines().chain([""]) {
+        if !line.trim().is_empty() {
+            block.push(line);
+            continue;
+        }
+
+        // 序号行可以省略
+        let timing = block.iter().position(|line| line.contains("-->"));
+        if let Some(timing) = timing {
+            let range = block[timing].split_once("-->").and_then(|(start, end)| {
+                // 结束时间后面可以有位置信息
+                let end = end.split_whitespace().next()?;
+                Some((_parse_srt_timestamp(start)?, _parse_srt_timestamp(end)?))
+            });
+            let texts: Vec<String> = block[timing + 1..]
+                .iter()
+                .map(|line| _strip_tags(line))
+                .filter(|line| !line.is_empty())
+                .collect();
+            if let Some((start, end)) = range {
+                lines.push(LyricLine {
+                    start,
+                    end,
+                    text: texts.first().cloned().unwrap_or_default(),
+                    words: vec![],
+                    translation: (texts.len() > 1).then(|| texts[1..].join("\n")),
+                    agent: None,
+                    background: vec![],
+                    romanization: None,
+                });
+            }
+        }
+        block.clear();
+    }
+
+    Lyric::new(LyricFormat::Srt, vec![], lines)
+}
@@ -0,0 +1,331 @@
+use std::collections::HashMap;
+
+use roxmltree::{Document, Node};
+
+use super::{Lyric, LyricAgent, LyricFormat, LyricLine, LyricTag, LyricWord};
+
+const TTML_NS: &str = "http://www.w3.org/ns/ttml";
+const TTM_NS: &str = "http://www.w3.org/ns/ttml#metadata";
+const ITUNES_NS: &str = "http://music.apple.com/lyric-ttml-internal";
+
+pub(super) fn is_ttml(text: &str) -> bool {
+    let text = text.trim_start_matches('\u{feff}').trim_start();
+    (text.starts_with("<?xml") || text.starts_with("<tt")) && text.contains("<tt")
+}
+
+/// 按本地名称读取属性，不关心命名空间前缀（ttm:agent, itunes:key, xml:lang 等）
+fn _attr<'a>(node: Node<'a, '_>, name: &str) -> Option<&'a str> {
+    node.attributes()
+        .find(|attr| attr.name() == name)
+        .map(|attr| attr.value())
+}
+
+/// 12.345, 12.345s, 1500ms, 01:02.345, 1:01:02.345 -> ms
+fn _parse_time(time: &str) -> Option<u32> {
+    let time = time.trim();
+    let seconds_to_ms = |seconds: &str| -> Option<u32> {
+        let seconds: f64 = seconds.parse().ok()?;
+        (seconds.is_finite() && seconds >= 0.0).then(|| (seconds * 1000.0).round() as u32)
+    };
+
+    if let Some(ms) = time.strip_suffix("ms") {
+        return ms.parse::<f64>().ok().map(|ms| ms.round() as u32);
+    }
+    if let Some(seconds) = time.strip_suffix('s') {
+        return seconds_to_ms(seconds);
+    }
+    if !time.contains(':') {
+        return seconds_to_ms(time);
+    }
+
+    let mut parts: Vec<&str> = time.split(':').collect();
+    let seconds = seconds_to_ms(parts.pop()?)?;
+    let mut minutes = 0u32;
+    for part in parts {
+        minutes = minutes.checked_mul(60)?.checked_add(part.parse().ok()?)?;
+    }
+    minutes.checked_mul(60_000)?.checked_add(seconds)
+}
+
+fn _text_of(node: Node) -> String {
+    node.descendants()
+        .filter(|n| n.is_text())
+        .filter_map(|n| n.text())
+        .collect()
+}
+
+/// span 中的逐字时间。span 之间的空白属于前一个字
+fn _parse_spans(parent: Node, words: &mut Vec<LyricWord>, line: &mut LyricLine) {
+    for child in parent.children() {
+        if child.is_text() {
+            let text = child.text().unwrap_or_default();
+            match words.last_mut() {
+                Some(word) if text.trim().is_empty() => word.text.push(' '),
+                Some(word) => word
+                    .text
+                    .push_str(&text.split_whitespace().collect::<Vec<_>>().join(" ")),
+                None => {}
+            }
+            continue;
+        }
+        if !child.is_element() || child.tag_name().name() != "span" {
+            continue;
+        }
+
+        match _attr(child, "role") {
+            Some("x-bg") => {
+                let mut background = vec![];
+                _parse_spans(child, &mut background, line);
+                // 和声通常带括号：(ooh)
+                line.background.extend(background);
+            }
+            Some("x-translation") => {
+                let text = _text_of(child).trim().to_string();
+                if !text.is_empty() {
+                    line.translation = Some(text);
+                }
+            }
+            Some("x-roman") => {
+                let text = _text_of(child).trim().to_string();
+                if !text.is_empty() {
+                    line.romanization = Some(text);
+                }
+            }
+            Some(_) => {}
+            None => {
+                let begin = _attr(child, "begin").and_then(_parse_time);
+                let end = _attr(child, "end").and_then(_parse_time);
+                match begin {
+                    Some(start) => words.push(LyricWord {
+                        start,
+                        end: end.unwrap_or(0),
+                        text: _text_of(child),
+                    }),
+                    // 没有时间的 span 当作普通文字
+                    None => _parse_spans(child, words, line),
+                }
+            }
+        }
+    }
+}
+
+/// head 中 iTunesMetadata 的翻译：itunes:key -> 翻译
+fn _parse_itunes_translations(doc: &Document) -> HashMap<String, String> {
+    let mut translations = HashMap::new();
+    for translation in doc
+        .descendants()
+        .filter(|n| n.tag_name().name() == "translation")
+    {
+        for text in translation
+            .children()
+            .filter(|n| n.tag_name().name() == "text")
+        {
+            if let Some(key) = _attr(text, "for") {
+                translations.insert(key.to_string(), _text_of(text).trim().to_string());
+            }
+        }
+    }
+    translations
+}
+
+fn _parse_agents(doc: &Document) -> Vec<LyricAgent> {
+    doc.descendants()
+        .filter(|n| n.is_element() && n.tag_name().name() == "agent")
+        .filter_map(|agent| {
+            Some(LyricAgent {
+                id: _attr(agent, "id")?.to_string(),
+                kind: _attr(agent, "type").unwrap_or("person").to_string(),
+                name: agent
+                    .children()
+                    .find(|n| n.tag_name().name() == "name")
+                    .map(|name| _text_of(name).trim().to_string()),
+            })
+        })
+        .collect()
+}
+
+pub(super) fn parse(text: &str) -> Option<Lyric> {
+    let text = text.trim_start_matches('\u{feff}');
+    let doc = Document::parse(text).ok()?;
+    let root = doc.root_element();
+    if root.tag_name().name() != "tt" {
+        return None;
+    }
+
+    let mut tags = vec![];
+    if let Some(lang) = _attr(root, "lang") {
+        tags.push(LyricTag {
+            key: "language".to_string(),
+            value: lang.to_string(),
+        });
+    }
+    if let Some(title) = doc
+        .descendants()
+        .find(|n| n.tag_name().name() == "title")
+        .map(_text_of)
+    {
+        tags.push(LyricTag {
+            key: "ti".to_string(),
+            value: title.trim().to_string(),
+        });
+    }
+
+    let translations = _parse_itunes_translations(&doc);
+    let mut lines = vec![];
+    for p in doc
+        .descendants()
+        .filter(|n| n.is_element() && n.tag_name().name() == "p")
+    {
+        let Some(start) = _attr(p, "begin").and_then(_parse_time) else {
+            continue;
+        };
+        let mut line = LyricLine {
+            start,
+            end: _attr(p, "end").and_then(_parse_time).unwrap_or(0),
+            text: String::new(),
+            words: vec![],
+            translation: _attr(p, "key").and_then(|key| translations.get(key).cloned()),
+            agent: _attr(p, "agent").map(|agent| agent.to_string()),
+            background: vec![],
+            romanization: None,
+        };
+
+        let mut words = vec![];
+        _parse_spans(p, &mut words, &mut line);
+        if words.is_empty() {
+            // 逐行歌词：p 中直接是文字
+            line.text = p
+                .children()
+                .filter(|n| n.is_text())
+                .filter_map(|n| n.text())
+                .collect::<String>()
+                .trim()
+                .to_string();
+        } else {
+            if let Some(last) = words.last_mut() {
+                last.text = last.text.trim_end().to_string();
+            }
+            line.text = words.iter().map(|word| word.text.as_str()).collect();
+        }
+        line.words = words;
+        if let Some(last) = line.background.last_mut() {
+            last.text = last.text.trim_end().to_string();
+        }
+        lines.push(line);
+    }
+
+    let mut lyric = Lyric::new(LyricFormat::Ttml, tags, lines)?;
+    lyric.agents = _parse_agents(&doc);
+    Some(lyric)
+}
+
+fn _escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// h:mm:ss.xxx 或 m:ss.xxx
+fn _format_time(ms: u32) -> String {
+    let (hours, minutes, seconds, millis) =
+        (ms / 3_600_000, ms / 60_000 % 60, ms / 1000 % 60, ms % 1000);
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}.{millis:03}")
+    } else {
+        format!("{minutes}:{seconds:02}.{millis:03}")
+    }
+}
+
+/// 字末尾的空白写在 span 外面
+fn _write_spans(words: &[LyricWord], result: &mut String) {
+    for word in words {
+        let text = word.text.trim_end();
+        result.push_str(&format!(
+            r#"<span begin="{}" end="{}">{}</span>"#,
+            _format_time(word.start),
+            _format_time(word.end),
+            _escape_xml(text)
+        ));
+        if text.len() != word.text.len() {
+            result.push(' ');
+        }
+    }
+}
+
+/// 写成 Apple Music 风格的 TTML。翻译和罗马字分别写在 ttm:role 为 x-translation, x-roman 的 span 中
+pub(super) fn write(lyric: &Lyric) -> String {
+    let word_timed = lyric.lines.iter().any(|line| !line.words.is_empty());
+    let lang = lyric
+        .tags
+        .iter()
+        .find(|tag| tag.key == "language")
+        .map(|tag| format!(r#" xml:lang="{}""#, _escape_xml(&tag.value)))
+        .unwrap_or_default();
+
+    let mut result = format!(
+        r#"<tt xmlns="{TTML_NS}" xmlns:ttm="{TTM_NS}" xmlns:itunes="{ITUNES_NS}" itunes:timing="{}"{lang}>"#,
+        if word_timed { "Word" } else { "Line" }
+    );
+
+    result.push_str("<head><metadata>");
+    if let Some(title) = lyric.tags.iter().find(|tag| tag.key == "ti") {
+        result.push_str(&format!(
+            "<ttm:title>{}</ttm:title>",
+            _escape_xml(&title.value)
+        ));
+    }
+    for agent in &lyric.agents {
+        result.push_str(&format!(
+            r#"<ttm:agent type="{}" xml:id="{}">"#,
+            _escape_xml(&agent.kind),
+            _escape_xml(&agent.id)
+        ));
+        if let Some(name) = &agent.name {
+            result.push_str(&format!("<ttm:name>{}</ttm:name>", _escape_xml(name)));
+        }
+        result.push_str("</ttm:agent>");
+    }
+    result.push_str("</metadata></head>");
+
+    let end = lyric.lines.iter().map(|line| line.end).max().unwrap_or(0);
+    result.push_str(&format!(r#"<body dur="{}"><div>"#, _format_time(end)));
+    for (i, line) in lyric.lines.iter().enumerate() {
+        result.push_str(&format!(
+            r#"<p begin="{}" end="{}" itunes:key="L{}""#,
+            _format_time(line.start),
+            _format_time(line.end),
+            i + 1
+        ));
+        if let Some(agent) = &line.agent {
+            result.push_str(&format!(r#" ttm:agent="{}""#, _escape_xml(agent)));
+        }
+        result.push('>');
+
+        if line.words.is_empty() {
+            result.push_str(&_escape_xml(&line.text));
+        } else {
+            _write_spans(&line.words, &mut result);
+        }
+        if !line.background.is_empty() {
+            result.push_str(r#"<span ttm:role="x-bg">"#);
+            _write_spans(&line.background, &mut result);
+            result.push_str("</span>");
+        }
+        if let Some(romanization) = &line.romanization {
+            result.push_str(&format!(
+                r#"<span ttm:role="x-roman">{}</span>"#,
+                _escape_xml(romanization)
+            ));
+        }
+        if let Some(translation) = &line.translation {
+            result.push_str(&format!(
+                r#"<span ttm:role="x-translation">{}</span>"#,
+                _escape_xml(translation)
+            ));
+        }
+        result.push_str("</p>");
+    }
+    result.push_str("</div></body></tt>");
+    result
+}
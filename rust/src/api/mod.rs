@@ -2,12 +2,19 @@
 // Do not put code in `mod.rs`, but put in e.g. `simple.rs`.
 //
 
+pub mod album_palette;
+pub mod embedded_picture;
 pub mod installed_font;
 pub mod library_db;
 pub mod logger;
+pub mod lyric;
+pub mod music_matcher;
+pub mod picture_cache;
 pub mod smtc_flutter;
 pub mod system_theme;
 pub mod system_volume;
 pub mod tag_reader;
+pub mod text_convert;
+pub mod text_encoding;
 pub mod utils;
 // pub mod consts;
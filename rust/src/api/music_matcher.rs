@@ -0,0 +1,498 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use flutter_rust_bridge::frb;
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+
+use super::library_db;
+use super::text_convert::{self, ChineseVariant};
+
+/// 用于匹配的歌曲信息
+#[derive(Clone, Debug)]
+pub struct MatchTrack {
+    pub title: String,
+    /// 多个艺术家可以用 、/ , & ; feat. 等分隔
+    pub artist: String,
+    /// s，未知时为 None
+    pub duration: Option<f64>,
+}
+
+#[derive(Clone, Debug)]
+pub struct MatchScore {
+    /// 0~1，已知的各项按权重平均
+    pub total: f64,
+    pub title: f64,
+    /// 任一方没有艺术家时为 None
+    pub artist: Option<f64>,
+    /// 任一方没有时长时为 None
+    pub duration: Option<f64>,
+    /// 给人看的说明，如 "title: exact match after normalization"
+    pub reasons: Vec<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct RankedMatch {
+    /// 在 candidates 中的位置
+    pub index: u32,
+    pub score: MatchScore,
+}
+
+const TITLE_WEIGHT: f64 = 0.55;
+const ARTIST_WEIGHT: f64 = 0.3;
+const DURATION_WEIGHT: f64 = 0.15;
+
+/// 时长相差不超过这个值（s）时为满分，相差 DURATION_ZERO_DIFF 时为 0
+const DURATION_FULL_DIFF: f64 = 2.0;
+const DURATION_ZERO_DIFF: f64 = 20.0;
+
+/// " - " 后面有这些词时，整个后缀是版本说明
+const VERSION_KEYWORDS: [&str; 16] = [
+    "live",
+    "remaster",
+    "remastered",
+    "version",
+    "ver.",
+    "edit",
+    "mix",
+    "remix",
+    "acoustic",
+    "instrumental",
+    "demo",
+    "mono",
+    "stereo",
+    "explicit",
+    "伴奏",
+    "现场",
+];
+
+const FEAT_MARKERS: [&str; 4] = ["feat.", "feat ", "ft.", "featuring "];
+
+const ARTIST_SEPARATORS: [&str; 12] = [
+    "、",
+    "/",
+    ",",
+    "&",
+    ";",
+    " x ",
+    " × ",
+    " feat. ",
+    " feat ",
+    " ft. ",
+    " featuring ",
+    " vs. ",
+];
+
+/// 全角转半角，去掉重音符号，小写，片假名转平假名，繁体转简体
+fn _fold(text: &str) -> String {
+    let text = text_convert::convert_chinese(text.to_string(), ChineseVariant::Simplified);
+    text.nfkd()
+        // 保留假名的浊点、半浊点，否则 が 和 か 相同
+        .filter(|c| !is_combining_mark(*c) || matches!(c, '\u{3099}' | '\u{309A}'))
+        .flat_map(char::to_lowercase)
+        .map(|c| match c {
+            '\u{30A1}'..='\u{30F6}' => char::from_u32(c as u32 - 0x60).unwrap_or(c),
+            _ => c,
+        })
+        .collect()
+}
+
+fn _closing_bracket(c: char) -> Option<char> {
+    Some(match c {
+        '(' => ')',
+        '[' => ']',
+        '{' => '}',
+        '【' => '】',
+        '「' => '」',
+        '『' => '』',
+        '《' => '》',
+        '〈' => '〉',
+        '〔' => '〕',
+        _ => return None,
+    })
+}
+
+/// 去掉括号及其中的内容，如 (Live), [Remastered], 【伴奏】
+fn _strip_brackets(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut closing: Vec<char> = vec![];
+    for c in text.chars() {
+        if let Some(close) = _closing_bracket(c) {
+            closing.push(close);
+        } else if closing.last() == Some(&c) {
+            closing.pop();
+        } else if closing.is_empty() {
+            result.push(c);
+        }
+    }
+    result
+}
+
+/// 去掉 feat. xxx 直到结尾
+fn _strip_feat(text: &str) -> &str {
+    FEAT_MARKERS
+        .iter()
+        .flat_map(|marker| text.match_indices(marker).map(|(i, _)| i))
+        // 必须是一个单词的开头；标题以 feat 开头时不处理
+        .filter(|i| text[..*i].ends_with([' ', '(', '[', '-']))
+        .min()
+        .map_or(text, |i| &text[..i])
+}
+
+/// 去掉 " - Live at xxx", " - 2011 Remastered" 等版本后缀
+fn _strip_version_suffix(text: &str) -> &str {
+    match text.rfind(" - ") {
+        Some(i)
+            if text[i + 3..]
+                .split_whitespace()
+                .any(|word| VERSION_KEYWORDS.contains(&word)) =>
+        {
+            &text[..i]
+        }
+        _ => text,
+    }
+}
+
+/// 去掉标点和符号，合并空白
+fn _strip_punctuation(text: &str) -> String {
+    text.chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// for Flutter
+/// 用于比较的标题：全角转半角，去掉重音、feat.、括号和版本后缀，小写，片假名转平假名，繁体转简体
+#[frb(sync)]
+pub fn normalize_title(title: String) -> String {
+    let folded = _fold(&title);
+    let stripped = _strip_brackets(_strip_version_suffix(_strip_feat(&folded)));
+    let normalized = _strip_punctuation(&stripped);
+    if normalized.is_empty() {
+        // 整个标题都在括号中时保留括号中的内容
+        _strip_punctuation(&folded)
+    } else {
+        normalized
+    }
+}
+
+/// for Flutter
+/// 拆分并规范化艺术家，去掉重复的
+#[frb(sync)]
+pub fn normalize_artists(artist: String) -> Vec<String> {
+    let mut parts = vec![_fold(&artist)];
+    for separator in ARTIST_SEPARATORS {
+        parts = parts
+            .iter()
+            .flat_map(|part| part.split(separator))
+            .map(|part| part.to_string())
+            .collect();
+    }
+
+    let mut artists: Vec<String> = vec![];
+    for part in parts {
+        let part = _strip_punctuation(&part);
+        if !part.is_empty() && !artists.contains(&part) {
+            artists.push(part);
+        }
+    }
+    artists
+}
+
+/// 1 - 编辑距离 / 较长的字数
+fn _similarity(a: &str, b: &str) -> f64 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let max_len = a.len().max(b.len());
+    if max_len == 0 {
+        return 1.0;
+    }
+
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, ca) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    1.0 - previous[b.len()] as f64 / max_len as f64
+}
+
+fn _title_score(query: &str, candidate: &str, reasons: &mut Vec<String>) -> f64 {
+    if query == candidate {
+        reasons.push("title: exact match after normalization".to_string());
+        return 1.0;
+    }
+
+    let similarity = _similarity(query, candidate);
+    let (shorter, longer) = if query.len() <= candidate.len() {
+        (query, candidate)
+    } else {
+        (candidate, query)
+    };
+    // 一方是另一方的一部分，如 "song" 和 "song acoustic"
+    if shorter.chars().count() >= 2 && longer.contains(shorter) && similarity < 0.85 {
+        reasons.push(format!("title: \"{shorter}\" is part of \"{longer}\""));
+        return 0.85;
+    }
+    reasons.push(format!("title: {:.0}% similar", similarity * 100.0));
+    similarity
+}
+
+/// from 中每个艺术家在 to 中最相似的平均值
+fn _artist_coverage(from: &[String], to: &[String]) -> f64 {
+    from.iter()
+        .map(|a| to.iter().map(|b| _similarity(a, b)).fold(0.0, f64::max))
+        .sum::<f64>()
+        / from.len() as f64
+}
+
+fn _artist_score(query: &[String], candidate: &[String], reasons: &mut Vec<String>) -> Option<f64> {
+    if query.is_empty() || candidate.is_empty() {
+        reasons.push("artist: unknown".to_string());
+        return None;
+    }
+    // 只要一方的艺术家都能在另一方找到即可，在线结果经常多列或少列合唱者
+    let score = _artist_coverage(query, candidate).max(_artist_coverage(candidate, query));
+    if score >= 1.0 {
+        reasons.push("artist: all artists match".to_string());
+    } else {
+        reasons.push(format!("artist: {:.0}% similar", score * 100.0));
+    }
+    Some(score)
+}
+
+fn _duration_score(
+    query: Option<f64>,
+    candidate: Option<f64>,
+    reasons: &mut Vec<String>,
+) -> Option<f64> {
+    let (Some(query), Some(candidate)) =
+        (query.filter(|d| *d > 0.0), candidate.filter(|d| *d > 0.0))
+    else {
+        reasons.push("duration: unknown".to_string());
+        return None;
+    };
+    let diff = (query - candidate).abs();
+    reasons.push(format!("duration: differs by {diff:.1}s"));
+    Some(
+        (1.0 - (diff - DURATION_FULL_DIFF).max(0.0) / (DURATION_ZERO_DIFF - DURATION_FULL_DIFF))
+            .clamp(0.0, 1.0),
+    )
+}
+
+fn _score_normalized(
+    query: (&str, &[String], Option<f64>),
+    candidate: (&str, &[String], Option<f64>),
+) -> MatchScore {
+    let mut reasons = vec![];
+    let title = _title_score(query.0, candidate.0, &mut reasons);
+    let artist = _artist_score(query.1, candidate.1, &mut reasons);
+    let duration = _duration_score(query.2, candidate.2, &mut reasons);
+
+    let mut weighted = title * TITLE_WEIGHT;
+    let mut weights = TITLE_WEIGHT;
+    if let Some(artist) = artist {
+        weighted += artist * ARTIST_WEIGHT;
+        weights += ARTIST_WEIGHT;
+    }
+    if let Some(duration) = duration {
+        weighted += duration * DURATION_WEIGHT;
+        weights += DURATION_WEIGHT;
+    }
+
+    MatchScore {
+        total: weighted / weights,
+        title,
+        artist,
+        duration,
+        reasons,
+    }
+}
+
+struct NormalizedTrack {
+    title: String,
+    artists: Vec<String>,
+    duration: Option<f64>,
+}
+
+impl NormalizedTrack {
+    fn new(track: &MatchTrack) -> Self {
+        NormalizedTrack {
+            title: normalize_title(track.title.clone()),
+            artists: normalize_artists(track.artist.clone()),
+            duration: track.duration,
+        }
+    }
+
+    fn score(&self, other: &NormalizedTrack) -> MatchScore {
+        _score_normalized(
+            (&self.title, &self.artists, self.duration),
+            (&other.title, &other.artists, other.duration),
+        )
+    }
+}
+
+/// for Flutter
+#[frb(sync)]
+pub fn score_match(query: MatchTrack, candidate: MatchTrack) -> MatchScore {
+    NormalizedTrack::new(&query).score(&NormalizedTrack::new(&candidate))
+}
+
+/// for Flutter
+/// 按 total 从高到低排列，相同时保持原来的顺序
+#[frb(sync)]
+pub fn rank_matches(query: MatchTrack, candidates: Vec<MatchTrack>) -> Vec<RankedMatch> {
+    let query = NormalizedTrack::new(&query);
+    let mut ranked: Vec<RankedMatch> = candidates
+        .iter()
+        .enumerate()
+        .map(|(i, candidate)| RankedMatch {
+            index: i as u32,
+            score: query.score(&NormalizedTrack::new(candidate)),
+        })
+        .collect();
+    ranked.sort_by(|a, b| b.score.total.total_cmp(&a.score.total));
+    ranked
+}
+
+fn _find_root(parents: &mut [usize], i: usize) -> usize {
+    let mut root = i;
+    while parents[root] != root {
+        root = parents[root];
+    }
+    parents[i] = root;
+    root
+}
+
+/// for Flutter
+/// 找出可能重复的歌曲，返回每组在 tracks 中的位置（至少两首）。
+/// 只比较规范化后标题相同的歌曲，total 不低于 threshold 时视为重复
+pub fn find_duplicates(tracks: Vec<MatchTrack>, threshold: f64) -> Vec<Vec<u32>> {
+    let normalized: Vec<_> = tracks.iter().map(NormalizedTrack::new).collect();
+    let mut by_title: HashMap<&str, Vec<usize>> = HashMap::new();
+    for (i, track) in normalized.iter().enumerate() {
+        if !track.title.is_empty() {
+            by_title.entry(&track.title).or_default().push(i);
+        }
+    }
+
+    let mut parents: Vec<usize> = (0..tracks.len()).collect();
+    for group in by_title.values() {
+        for (n, &i) in group.iter().enumerate() {
+            for &j in &group[n + 1..] {
+                if normalized[i].score(&normalized[j]).total >= threshold {
+                    let (root_i, root_j) =
+                        (_find_root(&mut parents, i), _find_root(&mut parents, j));
+                    parents[root_j] = root_i;
+                }
+            }
+        }
+    }
+
+    let mut groups: HashMap<usize, Vec<u32>> = HashMap::new();
+    for i in 0..tracks.len() {
+        let root = _find_root(&mut parents, i);
+        groups.entry(root).or_default().push(i as u32);
+    }
+    let mut groups: Vec<Vec<u32>> = groups.into_values().filter(|g| g.len() > 1).collect();
+    groups.sort();
+    groups
+}
+
+/// for Flutter
+/// 在 index_path 的音乐库中查找重复的歌曲，返回每组的路径。见 [find_duplicates]
+pub fn find_duplicate_audios(index_path: String, threshold: f64) -> Result<Vec<Vec<String>>> {
+    let audios: Vec<_> = library_db::read_index_from_sqlite(index_path)?
+        .into_iter()
+        .flat_map(|folder| folder.audios)
+        .collect();
+    let tracks = audios
+        .iter()
+        .map(|audio| MatchTrack {
+            title: audio.title.clone(),
+            artist: audio.artist.clone(),
+            duration: Some(audio.duration as f64),
+        })
+        .collect();
+
+    Ok(find_duplicates(tracks, threshold)
+        .into_iter()
+        .map(|group| {
+            group
+                .into_iter()
+                .map(|i| audios[i as usize].path.clone())
+                .collect()
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn track(title: &str, artist: &str, duration: Option<f64>) -> MatchTrack {
+        MatchTrack {
+            title: title.to_string(),
+            artist: artist.to_string(),
+            duration,
+        }
+    }
+
+    #[test]
+    fn normalize() {
+        assert_eq!(normalize_title("Ｈｅｌｌｏ (Live)".to_string()), "hello");
+        assert_eq!(
+            normalize_title("Café del Mar - 2011 Remastered".to_string()),
+            "cafe del mar"
+        );
+        assert_eq!(normalize_title("Song feat. Someone".to_string()), "song");
+        assert_eq!(normalize_title("Defeat (ft. X)".to_string()), "defeat");
+        assert_eq!(normalize_title("【伴奏】後來".to_string()), "后来");
+        assert_eq!(normalize_title("カタオモイ".to_string()), "かたおもい");
+        assert_eq!(normalize_title("(Intro)".to_string()), "intro");
+        assert_eq!(
+            normalize_artists("周杰倫、費玉清 / Someone feat. Other".to_string()),
+            ["周杰伦", "费玉清", "someone", "other"]
+        );
+    }
+
+    #[test]
+    fn rank_by_title_artist_and_duration() {
+        let query = track("後來", "劉若英", Some(305.0));
+        let ranked = rank_matches(
+            query,
+            vec![
+                track("后来 (Live)", "刘若英", Some(340.0)),
+                track("后来", "刘若英", Some(304.0)),
+                track("后来", "其他人", Some(305.0)),
+                track("以后", "刘若英", None),
+            ],
+        );
+        let order: Vec<u32> = ranked.iter().map(|m| m.index).collect();
+        assert_eq!(order, [1, 0, 2, 3]);
+        assert_eq!(ranked[0].score.title, 1.0);
+        assert!(ranked[0]
+            .score
+            .reasons
+            .iter()
+            .any(|r| r.starts_with("duration")));
+        assert_eq!(ranked[3].score.duration, None);
+    }
+
+    #[test]
+    fn group_duplicates() {
+        let tracks = vec![
+            track("Yesterday", "The Beatles", Some(125.0)),
+            track("Yesterday - Remastered 2009", "Beatles, The", Some(126.0)),
+            track("Yesterday", "Someone Else", Some(200.0)),
+            track("Let It Be", "The Beatles", Some(243.0)),
+            track("YESTERDAY", "the beatles", Some(125.0)),
+        ];
+        assert_eq!(find_duplicates(tracks, 0.8), [vec![0, 1, 4]]);
+    }
+}
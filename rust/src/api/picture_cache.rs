@@ -0,0 +1,534 @@
+use std::{
+    fs,
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex, OnceLock,
+    },
+    thread,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::Result;
+use flutter_rust_bridge::frb;
+use lru::LruCache;
+use rusqlite::{params, Connection, OptionalExtension};
+use xxhash_rust::xxh3::xxh3_128;
+
+use super::library_db;
+use super::logger::log_to_dart;
+use super::tag_reader::{self, PictureSource, PictureWithSource};
+
+const MEMORY_CACHE_CAPACITY: usize = 256;
+
+/// 默认的磁盘缓存上限：256 MiB
+const DEFAULT_BYTE_BUDGET: u64 = 256 * 1024 * 1024;
+
+static MEMORY_CACHE: OnceLock<Mutex<LruCache<String, PictureWithSource>>> = OnceLock::new();
+static DISK_CACHE: Mutex<Option<DiskCache>> = Mutex::new(None);
+
+static PREWARMING: AtomicBool = AtomicBool::new(false);
+static PREWARM_CANCELLED: AtomicBool = AtomicBool::new(false);
+
+fn _memory_cache() -> &'static Mutex<LruCache<String, PictureWithSource>> {
+    MEMORY_CACHE.get_or_init(|| {
+        Mutex::new(LruCache::new(
+            NonZeroUsize::new(MEMORY_CACHE_CAPACITY).unwrap(),
+        ))
+    })
+}
+
+fn _now_secs() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+/// 缩略图的缓存键。音乐文件的修改时间或大小变化后自动失效。
+pub(crate) struct PictureCacheKey {
+    path: String,
+    /// 缩略图的尺寸、格式等参数
+    pub(crate) variant: String,
+    key: String,
+}
+
+impl PictureCacheKey {
+    /// CUE 虚拟音轨和所在的音乐文件使用同一个键
+    pub(crate) fn new(path: &str, variant: String) -> Self {
+        let path = tag_reader::cue_source_path(path);
+        let (modified, size) = fs::metadata(path)
+            .map(|m| {
+                let modified = m
+                    .modified()
+                    .ok()
+                    .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+                    .map(|d| d.as_millis() as u64)
+                    .unwrap_or(0);
+                (modified, m.len())
+            })
+            .unwrap_or((0, 0));
+
+        Self {
+            key: format!("{path}|{modified}|{size}|{variant}"),
+            path: path.to_string(),
+            variant,
+        }
+    }
+}
+
+/// 同一张原图生成的同一规格缩略图只存储一次
+pub(crate) fn blob_id(raw_picture: &[u8], variant: &str) -> String {
+    format!("{:032x}|{variant}", xxh3_128(raw_picture))
+}
+
+fn _source_to_string(source: &PictureSource) -> String {
+    match source {
+        PictureSource::Embedded => "embedded".to_string(),
+        PictureSource::Folder { path } => format!("folder:{path}"),
+        PictureSource::System => "system".to_string(),
+    }
+}
+
+fn _source_from_string(source: &str) -> PictureSource {
+    match source {
+        "embedded" => PictureSource::Embedded,
+        "system" => PictureSource::System,
+        _ => match source.strip_prefix("folder:") {
+            Some(path) => PictureSource::Folder {
+                path: path.to_string(),
+            },
+            None => PictureSource::Embedded,
+        },
+    }
+}
+
+struct DiskCache {
+    conn: Connection,
+    byte_budget: u64,
+    total_bytes: u64,
+}
+
+impl DiskCache {
+    fn open(index_dir: &Path, byte_budget: u64) -> Result<Self> {
+        fs::create_dir_all(index_dir)?;
+        let conn = Connection::open(index_dir.join("thumbnails.sqlite"))?;
+        conn.execute_batch(
+            r#"
+            PRAGMA journal_mode = WAL;
+            PRAGMA synchronous = NORMAL;
+
+            CREATE TABLE IF NOT EXISTS thumbnail_blobs (
+              id TEXT PRIMARY KEY,
+              data BLOB NOT NULL,
+              bytes INTEGER NOT NULL,
+              last_access INTEGER NOT NULL
+            );
+
+            CREATE TABLE IF NOT EXISTS thumbnails (
+              key TEXT PRIMARY KEY,
+              path TEXT NOT NULL,
+              variant TEXT NOT NULL,
+              blob_id TEXT NOT NULL,
+              source TEXT NOT NULL
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_thumbnail_blobs_last_access ON thumbnail_blobs(last_access);
+            CREATE INDEX IF NOT EXISTS idx_thumbnails_path ON thumbnails(path);
+            CREATE INDEX IF NOT EXISTS idx_thumbnails_blob_id ON thumbnails(blob_id);
+            "#,
+        )?;
+
+        let total_bytes: i64 = conn.query_row(
+            "SELECT COALESCE(SUM(bytes), 0) FROM thumbnail_blobs",
+            [],
+            |row| row.get(0),
+        )?;
+
+        let mut cache = Self {
+            conn,
+            byte_budget,
+            total_bytes: total_bytes.max(0) as u64,
+        };
+        cache.evict()?;
+        Ok(cache)
+    }
+
+    fn get(&self, key: &PictureCacheKey) -> Result<Option<PictureWithSource>> {
+        let row: Option<(String, String)> = self
+            .conn
+            .query_row(
+                "SELECT blob_id, source FROM thumbnails WHERE key = ?1",
+                params![key.key],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?;
+        let Some((blob_id, source)) = row else {
+            return Ok(None);
+        };
+
+        Ok(self.touch_blob(&blob_id)?.map(|data| PictureWithSource {
+            data,
+            source: _source_from_string(&source),
+        }))
+    }
+
+    /// 读取缩略图并更新访问时间
+    fn touch_blob(&self, blob_id: &str) -> Result<Option<Vec<u8>>> {
+        let data: Option<Vec<u8>> = self
+            .conn
+            .query_row(
+                "SELECT data FROM thumbnail_blobs WHERE id = ?1",
+                params![blob_id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        if data.is_some() {
+            self.conn.execute(
+                "UPDATE thumbnail_blobs SET last_access = ?2 WHERE id = ?1",
+                params![blob_id, _now_secs()],
+            )?;
+        }
+        Ok(data)
+    }
+
+    /// 同一文件的同一规格只保留一个缩略图。被替换的缩略图没有其他文件使用时一起删除
+    fn link(&mut self, key: &PictureCacheKey, blob_id: &str, source: &PictureSource) -> Result<()> {
+        let tx = self.conn.transaction()?;
+        let replaced: Vec<String> = {
+            let mut stmt = tx.prepare(
+                "SELECT DISTINCT blob_id FROM thumbnails WHERE (path = ?1 AND variant = ?2) OR key = ?3",
+            )?;
+            let rows = stmt.query_map(params![key.path, key.variant, key.key], |row| row.get(0))?;
+            rows.collect::<rusqlite::Result<_>>()?
+        };
+        tx.execute(
+            "DELETE FROM thumbnails WHERE path = ?1 AND variant = ?2",
+            params![key.path, key.variant],
+        )?;
+        tx.execute(
+            "INSERT OR REPLACE INTO thumbnails(key, path, variant, blob_id, source) VALUES(?1, ?2, ?3, ?4, ?5)",
+            params![key.key, key.path, key.variant, blob_id, _source_to_string(source)],
+        )?;
+
+        let mut freed = 0;
+        for id in replaced.iter().filter(|id| *id != blob_id) {
+            let bytes: Option<i64> = tx
+                .query_row(
+                    "SELECT bytes FROM thumbnail_blobs WHERE id = ?1
+                     AND NOT EXISTS (SELECT 1 FROM thumbnails WHERE blob_id = ?1)",
+                    params![id],
+                    |row| row.get(0),
+                )
+                .optional()?;
+            if let Some(bytes) = bytes {
+                tx.execute("DELETE FROM thumbnail_blobs WHERE id = ?1", params![id])?;
+                freed += bytes.max(0) as u64;
+            }
+        }
+        tx.commit()?;
+
+        self.total_bytes = self.total_bytes.saturating_sub(freed);
+        Ok(())
+    }
+
+    fn put(&mut self, key: &PictureCacheKey, blob_id: &str, pic: &PictureWithSource) -> Result<()> {
+        let inserted = self.conn.execute(
+            "INSERT OR IGNORE INTO thumbnail_blobs(id, data, bytes, last_access) VALUES(?1, ?2, ?3, ?4)",
+            params![blob_id, pic.data, pic.data.len() as i64, _now_secs()],
+        )?;
+        if inserted > 0 {
+            self.total_bytes += pic.data.len() as u64;
+        }
+        self.link(key, blob_id, &pic.source)?;
+        self.evict()
+    }
+
+    /// 超出上限时按最近访问时间淘汰
+    fn evict(&mut self) -> Result<()> {
+        if self.total_bytes <= self.byte_budget {
+            return Ok(());
+        }
+
+        let mut victims: Vec<String> = vec![];
+        {
+            let mut stmt = self
+                .conn
+                .prepare("SELECT id, bytes FROM thumbnail_blobs ORDER BY last_access, rowid")?;
+            let mut rows = stmt.query([])?;
+            while let Some(row) = rows.next()? {
+                if self.total_bytes <= self.byte_budget {
+                    break;
+                }
+                let id: String = row.get(0)?;
+                let bytes: i64 = row.get(1)?;
+                self.total_bytes = self.total_bytes.saturating_sub(bytes.max(0) as u64);
+                victims.push(id);
+            }
+        }
+
+        let tx = self.conn.transaction()?;
+        for id in &victims {
+            tx.execute("DELETE FROM thumbnail_blobs WHERE id = ?1", params![id])?;
+            tx.execute("DELETE FROM thumbnails WHERE blob_id = ?1", params![id])?;
+        }
+        tx.commit()?;
+
+        Ok(())
+    }
+
+    fn invalidate(&self, path: &str) -> Result<()> {
+        self.conn
+            .execute("DELETE FROM thumbnails WHERE path = ?1", params![path])?;
+        Ok(())
+    }
+
+    /// 外置封面不会改变音乐文件的修改时间，需要单独清除
+    fn invalidate_non_embedded(&self) -> Result<()> {
+        self.conn
+            .execute("DELETE FROM thumbnails WHERE source <> 'embedded'", [])?;
+        Ok(())
+    }
+
+    fn clear(&mut self) -> Result<()> {
+        self.conn
+            .execute_batch("DELETE FROM thumbnails; DELETE FROM thumbnail_blobs;")?;
+        self.total_bytes = 0;
+        Ok(())
+    }
+}
+
+/// 内存缓存 -> 磁盘缓存
+pub(crate) fn get(key: &PictureCacheKey) -> Option<PictureWithSource> {
+    if let Ok(mut cache) = _memory_cache().lock() {
+        if let Some(pic) = cache.get(&key.key) {
+            return Some(pic.clone());
+        }
+    }
+
+    let pic = {
+        let disk_cache = DISK_CACHE.lock().ok()?;
+        match disk_cache.as_ref()?.get(key) {
+            Ok(val) => val?,
+            Err(err) => {
+                log_to_dart(format!("fail to read picture cache: {}", err));
+                return None;
+            }
+        }
+    };
+
+    if let Ok(mut cache) = _memory_cache().lock() {
+        cache.put(key.key.clone(), pic.clone());
+    }
+    Some(pic)
+}
+
+/// 已经存在同一张原图生成的缩略图时直接复用，不再重新缩放
+pub(crate) fn get_by_blob(
+    key: &PictureCacheKey,
+    blob_id: &str,
+    source: &PictureSource,
+) -> Option<PictureWithSource> {
+    let pic = {
+        let mut disk_cache = DISK_CACHE.lock().ok()?;
+        let disk_cache = disk_cache.as_mut()?;
+        let data = match disk_cache.touch_blob(blob_id) {
+            Ok(val) => val?,
+            Err(err) => {
+                log_to_dart(format!("fail to read picture cache: {}", err));
+                return None;
+            }
+        };
+        if let Err(err) = disk_cache.link(key, blob_id, source) {
+            log_to_dart(format!("fail to write picture cache: {}", err));
+        }
+        PictureWithSource {
+            data,
+            source: source.clone(),
+        }
+    };
+
+    if let Ok(mut cache) = _memory_cache().lock() {
+        cache.put(key.key.clone(), pic.clone());
+    }
+    Some(pic)
+}
+
+pub(crate) fn put(key: &PictureCacheKey, blob_id: &str, pic: &PictureWithSource) {
+    if let Ok(mut cache) = _memory_cache().lock() {
+        cache.put(key.key.clone(), pic.clone());
+    }
+
+    if let Ok(mut disk_cache) = DISK_CACHE.lock() {
+        if let Some(disk_cache) = disk_cache.as_mut() {
+            if let Err(err) = disk_cache.put(key, blob_id, pic) {
+                log_to_dart(format!("fail to write picture cache: {}", err));
+            }
+        }
+    }
+}
+
+/// 删除某个音乐文件的所有缩略图
+pub(crate) fn invalidate(path: &str) {
+    let prefix = format!("{path}|");
+    if let Ok(mut cache) = _memory_cache().lock() {
+        let keys: Vec<String> = cache
+            .iter()
+            .filter(|(k, _)| k.starts_with(&prefix))
+            .map(|(k, _)| k.clone())
+            .collect();
+        for k in keys {
+            cache.pop(&k);
+        }
+    }
+
+    if let Ok(disk_cache) = DISK_CACHE.lock() {
+        if let Some(disk_cache) = disk_cache.as_ref() {
+            if let Err(err) = disk_cache.invalidate(path) {
+                log_to_dart(format!("fail to invalidate picture cache: {}", err));
+            }
+        }
+    }
+}
+
+/// 外置封面的查找规则变化后调用
+pub(crate) fn invalidate_non_embedded() {
+    if let Ok(mut cache) = _memory_cache().lock() {
+        cache.clear();
+    }
+
+    if let Ok(disk_cache) = DISK_CACHE.lock() {
+        if let Some(disk_cache) = disk_cache.as_ref() {
+            if let Err(err) = disk_cache.invalidate_non_embedded() {
+                log_to_dart(format!("fail to invalidate picture cache: {}", err));
+            }
+        }
+    }
+}
+
+/// for Flutter
+/// 在 index_path/thumbnails.sqlite 启用缩略图的磁盘缓存。
+/// byte_budget: 缓存上限（bytes），为 0 时使用默认值 256 MiB。超出时按最近访问时间淘汰。
+#[frb(sync)]
+pub fn init_picture_cache(index_path: String, byte_budget: u64) -> Result<()> {
+    let byte_budget = if byte_budget == 0 {
+        DEFAULT_BYTE_BUDGET
+    } else {
+        byte_budget
+    };
+    let disk_cache = DiskCache::open(&PathBuf::from(index_path), byte_budget)?;
+
+    let mut guard = match DISK_CACHE.lock() {
+        Ok(val) => val,
+        Err(val) => val.into_inner(),
+    };
+    *guard = Some(disk_cache);
+    Ok(())
+}
+
+/// for Flutter
+/// 清空内存和磁盘中的缩略图缓存
+pub fn clear_picture_cache() -> Result<()> {
+    if let Ok(mut cache) = _memory_cache().lock() {
+        cache.clear();
+    }
+
+    let mut guard = match DISK_CACHE.lock() {
+        Ok(val) => val,
+        Err(val) => val.into_inner(),
+    };
+    if let Some(disk_cache) = guard.as_mut() {
+        disk_cache.clear()?;
+    }
+    Ok(())
+}
+
+/// for Flutter
+/// 在后台为 index_path 索引中的所有音乐生成 width x height 的缩略图，已有缓存的会被跳过。
+/// 立即返回；已经在预热时不会重复开始。扫描或更新索引后调用。
+#[frb(sync)]
+pub fn prewarm_picture_cache(index_path: String, width: u32, height: u32) {
+    if PREWARMING.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    PREWARM_CANCELLED.store(false, Ordering::SeqCst);
+
+    thread::spawn(move || {
+        match library_db::read_index_from_sqlite(index_path) {
+            Ok(folders) => {
+                let paths = folders
+                    .into_iter()
+                    .flat_map(|folder| folder.audios.into_iter().map(|audio| audio.path));
+                for path in paths {
+                    if PREWARM_CANCELLED.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    let _ = tag_reader::get_picture_from_path(path, width, height);
+                }
+            }
+            Err(err) => log_to_dart(format!("fail to prewarm picture cache: {}", err)),
+        }
+        PREWARMING.store(false, Ordering::SeqCst);
+    });
+}
+
+/// for Flutter
+/// 停止 [prewarm_picture_cache]
+#[frb(sync)]
+pub fn cancel_picture_cache_prewarm() {
+    PREWARM_CANCELLED.store(true, Ordering::SeqCst);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disk_cache_dedup_and_eviction() {
+        let base = std::env::temp_dir().join(format!(
+            "coriander_player_picture_cache_test_{}",
+            std::process::id()
+        ));
+        let mut disk_cache = DiskCache::open(&base, 100).unwrap();
+        disk_cache.clear().unwrap();
+
+        let pic = |len: usize| PictureWithSource {
+            data: vec![7u8; len],
+            source: PictureSource::Embedded,
+        };
+        let a = PictureCacheKey::new("a.flac", "48x48".to_string());
+        let b = PictureCacheKey::new("b.flac", "48x48".to_string());
+        let c = PictureCacheKey::new("c.flac", "48x48".to_string());
+
+        // 同一张专辑图只存一份
+        disk_cache.put(&a, "album", &pic(60)).unwrap();
+        disk_cache
+            .link(&b, "album", &PictureSource::Embedded)
+            .unwrap();
+        assert_eq!(disk_cache.total_bytes, 60);
+        assert_eq!(disk_cache.get(&b).unwrap().unwrap().data.len(), 60);
+
+        // 超出上限时淘汰最久未访问的
+        disk_cache.put(&c, "other", &pic(60)).unwrap();
+        assert_eq!(disk_cache.total_bytes, 60);
+        assert!(disk_cache.get(&a).unwrap().is_none());
+        assert!(disk_cache.get(&b).unwrap().is_none());
+        assert!(disk_cache.get(&c).unwrap().is_some());
+
+        // 替换后没有文件使用的缩略图被删除，仍在使用的保留
+        disk_cache.put(&a, "shared", &pic(20)).unwrap();
+        disk_cache
+            .link(&b, "shared", &PictureSource::Embedded)
+            .unwrap();
+        disk_cache.put(&c, "new", &pic(30)).unwrap();
+        assert!(disk_cache.touch_blob("other").unwrap().is_none());
+        assert_eq!(disk_cache.total_bytes, 50);
+        disk_cache.put(&a, "newer", &pic(10)).unwrap();
+        assert!(disk_cache.touch_blob("shared").unwrap().is_some());
+        assert_eq!(disk_cache.total_bytes, 60);
+
+        drop(disk_cache);
+        let _ = fs::remove_dir_all(base);
+    }
+}
@@ -1,39 +1,141 @@
-use std::time::Duration;
+use std::{fmt, fs, sync::Mutex, time::Instant};
 
 use flutter_rust_bridge::frb;
-use windows::{
-    core::HSTRING,
-    Foundation::{TimeSpan, TypedEventHandler},
-    Media::{
-        MediaPlaybackStatus, MediaPlaybackType, Playback::MediaPlayer,
-        SystemMediaTransportControls, SystemMediaTransportControlsButton,
-        SystemMediaTransportControlsButtonPressedEventArgs,
-        SystemMediaTransportControlsTimelineProperties,
-    },
-    Storage::{
-        FileProperties::ThumbnailMode,
-        StorageFile,
-        Streams::{DataWriter, InMemoryRandomAccessStream, RandomAccessStreamReference},
-    },
-};
 
 use crate::frb_generated::StreamSink;
+use crate::platform::{
+    MediaControlEvent, MediaControls, MediaDisplay, PlatformMediaControls, RepeatMode,
+    TimelineTracker,
+};
 
 use super::{logger::log_to_dart, tag_reader};
 
 pub struct SMTCFlutter {
-    _smtc: SystemMediaTransportControls,
-    _player: MediaPlayer,
+    _session: Mutex<SMTCSession>,
+}
+
+/// 媒体控件和重新初始化时需要恢复的全部状态
+struct SMTCSession<C: MediaControls = PlatformMediaControls> {
+    /// 创建媒体控件，重新初始化时再次调用
+    connect: Box<dyn Fn() -> anyhow::Result<C> + Send>,
+    controls: Option<C>,
+    status: SMTCStatus,
+    sink: Option<StreamSink<SMTCControlEvent>>,
+    state: Option<SMTCState>,
+    display: Option<MediaDisplay>,
+    shuffle: Option<bool>,
+    repeat: Option<RepeatMode>,
+    timeline: TimelineTracker,
+}
+
+/// 媒体控件的状态，供界面显示
+#[derive(Clone, Debug, PartialEq)]
+pub enum SMTCStatus {
+    Ready,
+    /// 创建失败，没有可用的媒体控件
+    Unavailable {
+        message: String,
+    },
+    /// 最近一次操作失败，媒体控件可能已经失效。可以调用 reinitialize
+    Failed {
+        message: String,
+    },
+}
+
+#[derive(Debug)]
+pub enum SMTCError {
+    /// 创建媒体控件失败
+    Init { message: String },
+    /// 恢复订阅或显示内容失败
+    Restore { message: String },
 }
 
+impl fmt::Display for SMTCError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SMTCError::Init { message } => write!(f, "fail to init smtc: {}", message),
+            SMTCError::Restore { message } => write!(f, "fail to restore smtc: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for SMTCError {}
+
+#[derive(Clone)]
 pub enum SMTCControlEvent {
     Play,
     Pause,
     Previous,
     Next,
     Unknown,
+    Stop,
+    FastForward,
+    Rewind,
+    /// 跳转到指定位置，ms
+    Seek {
+        position: u64,
+    },
+    /// 相对当前位置跳转，ms。只有 Linux 会发出
+    SeekBy {
+        offset: i64,
+    },
+    Shuffle {
+        enabled: bool,
+    },
+    Repeat {
+        mode: SMTCRepeatMode,
+    },
+    Rate {
+        rate: f64,
+    },
+    /// 0.0 ~ 1.0。只有 Linux 会发出
+    Volume {
+        volume: f64,
+    },
 }
 
+#[derive(Clone, Copy)]
+pub enum SMTCRepeatMode {
+    None,
+    Track,
+    List,
+}
+
+/// 媒体控件上显示的封面
+pub enum SMTCArtwork {
+    None,
+    /// 已经在内存中的图片，例如正在显示的封面
+    Bytes {
+        data: Vec<u8>,
+    },
+    /// 图片文件
+    File {
+        path: String,
+    },
+    /// 音乐文件的缩略图，参数和 get_picture_from_path 相同。
+    /// 命中缩略图缓存时不会重新读取音乐文件
+    Thumbnail {
+        path: String,
+        width: u32,
+        height: u32,
+    },
+}
+
+/// 媒体控件上显示的信息
+pub struct SMTCMetadata {
+    pub title: String,
+    pub artist: String,
+    pub album: String,
+    pub album_artist: Option<String>,
+    pub track_number: Option<u32>,
+    pub genre: Option<String>,
+    /// ms
+    pub duration: u32,
+    /// 音乐文件的路径
+    pub path: String,
+}
+
+#[derive(Clone, Copy)]
 pub enum SMTCState {
     Paused,
     Playing,
@@ -41,45 +143,49 @@ pub enum SMTCState {
 
 /// Apis for Flutter
 impl SMTCFlutter {
+    /// 创建失败时不会 panic，status 为 Unavailable
     #[frb(sync)]
     pub fn new() -> Self {
-        Self::_new().unwrap()
+        Self {
+            _session: Mutex::new(SMTCSession::new(Box::new(PlatformMediaControls::new))),
+        }
     }
 
-    pub fn subscribe_to_control_events(&self, sink: StreamSink<SMTCControlEvent>) {
-        self._smtc
-            .ButtonPressed(&TypedEventHandler::<
-                SystemMediaTransportControls,
-                SystemMediaTransportControlsButtonPressedEventArgs,
-            >::new(move |_, event| {
-                let event = event.as_ref().unwrap().Button().unwrap();
-                let event = match event {
-                    SystemMediaTransportControlsButton::Play => SMTCControlEvent::Play,
-                    SystemMediaTransportControlsButton::Pause => SMTCControlEvent::Pause,
-                    SystemMediaTransportControlsButton::Next => SMTCControlEvent::Next,
-                    SystemMediaTransportControlsButton::Previous => SMTCControlEvent::Previous,
-                    _ => SMTCControlEvent::Unknown,
-                };
-                sink.add(event).unwrap();
+    #[frb(sync)]
+    pub fn try_new() -> Result<Self, SMTCError> {
+        let smtc = Self::new();
+        match smtc.status() {
+            SMTCStatus::Ready => Ok(smtc),
+            SMTCStatus::Unavailable { message } | SMTCStatus::Failed { message } => {
+                Err(SMTCError::Init { message })
+            }
+        }
+    }
 
-                Ok(())
-            }))
-            .unwrap();
+    #[frb(sync)]
+    pub fn status(&self) -> SMTCStatus {
+        self._lock().status.clone()
+    }
+
+    /// 关闭当前的媒体控件并重新创建，恢复事件订阅、播放状态、显示内容、随机播放、循环模式和时间线
+    pub fn reinitialize(&self) -> Result<(), SMTCError> {
+        self._lock().reinitialize()
+    }
+
+    pub fn subscribe_to_control_events(&self, sink: StreamSink<SMTCControlEvent>) {
+        self._lock().subscribe(sink);
     }
 
     pub fn update_state(&self, state: SMTCState) {
-        if let Err(err) = self._update_state(state) {
-            log_to_dart(format!("fail to update state: {}", err));
-        }
+        self._lock().update_state(state);
     }
 
-    /// progress, duration: ms
+    /// progress: ms。播放时每秒最多发送一次，跳转时立即发送
     pub fn update_time_properties(&self, progress: u32) {
-        if let Err(err) = self._update_time_properties(progress) {
-            log_to_dart(format!("fail to update state: {}", err));
-        }
+        self._lock().update_position(progress);
     }
 
+    /// 封面使用 256x256 的缩略图。需要更多信息时使用 [SMTCFlutter::update_metadata]
     pub fn update_display(
         &self,
         title: String,
@@ -88,130 +194,538 @@ impl SMTCFlutter {
         duration: u32,
         path: String,
     ) {
-        if let Err(err) = self._update_display(
-            HSTRING::from(title),
-            HSTRING::from(artist),
-            HSTRING::from(album),
-            duration,
-            HSTRING::from(path),
-        ) {
-            log_to_dart(format!("fail to update display: {}", err));
-        }
+        let artwork = SMTCArtwork::Thumbnail {
+            path: path.clone(),
+            width: 256,
+            height: 256,
+        };
+        self.update_metadata(
+            SMTCMetadata {
+                title,
+                artist,
+                album,
+                album_artist: None,
+                track_number: None,
+                genre: None,
+                duration,
+                path,
+            },
+            artwork,
+        );
+    }
+
+    pub fn update_metadata(&self, metadata: SMTCMetadata, artwork: SMTCArtwork) {
+        let picture = _resolve_artwork(artwork);
+        self._lock().update_display(MediaDisplay {
+            title: metadata.title,
+            artist: metadata.artist,
+            album: metadata.album,
+            album_artist: metadata.album_artist.filter(|val| !val.is_empty()),
+            track_number: metadata.track_number.filter(|val| *val > 0),
+            genre: metadata.genre.filter(|val| !val.is_empty()),
+            duration: metadata.duration,
+            picture,
+            path: metadata.path,
+        });
+    }
+
+    /// 同步播放器的随机播放状态
+    pub fn update_shuffle(&self, enabled: bool) {
+        self._lock().update_shuffle(enabled);
+    }
+
+    /// 同步播放器的循环模式
+    pub fn update_repeat_mode(&self, mode: SMTCRepeatMode) {
+        self._lock().update_repeat(mode.into());
+    }
+
+    /// 同步播放器的播放速度
+    pub fn update_playback_rate(&self, rate: f64) {
+        self._lock().update_rate(rate);
     }
 
     pub fn close(self) {
-        self._player.Close().unwrap();
+        self._session
+            .into_inner()
+            .unwrap_or_else(|err| err.into_inner())
+            .close();
     }
 }
 
 impl SMTCFlutter {
-    fn _init_controls(smtc: &SystemMediaTransportControls) -> Result<(), windows::core::Error> {
-        // 下一首
-        smtc.SetIsNextEnabled(true)?;
-        // 暂停
-        smtc.SetIsPauseEnabled(true)?;
-        // 播放（恢复）
-        smtc.SetIsPlayEnabled(true)?;
-        // 上一首
-        smtc.SetIsPreviousEnabled(true)?;
+    fn _lock(&self) -> std::sync::MutexGuard<'_, SMTCSession> {
+        self._session.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+impl<C: MediaControls> SMTCSession<C> {
+    fn new(connect: Box<dyn Fn() -> anyhow::Result<C> + Send>) -> Self {
+        let (controls, status) = match connect() {
+            Ok(controls) => (Some(controls), SMTCStatus::Ready),
+            Err(err) => {
+                log_to_dart(format!("fail to init smtc: {}", err));
+                (
+                    None,
+                    SMTCStatus::Unavailable {
+                        message: err.to_string(),
+                    },
+                )
+            }
+        };
+        Self {
+            connect,
+            controls,
+            status,
+            sink: None,
+            state: None,
+            display: None,
+            shuffle: None,
+            repeat: None,
+            timeline: TimelineTracker::new(),
+        }
+    }
+
+    fn reinitialize(&mut self) -> Result<(), SMTCError> {
+        if let Some(old) = self.controls.take() {
+            if let Err(err) = old.close() {
+                log_to_dart(format!("fail to close smtc: {}", err));
+            }
+        }
+
+        let controls = match (self.connect)() {
+            Ok(controls) => controls,
+            Err(err) => {
+                self.status = SMTCStatus::Unavailable {
+                    message: err.to_string(),
+                };
+                return Err(SMTCError::Init {
+                    message: err.to_string(),
+                });
+            }
+        };
+        let restored = (|| {
+            if let Some(sink) = &self.sink {
+                controls.subscribe(_forward_events(sink.clone()))?;
+            }
+            if let Some(display) = &self.display {
+                controls.set_display(display.clone())?;
+            }
+            if let Some(state) = self.state {
+                controls.set_state(state)?;
+            }
+            if let Some(shuffle) = self.shuffle {
+                controls.set_shuffle(shuffle)?;
+            }
+            if let Some(repeat) = self.repeat {
+                controls.set_repeat(repeat)?;
+            }
+            controls.set_timeline(&self.timeline.current())
+        })();
+        self.controls = Some(controls);
+
+        match restored {
+            Ok(()) => {
+                self.status = SMTCStatus::Ready;
+                Ok(())
+            }
+            Err(err) => {
+                self.status = SMTCStatus::Failed {
+                    message: err.to_string(),
+                };
+                Err(SMTCError::Restore {
+                    message: err.to_string(),
+                })
+            }
+        }
+    }
+
+    fn subscribe(&mut self, sink: StreamSink<SMTCControlEvent>) {
+        self.sink = Some(sink.clone());
+        self._run("subscribe to control events", |controls| {
+            controls.subscribe(_forward_events(sink))
+        });
+    }
+
+    fn update_state(&mut self, state: SMTCState) {
+        self.state = Some(state);
+        self._run("update state", |controls| controls.set_state(state));
 
-        Ok(())
+        let playing = matches!(state, SMTCState::Playing);
+        if let Some(timeline) = self.timeline.set_playing(playing, Instant::now()) {
+            self._run("update timeline", |controls| {
+                controls.set_timeline(&timeline)
+            });
+        }
     }
 
-    fn _new() -> Result<Self, windows::core::Error> {
-        let _player = MediaPlayer::new()?;
-        _player.CommandManager()?.SetIsEnabled(false)?;
+    fn update_position(&mut self, progress: u32) {
+        if let Some(timeline) = self.timeline.set_position(progress, Instant::now()) {
+            self._run("update timeline", |controls| {
+                controls.set_timeline(&timeline)
+            });
+        }
+    }
 
-        let _smtc = _player.SystemMediaTransportControls()?;
-        Self::_init_controls(&_smtc)?;
+    fn update_display(&mut self, display: MediaDisplay) {
+        let timeline = self.timeline.set_duration(display.duration, Instant::now());
+        self.display = Some(display.clone());
+        self._run("update display", |controls| controls.set_display(display));
+        self._run("update timeline", |controls| {
+            controls.set_timeline(&timeline)
+        });
+    }
 
-        Ok(Self { _smtc, _player })
+    fn update_shuffle(&mut self, enabled: bool) {
+        self.shuffle = Some(enabled);
+        self._run("update shuffle", |controls| controls.set_shuffle(enabled));
     }
 
-    fn _update_state(&self, state: SMTCState) -> Result<(), windows::core::Error> {
-        let state = match state {
-            SMTCState::Playing => MediaPlaybackStatus::Playing,
-            SMTCState::Paused => MediaPlaybackStatus::Paused,
+    fn update_repeat(&mut self, repeat: RepeatMode) {
+        self.repeat = Some(repeat);
+        self._run("update repeat mode", |controls| controls.set_repeat(repeat));
+    }
+
+    fn update_rate(&mut self, rate: f64) {
+        let timeline = self.timeline.set_rate(rate, Instant::now());
+        self._run("update timeline", |controls| {
+            controls.set_timeline(&timeline)
+        });
+    }
+
+    fn close(self) {
+        if let Some(controls) = self.controls {
+            if let Err(err) = controls.close() {
+                log_to_dart(format!("fail to close smtc: {}", err));
+            }
+        }
+    }
+
+    /// 没有媒体控件时什么也不做。失败时记录日志并把状态设为 Failed，之后成功时恢复为 Ready
+    fn _run(&mut self, action: &str, f: impl FnOnce(&C) -> anyhow::Result<()>) {
+        let Some(controls) = &self.controls else {
+            return;
         };
-        self._smtc.SetPlaybackStatus(state)?;
+        match f(controls) {
+            Ok(()) => {
+                if let SMTCStatus::Failed { .. } = self.status {
+                    self.status = SMTCStatus::Ready;
+                }
+            }
+            Err(err) => {
+                log_to_dart(format!("fail to {}: {}", action, err));
+                self.status = SMTCStatus::Failed {
+                    message: format!("fail to {}: {}", action, err),
+                };
+            }
+        }
+    }
+}
 
-        Ok(())
+/// 缩略图和 Flutter 使用同一个缓存
+fn _resolve_artwork(artwork: SMTCArtwork) -> Option<Vec<u8>> {
+    match artwork {
+        SMTCArtwork::None => None,
+        SMTCArtwork::Bytes { data } => Some(data).filter(|data| !data.is_empty()),
+        SMTCArtwork::File { path } => match fs::read(&path) {
+            Ok(data) => Some(data),
+            Err(err) => {
+                log_to_dart(format!("fail to read artwork {}: {}", path, err));
+                None
+            }
+        },
+        SMTCArtwork::Thumbnail {
+            path,
+            width,
+            height,
+        } => {
+            let picture = tag_reader::get_picture_from_path(path.clone(), width, height);
+            if picture.is_none() {
+                log_to_dart(format!("no picture found for file: {}", path));
+            }
+            picture
+        }
     }
+}
 
-    /// progress, duration: ms
-    fn _update_time_properties(&self, progress: u32) -> Result<(), windows::core::Error> {
-        let time_properties = SystemMediaTransportControlsTimelineProperties::new()?;
-        time_properties.SetPosition(TimeSpan::from(Duration::from_millis(progress.into())))?;
-        self._smtc.UpdateTimelineProperties(&time_properties)?;
+/// 把平台的事件转发给 Flutter。Flutter 取消订阅后 add 会失败，忽略即可
+fn _forward_events(
+    sink: StreamSink<SMTCControlEvent>,
+) -> Box<dyn Fn(MediaControlEvent) + Send + Sync> {
+    Box::new(move |event| {
+        let event = match event {
+            MediaControlEvent::Play => SMTCControlEvent::Play,
+            MediaControlEvent::Pause => SMTCControlEvent::Pause,
+            MediaControlEvent::Stop => SMTCControlEvent::Stop,
+            MediaControlEvent::Next => SMTCControlEvent::Next,
+            MediaControlEvent::Previous => SMTCControlEvent::Previous,
+            MediaControlEvent::FastForward => SMTCControlEvent::FastForward,
+            MediaControlEvent::Rewind => SMTCControlEvent::Rewind,
+            MediaControlEvent::Seek(offset) => SMTCControlEvent::SeekBy { offset },
+            MediaControlEvent::SetPosition(position) => SMTCControlEvent::Seek { position },
+            MediaControlEvent::Volume(volume) => SMTCControlEvent::Volume { volume },
+            MediaControlEvent::Shuffle(enabled) => SMTCControlEvent::Shuffle { enabled },
+            MediaControlEvent::Repeat(mode) => SMTCControlEvent::Repeat { mode: mode.into() },
+            MediaControlEvent::Rate(rate) => SMTCControlEvent::Rate { rate },
+        };
+        let _ = sink.add(event);
+    })
+}
 
-        Ok(())
+impl From<RepeatMode> for SMTCRepeatMode {
+    fn from(mode: RepeatMode) -> Self {
+        match mode {
+            RepeatMode::None => SMTCRepeatMode::None,
+            RepeatMode::Track => SMTCRepeatMode::Track,
+            RepeatMode::List => SMTCRepeatMode::List,
+        }
     }
+}
 
-    fn _ras_ref_from_pic_data(
-        picture_data: &[u8],
-    ) -> Result<RandomAccessStreamReference, windows::core::Error> {
-        let stream = InMemoryRandomAccessStream::new()?;
+impl From<SMTCRepeatMode> for RepeatMode {
+    fn from(mode: SMTCRepeatMode) -> Self {
+        match mode {
+            SMTCRepeatMode::None => RepeatMode::None,
+            SMTCRepeatMode::Track => RepeatMode::Track,
+            SMTCRepeatMode::List => RepeatMode::List,
+        }
+    }
+}
 
-        let writer = DataWriter::CreateDataWriter(&stream)?;
-        writer.WriteBytes(picture_data)?;
-        writer.StoreAsync()?.get()?;
+#[cfg(test)]
+mod tests {
+    use std::cell::{Cell, RefCell};
+
+    use super::*;
+    use crate::platform::Timeline;
+
+    #[derive(Debug, PartialEq)]
+    enum Call {
+        Display(String),
+        State(bool),
+        Timeline(Timeline),
+        Shuffle(bool),
+        Repeat(RepeatMode),
+        Close,
+    }
 
-        // 调用 DetachStream() 的意义在于“把流从 DataWriter 脱附”，
-        // 这样可以安全地释放/关闭 DataWriter 而不影响流的生命周期。
-        // stream 不会因为 writer drop 而被销毁
-        writer.DetachStream()?;
+    thread_local! {
+        static CALLS: RefCell<Vec<Call>> = const { RefCell::new(vec![]) };
+        /// 为 true 时所有调用都失败
+        static FAILING: Cell<bool> = const { Cell::new(false) };
+    }
 
-        stream.Seek(0)?;
+    /// 记录发送给媒体控件的内容
+    struct RecordingControls;
 
-        Ok(RandomAccessStreamReference::CreateFromStream(&stream)?)
+    impl RecordingControls {
+        fn take() -> Vec<Call> {
+            CALLS.with(|calls| std::mem::take(&mut *calls.borrow_mut()))
+        }
+
+        fn record(call: Call) -> anyhow::Result<()> {
+            if FAILING.get() {
+                return Err(anyhow::anyhow!("controls are gone"));
+            }
+            CALLS.with(|calls| calls.borrow_mut().push(call));
+            Ok(())
+        }
     }
 
-    fn _update_display(
-        &self,
-        title: HSTRING,
-        artist: HSTRING,
-        album: HSTRING,
-        duration: u32,
-        path: HSTRING,
-    ) -> Result<(), windows::core::Error> {
-        let updater = self._smtc.DisplayUpdater()?;
-        updater.SetType(MediaPlaybackType::Music)?;
-
-        let time_properties = SystemMediaTransportControlsTimelineProperties::new()?;
-        time_properties.SetStartTime(TimeSpan { Duration: 0 })?;
-        time_properties.SetEndTime(TimeSpan::from(Duration::from_millis(duration.into())))?;
-        time_properties.SetMinSeekTime(TimeSpan { Duration: 0 })?;
-        time_properties.SetMaxSeekTime(TimeSpan::from(Duration::from_millis(duration.into())))?;
-        self._smtc.UpdateTimelineProperties(&time_properties)?;
-
-        let music_properties = updater.MusicProperties()?;
-        music_properties.SetTitle(&title)?;
-        music_properties.SetArtist(&artist)?;
-        music_properties.SetAlbumTitle(&album)?;
-
-        let pic_stream_ref =
-            if let Some(pic_data) = tag_reader::get_picture_from_path(path.to_string(), 256, 256) {
-                Self::_ras_ref_from_pic_data(&pic_data)?
-            } else {
-                log_to_dart(format!(
-                    "no embedded picture found for file: {}",
-                    path.to_string()
-                ));
-                let file = StorageFile::GetFileFromPathAsync(&path)?.get()?;
-                let thumbnail = file
-                    .GetThumbnailAsyncOverloadDefaultSizeDefaultOptions(ThumbnailMode::MusicView)?
-                    .get()?;
-                RandomAccessStreamReference::CreateFromStream(&thumbnail)?
-            };
-
-        updater.SetThumbnail(&pic_stream_ref)?;
-
-        updater.Update()?;
-
-        if !(self._smtc.IsEnabled()?) {
-            self._smtc.SetIsEnabled(true)?;
-        }
-
-        Ok(())
+    impl MediaControls for RecordingControls {
+        fn new() -> anyhow::Result<Self> {
+            Ok(Self)
+        }
+
+        fn subscribe(&self, _: Box<dyn Fn(MediaControlEvent) + Send + Sync>) -> anyhow::Result<()> {
+            Ok(())
+        }
+
+        fn set_state(&self, state: SMTCState) -> anyhow::Result<()> {
+            Self::record(Call::State(matches!(state, SMTCState::Playing)))
+        }
+
+        fn set_timeline(&self, timeline: &Timeline) -> anyhow::Result<()> {
+            Self::record(Call::Timeline(*timeline))
+        }
+
+        fn set_display(&self, display: MediaDisplay) -> anyhow::Result<()> {
+            Self::record(Call::Display(display.title))
+        }
+
+        fn set_shuffle(&self, shuffle: bool) -> anyhow::Result<()> {
+            Self::record(Call::Shuffle(shuffle))
+        }
+
+        fn set_repeat(&self, repeat: RepeatMode) -> anyhow::Result<()> {
+            Self::record(Call::Repeat(repeat))
+        }
+
+        fn close(&self) -> anyhow::Result<()> {
+            Self::record(Call::Close)
+        }
+    }
+
+    #[test]
+    fn timeline_updates_carry_every_property() {
+        let timeline = |position: u32, rate: f64| {
+            Call::Timeline(Timeline {
+                start: 0,
+                end: 180_000,
+                min_seek: 0,
+                max_seek: 180_000,
+                position,
+                rate,
+            })
+        };
+        let mut session = SMTCSession::new(Box::new(RecordingControls::new));
+        assert_eq!(session.status, SMTCStatus::Ready);
+
+        session.update_display(MediaDisplay {
+            title: "Song".to_string(),
+            artist: String::new(),
+            album: String::new(),
+            album_artist: None,
+            track_number: None,
+            genre: None,
+            duration: 180_000,
+            picture: None,
+            path: String::new(),
+        });
+        assert_eq!(
+            RecordingControls::take(),
+            [Call::Display("Song".to_string()), timeline(0, 1.0)]
+        );
+
+        session.update_state(SMTCState::Playing);
+        assert_eq!(
+            RecordingControls::take(),
+            [Call::State(true), timeline(0, 1.0)]
+        );
+
+        // 连续的播放进度被节流，跳转立即发送完整的时间线
+        session.update_position(100);
+        session.update_position(200);
+        assert_eq!(RecordingControls::take(), []);
+        session.update_position(60_000);
+        assert_eq!(RecordingControls::take(), [timeline(60_000, 1.0)]);
+
+        session.update_rate(1.5);
+        assert_eq!(RecordingControls::take(), [timeline(60_000, 1.5)]);
+
+        session.update_shuffle(true);
+        session.update_repeat(RepeatMode::List);
+        RecordingControls::take();
+
+        // 重新初始化后恢复所有内容
+        session.reinitialize().unwrap();
+        assert_eq!(
+            RecordingControls::take(),
+            [
+                Call::Close,
+                Call::Display("Song".to_string()),
+                Call::State(true),
+                Call::Shuffle(true),
+                Call::Repeat(RepeatMode::List),
+                timeline(60_000, 1.5)
+            ]
+        );
+    }
+
+    #[test]
+    fn failed_status_clears_after_successful_update() {
+        let mut session = SMTCSession::new(Box::new(RecordingControls::new));
+
+        FAILING.set(true);
+        session.update_shuffle(true);
+        assert!(matches!(session.status, SMTCStatus::Failed { .. }));
+
+        FAILING.set(false);
+        session.update_shuffle(false);
+        assert_eq!(session.status, SMTCStatus::Ready);
+        assert_eq!(RecordingControls::take(), [Call::Shuffle(false)]);
+    }
+
+    #[test]
+    fn artwork_from_bytes_or_file() {
+        let path = std::env::temp_dir().join(format!("smtc_artwork_{}.png", std::process::id()));
+        fs::write(&path, b"png").unwrap();
+
+        assert_eq!(
+            _resolve_artwork(SMTCArtwork::Bytes { data: vec![1, 2] }),
+            Some(vec![1, 2])
+        );
+        assert_eq!(_resolve_artwork(SMTCArtwork::Bytes { data: vec![] }), None);
+        assert_eq!(
+            _resolve_artwork(SMTCArtwork::File {
+                path: path.to_string_lossy().to_string()
+            }),
+            Some(b"png".to_vec())
+        );
+        fs::remove_file(&path).unwrap();
+        assert_eq!(
+            _resolve_artwork(SMTCArtwork::File {
+                path: path.to_string_lossy().to_string()
+            }),
+            None
+        );
+        assert_eq!(_resolve_artwork(SMTCArtwork::None), None);
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn unavailable_controls_can_be_reinitialized() {
+        use std::sync::Arc;
+
+        let bus =
+            crate::platform::PrivateBus::start().expect("dbus-daemon is required to test MPRIS");
+
+        // 先连接到不存在的 bus，之后换成私有 bus
+        let address = Arc::new(Mutex::new("unix:path=/nonexistent/bus".to_string()));
+        let connect_to = address.clone();
+        let mut session = SMTCSession::new(Box::new(move || {
+            PlatformMediaControls::connect(Some(&connect_to.lock().unwrap()))
+        }));
+        assert!(matches!(session.status, SMTCStatus::Unavailable { .. }));
+
+        // 没有媒体控件时更新不会 panic，只会被记住
+        session.update_state(SMTCState::Playing);
+        session.update_position(1000);
+        session.update_shuffle(true);
+        session.update_repeat(RepeatMode::Track);
+        assert!(matches!(
+            session.reinitialize(),
+            Err(SMTCError::Init { .. })
+        ));
+
+        *address.lock().unwrap() = bus.address.clone();
+        session.reinitialize().unwrap();
+        assert_eq!(session.status, SMTCStatus::Ready);
+
+        // 重新初始化后恢复了之前的播放状态、随机播放和循环模式
+        let client = zbus::blocking::connection::Builder::address(bus.address.as_str())
+            .unwrap()
+            .build()
+            .unwrap();
+        let properties = zbus::blocking::fdo::PropertiesProxy::builder(&client)
+            .destination("org.mpris.MediaPlayer2.coriander_player")
+            .unwrap()
+            .path("/org/mpris/MediaPlayer2")
+            .unwrap()
+            .build()
+            .unwrap();
+        let get = |name: &str| {
+            properties
+                .get(
+                    zbus::names::InterfaceName::from_static_str_unchecked(
+                        "org.mpris.MediaPlayer2.Player",
+                    ),
+                    name,
+                )
+                .unwrap()
+        };
+        assert_eq!(String::try_from(get("PlaybackStatus")).unwrap(), "Playing");
+        assert!(bool::try_from(get("Shuffle")).unwrap());
+        assert_eq!(String::try_from(get("LoopStatus")).unwrap(), "Track");
+        session.close();
     }
 }
@@ -1,5 +1,6 @@
 use flutter_rust_bridge::frb;
-use windows::UI::ViewManagement::{UIColorType, UISettings};
+
+use crate::platform::{Platform, ThemeProvider};
 
 use super::logger::log_to_dart;
 
@@ -18,23 +19,9 @@ impl SystemTheme {
         }
     }
 
-    fn from_ui_settings(ui_settings: UISettings) -> Result<Self, windows::core::Error> {
-        let fore = ui_settings.GetColorValue(UIColorType::Foreground)?;
-        let accent = ui_settings.GetColorValue(UIColorType::Accent)?;
-
-        Ok(SystemTheme {
-            fore: (fore.A, fore.R, fore.G, fore.B),
-            accent: (accent.A, accent.R, accent.G, accent.B),
-        })
-    }
-
-    fn _get_system_theme() -> Result<SystemTheme, windows::core::Error> {
-        SystemTheme::from_ui_settings(UISettings::new()?)
-    }
-
     #[frb(sync)]
     pub fn get_system_theme() -> SystemTheme {
-        match Self::_get_system_theme() {
+        match Platform.system_theme() {
             Ok(value) => value,
             Err(err) => {
                 log_to_dart(format!("fail to get sys theme: {}", err));
@@ -1,325 +1,104 @@
+// Linux 上的实现会运行 pactl，这里的函数都不使用 frb(sync)，避免阻塞 UI isolate
+
 use crate::frb_generated::StreamSink;
 use anyhow::Result;
-use flutter_rust_bridge::frb;
-
-#[cfg(all(not(frb_expand), target_os = "windows"))]
-mod imp {
-    use std::sync::{Arc, Mutex};
-
-    use crate::frb_generated::StreamSink;
-    use anyhow::Result;
-    use windows::{
-        core::{implement, GUID, PCWSTR},
-        Win32::{
-            Media::Audio::{
-                eMultimedia, eRender,
-                Endpoints::{
-                    IAudioEndpointVolume, IAudioEndpointVolumeCallback, IAudioEndpointVolumeCallback_Impl,
-                },
-                IMMDeviceEnumerator, IMMNotificationClient, IMMNotificationClient_Impl, MMDeviceEnumerator,
-                AUDIO_VOLUME_NOTIFICATION_DATA, DEVICE_STATE, EDataFlow, ERole,
-            },
-            System::Com::{
-                CoCreateInstance, CoInitializeEx, CoUninitialize, CLSCTX_ALL, COINIT_MULTITHREADED,
-            },
-        },
-    };
-
-    struct ComGuard;
-
-    impl ComGuard {
-        fn new() -> Result<Self> {
-            unsafe { CoInitializeEx(None, COINIT_MULTITHREADED).ok() }?;
-            Ok(ComGuard)
-        }
-    }
-
-    impl Drop for ComGuard {
-        fn drop(&mut self) {
-            unsafe { CoUninitialize() };
-        }
-    }
-
-    #[implement(IAudioEndpointVolumeCallback)]
-    struct VolumeChangeCallback {
-        sink: Arc<Mutex<Option<StreamSink<f64>>>>,
-    }
-
-    impl IAudioEndpointVolumeCallback_Impl for VolumeChangeCallback {
-        fn OnNotify(
-            &self,
-            pnotify: *mut AUDIO_VOLUME_NOTIFICATION_DATA,
-        ) -> windows::core::Result<()> {
-            if let Some(data) = unsafe { pnotify.as_ref() } {
-                let volume = data.fMasterVolume;
-                if let Ok(guard) = self.sink.lock() {
-                    if let Some(sink) = guard.as_ref() {
-                        let _ = sink.add(volume as f64);
-                    }
-                }
-            }
-            Ok(())
-        }
-    }
-
-    #[implement(IMMNotificationClient)]
-    struct DeviceChangeCallback {
-        manager: Arc<Mutex<Option<VolumeManager>>>,
-    }
-
-    impl IMMNotificationClient_Impl for DeviceChangeCallback {
-        fn OnDeviceStateChanged(
-            &self,
-            _pwstrdeviceid: &PCWSTR,
-            _dwnewstate: DEVICE_STATE,
-        ) -> windows::core::Result<()> {
-            Ok(())
-        }
-
-        fn OnDeviceAdded(&self, _pwstrdeviceid: &PCWSTR) -> windows::core::Result<()> {
-            Ok(())
-        }
-
-        fn OnDeviceRemoved(&self, _pwstrdeviceid: &PCWSTR) -> windows::core::Result<()> {
-            Ok(())
-        }
-
-        fn OnDefaultDeviceChanged(
-            &self,
-            flow: EDataFlow,
-            role: ERole,
-            _pwstrdefaultdeviceid: &PCWSTR,
-        ) -> windows::core::Result<()> {
-            if flow == eRender && role == eMultimedia {
-                if let Ok(mut guard) = self.manager.lock() {
-                    if let Some(manager) = guard.as_mut() {
-                        let _ = manager.rebind_volume_interface();
-                    }
-                }
-            }
-            Ok(())
-        }
-
-        fn OnPropertyValueChanged(
-            &self,
-            _pwstrdeviceid: &PCWSTR,
-            _key: &windows::Win32::UI::Shell::PropertiesSystem::PROPERTYKEY,
-        ) -> windows::core::Result<()> {
-            Ok(())
-        }
-    }
-
-    struct VolumeManager {
-        enumerator: IMMDeviceEnumerator,
-        endpoint_volume: Option<IAudioEndpointVolume>,
-        volume_callback: Option<IAudioEndpointVolumeCallback>,
-        device_notification_client: Option<IMMNotificationClient>,
-        sink: Arc<Mutex<Option<StreamSink<f64>>>>,
-    }
-
-    unsafe impl Send for VolumeManager {}
-    unsafe impl Sync for VolumeManager {}
-
-    impl VolumeManager {
-        fn new(sink: Arc<Mutex<Option<StreamSink<f64>>>>) -> Result<Self> {
-            let enumerator: IMMDeviceEnumerator =
-                unsafe { CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL)? };
-
-            let mut manager = Self {
-                enumerator,
-                endpoint_volume: None,
-                volume_callback: None,
-                device_notification_client: None,
-                sink,
-            };
-
-            manager.init_volume_interface()?;
-            Ok(manager)
-        }
-
-        fn init_volume_interface(&mut self) -> Result<()> {
-            unsafe {
-                let device = self.enumerator.GetDefaultAudioEndpoint(eRender, eMultimedia)?;
-                let endpoint_volume: IAudioEndpointVolume = device.Activate(CLSCTX_ALL, None)?;
-
-                let callback = VolumeChangeCallback {
-                    sink: self.sink.clone(),
-                };
-                let i_callback: IAudioEndpointVolumeCallback = callback.into();
-                endpoint_volume.RegisterControlChangeNotify(&i_callback)?;
-
-                self.endpoint_volume = Some(endpoint_volume);
-                self.volume_callback = Some(i_callback);
-            }
-            Ok(())
-        }
-
-        fn rebind_volume_interface(&mut self) -> Result<()> {
-            unsafe {
-                if let Some(ref endpoint) = self.endpoint_volume {
-                    if let Some(ref callback) = self.volume_callback {
-                        let _ = endpoint.UnregisterControlChangeNotify(callback);
-                    }
-                }
-            }
-
-            self.endpoint_volume = None;
-            self.volume_callback = None;
-
-            if let Err(e) = self.init_volume_interface() {
-                if let Ok(guard) = self.sink.lock() {
-                    if let Some(sink) = guard.as_ref() {
-                        let _ = sink.add(0.0);
-                    }
-                }
-                return Err(e);
-            }
 
-            if let Some(vol) = self.get_volume() {
-                if let Ok(guard) = self.sink.lock() {
-                    if let Some(sink) = guard.as_ref() {
-                        let _ = sink.add(vol as f64);
-                    }
-                }
-            }
+use crate::platform::{OutputDevices, Platform, SystemVolume};
 
-            Ok(())
-        }
-
-        fn register_device_notification(
-            &mut self,
-            self_arc: Arc<Mutex<Option<VolumeManager>>>,
-        ) -> Result<()> {
-            let client = DeviceChangeCallback { manager: self_arc };
-            let i_client: IMMNotificationClient = client.into();
-            unsafe {
-                self.enumerator.RegisterEndpointNotificationCallback(&i_client)?;
-            }
-            self.device_notification_client = Some(i_client);
-            Ok(())
-        }
-
-        fn get_volume(&self) -> Option<f32> {
-            unsafe {
-                self.endpoint_volume
-                    .as_ref()
-                    .and_then(|v| v.GetMasterVolumeLevelScalar().ok())
-            }
-        }
-
-        fn set_volume(&self, val: f32) -> Result<()> {
-            unsafe {
-                if let Some(ref v) = self.endpoint_volume {
-                    v.SetMasterVolumeLevelScalar(val, &GUID::zeroed())?;
-                }
-            }
-            Ok(())
-        }
-    }
-
-    impl Drop for VolumeManager {
-        fn drop(&mut self) {
-            unsafe {
-                if let Some(ref endpoint) = self.endpoint_volume {
-                    if let Some(ref callback) = self.volume_callback {
-                        let _ = endpoint.UnregisterControlChangeNotify(callback);
-                    }
-                }
-                if let Some(ref client) = self.device_notification_client {
-                    let _ = self
-                        .enumerator
-                        .UnregisterEndpointNotificationCallback(client);
-                }
-            }
-        }
-    }
-
-    static GLOBAL_MANAGER: Mutex<Option<Arc<Mutex<Option<VolumeManager>>>>> = Mutex::new(None);
-
-    pub(super) fn system_volume_init(sink: StreamSink<f64>) -> Result<f64> {
-        let _ = ComGuard::new();
-
-        let sink_arc = Arc::new(Mutex::new(Some(sink)));
-        let manager = VolumeManager::new(sink_arc.clone())?;
-        let current_vol = manager.get_volume().unwrap_or(0.0) as f64;
-
-        let manager_arc = Arc::new(Mutex::new(Some(manager)));
+pub fn system_volume_init(sink: StreamSink<f64>) -> Result<f64> {
+    Platform.init(sink)
+}
 
-        if let Ok(mut guard) = manager_arc.lock() {
-            if let Some(m) = guard.as_mut() {
-                m.register_device_notification(manager_arc.clone())?;
-            }
-        }
+pub fn system_volume_set(val: f64) -> Result<()> {
+    Platform.set(val)
+}
 
-        *GLOBAL_MANAGER.lock().unwrap() = Some(manager_arc);
-        Ok(current_vol)
-    }
+pub fn system_volume_get() -> Result<f64> {
+    Platform.get()
+}
 
-    pub(super) fn system_volume_set(val: f64) -> Result<()> {
-        let _ = ComGuard::new();
-        if let Some(manager_arc) = GLOBAL_MANAGER.lock().unwrap().as_ref() {
-            if let Ok(guard) = manager_arc.lock() {
-                if let Some(manager) = guard.as_ref() {
-                    manager.set_volume(val as f32)?;
-                }
-            }
-        }
-        Ok(())
-    }
+pub fn system_volume_dispose() {
+    Platform.dispose()
+}
 
-    pub(super) fn system_volume_get() -> Result<f64> {
-        let _ = ComGuard::new();
-        if let Some(manager_arc) = GLOBAL_MANAGER.lock().unwrap().as_ref() {
-            if let Ok(guard) = manager_arc.lock() {
-                if let Some(manager) = guard.as_ref() {
-                    return Ok(manager.get_volume().unwrap_or(0.0) as f64);
-                }
-            }
-        }
-        Ok(0.0)
-    }
+/// 音频输出设备
+pub struct OutputDevice {
+    /// Windows 上是 endpoint id，Linux 上是 PulseAudio 的 sink name
+    pub id: String,
+    pub name: String,
+    pub form_factor: OutputFormFactor,
+    /// 是否为默认输出设备
+    pub is_default: bool,
+    pub state: OutputDeviceState,
+}
 
-    pub(super) fn system_volume_dispose() {
-        *GLOBAL_MANAGER.lock().unwrap() = None;
-    }
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OutputFormFactor {
+    Speakers,
+    Headphones,
+    Headset,
+    LineOut,
+    Spdif,
+    Hdmi,
+    Network,
+    Unknown,
 }
 
-#[cfg(any(frb_expand, not(target_os = "windows")))]
-mod imp {
-    use crate::frb_generated::StreamSink;
-    use anyhow::Result;
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OutputDeviceState {
+    Active,
+    Disabled,
+    NotPresent,
+    /// 插孔上没有插入设备
+    Unplugged,
+}
 
-    pub(super) fn system_volume_init(_sink: StreamSink<f64>) -> Result<f64> {
-        Ok(0.0)
-    }
+#[derive(Debug, Clone, PartialEq)]
+pub enum OutputDeviceEvent {
+    Added {
+        id: String,
+    },
+    Removed {
+        id: String,
+    },
+    /// 没有默认输出设备时 id 为 None
+    DefaultChanged {
+        id: Option<String>,
+    },
+    /// 只有 Windows 会发出
+    StateChanged {
+        id: String,
+        state: OutputDeviceState,
+    },
+}
 
-    pub(super) fn system_volume_set(_val: f64) -> Result<()> {
-        Ok(())
-    }
+/// 列出所有输出设备，包括未启用和未插入的
+pub fn output_device_list() -> Result<Vec<OutputDevice>> {
+    Platform.devices()
+}
 
-    pub(super) fn system_volume_get() -> Result<f64> {
-        Ok(0.0)
-    }
+/// 0.0 ~ 1.0
+pub fn output_device_volume_get(id: String) -> Result<f64> {
+    Platform.device_volume(&id)
+}
 
-    pub(super) fn system_volume_dispose() {}
+pub fn output_device_volume_set(id: String, val: f64) -> Result<()> {
+    Platform.set_device_volume(&id, val)
 }
 
-#[frb(sync)]
-pub fn system_volume_init(sink: StreamSink<f64>) -> Result<f64> {
-    imp::system_volume_init(sink)
+pub fn output_device_mute_get(id: String) -> Result<bool> {
+    Platform.device_mute(&id)
 }
 
-#[frb(sync)]
-pub fn system_volume_set(val: f64) -> Result<()> {
-    imp::system_volume_set(val)
+pub fn output_device_mute_set(id: String, mute: bool) -> Result<()> {
+    Platform.set_device_mute(&id, mute)
 }
 
-#[frb(sync)]
-pub fn system_volume_get() -> Result<f64> {
-    imp::system_volume_get()
+/// 监听输出设备的添加、移除和默认设备变化。再次调用时替换之前的 sink
+pub fn output_device_events_init(sink: StreamSink<OutputDeviceEvent>) -> Result<()> {
+    Platform.watch_devices(sink)
 }
 
-#[frb(sync)]
-pub fn system_volume_dispose() {
-    imp::system_volume_dispose()
+pub fn output_device_events_dispose() {
+    Platform.unwatch_devices()
 }
@@ -1,33 +1,49 @@
 use std::{
-    collections::{HashSet, VecDeque},
+    borrow::Cow,
+    collections::HashSet,
     fs::{self},
     io::{self, Cursor, Write},
     path::{Path, PathBuf},
-    sync::{Mutex, OnceLock},
+    sync::RwLock,
     time::{Duration, UNIX_EPOCH},
 };
 
-use image::imageops;
-use lofty::config::WriteOptions;
-use lofty::prelude::{Accessor, AudioFile, ItemKey, TaggedFileExt};
-use lofty::tag::Tag;
-use windows::{
-    core::Interface,
-    core::HSTRING,
-    Storage::{
-        FileProperties::ThumbnailMode,
-        StorageFile,
-        Streams::{DataReader, IInputStream},
-    },
+mod ac3;
+mod amr;
+mod asf;
+mod cue;
+mod dsd;
+mod matroska;
+mod midi;
+
+use flutter_rust_bridge::frb;
+use image::{codecs::jpeg::JpegEncoder, imageops, DynamicImage};
+use lofty::aac::AacFile;
+use lofty::config::{ParseOptions, WriteOptions};
+use lofty::file::{FileType, TaggedFile};
+use lofty::id3::v2::{
+    BinaryFrame, Frame, FrameId, Id3v2Tag, SyncTextContentType, SynchronizedTextFrame,
+    TimestampFormat, UnsynchronizedTextFrame,
 };
-
+use lofty::iff::{aiff::AiffFile, wav::WavFile};
+use lofty::mpeg::MpegFile;
+use lofty::picture::PictureType;
+use lofty::prelude::{Accessor, AudioFile, ItemKey, TagExt, TaggedFileExt};
+use lofty::probe::Probe;
+use lofty::tag::{ItemValue, Tag, TagItem, TagType};
+use lofty::TextEncoding;
 use crate::frb_generated::StreamSink;
+use crate::platform::{Platform, ThumbnailProvider};
 
 use super::library_db;
+use super::lyric::{self, Lyric};
+use super::picture_cache::{self, PictureCacheKey};
+use super::text_encoding;
 use super::logger::log_to_dart;
 
 /// for Flutter
 pub fn read_audio_extra_metadata(path: String) -> String {
+    let path = cue_source_path(&path).to_string();
     let file_size = fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
     let extension = Path::new(&path)
         .extension()
@@ -206,7 +222,7 @@ static SUPPORT_FORMAT: phf::Map<&'static str, bool> = phf::phf_map! {
     "ogg" => true,
     "wav" => true, "wave" => true,
     "aif" => true, "aiff" => true, "aifc" => true,
-    // 通过 Windows 系统支持
+    // 通过 Rust 实现（见 read_natively）或系统接口（见 read_by_system_properties）
     "asf" => false, "wma" => false,
     "aac" => true, "adts" => true,
     "m4a" => true,
@@ -251,6 +267,23 @@ struct Audio {
     created: u64,
     /// 标签获取方式
     by: Option<String>,
+    /// 内嵌的 CUESHEET 标签，不写入索引
+    cue_sheet: Option<String>,
+    cue: Option<AudioCue>,
+}
+
+/// CUE 虚拟音轨在音乐文件中的位置
+#[derive(Debug)]
+struct AudioCue {
+    /// 音乐文件路径
+    source: String,
+    /// 外挂 .cue 文件路径，内嵌时为 None
+    sheet: Option<String>,
+    track: u32,
+    /// ms
+    start: u64,
+    /// ms，None 表示到文件结尾
+    end: Option<u64>,
 }
 
 impl Audio {
@@ -269,6 +302,8 @@ impl Audio {
             modified: 0,
             created: 0,
             by,
+            cue_sheet: None,
+            cue: None,
         })
     }
 
@@ -285,13 +320,20 @@ impl Audio {
             "path": self.path,
             "modified": self.modified,
             "created": self.created,
-            "by": self.by
+            "by": self.by,
+            "cue": self.cue.as_ref().map(|cue| serde_json::json!({
+                "source": cue.source,
+                "sheet": cue.sheet,
+                "track": cue.track,
+                "start": cue.start,
+                "end": cue.end
+            }))
         })
     }
 
     /// 不支持：None  
     /// Lofty 能获取到信息：read_by_lofty  
-    /// 不能的话：read_by_win_music_properties  
+    /// 不能的话：read_by_system_properties  
     /// 再不能的话：title: filename 代替
     fn read_from_path(path: impl AsRef<Path>) -> Option<Self> {
         let path = path.as_ref();
@@ -322,21 +364,15 @@ impl Audio {
             if let Some(value) = Self::read_by_lofty(path, modified, created) {
                 return Some(value);
             }
+        } else if let Some(value) = Self::read_natively(path, modified, created) {
+            return Some(value);
+        }
 
-            match Self::read_by_win_music_properties(path, modified, created) {
-                Ok(value) => Some(value),
-                Err(err) => {
-                    log_to_dart(format!("{:?}: {}", path, err));
-                    return Self::new_with_path(path, None);
-                }
-            }
-        } else {
-            match Self::read_by_win_music_properties(path, modified, created) {
-                Ok(value) => Some(value),
-                Err(err) => {
-                    log_to_dart(format!("{:?}: {}", path, err));
-                    return Self::new_with_path(path, None);
-                }
+        match Self::read_by_system_properties(path, modified, created) {
+            Ok(value) => Some(value),
+            Err(err) => {
+                log_to_dart(format!("{:?}: {}", path, err));
+                Self::new_with_path(path, None)
             }
         }
     }
@@ -358,30 +394,39 @@ impl Audio {
             .primary_tag()
             .or_else(|| tagged_file.first_tag())
         {
+            let latin1 = _latin1_items_of_file(&path.to_string_lossy(), &tagged_file, tag);
             let artist_strs: Vec<_> = tag.get_strings(&ItemKey::TrackArtist).collect();
             let artist = if artist_strs.is_empty() {
                 std::borrow::Cow::Borrowed("UNKNOWN").to_string()
             } else {
-                artist_strs.join("/")
+                _repair_id3_text(&latin1, &ItemKey::TrackArtist, artist_strs.join("/"))
             };
 
             let album_artist_strs: Vec<_> = tag.get_strings(&ItemKey::AlbumArtist).collect();
             let album_artist = if album_artist_strs.is_empty() {
                 None
             } else {
-                Some(album_artist_strs.join("/"))
+                Some(_repair_id3_text(
+                    &latin1,
+                    &ItemKey::AlbumArtist,
+                    album_artist_strs.join("/"),
+                ))
             };
 
             return Some(Audio {
-                title: tag
-                    .title()
-                    .unwrap_or(path.file_name()?.to_string_lossy())
-                    .to_string(),
+                title: match tag.title() {
+                    Some(title) => {
+                        _repair_id3_text(&latin1, &ItemKey::TrackTitle, title.to_string())
+                    }
+                    None => path.file_name()?.to_string_lossy().to_string(),
+                },
                 artist,
-                album: tag
-                    .album()
-                    .unwrap_or(std::borrow::Cow::Borrowed("UNKNOWN"))
-                    .to_string(),
+                album: match tag.album() {
+                    Some(album) => {
+                        _repair_id3_text(&latin1, &ItemKey::AlbumTitle, album.to_string())
+                    }
+                    None => "UNKNOWN".to_string(),
+                },
                 album_artist,
                 track: tag.track(),
                 duration: properties.duration().as_secs(),
@@ -391,10 +436,12 @@ impl Audio {
                 modified,
                 created,
                 by: Some("Lofty".to_string()),
+                cue_sheet: _get_cue_sheet_from_tag(tag),
+                cue: None,
             });
         }
 
-        return Some(Audio {
+        Some(Audio {
             title: path.file_name()?.to_string_lossy().to_string(),
             artist: std::borrow::Cow::Borrowed("UNKNOWN").to_string(),
             album: std::borrow::Cow::Borrowed("UNKNOWN").to_string(),
@@ -407,75 +454,333 @@ impl Audio {
             modified,
             created,
             by: Some("Lofty".to_string()),
-        });
+            cue_sheet: None,
+            cue: None,
+        })
+    }
+
+    /// 不使用 lofty 和 Windows 读取 asf, mka, webm, dsf, dff, amr, ac3, mid 的标签和属性。
+    /// 文件头不正确时返回 None
+    fn read_natively(path: &Path, modified: u64, created: u64) -> Option<Self> {
+        let extension = path.extension()?.to_ascii_lowercase();
+        let mut file = io::BufReader::new(fs::File::open(path).ok()?);
+        let tags = match extension.to_str()? {
+            "asf" | "wma" => asf::read(&mut file),
+            "mka" | "webm" => matroska::read(&mut file),
+            "dsf" => dsd::read_dsf(&mut file),
+            "dff" => dsd::read_dff(&mut file),
+            "amr" | "3ga" => amr::read(&mut file),
+            "ac3" => ac3::read(&mut file),
+            "mid" => midi::read(&mut file),
+            _ => None,
+        }?;
+
+        Some(Audio {
+            title: match tags.title {
+                Some(title) => title,
+                None => path.file_name()?.to_string_lossy().to_string(),
+            },
+            artist: tags.artist.unwrap_or_else(|| "UNKNOWN".to_string()),
+            album: tags.album.unwrap_or_else(|| "UNKNOWN".to_string()),
+            album_artist: tags.album_artist,
+            track: tags.track,
+            duration: tags.duration.as_secs(),
+            bitrate: tags.bitrate,
+            sample_rate: tags.sample_rate,
+            path: path.to_string_lossy().to_string(),
+            modified,
+            created,
+            by: Some("Native".to_string()),
+            cue_sheet: None,
+            cue: None,
+        })
     }
 
-    /// 使用 Windows Api 获取音乐标签。会因为各种原因返回 Err
-    fn read_by_win_music_properties(
+    /// 使用系统接口（Windows 的 MusicProperties）获取音乐标签。会因为各种原因返回 Err
+    fn read_by_system_properties(
         path: impl AsRef<Path>,
         modified: u64,
         created: u64,
-    ) -> Result<Self, windows::core::Error> {
+    ) -> anyhow::Result<Self> {
         let path = path.as_ref();
-        let storage_file = StorageFile::GetFileFromPathAsync(&HSTRING::from(path))?.get()?;
-        let music_properties = storage_file
-            .Properties()?
-            .GetMusicPropertiesAsync()?
-            .get()?;
-
-        let duration: Duration = music_properties.Duration()?.into();
-
-        let mut title = music_properties
-            .Title()
-            .or_else(|_| storage_file.Name())?
-            .to_string();
-        if title.is_empty() {
-            title = storage_file.Name()?.to_string();
-        }
-
-        let mut artist = music_properties
-            .Artist()
-            .unwrap_or(HSTRING::from("UNKNOWN"))
-            .to_string();
-        if artist.is_empty() {
-            artist = "UNKNOWN".to_string();
-        }
-
-        let mut album = music_properties
-            .Album()
-            .unwrap_or(HSTRING::from("UNKNOWN"))
-            .to_string();
-        if album.is_empty() {
-            album = "UNKNOWN".to_string();
-        }
+        let properties = Platform.music_properties(path)?;
 
-        let album_artist = music_properties
-            .AlbumArtist()
-            .unwrap_or(HSTRING::from(""))
-            .to_string();
-        let album_artist = if album_artist.is_empty() {
-            None
+        let title = if properties.title.is_empty() {
+            properties.name
         } else {
-            Some(album_artist)
+            properties.title
+        };
+        let or_unknown = |value: String| {
+            if value.is_empty() {
+                "UNKNOWN".to_string()
+            } else {
+                value
+            }
         };
 
         Ok(Audio {
             title,
-            artist,
-            album,
-            album_artist,
-            track: Some(music_properties.TrackNumber()?),
-            duration: duration.as_secs(),
-            bitrate: Some(music_properties.Bitrate()? / 1000),
+            artist: or_unknown(properties.artist),
+            album: or_unknown(properties.album),
+            album_artist: (!properties.album_artist.is_empty()).then_some(properties.album_artist),
+            track: Some(properties.track),
+            duration: properties.duration.as_secs(),
+            bitrate: Some(properties.bitrate / 1000),
             sample_rate: None,
             path: path.to_string_lossy().to_string(),
             modified,
             created,
-            by: Some("Windows".to_string()),
+            by: Some("System".to_string()),
+            cue_sheet: None,
+            cue: None,
         })
     }
 }
 
+/// [Audio::read_natively] 读取到的标签和属性
+#[frb(ignore)]
+#[derive(Debug, Default)]
+struct NativeTags {
+    title: Option<String>,
+    artist: Option<String>,
+    album: Option<String>,
+    album_artist: Option<String>,
+    track: Option<u32>,
+    duration: Duration,
+    /// kbps
+    bitrate: Option<u32>,
+    sample_rate: Option<u32>,
+}
+
+/// 按文件长度计算的平均码率（kbps）
+fn _average_bitrate(len: u64, duration: Duration) -> Option<u32> {
+    let ms = duration.as_millis();
+    (ms > 0).then(|| (len as u128 * 8 / ms) as u32)
+}
+
+/// 解析单独的 ID3v2 标签（DSF, DFF 中的 ID3 块），只覆盖标签中有的项
+fn _read_id3v2_bytes(data: &[u8], tags: &mut NativeTags) {
+    // lofty 没有单独解析 ID3v2 的接口，当作只有标签的 MPEG 文件读取。
+    // 后面补 0，使查找 ID3v1, APE 标签时不会越过开头
+    let mut bytes = data.to_vec();
+    bytes.resize(data.len() + 256, 0);
+    let options = ParseOptions::new().read_properties(false);
+    let Ok(mut file) = MpegFile::read_from(&mut Cursor::new(bytes), options) else {
+        return;
+    };
+    let Some(id3v2) = file.remove_id3v2() else {
+        return;
+    };
+    let latin1 = Latin1Items::Keys(_id3v2_latin1_keys(&id3v2));
+    let tag = Tag::from(id3v2);
+    let text = |key: ItemKey, text: Option<Cow<str>>| {
+        text.map(|text| _repair_id3_text(&latin1, &key, text.to_string()))
+    };
+
+    if let Some(title) = text(ItemKey::TrackTitle, tag.title()) {
+        tags.title = Some(title);
+    }
+    let artists: Vec<_> = tag.get_strings(&ItemKey::TrackArtist).collect();
+    if !artists.is_empty() {
+        tags.artist = Some(_repair_id3_text(
+            &latin1,
+            &ItemKey::TrackArtist,
+            artists.join("/"),
+        ));
+    }
+    if let Some(album) = text(ItemKey::AlbumTitle, tag.album()) {
+        tags.album = Some(album);
+    }
+    if let Some(album_artist) = text(
+        ItemKey::AlbumArtist,
+        tag.get_string(&ItemKey::AlbumArtist).map(Cow::Borrowed),
+    ) {
+        tags.album_artist = Some(album_artist);
+    }
+    if let Some(track) = tag.track() {
+        tags.track = Some(track);
+    }
+}
+
+const CUE_SHEET_KEY: &str = "CUESHEET";
+
+/// Vorbis Comments, APE 中的 CUESHEET
+fn _get_cue_sheet_from_tag(tag: &Tag) -> Option<String> {
+    tag.items()
+        .find_map(|item| match (item.key(), item.value()) {
+            (ItemKey::Unknown(key), ItemValue::Text(text))
+                if key.eq_ignore_ascii_case(CUE_SHEET_KEY) =>
+            {
+                Some(text.clone())
+            }
+            _ => None,
+        })
+}
+
+/// CUE 虚拟音轨的路径：音乐文件路径#音轨号
+fn _cue_track_path(source: &str, number: u32) -> String {
+    format!("{source}#{number}")
+}
+
+/// CUE 虚拟音轨拆分后的信息。不是虚拟音轨时返回 None
+fn _cue_track(path: &str) -> Option<Audio> {
+    let source = cue_source_path(path);
+    if source == path {
+        return None;
+    }
+    let audio = Audio::read_from_path(Path::new(source))?;
+    _apply_cue_sheets(Path::new(source).parent()?, vec![audio])
+        .into_iter()
+        .find(|track| track.path == path)
+}
+
+/// CUE 虚拟音轨对应的音乐文件路径。不是虚拟音轨时返回 path 本身
+pub(crate) fn cue_source_path(path: &str) -> &str {
+    if Path::new(path).exists() {
+        return path;
+    }
+    match path.rsplit_once('#') {
+        Some((source, number))
+            if !number.is_empty()
+                && number.bytes().all(|b| b.is_ascii_digit())
+                && Path::new(source).is_file() =>
+        {
+            source
+        }
+        _ => path,
+    }
+}
+
+fn _modified_secs(path: impl AsRef<Path>) -> Option<u64> {
+    Some(
+        fs::metadata(path)
+            .ok()?
+            .modified()
+            .ok()?
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or(Duration::ZERO)
+            .as_secs(),
+    )
+}
+
+/// 索引中记录的音乐文件和外挂 .cue 文件的修改时间中较新的。.cue 文件被删除时为 None
+fn _indexed_audio_modified(audio_item: &serde_json::Value) -> Option<u64> {
+    let modified = _modified_secs(_indexed_file_path(audio_item))?;
+    match audio_item["cue"]["sheet"].as_str() {
+        Some(sheet) => Some(modified.max(_modified_secs(sheet)?)),
+        None => Some(modified),
+    }
+}
+
+/// 索引中的音乐文件路径。CUE 虚拟音轨为它所在的音乐文件
+fn _indexed_file_path(audio_item: &serde_json::Value) -> &str {
+    audio_item["cue"]["source"]
+        .as_str()
+        .or_else(|| audio_item["path"].as_str())
+        .unwrap_or_default()
+}
+
+fn _cue_sheets_in_folder(folder: &Path) -> Vec<(PathBuf, cue::CueSheet)> {
+    let Ok(dir) = fs::read_dir(folder) else {
+        return vec![];
+    };
+    dir.filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension.eq_ignore_ascii_case("cue"))
+        })
+        .filter_map(|path| Some((path.clone(), cue::read(&path)?)))
+        .collect()
+}
+
+/// 有对应的 CUE 时拆分为虚拟音轨，音乐文件本身不再出现在索引中。
+/// 优先使用外挂 .cue，其次是内嵌的 CUESHEET
+fn _expand_cue_tracks(audio: Audio, sheets: &[(PathBuf, cue::CueSheet)]) -> Vec<Audio> {
+    let Some(file_name) = Path::new(&audio.path)
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+    else {
+        return vec![audio];
+    };
+    let embedded = audio.cue_sheet.as_deref().and_then(cue::parse);
+    let found = sheets
+        .iter()
+        .find_map(|(sheet_path, sheet)| {
+            let cue_stem = sheet_path.file_stem().map(|stem| stem.to_string_lossy());
+            let ranges = sheet.ranges_for(&file_name, cue_stem.as_deref());
+            (!ranges.is_empty()).then_some((Some(sheet_path), sheet, ranges))
+        })
+        .or_else(|| {
+            let sheet = embedded.as_ref()?;
+            let ranges = sheet.ranges_for(&file_name, None);
+            (!ranges.is_empty()).then_some((None, sheet, ranges))
+        });
+    let Some((sheet_path, sheet, ranges)) = found else {
+        return vec![audio];
+    };
+
+    let duration_ms = audio.duration * 1000;
+    let modified = sheet_path
+        .and_then(_modified_secs)
+        .map_or(audio.modified, |modified| modified.max(audio.modified));
+    ranges
+        .into_iter()
+        .filter(|range| duration_ms == 0 || range.track.start < duration_ms)
+        .map(|range| {
+            let number = range.track.number;
+            Audio {
+                title: range
+                    .track
+                    .title
+                    .unwrap_or_else(|| format!("{} - {:02}", audio.title, number)),
+                artist: range
+                    .track
+                    .performer
+                    .or_else(|| sheet.performer.clone())
+                    .unwrap_or_else(|| audio.artist.clone()),
+                album: sheet.title.clone().unwrap_or_else(|| audio.album.clone()),
+                album_artist: sheet
+                    .performer
+                    .clone()
+                    .or_else(|| audio.album_artist.clone()),
+                track: Some(number),
+                duration: range
+                    .end
+                    .unwrap_or(duration_ms)
+                    .saturating_sub(range.track.start)
+                    / 1000,
+                bitrate: audio.bitrate,
+                sample_rate: audio.sample_rate,
+                path: _cue_track_path(&audio.path, number),
+                modified,
+                created: audio.created,
+                by: audio.by.clone(),
+                cue_sheet: None,
+                cue: Some(AudioCue {
+                    source: audio.path.clone(),
+                    sheet: sheet_path.map(|path| path.to_string_lossy().to_string()),
+                    track: number,
+                    start: range.track.start,
+                    end: range.end,
+                }),
+            }
+        })
+        .collect()
+}
+
+/// 用文件夹中的 .cue 文件和内嵌的 CUESHEET 拆分 audios
+fn _apply_cue_sheets(folder: &Path, audios: Vec<Audio>) -> Vec<Audio> {
+    if audios.is_empty() {
+        return audios;
+    }
+    let sheets = _cue_sheets_in_folder(folder);
+    audios
+        .into_iter()
+        .flat_map(|audio| _expand_cue_tracks(audio, &sheets))
+        .collect()
+}
+
 #[derive(Debug)]
 struct AudioFolder {
     path: String,
@@ -538,6 +843,7 @@ impl AudioFolder {
             }
         }
 
+        let audios = _apply_cue_sheets(path, audios);
         if !audios.is_empty() {
             return Ok(AudioFolder {
                 path: path.to_string_lossy().to_string(),
@@ -624,6 +930,7 @@ impl AudioFolder {
             }
         }
 
+        let audios = _apply_cue_sheets(folder, audios);
         if !audios.is_empty() {
             if let Ok(metadata) = fs::metadata(folder) {
                 if let Ok(modified) = metadata.modified() {
@@ -650,108 +957,379 @@ impl AudioFolder {
     }
 }
 
-fn _get_picture_by_windows(path: &String) -> Result<Vec<u8>, windows::core::Error> {
-    let file = StorageFile::GetFileFromPathAsync(&HSTRING::from(path))?.get()?;
-    let thumbnail = file
-        .GetThumbnailAsyncOverloadDefaultSizeDefaultOptions(ThumbnailMode::MusicView)?
-        .get()?;
-
-    let size = thumbnail.Size()? as u32;
-    let stream: IInputStream = thumbnail.cast()?;
-
-    let mut buffer = vec![0u8; size as usize];
-    let data_reader = DataReader::CreateDataReader(&stream)?;
-    data_reader.LoadAsync(size)?.get()?;
-    data_reader.ReadBytes(&mut buffer)?;
-
-    data_reader.Close()?;
-    stream.Close()?;
-
-    Ok(buffer)
-}
-
 fn _get_picture_by_lofty(path: &String) -> Option<Vec<u8>> {
-    if let Ok(tagged_file) = lofty::read_from_path(&path) {
+    if let Ok(tagged_file) = lofty::read_from_path(path) {
         let tag = tagged_file
             .primary_tag()
             .or_else(|| tagged_file.first_tag())?;
 
-        return Some(tag.pictures().first()?.data().to_vec());
+        // 优先使用封面，没有的话使用第一张图片
+        let picture = tag
+            .get_picture_type(PictureType::CoverFront)
+            .or_else(|| tag.pictures().first())?;
+        return Some(picture.data().to_vec());
     }
 
     None
 }
 
-const PICTURE_CACHE_CAPACITY: usize = 96;
-static PICTURE_CACHE: OnceLock<Mutex<VecDeque<(String, Vec<u8>)>>> = OnceLock::new();
+/// 外置封面的扩展名
+const FOLDER_COVER_EXTENSIONS: [&str; 6] = ["jpg", "jpeg", "png", "webp", "bmp", "gif"];
 
-fn _picture_cache_key(path: &str, width: u32, height: u32) -> String {
-    let modified_secs = fs::metadata(path)
-        .and_then(|m| m.modified())
-        .ok()
-        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
-        .map(|d| d.as_secs())
-        .unwrap_or(0);
-    format!("{path}|{modified_secs}|{width}x{height}")
-}
+/// 默认的外置封面文件名及查找顺序
+const DEFAULT_FOLDER_COVER_NAMES: [&str; 5] = ["cover", "folder", "front", "albumart*", "*"];
+
+static FOLDER_COVER_NAMES: RwLock<Option<Vec<String>>> = RwLock::new(None);
 
 /// for Flutter  
-/// 如果无法通过 Lofty 获取则通过 Windows 获取
-pub fn get_picture_from_path(path: String, width: u32, height: u32) -> Option<Vec<u8>> {
-    let cache_key = _picture_cache_key(&path, width, height);
-    if let Some(cache_lock) = PICTURE_CACHE.get_or_init(|| Mutex::new(VecDeque::new())).lock().ok()
-    {
-        if let Some(pos) = cache_lock.iter().position(|(k, _)| k == &cache_key) {
-            let mut cache = cache_lock;
-            if let Some((k, v)) = cache.remove(pos) {
-                let val = v.clone();
-                cache.push_front((k, v));
-                return Some(val);
-            }
+/// 设置外置封面的文件名（不含扩展名，不区分大小写）及查找顺序。
+/// 以 `*` 结尾的名称按前缀匹配，有多个匹配时使用最大的文件；
+/// 单独的 `*` 表示文件夹里唯一的一张图片。
+/// 传入空列表时恢复默认：cover, folder, front, AlbumArt*, *
+#[frb(sync)]
+pub fn set_folder_cover_names(names: Vec<String>) {
+    let names: Vec<String> = names
+        .into_iter()
+        .map(|name| name.trim().to_lowercase())
+        .filter(|name| !name.is_empty())
+        .collect();
+
+    let mut folder_cover_names = match FOLDER_COVER_NAMES.write() {
+        Ok(val) => val,
+        Err(val) => val.into_inner(),
+    };
+    *folder_cover_names = if names.is_empty() { None } else { Some(names) };
+
+    picture_cache::invalidate_non_embedded();
+}
+
+fn _folder_cover_names() -> Vec<String> {
+    let folder_cover_names = match FOLDER_COVER_NAMES.read() {
+        Ok(val) => val,
+        Err(val) => val.into_inner(),
+    };
+    match folder_cover_names.as_ref() {
+        Some(names) => names.clone(),
+        None => DEFAULT_FOLDER_COVER_NAMES
+            .iter()
+            .map(|name| name.to_string())
+            .collect(),
+    }
+}
+
+/// 按 [set_folder_cover_names] 设置的顺序查找音乐文件所在文件夹的外置封面
+fn _find_folder_cover(path: &str) -> Option<PathBuf> {
+    let dir = Path::new(path).parent()?;
+
+    // (小写的文件名（不含扩展名）, 路径, 文件大小)
+    let mut images: Vec<(String, PathBuf, u64)> = vec![];
+    for entry in fs::read_dir(dir).ok()?.flatten() {
+        let entry_path = entry.path();
+        let is_image = entry_path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| FOLDER_COVER_EXTENSIONS.contains(&ext.to_lowercase().as_str()));
+        if !is_image {
+            continue;
         }
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if !metadata.is_file() {
+            continue;
+        }
+        let Some(stem) = entry_path.file_stem() else {
+            continue;
+        };
+        images.push((stem.to_string_lossy().to_lowercase(), entry_path, metadata.len()));
     }
+    images.sort_by(|a, b| a.1.cmp(&b.1));
 
-    let pic_option =
-        _get_picture_by_lofty(&path).or_else(|| match _get_picture_by_windows(&path) {
-            Ok(val) => Some(val),
-            Err(err) => {
-                log_to_dart(format!("fail to get pic: {}", err));
+    for name in _folder_cover_names() {
+        let found = if name == "*" {
+            if images.len() == 1 {
+                images.first()
+            } else {
                 None
             }
+        } else if let Some(prefix) = name.strip_suffix('*') {
+            images
+                .iter()
+                .filter(|(stem, _, _)| stem.starts_with(prefix))
+                .max_by_key(|(_, _, size)| *size)
+        } else {
+            images.iter().find(|(stem, _, _)| stem == &name)
+        };
+
+        if let Some((_, path, _)) = found {
+            return Some(path.clone());
+        }
+    }
+
+    None
+}
+
+/// 图片的来源
+#[derive(Clone, Debug)]
+pub enum PictureSource {
+    /// 音乐文件的内嵌图片
+    Embedded,
+    /// 同一文件夹下的外置封面
+    Folder { path: String },
+    /// 系统生成的缩略图（Windows 缩略图或 freedesktop 缩略图缓存）
+    System,
+}
+
+#[derive(Clone)]
+pub struct PictureWithSource {
+    pub data: Vec<u8>,
+    pub source: PictureSource,
+}
+
+/// 内嵌图片 -> 外置封面 -> 系统缩略图
+fn _get_picture_with_source(path: &str) -> Option<PictureWithSource> {
+    // CUE 虚拟音轨使用所在音乐文件的图片
+    let path = &cue_source_path(path).to_string();
+    if let Some(data) = _get_picture_by_lofty(path) {
+        return Some(PictureWithSource {
+            data,
+            source: PictureSource::Embedded,
         });
+    }
 
-    if let Some(pic) = &pic_option {
-        if let Ok(loaded_pic) = image::load_from_memory(pic) {
-            // 计算新的宽高，保持原比例
-            let pic_ratio = loaded_pic.width() as f32 / loaded_pic.height() as f32;
+    if let Some(cover_path) = _find_folder_cover(path) {
+        match fs::read(&cover_path) {
+            Ok(data) => {
+                return Some(PictureWithSource {
+                    data,
+                    source: PictureSource::Folder {
+                        path: cover_path.to_string_lossy().to_string(),
+                    },
+                })
+            }
+            Err(err) => log_to_dart(format!("fail to read {:?}: {}", cover_path, err)),
+        }
+    }
+
+    match Platform.thumbnail(path) {
+        Ok(data) => Some(PictureWithSource {
+            data,
+            source: PictureSource::System,
+        }),
+        Err(err) => {
+            log_to_dart(format!("fail to get pic: {}", err));
+            None
+        }
+    }
+}
+
+/// 缩略图的编码格式
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PictureFormat {
+    Png,
+    Jpeg,
+    WebP,
+}
+
+/// 缩放时使用的采样方式，从快到慢
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PictureFilter {
+    Nearest,
+    Triangle,
+    CatmullRom,
+    Gaussian,
+    Lanczos3,
+}
+
+impl PictureFilter {
+    fn to_filter_type(self) -> imageops::FilterType {
+        match self {
+            PictureFilter::Nearest => imageops::FilterType::Nearest,
+            PictureFilter::Triangle => imageops::FilterType::Triangle,
+            PictureFilter::CatmullRom => imageops::FilterType::CatmullRom,
+            PictureFilter::Gaussian => imageops::FilterType::Gaussian,
+            PictureFilter::Lanczos3 => imageops::FilterType::Lanczos3,
+        }
+    }
+}
+
+/// 图片和目标尺寸比例不同时的处理方式
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PictureFit {
+    /// 完整显示在 width x height 内，保持原比例
+    Contain,
+    /// 填满 width x height，居中裁掉多余的部分
+    Cover,
+    /// 居中裁剪为正方形，边长为 min(width, height)
+    CropSquare,
+}
+
+pub struct PictureOptions {
+    /// 逻辑像素
+    pub width: u32,
+    /// 逻辑像素
+    pub height: u32,
+    /// 实际输出的像素为 width * device_pixel_ratio
+    pub device_pixel_ratio: f64,
+    pub format: PictureFormat,
+    /// 1~100，只对 Jpeg 和 WebP 生效
+    pub quality: u8,
+    pub filter: PictureFilter,
+    pub fit: PictureFit,
+}
+
+impl PictureOptions {
+    /// [get_picture_from_path] 使用的参数
+    pub(crate) fn with_size(width: u32, height: u32) -> Self {
+        PictureOptions {
+            width,
+            height,
+            device_pixel_ratio: 1.0,
+            format: PictureFormat::Png,
+            quality: 90,
+            filter: PictureFilter::Triangle,
+            fit: PictureFit::Contain,
+        }
+    }
+
+    /// 乘以 device_pixel_ratio 后的像素尺寸
+    fn physical_size(&self) -> (u32, u32) {
+        let ratio = if self.device_pixel_ratio.is_finite() && self.device_pixel_ratio > 0.0 {
+            self.device_pixel_ratio
+        } else {
+            1.0
+        };
+        let width = (self.width as f64 * ratio).round().max(1.0) as u32;
+        let height = (self.height as f64 * ratio).round().max(1.0) as u32;
+        match self.fit {
+            PictureFit::CropSquare => (width.min(height), width.min(height)),
+            _ => (width, height),
+        }
+    }
+
+    /// 作为缓存键的一部分，参数不同的缩略图分别缓存
+    fn cache_variant(&self) -> String {
+        let (width, height) = self.physical_size();
+        let quality = match self.format {
+            PictureFormat::Png => 0,
+            _ => self.quality.clamp(1, 100),
+        };
+        format!(
+            "{width}x{height}|{:?}|{:?}|{:?}|{quality}",
+            self.fit, self.filter, self.format
+        )
+    }
+}
 
-            let (result_width, result_height) = if pic_ratio > 1.0 {
-                (width, (width as f32 / pic_ratio).round() as u32)
+/// 按 fit 缩放和裁剪。不会放大图片。
+fn _fit_picture(picture: DynamicImage, options: &PictureOptions) -> DynamicImage {
+    let (target_width, target_height) = options.physical_size();
+    let (width, height) = (picture.width().max(1), picture.height().max(1));
+
+    let picture = match options.fit {
+        PictureFit::Contain => picture,
+        PictureFit::Cover | PictureFit::CropSquare => {
+            // 原图中比例和目标相同的最大区域
+            let target_ratio = target_width as f64 / target_height as f64;
+            let (crop_width, crop_height) = if width as f64 / height as f64 > target_ratio {
+                ((height as f64 * target_ratio).round().max(1.0) as u32, height)
             } else {
-                ((height as f32 * pic_ratio).round() as u32, height)
+                (width, (width as f64 / target_ratio).round().max(1.0) as u32)
             };
+            picture.crop_imm(
+                (width - crop_width) / 2,
+                (height - crop_height) / 2,
+                crop_width,
+                crop_height,
+            )
+        }
+    };
 
-            let resized_img = imageops::resize(
-                &loaded_pic,
-                result_width,
-                result_height,
-                imageops::FilterType::Triangle,
-            );
+    let (width, height) = (picture.width().max(1), picture.height().max(1));
+    let scale = (target_width as f64 / width as f64)
+        .min(target_height as f64 / height as f64)
+        .min(1.0);
+    if scale >= 1.0 {
+        return picture;
+    }
+
+    picture.resize_exact(
+        (width as f64 * scale).round().max(1.0) as u32,
+        (height as f64 * scale).round().max(1.0) as u32,
+        options.filter.to_filter_type(),
+    )
+}
 
+fn _encode_picture(picture: DynamicImage, options: &PictureOptions) -> Option<Vec<u8>> {
+    let quality = options.quality.clamp(1, 100);
+    match options.format {
+        PictureFormat::Png => {
             let mut output = Cursor::new(Vec::new());
-            if let Ok(_) = resized_img.write_to(&mut output, image::ImageFormat::Png) {
-                let out = output.into_inner();
-                if let Some(mut cache) =
-                    PICTURE_CACHE.get_or_init(|| Mutex::new(VecDeque::new())).lock().ok()
-                {
-                    if let Some(pos) = cache.iter().position(|(k, _)| k == &cache_key) {
-                        cache.remove(pos);
-                    }
-                    cache.push_front((cache_key, out.clone()));
-                    while cache.len() > PICTURE_CACHE_CAPACITY {
-                        cache.pop_back();
-                    }
-                }
+            picture
+                .write_to(&mut output, image::ImageFormat::Png)
+                .ok()?;
+            Some(output.into_inner())
+        }
+        PictureFormat::Jpeg => {
+            let mut output = Cursor::new(Vec::new());
+            let encoder = JpegEncoder::new_with_quality(&mut output, quality);
+            // JPEG 不支持透明通道
+            picture.into_rgb8().write_with_encoder(encoder).ok()?;
+            Some(output.into_inner())
+        }
+        PictureFormat::WebP => {
+            let picture = picture.into_rgba8();
+            let encoded = webp::Encoder::from_rgba(&picture, picture.width(), picture.height())
+                .encode(quality as f32);
+            Some(encoded.to_vec())
+        }
+    }
+}
+
+/// for Flutter  
+/// 如果无法通过 Lofty 获取则查找外置封面，再不能的话使用系统生成的缩略图
+/// （Windows 缩略图或 freedesktop 缩略图缓存）
+pub fn get_picture_from_path(path: String, width: u32, height: u32) -> Option<Vec<u8>> {
+    get_picture_with_options(path, PictureOptions::with_size(width, height)).map(|pic| pic.data)
+}
+
+/// for Flutter  
+/// 和 [get_picture_from_path] 相同，同时返回图片的来源
+pub fn get_picture_with_source_from_path(
+    path: String,
+    width: u32,
+    height: u32,
+) -> Option<PictureWithSource> {
+    get_picture_with_options(path, PictureOptions::with_size(width, height))
+}
+
+/// for Flutter  
+/// 按 options 缩放、裁剪并编码图片。无法解码时返回原图。
+pub fn get_picture_with_options(
+    path: String,
+    options: PictureOptions,
+) -> Option<PictureWithSource> {
+    let cache_key = PictureCacheKey::new(&path, options.cache_variant());
+    if let Some(pic) = picture_cache::get(&cache_key) {
+        return Some(pic);
+    }
+
+    let pic_option = _get_picture_with_source(&path);
+
+    if let Some(pic) = &pic_option {
+        let blob_id = picture_cache::blob_id(&pic.data, &cache_key.variant);
+        if let Some(cached) = picture_cache::get_by_blob(&cache_key, &blob_id, &pic.source) {
+            return Some(cached);
+        }
+
+        if let Ok(loaded_pic) = image::load_from_memory(&pic.data) {
+            let fitted = _fit_picture(loaded_pic, &options);
+            if let Some(data) = _encode_picture(fitted, &options) {
+                let out = PictureWithSource {
+                    data,
+                    source: pic.source.clone(),
+                };
+                picture_cache::put(&cache_key, &blob_id, &out);
                 return Some(out);
             }
         }
@@ -760,82 +1338,432 @@ pub fn get_picture_from_path(path: String, width: u32, height: u32) -> Option<Ve
     pic_option
 }
 
-fn _get_lyric_from_lofty(path: &String) -> Option<String> {
-    if let Ok(tagged_file) = lofty::read_from_path(&path) {
+/// 标签中以 Latin-1 编码保存的项
+enum Latin1Items {
+    /// ID3v1 只有 Latin-1
+    All,
+    Keys(HashSet<ItemKey>),
+}
+
+/// id3v2 是 tag 的原始 ID3v2 标签，转换成 Tag 后会丢失每一帧的编码。
+/// 没有时不修复任何 ID3v2 的项
+fn _latin1_items(tag: &Tag, id3v2: Option<&Id3v2Tag>) -> Latin1Items {
+    match (tag.tag_type(), id3v2) {
+        (TagType::Id3v1, _) => Latin1Items::All,
+        (TagType::Id3v2, Some(id3v2)) => Latin1Items::Keys(_id3v2_latin1_keys(id3v2)),
+        _ => Latin1Items::Keys(HashSet::new()),
+    }
+}
+
+fn _id3v2_latin1_keys(tag: &Id3v2Tag) -> HashSet<ItemKey> {
+    tag.into_iter()
+        .filter(|frame| {
+            let encoding = match frame {
+                Frame::Text(frame) => frame.encoding,
+                Frame::UnsynchronizedText(frame) => frame.encoding,
+                Frame::Comment(frame) => frame.encoding,
+                _ => return false,
+            };
+            encoding == TextEncoding::Latin1
+        })
+        .map(|frame| ItemKey::from_key(TagType::Id3v2, frame.id().as_str()))
+        .collect()
+}
+
+/// ID3 的 Latin-1 文本帧经常存着 GBK 等本地编码的文本，识别出来时转换为正确的文本。
+/// 其他编码的帧本来就是正确的文本，不做处理
+fn _repair_id3_text(latin1: &Latin1Items, key: &ItemKey, text: String) -> String {
+    let is_latin1 = match latin1 {
+        Latin1Items::All => true,
+        Latin1Items::Keys(keys) => keys.contains(key),
+    };
+    match is_latin1 {
+        true => text_encoding::repair_latin1(&text).unwrap_or(text),
+        false => text,
+    }
+}
+
+/// 标签是 ID3v2 时读取原始标签，用来判断每一项的编码
+fn _latin1_items_of_file(path: &str, tagged_file: &TaggedFile, tag: &Tag) -> Latin1Items {
+    let id3v2 = match tag.tag_type() {
+        TagType::Id3v2 => _read_id3v2(path, tagged_file.file_type()),
+        _ => None,
+    };
+    _latin1_items(tag, id3v2.as_ref())
+}
+
+fn _get_lyric_from_lofty(path: &str) -> Option<String> {
+    let path = cue_source_path(path);
+    if let Ok(tagged_file) = lofty::read_from_path(path) {
         let tag = tagged_file
             .primary_tag()
             .or_else(|| tagged_file.first_tag())?;
         let lyric_tag = tag.get(&ItemKey::Lyrics)?;
         let lyric = lyric_tag.value().text()?;
 
-        return Some(lyric.to_string());
+        let latin1 = _latin1_items_of_file(path, &tagged_file, tag);
+        return Some(_repair_id3_text(
+            &latin1,
+            &ItemKey::Lyrics,
+            lyric.to_string(),
+        ));
     }
 
     None
 }
 
-fn _get_lyric_from_lrc_file(path: &String) -> anyhow::Result<String> {
-    let mut lrc_file_path = PathBuf::from(path);
-    lrc_file_path.set_extension("lrc");
+/// 默认的外挂歌词扩展名及优先顺序
+const DEFAULT_LYRIC_EXTENSIONS: [&str; 6] = ["lrc", "txt", "krc", "qrc", "ttml", "srt"];
+
+/// 默认的歌词子文件夹（不区分大小写，即 Lyrics 和 lyrics）
+const DEFAULT_LYRIC_SUBFOLDERS: [&str; 1] = ["lyrics"];
 
-    let lrc_bytes = fs::read(lrc_file_path)?;
+struct LyricSearchOptions {
+    extensions: Vec<String>,
+    subfolders: Vec<String>,
+    global_folder: Option<PathBuf>,
+}
 
-    let is_le = lrc_bytes.starts_with(&[0xFF, 0xFE]);
-    let is_utf16 = (is_le || lrc_bytes.starts_with(&[0xFE, 0xFF])) && lrc_bytes.len() % 2 == 0;
+static LYRIC_SEARCH_OPTIONS: RwLock<Option<LyricSearchOptions>> = RwLock::new(None);
 
-    if is_utf16 {
-        let convert_fn = match is_le {
-            true => u16::from_le_bytes,
-            false => u16::from_be_bytes,
+fn _lowercase_names(names: Vec<String>) -> Vec<String> {
+    names
+        .into_iter()
+        .map(|name| name.trim().trim_start_matches('.').to_lowercase())
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+/// for Flutter  
+/// 设置外挂歌词的查找方式，都不区分大小写。
+/// extensions: 扩展名及优先顺序，传入空列表时恢复默认：lrc, txt, krc, qrc, ttml, srt
+/// subfolders: 音乐文件所在文件夹下的歌词文件夹，传入空列表时恢复默认：Lyrics
+/// global_folder: 全局歌词文件夹，按“歌手 - 标题”或相同文件名匹配
+#[frb(sync)]
+pub fn set_lyric_search_options(
+    extensions: Vec<String>,
+    subfolders: Vec<String>,
+    global_folder: Option<String>,
+) {
+    let default_names = |names: &[&str]| names.iter().map(|name| name.to_string()).collect();
+    let extensions = _lowercase_names(extensions);
+    let subfolders = _lowercase_names(subfolders);
+
+    let mut options = match LYRIC_SEARCH_OPTIONS.write() {
+        Ok(val) => val,
+        Err(val) => val.into_inner(),
+    };
+    *options = Some(LyricSearchOptions {
+        extensions: if extensions.is_empty() {
+            default_names(&DEFAULT_LYRIC_EXTENSIONS)
+        } else {
+            extensions
+        },
+        subfolders: if subfolders.is_empty() {
+            default_names(&DEFAULT_LYRIC_SUBFOLDERS)
+        } else {
+            subfolders
+        },
+        global_folder: global_folder
+            .filter(|folder| !folder.trim().is_empty())
+            .map(PathBuf::from),
+    });
+}
+
+fn _lyric_search_options() -> (Vec<String>, Vec<String>, Option<PathBuf>) {
+    let options = match LYRIC_SEARCH_OPTIONS.read() {
+        Ok(val) => val,
+        Err(val) => val.into_inner(),
+    };
+    match options.as_ref() {
+        Some(options) => (
+            options.extensions.clone(),
+            options.subfolders.clone(),
+            options.global_folder.clone(),
+        ),
+        None => (
+            DEFAULT_LYRIC_EXTENSIONS.iter().map(|ext| ext.to_string()).collect(),
+            DEFAULT_LYRIC_SUBFOLDERS.iter().map(|name| name.to_string()).collect(),
+            None,
+        ),
+    }
+}
+
+/// 歌词的来源
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LyricCandidateSource {
+    /// 音乐文件的内嵌歌词
+    Embedded,
+    /// 相同文件夹、相同文件名的外挂歌词
+    SameFolder,
+    /// 歌词子文件夹（如 Lyrics/）中相同文件名的外挂歌词
+    Subfolder,
+    /// 全局歌词文件夹中“歌手 - 标题”或相同文件名的外挂歌词
+    GlobalFolder,
+}
+
+#[derive(Clone, Debug)]
+pub struct LyricCandidate {
+    pub source: LyricCandidateSource,
+    /// 内嵌歌词为 None
+    pub path: Option<String>,
+    /// 小写的扩展名，内嵌歌词为 None
+    pub extension: Option<String>,
+}
+
+/// 小写并合并连续的空白，用于不区分大小写地比较文件名
+fn _normalize_lyric_name(name: &str) -> String {
+    name.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// dir 中文件名（不含扩展名）属于 names、扩展名属于 extensions 的文件，按扩展名的顺序排列
+fn _find_lyric_files(
+    dir: &Path,
+    names: &[String],
+    extensions: &[String],
+) -> Vec<(PathBuf, String)> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+
+    let mut found: Vec<(usize, PathBuf, String)> = vec![];
+    for entry in entries.flatten() {
+        let entry_path = entry.path();
+        if !entry_path.is_file() {
+            continue;
+        }
+        let (Some(stem), Some(extension)) = (entry_path.file_stem(), entry_path.extension()) else {
+            continue;
+        };
+        let extension = extension.to_string_lossy().to_lowercase();
+        let Some(priority) = extensions.iter().position(|ext| ext == &extension) else {
+            continue;
         };
+        if names.contains(&_normalize_lyric_name(&stem.to_string_lossy())) {
+            found.push((priority, entry_path, extension));
+        }
+    }
+    found.sort();
+    found
+        .into_iter()
+        .map(|(_, path, extension)| (path, extension))
+        .collect()
+}
+
+/// 全局歌词文件夹中可以匹配的文件名：相同文件名、“歌手 - 标题”，有多个歌手时也尝试第一个歌手
+fn _global_lyric_names(stem: &str, artist_title: Option<(String, String)>) -> Vec<String> {
+    let mut names = vec![_normalize_lyric_name(stem)];
+    if let Some((artist, title)) = artist_title {
+        let first_artist = artist
+            .split(['/', ',', '、', '&', ';'])
+            .next()
+            .unwrap_or("");
+        for artist in [artist.as_str(), first_artist] {
+            let name = _normalize_lyric_name(&format!("{} - {}", artist.trim(), title.trim()));
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+    }
+    names
+}
+
+fn _get_artist_title(path: &str) -> Option<(String, String)> {
+    let tagged_file = lofty::read_from_path(path).ok()?;
+    let tag = tagged_file
+        .primary_tag()
+        .or_else(|| tagged_file.first_tag())?;
+    let artist: Vec<_> = tag.get_strings(&ItemKey::TrackArtist).collect();
+    let title = tag.title()?;
+    if artist.is_empty() {
+        return None;
+    }
+    let latin1 = _latin1_items_of_file(path, &tagged_file, tag);
+    Some((
+        _repair_id3_text(&latin1, &ItemKey::TrackArtist, artist.join("/")),
+        _repair_id3_text(&latin1, &ItemKey::TrackTitle, title.to_string()),
+    ))
+}
+
+/// artist_title 只在设置了全局歌词文件夹时调用
+fn _find_lyric_candidates(
+    path: &str,
+    artist_title: impl FnOnce() -> Option<(String, String)>,
+    has_embedded: bool,
+) -> Vec<LyricCandidate> {
+    let mut candidates = vec![];
+    if has_embedded {
+        candidates.push(LyricCandidate {
+            source: LyricCandidateSource::Embedded,
+            path: None,
+            extension: None,
+        });
+    }
+
+    // CUE 虚拟音轨按音轨标题查找，而不是整轨文件的文件名
+    let cue_track = _cue_track(path);
+    let audio_path = Path::new(cue_source_path(path));
+    let (Some(dir), Some(file_stem)) = (audio_path.parent(), audio_path.file_stem()) else {
+        return candidates;
+    };
+    let stem = match &cue_track {
+        Some(track) => track.title.clone(),
+        None => file_stem.to_string_lossy().to_string(),
+    };
+    let (extensions, subfolders, global_folder) = _lyric_search_options();
+    let same_name = vec![_normalize_lyric_name(&stem)];
+
+    let mut push = |source: LyricCandidateSource, files: Vec<(PathBuf, String)>| {
+        for (file, extension) in files {
+            candidates.push(LyricCandidate {
+                source: source.clone(),
+                path: Some(file.to_string_lossy().to_string()),
+                extension: Some(extension),
+            });
+        }
+    };
+
+    push(
+        LyricCandidateSource::SameFolder,
+        _find_lyric_files(dir, &same_name, &extensions),
+    );
+
+    let mut lyric_dirs: Vec<PathBuf> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|entry_path| {
+                    entry_path.is_dir()
+                        && entry_path.file_name().is_some_and(|name| {
+                            subfolders.contains(&name.to_string_lossy().to_lowercase())
+                        })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    lyric_dirs.sort();
+    for lyric_dir in lyric_dirs {
+        push(
+            LyricCandidateSource::Subfolder,
+            _find_lyric_files(&lyric_dir, &same_name, &extensions),
+        );
+    }
+
+    if let Some(global_folder) = global_folder {
+        push(
+            LyricCandidateSource::GlobalFolder,
+            _find_lyric_files(
+                &global_folder,
+                &_global_lyric_names(
+                    &stem,
+                    match cue_track {
+                        Some(track) => Some((track.artist, track.title)),
+                        None => artist_title(),
+                    },
+                ),
+                &extensions,
+            ),
+        );
+    }
+
+    candidates
+}
 
-        let mut u16_bytes: Vec<u16> = vec![];
-        let mut chunk_iter = lrc_bytes.chunks_exact(2);
-        chunk_iter.next();
+/// for Flutter  
+/// 列出音乐文件的所有歌词：内嵌歌词、相同文件夹、歌词子文件夹、全局歌词文件夹，
+/// 按这个顺序及 [set_lyric_search_options] 设置的扩展名顺序排列
+pub fn get_lyric_candidates(path: String) -> Vec<LyricCandidate> {
+    _find_lyric_candidates(
+        &path,
+        || _get_artist_title(&path),
+        _get_lyric_from_lofty(&path).is_some(),
+    )
+}
 
-        for chunk in chunk_iter {
-            u16_bytes.push(convert_fn([chunk[0], chunk[1]]));
+/// 读取外挂歌词文件的文本。krc, qrc 先按加密格式解密，失败时当作已经解密的文本；
+/// 其他格式自动识别编码
+fn _read_lyric_file(path: &str, extension: &str) -> Option<String> {
+    let bytes = match fs::read(path) {
+        Ok(val) => val,
+        Err(err) => {
+            log_to_dart(format!("fail to read {}: {}", path, err));
+            return None;
         }
-        return Ok(String::from_utf16(&u16_bytes)?);
+    };
+    let decrypted = match extension {
+        "krc" => lyric::decrypt_krc(bytes.clone()),
+        "qrc" => lyric::decrypt_qrc(bytes.clone()),
+        _ => return Some(text_encoding::decode_bytes(&bytes).text),
+    };
+    match decrypted {
+        Ok(text) => Some(text),
+        Err(err) => match String::from_utf8(bytes) {
+            Ok(text) => Some(text),
+            Err(_) => {
+                log_to_dart(format!("fail to decrypt {}: {}", path, err));
+                None
+            }
+        },
     }
+}
 
-    return Ok(String::from_utf8(lrc_bytes)?);
+/// for Flutter  
+/// 读取 [get_lyric_candidates] 返回的歌词的文本
+pub fn read_lyric_candidate(path: String, candidate: LyricCandidate) -> Option<String> {
+    match (candidate.path, candidate.extension) {
+        (Some(lyric_path), Some(extension)) => _read_lyric_file(&lyric_path, &extension),
+        _ => _get_lyric_from_lofty(&path),
+    }
+}
+
+/// for Flutter
+/// 读取外挂的 .krc（酷狗）, .qrc（QQ 音乐）歌词并解析为逐字歌词，查找方式和 [get_lyric_candidates] 相同
+pub fn get_encrypted_lyric_from_path(path: String) -> Option<Lyric> {
+    _find_lyric_candidates(&path, || _get_artist_title(&path), false)
+        .into_iter()
+        .filter(|candidate| matches!(candidate.extension.as_deref(), Some("krc" | "qrc")))
+        .find_map(|candidate| lyric::parse_lyric_candidate(path.clone(), candidate))
 }
 
 /// for Flutter   
 /// 只支持读取 ID3V2, VorbisComment, Mp4Ilst 存储的内嵌歌词
-/// 以及相同目录相同文件名的 .lrc 外挂歌词（utf-8 or utf-16）
+/// 以及 .lrc, .txt 外挂歌词（查找方式和 [get_lyric_candidates] 相同；
+/// 自动识别 UTF-8, UTF-16, GB18030, Big5, Shift-JIS, EUC-KR）
 pub fn get_lyric_from_path(path: String) -> Option<String> {
-    return _get_lyric_from_lofty(&path).or_else(|| match _get_lyric_from_lrc_file(&path) {
-        Ok(val) => Some(val),
-        Err(err) => {
-            log_to_dart(format!("fail to get lrc: {}", err.to_string()));
-            None
-        }
-    });
+    _get_lyric_from_lofty(&path).or_else(|| {
+        _find_lyric_candidates(&path, || _get_artist_title(&path), false)
+            .into_iter()
+            .filter(|candidate| matches!(candidate.extension.as_deref(), Some("lrc" | "txt")))
+            .find_map(|candidate| _read_lyric_file(candidate.path.as_deref()?, "lrc"))
+    })
+}
+
+/// 取得用于写入的标签，没有标签时按文件的主要标签类型新建
+fn _tag_for_write(tagged_file: &mut TaggedFile) -> Result<&mut Tag, String> {
+    if tagged_file.primary_tag().is_none() && tagged_file.first_tag().is_none() {
+        let tag_type = tagged_file.primary_tag_type();
+        tagged_file.insert_tag(Tag::new(tag_type));
+    }
+
+    if tagged_file.primary_tag().is_some() {
+        tagged_file.primary_tag_mut()
+    } else {
+        tagged_file.first_tag_mut()
+    }
+    .ok_or_else(|| "failed to create tag".to_string())
 }
 
 /// for Flutter
 /// 写入歌词到音频文件标签（ID3/VorbisComment/MP4 等），使用 Lofty 的 `ItemKey::Lyrics` 映射
 pub fn write_lyric_to_path(path: String, lyric: String) -> Result<(), String> {
+    let path = cue_source_path(&path).to_string();
     let mut tagged_file = lofty::read_from_path(&path).map_err(|e| e.to_string())?;
 
-    let tag = if let Some(tag) = tagged_file.primary_tag_mut() {
-        tag
-    } else if let Some(tag) = tagged_file.first_tag_mut() {
-        tag
-    } else {
-        let tag_type = tagged_file.primary_tag_type();
-        tagged_file.insert_tag(Tag::new(tag_type));
-        if let Some(tag) = tagged_file.primary_tag_mut() {
-            tag
-        } else if let Some(tag) = tagged_file.first_tag_mut() {
-            tag
-        } else {
-            return Err("failed to create tag".to_string());
-        }
-    };
-
+    let tag = _tag_for_write(&mut tagged_file)?;
     tag.insert_text(ItemKey::Lyrics, lyric);
     tagged_file
         .save_to_path(&path, WriteOptions::default())
@@ -843,6 +1771,265 @@ pub fn write_lyric_to_path(path: String, lyric: String) -> Result<(), String> {
     Ok(())
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EmbeddedLyricKind {
+    /// ID3v2 USLT, VorbisComment UNSYNCEDLYRICS, 以及没有时间的 LYRICS, MP4 ©lyr
+    Unsynced,
+    /// ID3v2 SYLT, 以及 LRC 格式的 LYRICS, MP4 ©lyr
+    Synced,
+}
+
+/// 内嵌在标签中的一份歌词
+#[derive(Clone, Debug, PartialEq)]
+pub struct EmbeddedLyric {
+    pub kind: EmbeddedLyricKind,
+    /// ISO 639-2 语言代码，如 eng, chi, jpn。只有 ID3v2 支持，未知时为 None
+    pub language: Option<String>,
+    /// ID3v2 帧的描述，用于区分同一语言的多份歌词（如原文和翻译）
+    pub description: String,
+    /// Synced 时为 LRC 文本
+    pub text: String,
+}
+
+const SYLT_FRAME_ID: FrameId<'static> = FrameId::Valid(Cow::Borrowed("SYLT"));
+const UNSYNCED_LYRICS_KEY: &str = "UNSYNCEDLYRICS";
+
+/// 以 ID3v2 作为歌词标签的格式
+fn _uses_id3v2_lyrics(file_type: FileType) -> bool {
+    matches!(
+        file_type,
+        FileType::Mpeg | FileType::Aac | FileType::Aiff | FileType::Wav
+    )
+}
+
+fn _probe_file_type(path: &str) -> Option<FileType> {
+    Probe::open(path).ok()?.guess_file_type().ok()?.file_type()
+}
+
+fn _read_id3v2(path: &str, file_type: FileType) -> Option<Id3v2Tag> {
+    _try_read_id3v2(path, file_type).ok().flatten()
+}
+
+/// 没有 ID3v2 标签时为 Ok(None)；文件（包括已有的标签）无法解析时返回错误
+fn _try_read_id3v2(path: &str, file_type: FileType) -> Result<Option<Id3v2Tag>, String> {
+    let mut file = fs::File::open(path).map_err(|e| e.to_string())?;
+    let options = ParseOptions::new().read_properties(false);
+    let tag = match file_type {
+        FileType::Mpeg => MpegFile::read_from(&mut file, options)
+            .map_err(|e| e.to_string())?
+            .remove_id3v2(),
+        FileType::Aac => AacFile::read_from(&mut file, options)
+            .map_err(|e| e.to_string())?
+            .remove_id3v2(),
+        FileType::Aiff => AiffFile::read_from(&mut file, options)
+            .map_err(|e| e.to_string())?
+            .remove_id3v2(),
+        FileType::Wav => WavFile::read_from(&mut file, options)
+            .map_err(|e| e.to_string())?
+            .remove_id3v2(),
+        _ => None,
+    };
+    Ok(tag)
+}
+
+fn _id3v2_language(language: &[u8; 3]) -> Option<String> {
+    let language = std::str::from_utf8(language).ok()?.to_ascii_lowercase();
+    (language.chars().all(|c| c.is_ascii_alphabetic()) && language != "xxx").then_some(language)
+}
+
+fn _id3v2_lyrics(tag: &Id3v2Tag) -> Vec<EmbeddedLyric> {
+    let mut lyrics = vec![];
+    for frame in tag {
+        match frame {
+            Frame::UnsynchronizedText(frame) => lyrics.push(EmbeddedLyric {
+                kind: EmbeddedLyricKind::Unsynced,
+                language: _id3v2_language(&frame.language),
+                description: frame.description.clone(),
+                text: if frame.encoding == TextEncoding::Latin1 {
+                    text_encoding::repair_latin1(&frame.content)
+                        .unwrap_or_else(|| frame.content.clone())
+                } else {
+                    frame.content.clone()
+                },
+            }),
+            Frame::Binary(frame) if frame.id() == &SYLT_FRAME_ID => {
+                // Lofty 要求 UTF-16 的描述带 BOM，否则会 panic
+                let utf16 = frame.data.first() == Some(&1);
+                if utf16 && !matches!(frame.data.get(6..8), Some([0xFF, 0xFE] | [0xFE, 0xFF])) {
+                    continue;
+                }
+                let sylt = match SynchronizedTextFrame::parse(&frame.data, frame.flags()) {
+                    Ok(sylt) => sylt,
+                    Err(err) => {
+                        log_to_dart(format!("invalid SYLT frame: {}", err));
+                        continue;
+                    }
+                };
+                // MPEG 帧为单位的时间需要知道帧长，不支持
+                if sylt.timestamp_format != TimestampFormat::MS
+                    || !matches!(
+                        sylt.content_type,
+                        SyncTextContentType::Lyrics | SyncTextContentType::TextTranscription
+                    )
+                {
+                    continue;
+                }
+                if let Some(lyric) = lyric::from_synced_text(&sylt.content) {
+                    lyrics.push(EmbeddedLyric {
+                        kind: EmbeddedLyricKind::Synced,
+                        language: _id3v2_language(&sylt.language),
+                        description: sylt.description.unwrap_or_default(),
+                        text: lyric.to_lrc(),
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+    lyrics
+}
+
+fn _tag_lyrics(tag: &Tag) -> Vec<EmbeddedLyric> {
+    let unsynced_key = ItemKey::Unknown(UNSYNCED_LYRICS_KEY.to_string());
+    let latin1 = _latin1_items(tag, None);
+    let synced = tag.get_strings(&ItemKey::Lyrics).map(|text| {
+        let kind = if lyric::parse_lrc(text.to_string()).is_some() {
+            EmbeddedLyricKind::Synced
+        } else {
+            EmbeddedLyricKind::Unsynced
+        };
+        (kind, text)
+    });
+    let unsynced = tag
+        .get_strings(&unsynced_key)
+        .map(|text| (EmbeddedLyricKind::Unsynced, text));
+
+    synced
+        .chain(unsynced)
+        .map(|(kind, text)| EmbeddedLyric {
+            kind,
+            language: None,
+            description: String::new(),
+            text: _repair_id3_text(&latin1, &ItemKey::Lyrics, text.to_string()),
+        })
+        .collect::<Vec<_>>()
+}
+
+/// for Flutter
+/// 列出标签中的所有歌词：ID3v2 的所有 USLT, SYLT 帧，
+/// VorbisComment 的所有 LYRICS, UNSYNCEDLYRICS，其他标签的歌词项
+pub fn get_embedded_lyrics_from_path(path: String) -> Vec<EmbeddedLyric> {
+    let path = cue_source_path(&path).to_string();
+    let Some(file_type) = _probe_file_type(&path) else {
+        return vec![];
+    };
+    if _uses_id3v2_lyrics(file_type) {
+        return _read_id3v2(&path, file_type)
+            .map(|tag| _id3v2_lyrics(&tag))
+            .unwrap_or_default();
+    }
+
+    let Ok(tagged_file) = lofty::read_from_path(&path) else {
+        return vec![];
+    };
+    let Some(tag) = tagged_file
+        .primary_tag()
+        .or_else(|| tagged_file.first_tag())
+    else {
+        return vec![];
+    };
+    _tag_lyrics(tag)
+}
+
+fn _id3v2_lang_code(language: Option<&str>) -> [u8; 3] {
+    match language.map(|language| language.as_bytes()) {
+        Some([a, b, c]) if [a, b, c].iter().all(|c| c.is_ascii_alphabetic()) => {
+            [*a, *b, *c].map(|c| c.to_ascii_lowercase())
+        }
+        _ => *b"XXX",
+    }
+}
+
+fn _write_id3v2_lyrics(
+    path: &str,
+    file_type: FileType,
+    lyrics: Vec<EmbeddedLyric>,
+) -> Result<(), String> {
+    // 已有的标签无法读取时不写入，否则保存时会用只有歌词的标签覆盖它
+    let mut tag = _try_read_id3v2(path, file_type)?.unwrap_or_default();
+    tag.retain(|frame| !matches!(frame.id_str(), "USLT" | "SYLT"));
+
+    for embedded in lyrics {
+        let language = _id3v2_lang_code(embedded.language.as_deref());
+        match embedded.kind {
+            EmbeddedLyricKind::Unsynced => {
+                // 语言和描述都相同的 USLT 只保留最后一个
+                tag.insert(Frame::UnsynchronizedText(UnsynchronizedTextFrame::new(
+                    TextEncoding::UTF8,
+                    language,
+                    embedded.description,
+                    embedded.text,
+                )));
+            }
+            EmbeddedLyricKind::Synced => {
+                let lyric = lyric::parse_lyric(embedded.text)
+                    .ok_or_else(|| "synced lyric has no timed line".to_string())?;
+                let sylt = SynchronizedTextFrame::new(
+                    TextEncoding::UTF8,
+                    language,
+                    TimestampFormat::MS,
+                    SyncTextContentType::Lyrics,
+                    Some(embedded.description),
+                    lyric.to_synced_text(),
+                );
+                let data = sylt.as_bytes().map_err(|e| e.to_string())?;
+                tag.insert(Frame::Binary(BinaryFrame::new(SYLT_FRAME_ID, data)));
+            }
+        }
+    }
+
+    tag.save_to_path(path, WriteOptions::default())
+        .map_err(|e| e.to_string())
+}
+
+/// for Flutter
+/// 用 lyrics 替换标签中的所有歌词。
+/// ID3v2：Unsynced 写为 USLT，Synced（LRC 文本）写为毫秒时间的 SYLT，每一项一个帧；
+/// VorbisComment：Synced 写为 LYRICS，Unsynced 写为 UNSYNCEDLYRICS，不支持语言和描述；
+/// 其他标签全部写为歌词项
+pub fn write_lyrics_to_path(path: String, lyrics: Vec<EmbeddedLyric>) -> Result<(), String> {
+    let path = cue_source_path(&path).to_string();
+    let file_type = _probe_file_type(&path).ok_or_else(|| "unknown file type".to_string())?;
+    if _uses_id3v2_lyrics(file_type) {
+        return _write_id3v2_lyrics(&path, file_type, lyrics);
+    }
+
+    let mut tagged_file = lofty::read_from_path(&path).map_err(|e| e.to_string())?;
+    let tag = _tag_for_write(&mut tagged_file)?;
+    let unsynced_key = ItemKey::Unknown(UNSYNCED_LYRICS_KEY.to_string());
+    tag.remove_key(&ItemKey::Lyrics);
+    tag.remove_key(&unsynced_key);
+
+    let is_vorbis = tag.tag_type() == TagType::VorbisComments;
+    for embedded in lyrics {
+        let key = match embedded.kind {
+            EmbeddedLyricKind::Unsynced if is_vorbis => unsynced_key.clone(),
+            _ => ItemKey::Lyrics,
+        };
+        let item = TagItem::new(key, ItemValue::Text(embedded.text));
+        if is_vorbis {
+            // UNSYNCEDLYRICS 没有对应的 ItemKey，push 会拒绝
+            tag.push_unchecked(item);
+        } else {
+            tag.push(item);
+        }
+    }
+
+    tagged_file
+        .save_to_path(&path, WriteOptions::default())
+        .map_err(|e| e.to_string())
+}
+
 /// for Flutter  
 /// 扫描给定路径下所有子文件夹（包括自己）的音乐文件并把索引保存在 index_path/index.json。
 pub fn build_index_from_folders_recursively(
@@ -996,39 +2183,44 @@ pub fn update_index(index_path: String, sink: StreamSink<IndexActionState>) -> a
 
         // 删除访问不到的文件的记录
         let audios = folder_item["audios"].as_array_mut().unwrap();
-        audios.retain(|item| {
-            let path = item["path"].as_str().unwrap();
-
-            Path::new(path).exists()
-        });
-
-        for audio_item in &mut *audios {
+        audios.retain(|item| Path::new(_indexed_file_path(item)).exists());
+
+        // 同一个音乐文件的 CUE 虚拟音轨只重新读取一次
+        let mut refreshed: HashSet<String> = HashSet::new();
+        let mut updated_audios: Vec<serde_json::Value> = Vec::with_capacity(audios.len());
+        for audio_item in audios.drain(..) {
+            let file_path = _indexed_file_path(&audio_item).to_string();
+            if refreshed.contains(&file_path) {
+                continue;
+            }
             let old_audio_modified = audio_item["modified"].as_u64().unwrap();
-            let audio_path = audio_item["path"].as_str().unwrap();
-            let new_audio_modified = match fs::metadata(&audio_path) {
-                Ok(value) => match value.modified() {
-                    Ok(value) => value
-                        .duration_since(UNIX_EPOCH)
-                        .unwrap_or(Duration::ZERO)
-                        .as_secs(),
-                    Err(_) => continue,
-                },
-                Err(_) => continue,
-            };
+            // 外挂 .cue 被删除时也重新读取
+            let new_audio_modified = _indexed_audio_modified(&audio_item).unwrap_or(u64::MAX);
             // 跳过没有被修改的文件
             if new_audio_modified <= old_audio_modified {
+                updated_audios.push(audio_item);
                 continue;
             }
 
             // 重新读取被修改的音乐文件的标签并更新
-            if let Some(modified_audio) = Audio::read_from_path(Path::new(audio_path)) {
-                *audio_item = modified_audio.to_json_value();
+            match Audio::read_from_path(Path::new(&file_path)) {
+                Some(modified_audio) => {
+                    refreshed.insert(file_path);
+                    updated_audios.extend(
+                        _apply_cue_sheets(Path::new(&folder_path), vec![modified_audio])
+                            .iter()
+                            .map(Audio::to_json_value),
+                    );
+                }
+                None => updated_audios.push(audio_item),
             }
         }
+        *audios = updated_audios;
 
         // 添加新增的音乐文件
         let mut new_latest: u64 = latest;
-        let dir = match fs::read_dir(folder_path) {
+        let mut new_sheets: Vec<PathBuf> = vec![];
+        let dir = match fs::read_dir(&folder_path) {
             Ok(value) => value,
             Err(_) => continue,
         };
@@ -1056,12 +2248,53 @@ pub fn update_index(index_path: String, sink: StreamSink<IndexActionState>) -> a
                 Err(_) => continue,
             };
             if entry_created > latest {
-                if let Some(new_audio) = Audio::read_from_path(entry.path()) {
+                let entry_path = entry.path();
+                if entry_path
+                    .extension()
+                    .is_some_and(|extension| extension.eq_ignore_ascii_case("cue"))
+                {
+                    new_sheets.push(entry_path);
+                } else if let Some(new_audio) = Audio::read_from_path(&entry_path) {
                     if entry_created > new_latest {
                         new_latest = entry_created;
                     }
 
-                    audios.push(new_audio.to_json_value());
+                    audios.extend(
+                        _apply_cue_sheets(Path::new(&folder_path), vec![new_audio])
+                            .iter()
+                            .map(Audio::to_json_value),
+                    );
+                }
+            }
+        }
+
+        // 新增的 .cue 文件对应已经索引的音乐文件时，重新读取并拆分
+        for sheet_path in new_sheets {
+            let Some(sheet) = cue::read(&sheet_path) else {
+                continue;
+            };
+            let cue_stem = sheet_path.file_stem().map(|stem| stem.to_string_lossy());
+            let matched: Vec<String> = audios
+                .iter()
+                .filter(|item| item["cue"].is_null())
+                .filter_map(|item| item["path"].as_str())
+                .filter(|path| {
+                    Path::new(path).file_name().is_some_and(|name| {
+                        !sheet
+                            .ranges_for(&name.to_string_lossy(), cue_stem.as_deref())
+                            .is_empty()
+                    })
+                })
+                .map(|path| path.to_string())
+                .collect();
+            for path in matched {
+                if let Some(audio) = Audio::read_from_path(Path::new(&path)) {
+                    audios.retain(|item| item["path"].as_str() != Some(path.as_str()));
+                    audios.extend(
+                        _apply_cue_sheets(Path::new(&folder_path), vec![audio])
+                            .iter()
+                            .map(Audio::to_json_value),
+                    );
                 }
             }
         }
@@ -1083,3 +2316,363 @@ pub fn update_index(index_path: String, sink: StreamSink<IndexActionState>) -> a
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_folder_cover_by_default_names() {
+        let base = std::env::temp_dir().join(format!(
+            "coriander_player_folder_cover_test_{}",
+            std::process::id()
+        ));
+        fs::create_dir_all(&base).unwrap();
+        let audio = base.join("01.flac").to_string_lossy().to_string();
+
+        fs::write(base.join("AlbumArtSmall.jpg"), [0u8; 4]).unwrap();
+        fs::write(base.join("AlbumArt_{0000}_Large.jpg"), [0u8; 16]).unwrap();
+        assert_eq!(
+            _find_folder_cover(&audio),
+            Some(base.join("AlbumArt_{0000}_Large.jpg"))
+        );
+
+        fs::write(base.join("Folder.JPG"), [0u8; 4]).unwrap();
+        assert_eq!(_find_folder_cover(&audio), Some(base.join("Folder.JPG")));
+
+        fs::write(base.join("COVER.png"), [0u8; 4]).unwrap();
+        assert_eq!(_find_folder_cover(&audio), Some(base.join("COVER.png")));
+
+        let _ = fs::remove_dir_all(base);
+    }
+
+    #[test]
+    fn repair_only_latin1_id3_frames() {
+        use lofty::id3::v2::TextInformationFrame;
+
+        let mojibake: String = encoding_rs::GBK
+            .encode("周杰伦 - 七里香")
+            .0
+            .iter()
+            .map(|b| *b as char)
+            .collect();
+        let mut id3v2 = Id3v2Tag::new();
+        for (id, encoding) in [
+            ("TIT2", TextEncoding::Latin1),
+            ("TALB", TextEncoding::UTF16),
+        ] {
+            id3v2.insert(Frame::Text(TextInformationFrame::new(
+                FrameId::Valid(Cow::Borrowed(id)),
+                encoding,
+                mojibake.clone(),
+            )));
+        }
+        let mut data = vec![];
+        id3v2.dump_to(&mut data, WriteOptions::default()).unwrap();
+
+        let mut tags = NativeTags::default();
+        _read_id3v2_bytes(&data, &mut tags);
+        assert_eq!(tags.title.as_deref(), Some("周杰伦 - 七里香"));
+        // UTF-16 的帧本来就是正确的文本
+        assert_eq!(tags.album, Some(mojibake));
+    }
+
+    #[test]
+    fn encrypted_sidecar_lyrics() {
+        let base = std::env::temp_dir().join(format!(
+            "coriander_player_encrypted_lyric_test_{}",
+            std::process::id()
+        ));
+        fs::create_dir_all(&base).unwrap();
+
+        let lyric_of = |name: &str| {
+            get_encrypted_lyric_from_path(base.join(name).to_string_lossy().to_string())
+        };
+
+        fs::write(
+            base.join("kugou.krc"),
+            include_bytes!("../../fixtures/lyric/sample.krc"),
+        )
+        .unwrap();
+        let krc = lyric_of("kugou.mp3").unwrap();
+        assert_eq!(krc.format, lyric::LyricFormat::Krc);
+        assert_eq!(krc.lines[0].text, "Hello");
+        assert_eq!(krc.lines[0].translation.as_deref(), Some("翻译一"));
+
+        fs::write(
+            base.join("qqmusic.qrc"),
+            include_bytes!("../../fixtures/lyric/sample.qrc"),
+        )
+        .unwrap();
+        let qrc = lyric_of("qqmusic.flac").unwrap();
+        assert_eq!(qrc.format, lyric::LyricFormat::Qrc);
+        assert_eq!(qrc.lines[1].words[0].text, "World");
+
+        // 已经解密的文本
+        fs::write(base.join("plain.qrc"), "[1000,500]Hi(1000,500)").unwrap();
+        assert_eq!(lyric_of("plain.mp3").unwrap().lines[0].text, "Hi");
+
+        assert!(lyric_of("none.mp3").is_none());
+        let _ = fs::remove_dir_all(base);
+    }
+
+    #[test]
+    fn find_lyric_candidates_in_folders() {
+        let base = std::env::temp_dir().join(format!(
+            "coriander_player_lyric_candidates_test_{}",
+            std::process::id()
+        ));
+        let global = base.join("global");
+        fs::create_dir_all(base.join("album").join("Lyrics")).unwrap();
+        fs::create_dir_all(&global).unwrap();
+        let audio = base.join("album").join("01 Song.flac");
+
+        fs::write(base.join("album").join("01 song.SRT"), "").unwrap();
+        fs::write(base.join("album").join("01 Song.lrc"), "").unwrap();
+        fs::write(base.join("album").join("01 Song.jpg"), "").unwrap();
+        fs::write(base.join("album").join("Lyrics").join("01 SONG.krc"), "").unwrap();
+        fs::write(global.join("singer a - song.LRC"), "").unwrap();
+        fs::write(global.join("Other - Song.lrc"), "").unwrap();
+
+        set_lyric_search_options(vec![], vec![], Some(global.to_string_lossy().to_string()));
+        let candidates = _find_lyric_candidates(
+            &audio.to_string_lossy(),
+            || Some(("Singer A/Singer B".to_string(), "Song".to_string())),
+            true,
+        );
+        set_lyric_search_options(vec![], vec![], None);
+
+        let found: Vec<(LyricCandidateSource, Option<String>)> = candidates
+            .into_iter()
+            .map(|candidate| {
+                let name = candidate.path.map(|path| {
+                    let file_name = Path::new(&path).file_name().unwrap();
+                    file_name.to_string_lossy().to_string()
+                });
+                (candidate.source, name)
+            })
+            .collect();
+        let expected = [
+            (LyricCandidateSource::Embedded, None),
+            (LyricCandidateSource::SameFolder, Some("01 Song.lrc")),
+            (LyricCandidateSource::SameFolder, Some("01 song.SRT")),
+            (LyricCandidateSource::Subfolder, Some("01 SONG.krc")),
+            (
+                LyricCandidateSource::GlobalFolder,
+                Some("singer a - song.LRC"),
+            ),
+        ];
+        assert_eq!(
+            found,
+            expected.map(|(source, name)| (source, name.map(|name| name.to_string())))
+        );
+
+        let _ = fs::remove_dir_all(base);
+    }
+
+    #[test]
+    fn fit_picture_without_upscaling() {
+        let picture = DynamicImage::new_rgb8(400, 200);
+        let fit = |width, height, fit| {
+            let options = PictureOptions {
+                fit,
+                ..PictureOptions::with_size(width, height)
+            };
+            let fitted = _fit_picture(picture.clone(), &options);
+            (fitted.width(), fitted.height())
+        };
+
+        assert_eq!(fit(100, 100, PictureFit::Contain), (100, 50));
+        assert_eq!(fit(100, 100, PictureFit::Cover), (100, 100));
+        assert_eq!(fit(300, 100, PictureFit::CropSquare), (100, 100));
+        assert_eq!(fit(1000, 1000, PictureFit::Contain), (400, 200));
+        assert_eq!(fit(1000, 1000, PictureFit::CropSquare), (200, 200));
+
+        let options = PictureOptions {
+            device_pixel_ratio: 2.0,
+            ..PictureOptions::with_size(100, 100)
+        };
+        let fitted = _fit_picture(picture.clone(), &options);
+        assert_eq!((fitted.width(), fitted.height()), (200, 100));
+    }
+
+    /// 三个 MPEG-1 Layer III, 128 kbps, 44.1 kHz 的静音帧
+    fn _silent_mp3() -> Vec<u8> {
+        let mut frame = vec![0u8; 417];
+        frame[..4].copy_from_slice(&[0xFF, 0xFB, 0x90, 0x64]);
+        frame.repeat(3)
+    }
+
+    /// 只有 STREAMINFO 和 PADDING 的 FLAC
+    fn _empty_flac() -> Vec<u8> {
+        let mut flac = b"fLaC".to_vec();
+        flac.extend([0x00, 0, 0, 34]);
+        flac.extend([0x10, 0x00, 0x10, 0x00]); // block size 4096
+        flac.extend([0; 6]); // frame size
+        flac.extend([0x0A, 0xC4, 0x42, 0xF0, 0, 0, 0, 0]); // 44100 Hz, stereo, 16 bit
+        flac.extend([0; 16]); // MD5
+        flac.extend([0x81, 0, 0, 16]);
+        flac.extend([0; 16]);
+        flac
+    }
+
+    #[test]
+    fn embedded_lyrics_roundtrip() {
+        let base = std::env::temp_dir().join(format!(
+            "coriander_player_embedded_lyric_test_{}",
+            std::process::id()
+        ));
+        fs::create_dir_all(&base).unwrap();
+
+        let lyrics = vec![
+            EmbeddedLyric {
+                kind: EmbeddedLyricKind::Synced,
+                language: Some("jpn".to_string()),
+                description: String::new(),
+                text: "[00:01.000]<00:01.000>こん<00:01.500>にちは<00:02.000>\n[00:03.000]<00:03.000>さよなら<00:04.000>\n".to_string(),
+            },
+            EmbeddedLyric {
+                kind: EmbeddedLyricKind::Unsynced,
+                language: Some("jpn".to_string()),
+                description: String::new(),
+                text: "こんにちは\nさよなら".to_string(),
+            },
+            EmbeddedLyric {
+                kind: EmbeddedLyricKind::Unsynced,
+                language: Some("chi".to_string()),
+                description: "translation".to_string(),
+                text: "你好\n再见".to_string(),
+            },
+        ];
+
+        let mp3 = base.join("song.mp3").to_string_lossy().to_string();
+        fs::write(&mp3, _silent_mp3()).unwrap();
+        write_lyrics_to_path(mp3.clone(), lyrics.clone()).unwrap();
+        let read = get_embedded_lyrics_from_path(mp3.clone());
+        assert_eq!(read.len(), 3);
+        for expected in &lyrics[1..] {
+            assert!(read.contains(expected));
+        }
+        let synced = read
+            .iter()
+            .find(|lyric| lyric.kind == EmbeddedLyricKind::Synced)
+            .unwrap();
+        assert_eq!(synced.language.as_deref(), Some("jpn"));
+        assert_eq!(
+            lyric::parse_lrc(synced.text.clone()).unwrap().lines,
+            lyric::parse_lrc(lyrics[0].text.clone()).unwrap().lines
+        );
+
+        let flac = base.join("song.flac").to_string_lossy().to_string();
+        fs::write(&flac, _empty_flac()).unwrap();
+        write_lyrics_to_path(flac.clone(), lyrics[..2].to_vec()).unwrap();
+        let read = get_embedded_lyrics_from_path(flac.clone());
+        let kinds: Vec<_> = read.iter().map(|lyric| lyric.kind).collect();
+        assert_eq!(
+            kinds,
+            [EmbeddedLyricKind::Synced, EmbeddedLyricKind::Unsynced]
+        );
+        assert_eq!(read[1].text, lyrics[1].text);
+
+        // 无法解析的 ID3v2 标签不会被覆盖
+        let broken = base.join("broken.mp3").to_string_lossy().to_string();
+        let mut bytes = b"ID3\x05\x00\x00\x00\x00\x00\x10".to_vec();
+        bytes.extend([0u8; 16]);
+        bytes.extend(_silent_mp3());
+        fs::write(&broken, &bytes).unwrap();
+        assert!(write_lyrics_to_path(broken.clone(), lyrics[1..].to_vec()).is_err());
+        assert_eq!(fs::read(&broken).unwrap(), bytes);
+
+        let _ = fs::remove_dir_all(base);
+    }
+
+    #[test]
+    fn split_cue_tracks_from_sidecar_and_tag() {
+        let base =
+            std::env::temp_dir().join(format!("coriander_player_cue_test_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&base);
+        fs::create_dir_all(&base).unwrap();
+
+        fs::write(base.join("CDImage.flac"), _empty_flac()).unwrap();
+        let sheet = "PERFORMER \"歌手\"\nTITLE \"专辑\"\nFILE \"CDImage.wav\" WAVE\n\
+            TRACK 01 AUDIO\nTITLE \"第一首\"\nINDEX 01 00:00:00\n\
+            TRACK 02 AUDIO\nTITLE \"第二首\"\nINDEX 01 04:00:00\n";
+        let (gbk, _, _) = encoding_rs::GBK.encode(sheet);
+        fs::write(base.join("CDImage.cue"), gbk).unwrap();
+
+        let rip = base.join("rip.flac");
+        fs::write(&rip, _empty_flac()).unwrap();
+        let mut tagged_file = lofty::read_from_path(&rip).unwrap();
+        _tag_for_write(&mut tagged_file)
+            .unwrap()
+            .push_unchecked(TagItem::new(
+                ItemKey::Unknown(CUE_SHEET_KEY.to_string()),
+                ItemValue::Text(
+                    "TRACK 01 AUDIO\nINDEX 01 00:00:00\nTRACK 02 AUDIO\nINDEX 01 01:00:00"
+                        .to_string(),
+                ),
+            ));
+        tagged_file
+            .save_to_path(&rip, WriteOptions::default())
+            .unwrap();
+
+        let audios = ["CDImage.flac", "rip.flac"]
+            .iter()
+            .filter_map(|name| Audio::read_by_lofty(base.join(name), 0, 0))
+            .collect();
+        let mut audios = _apply_cue_sheets(&base, audios);
+        audios.sort_by(|a, b| a.path.cmp(&b.path));
+        let paths: Vec<_> = audios.iter().map(|audio| audio.path.clone()).collect();
+        let image = base.join("CDImage.flac").to_string_lossy().to_string();
+        let rip = rip.to_string_lossy().to_string();
+        assert_eq!(
+            paths,
+            [
+                format!("{image}#1"),
+                format!("{image}#2"),
+                format!("{rip}#1"),
+                format!("{rip}#2")
+            ]
+        );
+
+        assert_eq!(audios[1].title, "第二首");
+        assert_eq!(audios[1].artist, "歌手");
+        assert_eq!(audios[1].album, "专辑");
+        let cue = audios[0].cue.as_ref().unwrap();
+        assert_eq!((cue.start, cue.end), (0, Some(240_000)));
+        assert!(cue.sheet.is_some());
+        assert!(audios[3].cue.as_ref().unwrap().sheet.is_none());
+        assert_eq!(cue_source_path(&audios[3].path), rip);
+
+        let json = audios[1].to_json_value();
+        assert_eq!(_indexed_file_path(&json), image);
+        assert!(_indexed_audio_modified(&json).is_some());
+
+        // 虚拟音轨按音轨标题查找外挂歌词，标签读写使用所在的音乐文件
+        fs::write(base.join("第二首.lrc"), "[00:01.00]第二首").unwrap();
+        fs::write(base.join("CDImage.lrc"), "[00:01.00]整轨").unwrap();
+        let same_folder: Vec<_> = _find_lyric_candidates(&audios[1].path, || None, false)
+            .into_iter()
+            .filter(|candidate| matches!(candidate.source, LyricCandidateSource::SameFolder))
+            .filter_map(|candidate| candidate.path)
+            .collect();
+        assert_eq!(
+            same_folder,
+            [base.join("第二首.lrc").to_string_lossy().to_string()]
+        );
+        write_lyric_to_path(audios[0].path.clone(), "[00:01.00]内嵌".to_string()).unwrap();
+        assert_eq!(
+            _get_lyric_from_lofty(&audios[1].path).as_deref(),
+            Some("[00:01.00]内嵌")
+        );
+        assert_eq!(
+            get_embedded_lyrics_from_path(audios[0].path.clone()).len(),
+            1
+        );
+        let extra: serde_json::Value =
+            serde_json::from_str(&read_audio_extra_metadata(audios[0].path.clone())).unwrap();
+        assert!(extra["file_size"].as_u64().unwrap() > 0);
+
+        let _ = fs::remove_dir_all(base);
+    }
+}
@@ -0,0 +1,97 @@
+use std::io::{Read, Seek, SeekFrom};
+use std::time::Duration;
+
+use super::NativeTags;
+
+/// 在文件开头查找同步字的范围，跳过 ID3 等前导数据
+const SYNC_SEARCH_LEN: u64 = 64 * 1024;
+
+/// frmsizecod / 2 -> kbps
+const AC3_BITRATES: [u32; 19] = [
+    32, 40, 48, 56, 64, 80, 96, 112, 128, 160, 192, 224, 256, 320, 384, 448, 512, 576, 640,
+];
+const SAMPLE_RATES: [u32; 3] = [48000, 44100, 32000];
+/// numblkscod -> 每帧的音频块数，每块 256 个采样
+const EAC3_BLOCKS: [u32; 4] = [1, 2, 3, 6];
+
+/// (采样率, kbps)
+fn _parse_frame_header(header: &[u8]) -> Option<(u32, u32)> {
+    let bsid = header[5] >> 3;
+    if bsid <= 10 {
+        // AC-3
+        let fscod = (header[4] >> 6) as usize;
+        let frmsizecod = (header[4] & 0x3F) as usize;
+        Some((
+            *SAMPLE_RATES.get(fscod)?,
+            *AC3_BITRATES.get(frmsizecod / 2)?,
+        ))
+    } else if bsid <= 16 {
+        // E-AC-3：按帧长度计算码率
+        let frame_bytes = ((((header[2] & 0x07) as u32) << 8 | header[3] as u32) + 1) * 2;
+        let fscod = (header[4] >> 6) as usize;
+        let (sample_rate, blocks) = if fscod == 3 {
+            let fscod2 = ((header[4] >> 4) & 0x03) as usize;
+            (*SAMPLE_RATES.get(fscod2)? / 2, 6)
+        } else {
+            (
+                SAMPLE_RATES[fscod],
+                EAC3_BLOCKS[((header[4] >> 4) & 0x03) as usize],
+            )
+        };
+        let bitrate = frame_bytes as u64 * 8 * sample_rate as u64 / (blocks as u64 * 256) / 1000;
+        Some((sample_rate, bitrate as u32))
+    } else {
+        None
+    }
+}
+
+/// .ac3（包括 E-AC-3）。按第一帧的码率和文件长度计算时长
+pub(super) fn read(reader: &mut (impl Read + Seek)) -> Option<NativeTags> {
+    reader.seek(SeekFrom::Start(0)).ok()?;
+    let mut data = vec![];
+    reader
+        .by_ref()
+        .take(SYNC_SEARCH_LEN)
+        .read_to_end(&mut data)
+        .ok()?;
+    let len = reader.seek(SeekFrom::End(0)).ok()?;
+
+    let (offset, (sample_rate, bitrate)) =
+        data.windows(6).enumerate().find_map(|(i, header)| {
+            (header[0] == 0x0B && header[1] == 0x77)
+                .then(|| _parse_frame_header(header))
+                .flatten()
+                .map(|properties| (i, properties))
+        })?;
+    let audio_bytes = len - offset as u64;
+    Some(NativeTags {
+        duration: Duration::from_millis(audio_bytes * 8 / bitrate.max(1) as u64),
+        bitrate: Some(bitrate),
+        sample_rate: Some(sample_rate),
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_ac3_and_eac3() {
+        // 48kHz, 192 kbps
+        let mut file = b"junk".to_vec();
+        file.extend([0x0B, 0x77, 0, 0, 0x14, 0x40]);
+        file.resize(4 + 192_000 / 8 * 10, 0);
+        let tags = read(&mut std::io::Cursor::new(file)).unwrap();
+        assert_eq!(tags.sample_rate, Some(48000));
+        assert_eq!(tags.bitrate, Some(192));
+        assert_eq!(tags.duration, Duration::from_secs(10));
+
+        // E-AC-3：48kHz, 6 块, 帧长 768 字节 -> 192 kbps
+        let mut file = vec![0x0B, 0x77, 0x01, 0x7F, 0x30, 0x80];
+        file.resize(192_000 / 8 * 2, 0);
+        let tags = read(&mut std::io::Cursor::new(file)).unwrap();
+        assert_eq!(tags.bitrate, Some(192));
+        assert_eq!(tags.duration, Duration::from_secs(2));
+    }
+}
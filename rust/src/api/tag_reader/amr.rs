@@ -0,0 +1,80 @@
+use std::io::{BufReader, Read, Seek, SeekFrom};
+use std::time::Duration;
+
+use super::NativeTags;
+
+const AMR_NB_MAGIC: &[u8] = b"#!AMR\n";
+const AMR_WB_MAGIC: &[u8] = b"#!AMR-WB\n";
+
+/// 按帧类型的帧长度（不含 1 字节帧头）。0 表示无数据（NO_DATA 等）
+const AMR_NB_FRAME_SIZES: [usize; 16] = [12, 13, 15, 17, 19, 20, 26, 31, 5, 0, 0, 0, 0, 0, 0, 0];
+const AMR_WB_FRAME_SIZES: [usize; 16] = [17, 23, 32, 36, 40, 46, 50, 58, 60, 5, 0, 0, 0, 0, 0, 0];
+
+/// 每帧 20ms
+const FRAME_MS: u64 = 20;
+
+/// .amr, .3ga（单声道 AMR 存储格式）。数帧得到时长
+pub(super) fn read(reader: &mut (impl Read + Seek)) -> Option<NativeTags> {
+    reader.seek(SeekFrom::Start(0)).ok()?;
+    let mut reader = BufReader::new(reader);
+    let mut magic = [0u8; 9];
+    reader.read_exact(&mut magic[..6]).ok()?;
+    let (frame_sizes, sample_rate) = if magic[..6] == *AMR_NB_MAGIC {
+        (AMR_NB_FRAME_SIZES, 8000)
+    } else {
+        reader.read_exact(&mut magic[6..]).ok()?;
+        if magic != *AMR_WB_MAGIC {
+            return None;
+        }
+        (AMR_WB_FRAME_SIZES, 16000)
+    };
+
+    let mut frames = 0u64;
+    let mut bytes = 0u64;
+    let mut header = [0u8; 1];
+    while reader.read_exact(&mut header).is_ok() {
+        let size = frame_sizes[((header[0] >> 3) & 0x0F) as usize];
+        if reader.seek_relative(size as i64).is_err() {
+            break;
+        }
+        frames += 1;
+        bytes += 1 + size as u64;
+    }
+
+    let duration = Duration::from_millis(frames * FRAME_MS);
+    Some(NativeTags {
+        duration,
+        bitrate: super::_average_bitrate(bytes, duration),
+        sample_rate: Some(sample_rate),
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn count_amr_frames() {
+        let mut file = AMR_NB_MAGIC.to_vec();
+        for _ in 0..250 {
+            // 12.2 kbps
+            file.push(7 << 3 | 0x04);
+            file.extend([0u8; 31]);
+        }
+        // SID
+        file.push(8 << 3 | 0x04);
+        file.extend([0u8; 5]);
+        let tags = read(&mut std::io::Cursor::new(file)).unwrap();
+        assert_eq!(tags.duration, Duration::from_millis(251 * 20));
+        assert_eq!(tags.sample_rate, Some(8000));
+
+        let mut file = AMR_WB_MAGIC.to_vec();
+        file.push(15 << 3);
+        let tags = read(&mut std::io::Cursor::new(file)).unwrap();
+        assert_eq!(tags.duration, Duration::from_millis(20));
+        assert_eq!(tags.sample_rate, Some(16000));
+
+        assert!(read(&mut std::io::Cursor::new(b"ftyp3gp4".to_vec())).is_none());
+    }
+}
@@ -0,0 +1,258 @@
+use std::io::{Read, Seek, SeekFrom};
+use std::time::Duration;
+
+use super::NativeTags;
+
+const HEADER_OBJECT: [u8; 16] = [
+    0x30, 0x26, 0xB2, 0x75, 0x8E, 0x66, 0xCF, 0x11, 0xA6, 0xD9, 0x00, 0xAA, 0x00, 0x62, 0xCE, 0x6C,
+];
+const FILE_PROPERTIES_OBJECT: [u8; 16] = [
+    0xA1, 0xDC, 0xAB, 0x8C, 0x47, 0xA9, 0xCF, 0x11, 0x8E, 0xE4, 0x00, 0xC0, 0x0C, 0x20, 0x53, 0x65,
+];
+const STREAM_PROPERTIES_OBJECT: [u8; 16] = [
+    0x91, 0x07, 0xDC, 0xB7, 0xB7, 0xA9, 0xCF, 0x11, 0x8E, 0xE6, 0x00, 0xC0, 0x0C, 0x20, 0x53, 0x65,
+];
+const CONTENT_DESCRIPTION_OBJECT: [u8; 16] = [
+    0x33, 0x26, 0xB2, 0x75, 0x8E, 0x66, 0xCF, 0x11, 0xA6, 0xD9, 0x00, 0xAA, 0x00, 0x62, 0xCE, 0x6C,
+];
+const EXTENDED_CONTENT_DESCRIPTION_OBJECT: [u8; 16] = [
+    0x40, 0xA4, 0xD0, 0xD2, 0x07, 0xE3, 0xD2, 0x11, 0x97, 0xF0, 0x00, 0xA0, 0xC9, 0x5E, 0xA8, 0x50,
+];
+const AUDIO_MEDIA: [u8; 16] = [
+    0x40, 0x9E, 0x69, 0xF8, 0x4D, 0x5B, 0xCF, 0x11, 0xA8, 0xFD, 0x00, 0x80, 0x5F, 0x5C, 0x44, 0x2B,
+];
+
+/// 头部对象的大小上限，避免读取损坏文件时分配过多内存
+const MAX_HEADER_SIZE: u64 = 64 * 1024 * 1024;
+
+fn _u16(data: &[u8], at: usize) -> Option<u16> {
+    Some(u16::from_le_bytes(data.get(at..at + 2)?.try_into().ok()?))
+}
+
+fn _u32(data: &[u8], at: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(data.get(at..at + 4)?.try_into().ok()?))
+}
+
+fn _u64(data: &[u8], at: usize) -> Option<u64> {
+    Some(u64::from_le_bytes(data.get(at..at + 8)?.try_into().ok()?))
+}
+
+/// UTF-16 LE，去掉结尾的 \0
+fn _utf16(data: &[u8]) -> Option<String> {
+    let units: Vec<u16> = data
+        .chunks_exact(2)
+        .map(|unit| u16::from_le_bytes([unit[0], unit[1]]))
+        .collect();
+    let text = String::from_utf16_lossy(&units)
+        .trim_end_matches('\0')
+        .trim()
+        .to_string();
+    (!text.is_empty()).then_some(text)
+}
+
+fn _read_file_properties(data: &[u8], tags: &mut NativeTags) {
+    // play duration 和 send duration 的单位是 100ns，preroll 的单位是 ms
+    let (Some(play_duration), Some(preroll)) = (_u64(data, 40), _u64(data, 56)) else {
+        return;
+    };
+    let ms = (play_duration / 10_000).saturating_sub(preroll);
+    tags.duration = Duration::from_millis(ms);
+}
+
+fn _read_stream_properties(data: &[u8], tags: &mut NativeTags) {
+    if data.get(0..16) != Some(&AUDIO_MEDIA) {
+        return;
+    }
+    // WAVEFORMATEX
+    let format = &data[54.min(data.len())..];
+    if let Some(sample_rate) = _u32(format, 4) {
+        tags.sample_rate = Some(sample_rate);
+    }
+    if let Some(bytes_per_sec) = _u32(format, 8) {
+        tags.bitrate = Some(bytes_per_sec * 8 / 1000);
+    }
+}
+
+fn _read_content_description(data: &[u8], tags: &mut NativeTags) {
+    let Some(lengths) = (0..5)
+        .map(|i| _u16(data, i * 2).map(usize::from))
+        .collect::<Option<Vec<_>>>()
+    else {
+        return;
+    };
+    let mut at = 10;
+    let mut fields = vec![];
+    for len in lengths {
+        fields.push(data.get(at..at + len).and_then(_utf16));
+        at += len;
+    }
+    tags.title = fields[0].take();
+    tags.artist = fields[1].take();
+}
+
+/// WM/AlbumTitle 等扩展属性
+fn _read_extended_content_description(data: &[u8], tags: &mut NativeTags) {
+    let Some(count) = _u16(data, 0) else {
+        return;
+    };
+    let mut at = 2;
+    for _ in 0..count {
+        let Some(name_len) = _u16(data, at).map(usize::from) else {
+            return;
+        };
+        let name = data.get(at + 2..at + 2 + name_len).and_then(_utf16);
+        at += 2 + name_len;
+        let (Some(value_type), Some(value_len)) = (_u16(data, at), _u16(data, at + 2)) else {
+            return;
+        };
+        let Some(value) = data.get(at + 4..at + 4 + value_len as usize) else {
+            return;
+        };
+        at += 4 + value_len as usize;
+
+        let text = match value_type {
+            0 => _utf16(value),
+            3 => _u32(value, 0).map(|v| v.to_string()),
+            4 => _u64(value, 0).map(|v| v.to_string()),
+            5 => _u16(value, 0).map(|v| v.to_string()),
+            _ => None,
+        };
+        let Some(text) = text else {
+            continue;
+        };
+        match name.as_deref() {
+            Some("WM/AlbumTitle") => tags.album = Some(text),
+            Some("WM/AlbumArtist") => tags.album_artist = Some(text),
+            Some("WM/TrackNumber") => {
+                tags.track = text.split('/').next().and_then(|t| t.trim().parse().ok())
+            }
+            // 从 0 开始，只在没有 WM/TrackNumber 时使用
+            Some("WM/Track") if tags.track.is_none() => {
+                tags.track = text.trim().parse::<u32>().ok().map(|t| t + 1)
+            }
+            Some("Author") if tags.artist.is_none() => tags.artist = Some(text),
+            Some("Title") if tags.title.is_none() => tags.title = Some(text),
+            _ => {}
+        }
+    }
+}
+
+/// .wma, .asf。只读取头部对象
+pub(super) fn read(reader: &mut (impl Read + Seek)) -> Option<NativeTags> {
+    let mut header = [0u8; 30];
+    reader.seek(SeekFrom::Start(0)).ok()?;
+    reader.read_exact(&mut header).ok()?;
+    if header[0..16] != HEADER_OBJECT {
+        return None;
+    }
+    let size = _u64(&header, 16)?;
+    if !(30..=MAX_HEADER_SIZE).contains(&size) {
+        return None;
+    }
+    let mut data = vec![0u8; (size - 30) as usize];
+    reader.read_exact(&mut data).ok()?;
+
+    let mut tags = NativeTags::default();
+    let mut at = 0;
+    while let (Some(guid), Some(object_size)) = (data.get(at..at + 16), _u64(&data, at + 16)) {
+        let Some(end) = usize::try_from(object_size)
+            .ok()
+            .filter(|size| *size >= 24)
+            .and_then(|size| at.checked_add(size))
+            .filter(|end| *end <= data.len())
+        else {
+            break;
+        };
+        let body = &data[at + 24..end];
+        let guid: [u8; 16] = guid.try_into().ok()?;
+        match guid {
+            FILE_PROPERTIES_OBJECT => _read_file_properties(body, &mut tags),
+            STREAM_PROPERTIES_OBJECT if tags.sample_rate.is_none() => {
+                _read_stream_properties(body, &mut tags)
+            }
+            CONTENT_DESCRIPTION_OBJECT => _read_content_description(body, &mut tags),
+            EXTENDED_CONTENT_DESCRIPTION_OBJECT => {
+                _read_extended_content_description(body, &mut tags)
+            }
+            _ => {}
+        }
+        at = end;
+    }
+    Some(tags)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _utf16_bytes(text: &str) -> Vec<u8> {
+        text.encode_utf16()
+            .chain([0])
+            .flat_map(|unit| unit.to_le_bytes())
+            .collect()
+    }
+
+    fn _object(guid: [u8; 16], body: &[u8]) -> Vec<u8> {
+        let mut object = guid.to_vec();
+        object.extend((24 + body.len() as u64).to_le_bytes());
+        object.extend(body);
+        object
+    }
+
+    #[test]
+    fn read_asf_header() {
+        let mut file_properties = vec![0u8; 80];
+        file_properties[40..48].copy_from_slice(&(65_000u64 * 10_000).to_le_bytes());
+        file_properties[56..64].copy_from_slice(&5_000u64.to_le_bytes());
+
+        let mut stream_properties = AUDIO_MEDIA.to_vec();
+        stream_properties.extend([0u8; 38]);
+        stream_properties.extend([0x61, 0x01, 2, 0]); // WMA, stereo
+        stream_properties.extend(44_100u32.to_le_bytes());
+        stream_properties.extend(24_000u32.to_le_bytes());
+
+        let (title, artist) = (_utf16_bytes("标题"), _utf16_bytes("Artist"));
+        let mut content = vec![];
+        for len in [title.len(), artist.len(), 0, 0, 0] {
+            content.extend((len as u16).to_le_bytes());
+        }
+        content.extend(&title);
+        content.extend(&artist);
+
+        let mut extended = 2u16.to_le_bytes().to_vec();
+        let name = _utf16_bytes("WM/AlbumTitle");
+        let value = _utf16_bytes("Album");
+        extended.extend((name.len() as u16).to_le_bytes());
+        extended.extend(&name);
+        extended.extend([0, 0]);
+        extended.extend((value.len() as u16).to_le_bytes());
+        extended.extend(&value);
+        let name = _utf16_bytes("WM/TrackNumber");
+        extended.extend((name.len() as u16).to_le_bytes());
+        extended.extend(&name);
+        extended.extend([3, 0, 4, 0]);
+        extended.extend(7u32.to_le_bytes());
+
+        let objects = [
+            _object(FILE_PROPERTIES_OBJECT, &file_properties),
+            _object(STREAM_PROPERTIES_OBJECT, &stream_properties),
+            _object(CONTENT_DESCRIPTION_OBJECT, &content),
+            _object(EXTENDED_CONTENT_DESCRIPTION_OBJECT, &extended),
+        ]
+        .concat();
+        let mut file = HEADER_OBJECT.to_vec();
+        file.extend((30 + objects.len() as u64).to_le_bytes());
+        file.extend(4u32.to_le_bytes());
+        file.extend([1, 2]);
+        file.extend(objects);
+
+        let tags = read(&mut std::io::Cursor::new(file)).unwrap();
+        assert_eq!(tags.title.as_deref(), Some("标题"));
+        assert_eq!(tags.artist.as_deref(), Some("Artist"));
+        assert_eq!(tags.album.as_deref(), Some("Album"));
+        assert_eq!(tags.track, Some(7));
+        assert_eq!(tags.duration, Duration::from_secs(60));
+        assert_eq!(tags.sample_rate, Some(44_100));
+        assert_eq!(tags.bitrate, Some(192));
+    }
+}
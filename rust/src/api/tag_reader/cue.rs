@@ -0,0 +1,285 @@
+use std::path::Path;
+
+use flutter_rust_bridge::frb;
+
+use super::super::text_encoding;
+
+#[frb(ignore)]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(super) struct CueSheet {
+    pub(super) title: Option<String>,
+    pub(super) performer: Option<String>,
+    pub(super) files: Vec<CueFile>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct CueFile {
+    /// FILE 中的文件名，可能是转换前的 .wav
+    pub(super) name: String,
+    pub(super) tracks: Vec<CueTrack>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct CueTrack {
+    pub(super) number: u32,
+    pub(super) title: Option<String>,
+    pub(super) performer: Option<String>,
+    /// INDEX 00（pregap 开始），ms
+    pub(super) pregap: Option<u64>,
+    /// INDEX 01，ms
+    pub(super) start: u64,
+}
+
+/// 一个虚拟音轨在文件中的范围
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct CueRange {
+    pub(super) track: CueTrack,
+    /// ms，最后一轨为 None，到文件结尾
+    pub(super) end: Option<u64>,
+}
+
+/// mm:ss:ff，一秒 75 帧 -> ms
+fn _parse_msf(time: &str) -> Option<u64> {
+    let mut parts = time.split(':');
+    let minutes: u64 = parts.next()?.parse().ok()?;
+    let seconds: u64 = parts.next()?.parse().ok()?;
+    let frames: u64 = parts.next()?.parse().ok()?;
+    if parts.next().is_some() || seconds >= 60 || frames >= 75 {
+        return None;
+    }
+    Some((minutes * 60 + seconds) * 1000 + frames * 1000 / 75)
+}
+
+/// 命令的参数：带引号的取引号中的内容，否则取到行尾
+fn _unquote(arg: &str) -> String {
+    let arg = arg.trim();
+    match arg.strip_prefix('"') {
+        Some(rest) => match rest.find('"') {
+            Some(end) => rest[..end].to_string(),
+            None => rest.to_string(),
+        },
+        None => arg.to_string(),
+    }
+}
+
+/// FILE "name" WAVE 中的 name
+fn _file_name(arg: &str) -> String {
+    let arg = arg.trim();
+    if arg.starts_with('"') {
+        return _unquote(arg);
+    }
+    match arg.rsplit_once(char::is_whitespace) {
+        Some((name, _file_type)) => name.trim().to_string(),
+        None => arg.to_string(),
+    }
+}
+
+fn _non_empty(text: String) -> Option<String> {
+    (!text.is_empty()).then_some(text)
+}
+
+/// 没有 INDEX 01 的音轨被丢弃。没有 FILE 的音轨（内嵌 CUESHEET 中常见）放在名称为空的文件中
+pub(super) fn parse(text: &str) -> Option<CueSheet> {
+    let mut sheet = CueSheet::default();
+    let mut track: Option<CueTrack> = None;
+    let mut has_start = false;
+    // 序号无法解析的 TRACK 和它的 TITLE, PERFORMER 被跳过
+    let mut skipping = false;
+
+    let finish_track =
+        |sheet: &mut CueSheet, track: &mut Option<CueTrack>, has_start: &mut bool| {
+            if let Some(track) = track.take() {
+                if *has_start {
+                    if sheet.files.is_empty() {
+                        sheet.files.push(CueFile {
+                            name: String::new(),
+                            tracks: vec![],
+                        });
+                    }
+                    sheet.files.last_mut().unwrap().tracks.push(track);
+                }
+            }
+            *has_start = false;
+        };
+
+    for line in text.trim_start_matches('\u{feff}').lines() {
+        let line = line.trim();
+        let (command, arg) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        match command.to_ascii_uppercase().as_str() {
+            "FILE" => {
+                finish_track(&mut sheet, &mut track, &mut has_start);
+                sheet.files.push(CueFile {
+                    name: _file_name(arg),
+                    tracks: vec![],
+                });
+            }
+            "TRACK" => {
+                finish_track(&mut sheet, &mut track, &mut has_start);
+                let Some(number) = arg.split_whitespace().next().and_then(|n| n.parse().ok())
+                else {
+                    skipping = true;
+                    continue;
+                };
+                skipping = false;
+                track = Some(CueTrack {
+                    number,
+                    title: None,
+                    performer: None,
+                    pregap: None,
+                    start: 0,
+                });
+            }
+            "TITLE" | "PERFORMER" => {
+                let value = _non_empty(_unquote(arg));
+                let is_title = command.eq_ignore_ascii_case("TITLE");
+                match (&mut track, is_title) {
+                    (Some(track), true) => track.title = value,
+                    (Some(track), false) => track.performer = value,
+                    (None, _) if skipping => {}
+                    (None, true) => sheet.title = value,
+                    (None, false) => sheet.performer = value,
+                }
+            }
+            "INDEX" => {
+                let (Some(track), Some((index, time))) = (&mut track, arg.trim().split_once(' '))
+                else {
+                    continue;
+                };
+                let Some(time) = _parse_msf(time.trim()) else {
+                    continue;
+                };
+                match index.parse::<u32>() {
+                    Ok(0) => track.pregap = Some(time),
+                    Ok(1) => {
+                        track.start = time;
+                        has_start = true;
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+    finish_track(&mut sheet, &mut track, &mut has_start);
+
+    sheet.files.retain(|file| !file.tracks.is_empty());
+    (!sheet.files.is_empty()).then_some(sheet)
+}
+
+/// 外挂 .cue 文件，自动识别编码（常见 GBK, Shift-JIS）
+pub(super) fn read(path: &Path) -> Option<CueSheet> {
+    let bytes = std::fs::read(path).ok()?;
+    parse(&text_encoding::decode_bytes(&bytes).text)
+}
+
+impl CueSheet {
+    /// FILE 对应音乐文件 file_name 的音轨。先比较完整文件名，再比较去掉扩展名的文件名
+    /// （FILE 常常是转换前的 .wav）；只有一个 FILE 时 cue_stem 和音乐文件名相同也算对应
+    pub(super) fn ranges_for(&self, file_name: &str, cue_stem: Option<&str>) -> Vec<CueRange> {
+        let stem = |name: &str| -> String {
+            let name = name.rsplit(['/', '\\']).next().unwrap_or(name);
+            Path::new(name)
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_lowercase())
+                .unwrap_or_default()
+        };
+        let base_name = |name: &str| {
+            name.rsplit(['/', '\\'])
+                .next()
+                .unwrap_or(name)
+                .to_lowercase()
+        };
+        let file_name_lower = file_name.to_lowercase();
+        let file_stem = stem(file_name);
+
+        let file = self
+            .files
+            .iter()
+            .find(|file| base_name(&file.name) == file_name_lower)
+            .or_else(|| self.files.iter().find(|file| stem(&file.name) == file_stem))
+            .or_else(|| match (&self.files[..], cue_stem) {
+                ([file], Some(cue_stem)) if cue_stem.to_lowercase() == file_stem => Some(file),
+                ([file], None) => Some(file),
+                _ => None,
+            });
+        let Some(file) = file else {
+            return vec![];
+        };
+
+        file.tracks
+            .iter()
+            .enumerate()
+            .map(|(i, track)| CueRange {
+                track: track.clone(),
+                end: file
+                    .tracks
+                    .get(i + 1)
+                    .map(|next| next.pregap.unwrap_or(next.start)),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHEET: &str = r#"REM GENRE Pop
+PERFORMER "Album Artist"
+TITLE "Album"
+FILE "Album.wav" WAVE
+  TRACK 01 AUDIO
+    TITLE "One"
+    INDEX 01 00:00:00
+  TRACK 02 AUDIO
+    TITLE "Two"
+    PERFORMER "Guest"
+    INDEX 00 03:10:00
+    INDEX 01 03:12:37
+  TRACK 03 AUDIO
+    TITLE "Three"
+    INDEX 01 07:00:74
+"#;
+
+    #[test]
+    fn parse_tracks_and_ranges() {
+        let sheet = parse(SHEET).unwrap();
+        assert_eq!(sheet.title.as_deref(), Some("Album"));
+        assert_eq!(sheet.performer.as_deref(), Some("Album Artist"));
+
+        let ranges = sheet.ranges_for("Album.flac", None);
+        assert_eq!(ranges.len(), 3);
+        assert_eq!(ranges[0].end, Some(190_000));
+        assert_eq!(ranges[1].track.start, 192_493);
+        assert_eq!(ranges[1].track.performer.as_deref(), Some("Guest"));
+        assert_eq!(ranges[1].end, Some(420_986));
+        assert_eq!(ranges[2].end, None);
+
+        assert!(sheet.ranges_for("Other.flac", Some("Album")).is_empty());
+        // .cue 和音乐文件同名时不比较 FILE
+        assert_eq!(sheet.ranges_for("Other.flac", Some("Other")).len(), 3);
+    }
+
+    #[test]
+    fn embedded_sheet_without_file() {
+        let sheet =
+            parse("TRACK 01 AUDIO\nINDEX 01 00:00:00\nTRACK 02 AUDIO\nINDEX 01 01:00:00").unwrap();
+        assert_eq!(sheet.ranges_for("rip.ape", None).len(), 2);
+        assert!(parse("REM COMMENT nothing").is_none());
+    }
+
+    #[test]
+    fn malformed_track_is_skipped() {
+        let sheet = parse(
+            "TITLE \"Album\"\nTRACK 01 AUDIO\nINDEX 01 00:00:00\n\
+             TRACK XX AUDIO\nTITLE \"Bad\"\nINDEX 01 01:00:00\n\
+             TRACK 03 AUDIO\nINDEX 01 02:00:00",
+        )
+        .unwrap();
+        assert_eq!(sheet.title.as_deref(), Some("Album"));
+        let ranges = sheet.ranges_for("rip.ape", None);
+        assert_eq!(ranges.len(), 2);
+        assert_eq!(ranges[1].track.number, 3);
+        assert_eq!(ranges[0].end, Some(120_000));
+    }
+}
@@ -0,0 +1,303 @@
+use std::io::{Read, Seek, SeekFrom};
+use std::time::Duration;
+
+use super::super::text_encoding;
+use super::NativeTags;
+
+/// ID3v2 标签的大小上限
+const MAX_ID3_SIZE: u64 = 16 * 1024 * 1024;
+
+fn _u32_le(data: &[u8], at: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(data.get(at..at + 4)?.try_into().ok()?))
+}
+
+fn _u64_le(data: &[u8], at: usize) -> Option<u64> {
+    Some(u64::from_le_bytes(data.get(at..at + 8)?.try_into().ok()?))
+}
+
+fn _read_id3(reader: &mut (impl Read + Seek), at: u64, len: u64, tags: &mut NativeTags) {
+    if len == 0 || len > MAX_ID3_SIZE || reader.seek(SeekFrom::Start(at)).is_err() {
+        return;
+    }
+    let mut data = vec![];
+    if reader.take(len).read_to_end(&mut data).is_ok() {
+        super::_read_id3v2_bytes(&data, tags);
+    }
+}
+
+/// .dsf：DSD chunk, fmt chunk 和文件末尾的 ID3v2 标签
+pub(super) fn read_dsf(reader: &mut (impl Read + Seek)) -> Option<NativeTags> {
+    let mut header = [0u8; 28 + 52];
+    reader.seek(SeekFrom::Start(0)).ok()?;
+    reader.read_exact(&mut header).ok()?;
+    if &header[0..4] != b"DSD " || &header[28..32] != b"fmt " {
+        return None;
+    }
+    let file_size = _u64_le(&header, 12)?;
+    let metadata = _u64_le(&header, 20)?;
+    let fmt = &header[28..];
+    let channels = _u32_le(fmt, 24)?;
+    let sample_rate = _u32_le(fmt, 28)?;
+    let sample_count = _u64_le(fmt, 36)?;
+
+    let mut tags = NativeTags {
+        sample_rate: Some(sample_rate),
+        bitrate: Some((sample_rate as u64 * channels as u64 / 1000) as u32),
+        ..Default::default()
+    };
+    if sample_rate > 0 {
+        tags.duration = Duration::try_from_secs_f64(sample_count as f64 / sample_rate as f64)
+            .unwrap_or_default();
+    }
+    if metadata > 0 {
+        _read_id3(
+            reader,
+            metadata,
+            file_size.saturating_sub(metadata),
+            &mut tags,
+        );
+    }
+    Some(tags)
+}
+
+fn _u16_be(data: &[u8], at: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(data.get(at..at + 2)?.try_into().ok()?))
+}
+
+fn _u32_be(data: &[u8], at: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(data.get(at..at + 4)?.try_into().ok()?))
+}
+
+/// 子块 (id, body)，块大小按 2 字节对齐
+fn _dff_chunks(data: &[u8]) -> Vec<([u8; 4], &[u8])> {
+    let mut chunks = vec![];
+    let mut at = 0;
+    while let Some(header) = data.get(at..at + 12) {
+        let id: [u8; 4] = header[0..4].try_into().unwrap();
+        let size = u64::from_be_bytes(header[4..12].try_into().unwrap());
+        let Some(body) = usize::try_from(size)
+            .ok()
+            .and_then(|size| data.get(at + 12..(at + 12).checked_add(size)?))
+        else {
+            break;
+        };
+        chunks.push((id, body));
+        at += 12 + body.len() + body.len() % 2;
+    }
+    chunks
+}
+
+/// DIIN 中的文字：u32 长度 + 文本
+fn _dff_text(data: &[u8]) -> Option<String> {
+    let len = _u32_be(data, 0)? as usize;
+    let text = text_encoding::decode_bytes(data.get(4..4 + len)?).text;
+    let text = text.trim_end_matches('\0').trim().to_string();
+    (!text.is_empty()).then_some(text)
+}
+
+/// .dff（DSDIFF）：PROP/SND 中的采样率和声道，DSD 或 DST 块的长度，DIIN 中的标题和艺术家。
+/// 不少软件在文件末尾写入非标准的 ID3 块，优先使用它
+pub(super) fn read_dff(reader: &mut (impl Read + Seek)) -> Option<NativeTags> {
+    let mut header = [0u8; 16];
+    reader.seek(SeekFrom::Start(0)).ok()?;
+    reader.read_exact(&mut header).ok()?;
+    if &header[0..4] != b"FRM8" || &header[12..16] != b"DSD " {
+        return None;
+    }
+
+    let mut tags = NativeTags::default();
+    let mut channels = 0u16;
+    let mut sound_bytes = None;
+    let mut dst_frames = None;
+    let mut id3 = None;
+    loop {
+        let mut chunk = [0u8; 12];
+        if reader.read_exact(&mut chunk).is_err() {
+            break;
+        }
+        let size = u64::from_be_bytes(chunk[4..12].try_into().unwrap());
+        let start = reader.stream_position().ok()?;
+        match &chunk[0..4] {
+            b"PROP" | b"DIIN" if size <= MAX_ID3_SIZE => {
+                let mut body = vec![];
+                reader.by_ref().take(size).read_to_end(&mut body).ok()?;
+                let sub_chunks = match &chunk[0..4] {
+                    // PROP 的前 4 字节是 "SND "
+                    b"PROP" => _dff_chunks(body.get(4..).unwrap_or_default()),
+                    _ => _dff_chunks(&body),
+                };
+                for (id, body) in sub_chunks {
+                    match &id {
+                        b"FS  " => tags.sample_rate = _u32_be(body, 0),
+                        b"CHNL" => channels = _u16_be(body, 0).unwrap_or(0),
+                        b"DITI" => tags.title = _dff_text(body),
+                        b"DIAR" => tags.artist = _dff_text(body),
+                        _ => {}
+                    }
+                }
+            }
+            // 只读取第一个子块 FRTE，不读取压缩数据
+            b"DST " => {
+                let mut frte = [0u8; 18];
+                if reader.read_exact(&mut frte).is_ok() && &frte[0..4] == b"FRTE" {
+                    dst_frames = _u32_be(&frte, 12).zip(_u16_be(&frte, 16));
+                }
+            }
+            b"DSD " => sound_bytes = Some(size),
+            b"ID3 " => id3 = Some((start, size)),
+            _ => {}
+        }
+        // 损坏的文件中 size 可能溢出，偏移不前进时也要停止
+        let Some(next) = start
+            .checked_add(size)
+            .and_then(|end| end.checked_add(size % 2))
+            .filter(|next| *next > start)
+        else {
+            break;
+        };
+        if reader.seek(SeekFrom::Start(next)).is_err() {
+            break;
+        }
+    }
+
+    let sample_rate = tags.sample_rate.unwrap_or(0) as u64;
+    if let Some((frames, rate)) = dst_frames.filter(|(_, rate)| *rate > 0) {
+        tags.duration =
+            Duration::try_from_secs_f64(frames as f64 / rate as f64).unwrap_or_default();
+    } else if let Some(bytes) = sound_bytes.filter(|_| sample_rate > 0 && channels > 0) {
+        tags.duration =
+            Duration::try_from_secs_f64(bytes as f64 * 8.0 / channels as f64 / sample_rate as f64)
+                .unwrap_or_default();
+    }
+    if channels > 0 {
+        tags.bitrate = Some((sample_rate * channels as u64 / 1000) as u32);
+    }
+    if let Some((at, len)) = id3 {
+        _read_id3(reader, at, len, &mut tags);
+    }
+    Some(tags)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lofty::config::WriteOptions;
+    use lofty::id3::v2::Id3v2Tag;
+    use lofty::prelude::{Accessor, TagExt};
+
+    fn _id3() -> Vec<u8> {
+        let mut tag = Id3v2Tag::new();
+        tag.set_title("DSD Song".to_string());
+        tag.set_artist("DSD Artist".to_string());
+        tag.set_album("DSD Album".to_string());
+        tag.set_track(5);
+        let mut data = vec![];
+        tag.dump_to(&mut data, WriteOptions::default()).unwrap();
+        data
+    }
+
+    #[test]
+    fn read_dsf_with_id3() {
+        let id3 = _id3();
+        let data_size = 12 + 4096u64;
+        let metadata = 28 + 52 + data_size;
+        let mut file = b"DSD ".to_vec();
+        file.extend(28u64.to_le_bytes());
+        file.extend((metadata + id3.len() as u64).to_le_bytes());
+        file.extend(metadata.to_le_bytes());
+        file.extend(b"fmt ");
+        file.extend(52u64.to_le_bytes());
+        for value in [1u32, 0, 2, 2, 2_822_400, 1] {
+            file.extend(value.to_le_bytes());
+        }
+        file.extend((2_822_400u64 * 90).to_le_bytes());
+        file.extend(4096u32.to_le_bytes());
+        file.extend([0u8; 4]);
+        file.extend(b"data");
+        file.extend(data_size.to_le_bytes());
+        file.extend([0x69u8; 4096]);
+        file.extend(&id3);
+
+        let tags = read_dsf(&mut std::io::Cursor::new(file)).unwrap();
+        assert_eq!(tags.title.as_deref(), Some("DSD Song"));
+        assert_eq!(tags.artist.as_deref(), Some("DSD Artist"));
+        assert_eq!(tags.album.as_deref(), Some("DSD Album"));
+        assert_eq!(tags.track, Some(5));
+        assert_eq!(tags.duration, Duration::from_secs(90));
+        assert_eq!(tags.sample_rate, Some(2_822_400));
+        assert_eq!(tags.bitrate, Some(5644));
+    }
+
+    fn _chunk(id: &[u8; 4], body: &[u8]) -> Vec<u8> {
+        let mut chunk = id.to_vec();
+        chunk.extend((body.len() as u64).to_be_bytes());
+        chunk.extend(body);
+        if body.len() % 2 == 1 {
+            chunk.push(0);
+        }
+        chunk
+    }
+
+    #[test]
+    fn read_dff_chunks() {
+        let mut prop = b"SND ".to_vec();
+        prop.extend(_chunk(b"FS  ", &2_822_400u32.to_be_bytes()));
+        prop.extend(_chunk(
+            b"CHNL",
+            &[0, 2, b'S', b'L', b'F', b'T', b'S', b'R', b'G', b'T'],
+        ));
+        let mut diin = _chunk(b"DITI", &[&5u32.to_be_bytes()[..], b"Title"].concat());
+        diin.extend(_chunk(
+            b"DIAR",
+            &[&6u32.to_be_bytes()[..], b"Artist"].concat(),
+        ));
+        let body = [
+            _chunk(b"FVER", &[1, 5, 0, 0]),
+            _chunk(b"PROP", &prop),
+            _chunk(b"DSD ", &vec![0x69u8; 2_822_400 * 2 / 8]),
+            _chunk(b"DIIN", &diin),
+        ]
+        .concat();
+        let mut file = b"FRM8".to_vec();
+        file.extend((4 + body.len() as u64).to_be_bytes());
+        file.extend(b"DSD ");
+        file.extend(&body);
+
+        let tags = read_dff(&mut std::io::Cursor::new(file.clone())).unwrap();
+        assert_eq!(tags.title.as_deref(), Some("Title"));
+        assert_eq!(tags.artist.as_deref(), Some("Artist"));
+        assert_eq!(tags.duration, Duration::from_secs(1));
+        assert_eq!(tags.sample_rate, Some(2_822_400));
+
+        file.extend(_chunk(b"ID3 ", &_id3()));
+        let tags = read_dff(&mut std::io::Cursor::new(file)).unwrap();
+        assert_eq!(tags.title.as_deref(), Some("DSD Song"));
+        assert_eq!(tags.track, Some(5));
+    }
+
+    #[test]
+    fn corrupt_sizes_do_not_panic() {
+        // 时长超出 Duration 的范围
+        let mut file = b"DSD ".to_vec();
+        file.extend(28u64.to_le_bytes());
+        file.extend(0u64.to_le_bytes());
+        file.extend(0u64.to_le_bytes());
+        file.extend(b"fmt ");
+        file.extend(52u64.to_le_bytes());
+        for value in [1u32, 0, 2, 2, 1, 1] {
+            file.extend(value.to_le_bytes());
+        }
+        file.extend(u64::MAX.to_le_bytes());
+        file.extend([0u8; 8]);
+        let tags = read_dsf(&mut std::io::Cursor::new(file)).unwrap();
+        assert_eq!(tags.duration, Duration::ZERO);
+
+        // chunk 的大小溢出偏移
+        let mut file = b"FRM8".to_vec();
+        file.extend(0u64.to_be_bytes());
+        file.extend(b"DSD ");
+        file.extend(b"JUNK");
+        file.extend((u64::MAX - 8).to_be_bytes());
+        assert!(read_dff(&mut std::io::Cursor::new(file)).is_some());
+    }
+}
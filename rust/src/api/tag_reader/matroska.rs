@@ -0,0 +1,318 @@
+use std::io::{Read, Seek, SeekFrom};
+use std::time::Duration;
+
+use super::NativeTags;
+
+const EBML: u32 = 0x1A45_DFA3;
+const SEGMENT: u32 = 0x1853_8067;
+const INFO: u32 = 0x1549_A966;
+const TIMESTAMP_SCALE: u32 = 0x2A_D7B1;
+const DURATION: u32 = 0x4489;
+const TITLE: u32 = 0x7BA9;
+const TRACKS: u32 = 0x1654_AE6B;
+const TRACK_ENTRY: u32 = 0xAE;
+const TRACK_TYPE: u32 = 0x83;
+const AUDIO: u32 = 0xE1;
+const SAMPLING_FREQUENCY: u32 = 0xB5;
+const TAGS: u32 = 0x1254_C367;
+const TAG: u32 = 0x7373;
+const TARGETS: u32 = 0x63C0;
+const TARGET_TYPE_VALUE: u32 = 0x68CA;
+const SIMPLE_TAG: u32 = 0x67C8;
+const TAG_NAME: u32 = 0x45A3;
+const TAG_STRING: u32 = 0x4487;
+
+/// Info, Tracks, Tags 的大小上限
+const MAX_ELEMENT_SIZE: u64 = 16 * 1024 * 1024;
+const ALBUM_TARGET: u64 = 50;
+
+/// EBML 变长整数。返回 (值, 长度)。id 保留长度标记，size 去掉长度标记
+fn _vint(data: &[u8], keep_marker: bool) -> Option<(u64, usize)> {
+    let first = *data.first()?;
+    let len = first.leading_zeros() as usize + 1;
+    if len > 8 {
+        return None;
+    }
+    let mut value = if keep_marker {
+        first as u64
+    } else {
+        (first as u64) & (0xFF >> len)
+    };
+    for byte in data.get(1..len)? {
+        value = (value << 8) | *byte as u64;
+    }
+    Some((value, len))
+}
+
+/// 未知大小（所有位都是 1）时 size 为 None
+fn _element_header(data: &[u8]) -> Option<(u32, Option<u64>, usize)> {
+    let (id, id_len) = _vint(data, true)?;
+    let (size, size_len) = _vint(&data[id_len..], false)?;
+    let unknown = size == (1u64 << (7 * size_len)) - 1;
+    Some((id as u32, (!unknown).then_some(size), id_len + size_len))
+}
+
+/// 子元素 (id, body)。遇到损坏的元素时停止
+fn _children(data: &[u8]) -> Vec<(u32, &[u8])> {
+    let mut children = vec![];
+    let mut at = 0;
+    while let Some((id, Some(size), header_len)) = data.get(at..).and_then(_element_header) {
+        let start = at + header_len;
+        let Some(body) = usize::try_from(size)
+            .ok()
+            .and_then(|size| data.get(start..start.checked_add(size)?))
+        else {
+            break;
+        };
+        children.push((id, body));
+        at = start + body.len();
+    }
+    children
+}
+
+fn _uint(data: &[u8]) -> Option<u64> {
+    (data.len() <= 8).then(|| {
+        data.iter()
+            .fold(0, |value, byte| (value << 8) | *byte as u64)
+    })
+}
+
+fn _float(data: &[u8]) -> Option<f64> {
+    match data.len() {
+        4 => Some(f32::from_be_bytes(data.try_into().ok()?) as f64),
+        8 => Some(f64::from_be_bytes(data.try_into().ok()?)),
+        _ => None,
+    }
+}
+
+fn _string(data: &[u8]) -> Option<String> {
+    let text = String::from_utf8_lossy(data)
+        .trim_end_matches('\0')
+        .trim()
+        .to_string();
+    (!text.is_empty()).then_some(text)
+}
+
+fn _read_info(data: &[u8], tags: &mut NativeTags) {
+    let mut scale = 1_000_000u64;
+    let mut duration = None;
+    for (id, body) in _children(data) {
+        match id {
+            TIMESTAMP_SCALE => scale = _uint(body).unwrap_or(scale),
+            DURATION => duration = _float(body),
+            TITLE => tags.title = tags.title.take().or_else(|| _string(body)),
+            _ => {}
+        }
+    }
+    if let Some(duration) = duration.filter(|d| d.is_finite() && *d > 0.0) {
+        tags.duration =
+            Duration::try_from_secs_f64(duration * scale as f64 / 1e9).unwrap_or_default();
+    }
+}
+
+fn _read_tracks(data: &[u8], tags: &mut NativeTags) {
+    for (_, entry) in _children(data)
+        .into_iter()
+        .filter(|(id, _)| *id == TRACK_ENTRY)
+    {
+        let children = _children(entry);
+        let is_audio = children
+            .iter()
+            .any(|(id, body)| *id == TRACK_TYPE && _uint(body) == Some(2));
+        if !is_audio {
+            continue;
+        }
+        let sample_rate = children
+            .iter()
+            .filter(|(id, _)| *id == AUDIO)
+            .flat_map(|(_, audio)| _children(audio))
+            .find(|(id, _)| *id == SAMPLING_FREQUENCY)
+            .and_then(|(_, body)| _float(body));
+        if let Some(sample_rate) = sample_rate {
+            tags.sample_rate = Some(sample_rate.round() as u32);
+            return;
+        }
+    }
+}
+
+/// TargetTypeValue 50 为专辑，没有 Targets 或者 30 为曲目
+fn _read_tags(data: &[u8], tags: &mut NativeTags) {
+    for (_, tag) in _children(data).into_iter().filter(|(id, _)| *id == TAG) {
+        let children = _children(tag);
+        let target = children
+            .iter()
+            .filter(|(id, _)| *id == TARGETS)
+            .flat_map(|(_, targets)| _children(targets))
+            .find(|(id, _)| *id == TARGET_TYPE_VALUE)
+            .and_then(|(_, body)| _uint(body));
+        let is_album = target == Some(ALBUM_TARGET);
+
+        for (_, simple_tag) in children.iter().filter(|(id, _)| *id == SIMPLE_TAG) {
+            let simple_tag = _children(simple_tag);
+            let find = |wanted: u32| {
+                simple_tag
+                    .iter()
+                    .find(|(id, _)| *id == wanted)
+                    .and_then(|(_, body)| _string(body))
+            };
+            let (Some(name), Some(value)) = (find(TAG_NAME), find(TAG_STRING)) else {
+                continue;
+            };
+            match (name.to_ascii_uppercase().as_str(), is_album) {
+                ("TITLE", true) => tags.album = Some(value),
+                ("ARTIST", true) => tags.album_artist = Some(value),
+                ("TITLE", false) => tags.title = Some(value),
+                ("ARTIST", false) => tags.artist = Some(value),
+                ("ALBUM", _) => tags.album = Some(value),
+                ("PART_NUMBER", false) => tags.track = value.parse().ok(),
+                _ => {}
+            }
+        }
+    }
+}
+
+fn _read_header(reader: &mut impl Read) -> Option<(u32, Option<u64>, usize)> {
+    let mut header = [0u8; 12];
+    reader.read_exact(&mut header[..1]).ok()?;
+    let id_len = header[0].leading_zeros() as usize + 1;
+    if id_len > 4 {
+        return None;
+    }
+    reader.read_exact(&mut header[1..id_len + 1]).ok()?;
+    let size_len = header[id_len].leading_zeros() as usize + 1;
+    if size_len > 8 {
+        return None;
+    }
+    reader
+        .read_exact(&mut header[id_len + 1..id_len + size_len])
+        .ok()?;
+    _element_header(&header[..id_len + size_len])
+}
+
+/// .mka, .webm。跳过 Cluster，只读取 Segment 中的 Info, Tracks, Tags
+pub(super) fn read(reader: &mut (impl Read + Seek)) -> Option<NativeTags> {
+    reader.seek(SeekFrom::Start(0)).ok()?;
+    let (id, size, _) = _read_header(reader)?;
+    if id != EBML {
+        return None;
+    }
+    reader.seek(SeekFrom::Current(size? as i64)).ok()?;
+    let (id, segment_size, _) = _read_header(reader)?;
+    if id != SEGMENT {
+        return None;
+    }
+    let segment_start = reader.stream_position().ok()?;
+    let segment_end = segment_size.map(|size| segment_start + size);
+
+    let mut tags = NativeTags::default();
+    loop {
+        let position = reader.stream_position().ok()?;
+        if segment_end.is_some_and(|end| position >= end) {
+            break;
+        }
+        let Some((id, size, _)) = _read_header(reader) else {
+            break;
+        };
+        // 未知大小的 Cluster 后面通常不会有 Tags
+        let Some(size) = size else {
+            break;
+        };
+        match id {
+            INFO | TRACKS | TAGS if size <= MAX_ELEMENT_SIZE => {
+                let mut body = vec![0u8; size as usize];
+                if reader.read_exact(&mut body).is_err() {
+                    break;
+                }
+                match id {
+                    INFO => _read_info(&body, &mut tags),
+                    TRACKS => _read_tracks(&body, &mut tags),
+                    _ => _read_tags(&body, &mut tags),
+                }
+            }
+            // Cluster 等
+            _ => {
+                if reader.seek(SeekFrom::Current(size as i64)).is_err() {
+                    break;
+                }
+            }
+        }
+    }
+
+    let len = reader.seek(SeekFrom::End(0)).ok()?;
+    tags.bitrate = super::_average_bitrate(len, tags.duration);
+    Some(tags)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CLUSTER: u32 = 0x1F43_B675;
+
+    /// size 固定写成 8 字节
+    fn _element(id: u32, body: &[u8]) -> Vec<u8> {
+        let mut element: Vec<u8> = id
+            .to_be_bytes()
+            .into_iter()
+            .skip_while(|byte| *byte == 0)
+            .collect();
+        element.push(0x01);
+        element.extend(&(body.len() as u64).to_be_bytes()[1..]);
+        element.extend(body);
+        element
+    }
+
+    #[test]
+    fn read_matroska_segment() {
+        let info = [
+            _element(TIMESTAMP_SCALE, &[0x0F, 0x42, 0x40]),
+            _element(DURATION, &90_000f64.to_be_bytes()),
+            _element(TITLE, b"Info Title"),
+        ]
+        .concat();
+        let audio = _element(SAMPLING_FREQUENCY, &48_000f32.to_be_bytes());
+        let track = [_element(TRACK_TYPE, &[2]), _element(AUDIO, &audio)].concat();
+        let tracks = _element(TRACK_ENTRY, &track);
+        let simple_tag = |name: &str, value: &str| {
+            _element(
+                SIMPLE_TAG,
+                &[
+                    _element(TAG_NAME, name.as_bytes()),
+                    _element(TAG_STRING, value.as_bytes()),
+                ]
+                .concat(),
+            )
+        };
+        let album_tag = [
+            _element(TARGETS, &_element(TARGET_TYPE_VALUE, &[50])),
+            simple_tag("TITLE", "Album"),
+            simple_tag("ARTIST", "Album Artist"),
+        ]
+        .concat();
+        let track_tag = [
+            simple_tag("TITLE", "Song"),
+            simple_tag("ARTIST", "Singer"),
+            simple_tag("PART_NUMBER", "3"),
+        ]
+        .concat();
+        let tags = [_element(TAG, &album_tag), _element(TAG, &track_tag)].concat();
+
+        let segment = [
+            _element(INFO, &info),
+            _element(TRACKS, &tracks),
+            _element(CLUSTER, &[0u8; 1000]),
+            _element(TAGS, &tags),
+        ]
+        .concat();
+        let file = [_element(EBML, &[]), _element(SEGMENT, &segment)].concat();
+
+        let tags = read(&mut std::io::Cursor::new(file)).unwrap();
+        assert_eq!(tags.title.as_deref(), Some("Song"));
+        assert_eq!(tags.artist.as_deref(), Some("Singer"));
+        assert_eq!(tags.album.as_deref(), Some("Album"));
+        assert_eq!(tags.album_artist.as_deref(), Some("Album Artist"));
+        assert_eq!(tags.track, Some(3));
+        assert_eq!(tags.duration, Duration::from_secs(90));
+        assert_eq!(tags.sample_rate, Some(48_000));
+    }
+}
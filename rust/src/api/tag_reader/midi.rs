@@ -0,0 +1,212 @@
+use std::io::{Read, Seek, SeekFrom};
+use std::time::Duration;
+
+use super::super::text_encoding;
+use super::NativeTags;
+
+/// MIDI 文件的大小上限
+const MAX_MIDI_SIZE: u64 = 16 * 1024 * 1024;
+/// 默认 120 BPM
+const DEFAULT_TEMPO: u64 = 500_000;
+
+/// MIDI 变长数值
+fn _vlq(data: &[u8], at: &mut usize) -> Option<u64> {
+    let mut value = 0u64;
+    for _ in 0..4 {
+        let byte = *data.get(*at)?;
+        *at += 1;
+        value = (value << 7) | (byte & 0x7F) as u64;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+    None
+}
+
+struct MidiTrack {
+    name: Option<String>,
+    /// (tick, 每个四分音符的微秒数)
+    tempos: Vec<(u64, u64)>,
+    end: u64,
+}
+
+fn _parse_track(data: &[u8]) -> MidiTrack {
+    let mut track = MidiTrack {
+        name: None,
+        tempos: vec![],
+        end: 0,
+    };
+    let mut at = 0;
+    let mut tick = 0u64;
+    let mut running_status = None;
+    while at < data.len() {
+        let Some(delta) = _vlq(data, &mut at) else {
+            break;
+        };
+        tick += delta;
+        let Some(&first) = data.get(at) else {
+            break;
+        };
+        let status = if first & 0x80 != 0 {
+            at += 1;
+            first
+        } else {
+            match running_status {
+                Some(status) => status,
+                None => break,
+            }
+        };
+        match status {
+            0xFF => {
+                let Some(&kind) = data.get(at) else {
+                    break;
+                };
+                at += 1;
+                let Some(len) = _vlq(data, &mut at) else {
+                    break;
+                };
+                let Some(body) = data.get(at..at + len as usize) else {
+                    break;
+                };
+                at += len as usize;
+                match kind {
+                    0x03 if track.name.is_none() => {
+                        let name = text_encoding::decode_bytes(body).text.trim().to_string();
+                        track.name = (!name.is_empty()).then_some(name);
+                    }
+                    0x51 if body.len() == 3 => {
+                        let tempo = (body[0] as u64) << 16 | (body[1] as u64) << 8 | body[2] as u64;
+                        track.tempos.push((tick, tempo));
+                    }
+                    0x2F => break,
+                    _ => {}
+                }
+            }
+            0xF0 | 0xF7 => {
+                let Some(len) = _vlq(data, &mut at) else {
+                    break;
+                };
+                at += len as usize;
+            }
+            0x80..=0xEF => {
+                running_status = Some(status);
+                at += if matches!(status & 0xF0, 0xC0 | 0xD0) {
+                    1
+                } else {
+                    2
+                };
+            }
+            _ => break,
+        }
+    }
+    track.end = tick;
+    track
+}
+
+/// 按速度变化把 tick 换算为微秒
+fn _ticks_to_micros(end: u64, tempos: &[(u64, u64)], ticks_per_quarter: u64) -> u64 {
+    let mut micros = 0u64;
+    let mut last_tick = 0u64;
+    let mut tempo = DEFAULT_TEMPO;
+    for &(tick, next_tempo) in tempos.iter().take_while(|(tick, _)| *tick < end) {
+        micros += (tick - last_tick) * tempo / ticks_per_quarter;
+        last_tick = tick;
+        tempo = next_tempo;
+    }
+    micros + (end - last_tick) * tempo / ticks_per_quarter
+}
+
+/// .mid。时长为最长的音轨的长度，标题为第一个音轨的名称
+pub(super) fn read(reader: &mut (impl Read + Seek)) -> Option<NativeTags> {
+    reader.seek(SeekFrom::Start(0)).ok()?;
+    let mut data = vec![];
+    reader.take(MAX_MIDI_SIZE).read_to_end(&mut data).ok()?;
+    // RIFF RMID 中的 MIDI
+    let start = match data.get(0..4)? {
+        b"RIFF" => data.windows(4).position(|w| w == b"MThd")?,
+        _ => 0,
+    };
+    let data = &data[start..];
+    if data.get(0..4)? != b"MThd" {
+        return None;
+    }
+    let header_len = u32::from_be_bytes(data.get(4..8)?.try_into().ok()?) as usize;
+    let division = u16::from_be_bytes(data.get(12..14)?.try_into().ok()?);
+
+    let mut tracks = vec![];
+    let mut at = 8 + header_len;
+    while let Some(chunk) = data.get(at..at + 8) {
+        let len = u32::from_be_bytes(chunk[4..8].try_into().unwrap()) as usize;
+        let body = data.get(at + 8..(at + 8 + len).min(data.len()))?;
+        if &chunk[0..4] == b"MTrk" {
+            tracks.push(_parse_track(body));
+        }
+        at += 8 + len;
+    }
+
+    let end = tracks.iter().map(|track| track.end).max().unwrap_or(0);
+    let micros = if division & 0x8000 != 0 {
+        // SMPTE：每秒帧数 * 每帧 tick 数
+        let fps = match (division >> 8) as u8 as i8 {
+            -29 => 29.97,
+            fps => -(fps as f64),
+        };
+        let ticks_per_second = fps * (division & 0xFF) as f64;
+        if ticks_per_second > 0.0 {
+            (end as f64 / ticks_per_second * 1e6) as u64
+        } else {
+            0
+        }
+    } else {
+        let mut tempos: Vec<(u64, u64)> = tracks
+            .iter()
+            .flat_map(|track| track.tempos.iter().copied())
+            .collect();
+        tempos.sort_by_key(|(tick, _)| *tick);
+        _ticks_to_micros(end, &tempos, division.max(1) as u64)
+    };
+
+    Some(NativeTags {
+        title: tracks.iter().find_map(|track| track.name.clone()),
+        duration: Duration::from_micros(micros),
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _track(events: &[u8]) -> Vec<u8> {
+        let mut track = b"MTrk".to_vec();
+        track.extend((events.len() as u32).to_be_bytes());
+        track.extend(events);
+        track
+    }
+
+    #[test]
+    fn read_midi_duration_and_name() {
+        let mut file = b"MThd".to_vec();
+        file.extend(6u32.to_be_bytes());
+        file.extend([0, 1, 0, 2, 0x01, 0xE0]); // format 1, 2 tracks, 480 ticks per quarter
+                                               // 音轨 0：名称；0 tick 时 120 BPM，第 960 tick 变为 60 BPM
+        file.extend(_track(&[
+            0x00, 0xFF, 0x03, 0x04, b'S', b'o', b'n', b'g', //
+            0x00, 0xFF, 0x51, 0x03, 0x07, 0xA1, 0x20, //
+            0x87, 0x40, 0xFF, 0x51, 0x03, 0x0F, 0x42, 0x40, //
+            0x00, 0xFF, 0x2F, 0x00,
+        ]));
+        // 音轨 1：音符持续到第 1920 tick，使用 running status
+        file.extend(_track(&[
+            0x00, 0x90, 0x3C, 0x40, //
+            0x87, 0x40, 0x3C, 0x00, //
+            0x87, 0x40, 0x80, 0x3C, 0x00, //
+            0x00, 0xFF, 0x2F, 0x00,
+        ]));
+
+        let tags = read(&mut std::io::Cursor::new(file)).unwrap();
+        assert_eq!(tags.title.as_deref(), Some("Song"));
+        // 960 tick * 0.5s/480 + 960 tick * 1s/480
+        assert_eq!(tags.duration, Duration::from_secs(3));
+    }
+}
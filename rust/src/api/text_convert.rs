@@ -0,0 +1,318 @@
+use std::{
+    borrow::Cow,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use flutter_rust_bridge::frb;
+use lindera::{dictionary::load_fs_dictionary, mode::Mode, segmenter::Segmenter};
+use pinyin::ToPinyin;
+use wana_kana::ConvertJapanese;
+use zhconv::{zhconv, Variant};
+
+/// 简繁转换的目标。字表和词表（MediaWiki, OpenCC）编译在程序中
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChineseVariant {
+    /// 简体（不转换地区用词）
+    Simplified,
+    /// 大陆简体，包括地区用词
+    Mainland,
+    /// 繁体（不转换地区用词）
+    Traditional,
+    /// 台湾正体，包括地区用词
+    Taiwan,
+    /// 香港繁体，包括地区用词
+    HongKong,
+}
+
+impl ChineseVariant {
+    fn to_zhconv_variant(self) -> Variant {
+        match self {
+            ChineseVariant::Simplified => Variant::ZhHans,
+            ChineseVariant::Mainland => Variant::ZhCN,
+            ChineseVariant::Traditional => Variant::ZhHant,
+            ChineseVariant::Taiwan => Variant::ZhTW,
+            ChineseVariant::HongKong => Variant::ZhHK,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RomanizationKind {
+    /// 有假名时为罗马字，否则为拼音
+    Auto,
+    /// 带声调的拼音
+    Pinyin,
+    /// 罗马字。汉字的读音来自 IPADIC 词典，没有词典时含有汉字的文本不转换
+    Romaji,
+}
+
+/// for Flutter
+#[frb(sync)]
+pub fn convert_chinese(text: String, variant: ChineseVariant) -> String {
+    zhconv(&text, variant.to_zhconv_variant())
+}
+
+/// for Flutter
+/// 不是汉字和假名的文字保持不变。罗马字时没有读音词典，含有汉字的文本原样返回，
+/// 否则会得到罗马字和汉字混在一起的结果
+#[frb(sync)]
+pub fn romanize(text: String, kind: RomanizationKind) -> String {
+    match resolve_romanization(kind, contains_kana(&text)) {
+        RomanizationKind::Romaji => match _ipadic_segmenter() {
+            Some(segmenter) => _to_romaji_by_words(segmenter, &text),
+            None if _contains_kanji(&text) => text,
+            None => _to_romaji(&text),
+        },
+        _ => _to_pinyin(&text),
+    }
+}
+
+/// 汉字读音词典：lindera 编译的 IPADIC，和 BASS 一样放在程序所在的文件夹中
+fn _ipadic_dir() -> Option<PathBuf> {
+    Some(
+        std::env::current_exe()
+            .ok()?
+            .parent()?
+            .join("dict")
+            .join("ipadic"),
+    )
+}
+
+fn _load_segmenter(dir: &Path) -> Option<Segmenter> {
+    let dictionary = load_fs_dictionary(dir).ok()?;
+    Some(Segmenter::new(Mode::Normal, dictionary, None))
+}
+
+/// 第一次转换罗马字时加载，没有词典时为 None
+fn _ipadic_segmenter() -> Option<&'static Segmenter> {
+    static SEGMENTER: OnceLock<Option<Segmenter>> = OnceLock::new();
+    SEGMENTER
+        .get_or_init(|| _load_segmenter(&_ipadic_dir()?))
+        .as_ref()
+}
+
+pub(crate) fn resolve_romanization(kind: RomanizationKind, has_kana: bool) -> RomanizationKind {
+    match kind {
+        RomanizationKind::Auto if has_kana => RomanizationKind::Romaji,
+        RomanizationKind::Auto => RomanizationKind::Pinyin,
+        kind => kind,
+    }
+}
+
+fn _is_kana(c: char) -> bool {
+    matches!(c, '\u{3041}'..='\u{309F}' | '\u{30A0}'..='\u{30FF}' | '\u{FF66}'..='\u{FF9F}')
+}
+
+pub(crate) fn contains_kana(text: &str) -> bool {
+    text.chars().any(_is_kana)
+}
+
+/// 有拼音的字就是汉字，包括日文的汉字
+fn _contains_kanji(text: &str) -> bool {
+    text.to_pinyin().any(|pinyin| pinyin.is_some())
+}
+
+/// 是否有需要转换的文字。罗马字时没有读音词典，含有汉字的文本无法完整转换，不需要转换
+pub(crate) fn needs_romanization(text: &str, kind: RomanizationKind) -> bool {
+    match kind {
+        RomanizationKind::Romaji => {
+            contains_kana(text) && (_ipadic_segmenter().is_some() || !_contains_kanji(text))
+        }
+        _ => _contains_kanji(text) || contains_kana(text),
+    }
+}
+
+/// 全角标点转为半角，和拼音放在一起更自然
+fn _to_ascii_punctuation(c: char) -> Option<&'static str> {
+    Some(match c {
+        '，' | '、' => ",",
+        '。' => ".",
+        '！' => "!",
+        '？' => "?",
+        '：' => ":",
+        '；' => ";",
+        '（' => "(",
+        '）' => ")",
+        '“' | '”' | '「' | '」' => "\"",
+        '　' => " ",
+        _ => return None,
+    })
+}
+
+/// 每个字的拼音之间用空格分隔
+fn _to_pinyin(text: &str) -> String {
+    let mut result = String::with_capacity(text.len() * 2);
+    let mut after_syllable = false;
+    for (c, pinyin) in text.chars().zip(text.to_pinyin()) {
+        match pinyin {
+            Some(pinyin) => {
+                if !result.is_empty() && !result.ends_with([' ', '(', '"']) {
+                    result.push(' ');
+                }
+                result.push_str(pinyin.with_tone());
+                after_syllable = true;
+            }
+            None => {
+                if after_syllable && c.is_alphanumeric() {
+                    result.push(' ');
+                }
+                match _to_ascii_punctuation(c) {
+                    Some(punctuation) => result.push_str(punctuation),
+                    None => result.push(c),
+                }
+                after_syllable = false;
+            }
+        }
+    }
+    result
+}
+
+fn _to_romaji(text: &str) -> String {
+    text.to_romaji()
+}
+
+/// 整行分词后逐词转换，词之间用空格分隔。汉字使用词典中的读音（片假名），
+/// 助词 は, へ 按发音转换为 wa, e。分词失败时只转换假名
+fn _to_romaji_by_words(segmenter: &Segmenter, text: &str) -> String {
+    let Ok(tokens) = segmenter.segment(Cow::Borrowed(text)) else {
+        return _to_romaji(text);
+    };
+    let mut result = String::with_capacity(text.len() * 2);
+    for mut token in tokens {
+        let surface = token.surface.to_string();
+        if let Some(punctuation) = _single_char(&surface).and_then(_to_ascii_punctuation) {
+            result.push_str(punctuation);
+            continue;
+        }
+        let is_particle = token.get("major_pos") == Some("助詞");
+        let word = match surface.as_str() {
+            "は" if is_particle => "wa".to_string(),
+            "へ" if is_particle => "e".to_string(),
+            _ => match token.get("reading") {
+                Some(reading) if reading != "*" => reading.to_romaji(),
+                _ => surface.to_romaji(),
+            },
+        };
+        if !result.is_empty() && !result.ends_with([' ', '(', '"']) {
+            result.push(' ');
+        }
+        result.push_str(&word);
+    }
+    result
+}
+
+fn _single_char(text: &str) -> Option<char> {
+    let mut chars = text.chars();
+    let c = chars.next()?;
+    chars.next().is_none().then_some(c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convert_between_variants() {
+        assert_eq!(
+            convert_chinese("头发和软件".to_string(), ChineseVariant::Traditional),
+            "頭髮和軟件"
+        );
+        assert_eq!(
+            convert_chinese("头发和软件".to_string(), ChineseVariant::Taiwan),
+            "頭髮和軟體"
+        );
+        assert_eq!(
+            convert_chinese("頭髮和軟體".to_string(), ChineseVariant::Simplified),
+            "头发和软体"
+        );
+        assert_eq!(
+            convert_chinese("頭髮和軟體".to_string(), ChineseVariant::Mainland),
+            "头发和软件"
+        );
+    }
+
+    #[test]
+    fn romanize_chinese_and_japanese() {
+        assert_eq!(
+            romanize("你好，世界 OK".to_string(), RomanizationKind::Auto),
+            "nǐ hǎo, shì jiè OK"
+        );
+        assert_eq!(
+            romanize("きみのなまえ".to_string(), RomanizationKind::Auto),
+            "kiminonamae"
+        );
+        assert_eq!(
+            romanize("カタカナ".to_string(), RomanizationKind::Romaji),
+            "katakana"
+        );
+        assert!(!needs_romanization(
+            "hello, world",
+            RomanizationKind::Pinyin
+        ));
+        assert!(needs_romanization("君の名は", RomanizationKind::Pinyin));
+
+        // 没有汉字的读音，不输出罗马字和汉字混在一起的结果
+        assert!(!needs_romanization("君の名は", RomanizationKind::Romaji));
+        assert!(needs_romanization("きみのなまえ", RomanizationKind::Romaji));
+        assert_eq!(
+            romanize("君の名は".to_string(), RomanizationKind::Auto),
+            "君の名は"
+        );
+    }
+
+    /// 只有几个词的 IPADIC 格式词典
+    fn build_test_dictionary(base: &Path) -> PathBuf {
+        use lindera::dictionary::{DictionaryBuilder, Metadata};
+
+        let input = base.join("src");
+        let output = base.join("ipadic");
+        std::fs::create_dir_all(&input).unwrap();
+        std::fs::write(
+            input.join("char.def"),
+            "DEFAULT 0 1 0\nSPACE 0 1 0\nKANJI 0 0 2\nHIRAGANA 1 1 0\nKATAKANA 1 1 0\n\
+             0x0020 SPACE\n0x3041..0x309F HIRAGANA\n0x30A1..0x30FF KATAKANA\n0x4E00..0x9FFF KANJI\n",
+        )
+        .unwrap();
+        std::fs::write(
+            input.join("unk.def"),
+            "DEFAULT,0,0,10000,記号,一般,*,*,*,*,*\nSPACE,0,0,10000,記号,空白,*,*,*,*,*\n\
+             KANJI,0,0,10000,名詞,一般,*,*,*,*,*\nHIRAGANA,0,0,10000,名詞,一般,*,*,*,*,*\n\
+             KATAKANA,0,0,10000,名詞,一般,*,*,*,*,*\n",
+        )
+        .unwrap();
+        std::fs::write(input.join("matrix.def"), "1 1\n0 0 0\n").unwrap();
+        std::fs::write(
+            input.join("words.csv"),
+            "君,0,0,100,名詞,代名詞,一般,*,*,*,君,キミ,キミ\n\
+             の,0,0,100,助詞,連体化,*,*,*,*,の,ノ,ノ\n\
+             名,0,0,100,名詞,一般,*,*,*,*,名,ナ,ナ\n\
+             は,0,0,100,助詞,係助詞,*,*,*,*,は,ハ,ワ\n\
+             東京,0,0,100,名詞,固有名詞,地域,一般,*,*,東京,トウキョウ,トーキョー\n\
+             へ,0,0,100,助詞,格助詞,一般,*,*,*,へ,ヘ,エ\n",
+        )
+        .unwrap();
+        DictionaryBuilder::new(Metadata::default())
+            .build_dictionary(&input, &output)
+            .unwrap();
+        output
+    }
+
+    #[test]
+    fn romanize_kanji_with_dictionary() {
+        let base = std::env::temp_dir().join(format!(
+            "coriander_player_ipadic_test_{}",
+            std::process::id()
+        ));
+        let segmenter = _load_segmenter(&build_test_dictionary(&base)).unwrap();
+
+        assert_eq!(_to_romaji_by_words(&segmenter, "君の名は"), "kimi no na wa");
+        assert_eq!(
+            _to_romaji_by_words(&segmenter, "東京へ、君の"),
+            "toukyou e, kimi no"
+        );
+        assert!(_load_segmenter(&base.join("missing")).is_none());
+
+        std::fs::remove_dir_all(&base).unwrap();
+    }
+}
@@ -0,0 +1,348 @@
+use encoding_rs::{
+    Encoding, BIG5, EUC_KR, GB18030, GBK, SHIFT_JIS, UTF_16BE, UTF_16LE, WINDOWS_1252,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TextEncoding {
+    Utf8,
+    Utf16Le,
+    Utf16Be,
+    Gb18030,
+    Big5,
+    ShiftJis,
+    EucKr,
+    /// 无法识别时的回退，Latin-1 的超集
+    Windows1252,
+}
+
+impl TextEncoding {
+    fn encoding(self) -> &'static Encoding {
+        match self {
+            TextEncoding::Utf8 => encoding_rs::UTF_8,
+            TextEncoding::Utf16Le => UTF_16LE,
+            TextEncoding::Utf16Be => UTF_16BE,
+            TextEncoding::Gb18030 => GB18030,
+            TextEncoding::Big5 => BIG5,
+            TextEncoding::ShiftJis => SHIFT_JIS,
+            TextEncoding::EucKr => EUC_KR,
+            TextEncoding::Windows1252 => WINDOWS_1252,
+        }
+    }
+}
+
+pub struct DecodedText {
+    pub text: String,
+    pub encoding: TextEncoding,
+    pub has_bom: bool,
+    /// 0.0 ~ 1.0。BOM 和合法的多字节 UTF-8 接近 1.0
+    pub confidence: f32,
+}
+
+/// 简体中文常用字
+const FREQUENT_SIMPLIFIED: &str = "的一是不了人我在有他这中大来上个国到说们为子和你地出道也时年得就那要下以生会自着去之过家学对可她里后小么心多天而能好都然没日于起还发成事只作当想看文无开手十用主行方又如前所本见经头面公同三已老从动两长知民样现分将外但身些与高意进把法此实回二理美点月明其种声全工己话儿者向情部正名定女问力机给等几很业最间新什打便位因重被走电四第门相次东政海口使教西再平真听世气信北少关并内加化由却代军产入先山五太水万市眼体别处总才场师书比住员九笑性通目华报立马命张活难神数件安表原车白应路期叫死常提感金何更反合放做系计或司利受光王果亲界及今京务制解各任至清物台象记边共风战干接它许八特觉望直服毛林题建南度统色字请交爱让认算论百吃义科怎元社术结六功指思非流每青管夫连远资队跟带花快条院变联言权往展该领传近留红治决周保达办运武半候七必城父强步完深区即求品士转量空众技轻程告江语英基满式李息写呢识极令黄德收脸钱倒未持取设始双历越史商千片容像找友孩站广改议形早房音火际则首单据影失拿网香似专石若弟谁校读志飞观争究包组造落视喜离虽坏兴切局况克照足唯须乐哥准河怕敢吗哪啊梦泪雨夜";
+/// 繁体中文常用字
+const FREQUENT_TRADITIONAL: &str = "的一是不了人我在有他這中大來上個國到說們為子和你地出道也時年得就那要下以生會自著去之過家學對可她裡後小麼心多天而能好都然沒日於起還發成事只作當想看文無開手十用主行方又如前所本見經頭面公同三已老從動兩長知民樣現分將外但身些與高意進把法此實回二理美點月明其種聲全工己話兒者向情部正名定女問力機給等幾很業最間新什打便位因重被走電四第門相次東政海口使教西再平真聽世氣信北少關並內加化由卻代軍產入先山五太水萬市眼體別處總才場師書比住員九笑性通目華報立馬命張活難神數件安表原車白應路期叫死常提感金何更反合放做系計或司利受光王果親界及今京務制解各任至清物台象記邊共風戰干接它許八特覺望直服毛林題建南度統色字請交愛讓認算論百吃義科怎元社術結六功指思非流每青管夫連遠資隊跟帶花快條院變聯言權往展該領傳近留紅治決周保達辦運武半候七必城父強步完深區即求品士轉量空眾技輕程告江語英基滿式李息寫呢識極令黃德收臉錢倒未持取設始雙歷越史商千片容像找友孩站廣改議形早房音火際則首單據影失拿網香似專石若弟誰校讀志飛觀爭究包組造落視喜離雖壞興切局況克照足唯須樂哥準河怕敢嗎哪啊夢淚雨夜";
+/// 韩语常用音节
+const FREQUENT_HANGUL: &str = "이다는의에가을하고지기사나로한서도있를어리아자대게수보해요내우마으그시니말너없랑거면네날때것들만줘속난워잖같안오데잘더알던까정싶봐눈길꿈함께세상음";
+
+/// 字节是否是用 encoding 编码的 lead 范围内、trail >= 0xA1 的双字节字符
+fn _is_in_rows(c: char, encoding: &'static Encoding, leads: std::ops::RangeInclusive<u8>) -> bool {
+    let mut buf = [0u8; 4];
+    let (bytes, _, had_errors) = encoding.encode(c.encode_utf8(&mut buf));
+    !had_errors && bytes.len() == 2 && leads.contains(&bytes[0]) && bytes[1] >= 0xA1
+}
+
+/// 单个非 ASCII 字符对 candidate 的得分：常用字 2，该编码的常用区 1，全角标点 0.5，
+/// 私用区、控制字符、半角片假名等在正确解码的歌词中几乎不会出现的字符 -1
+fn _char_score(c: char, candidate: TextEncoding, prev: Option<char>, next: Option<char>) -> f32 {
+    let code = c as u32;
+    let is_private_use = (0xE000..=0xF8FF).contains(&code);
+    let is_control = c.is_control();
+    if is_private_use || is_control || c == '\u{FFFD}' {
+        return -1.0;
+    }
+    if matches!(code, 0x3000..=0x303F | 0xFF01..=0xFF5E) {
+        return 0.5;
+    }
+
+    match candidate {
+        TextEncoding::Gb18030 => {
+            if FREQUENT_SIMPLIFIED.contains(c) {
+                2.0
+            } else if _is_in_rows(c, GBK, 0xB0..=0xF7) {
+                1.0
+            } else {
+                0.0
+            }
+        }
+        TextEncoding::Big5 => {
+            if FREQUENT_TRADITIONAL.contains(c) {
+                2.0
+            } else if _is_in_rows(c, BIG5, 0xA4..=0xC6) {
+                1.0
+            } else {
+                0.0
+            }
+        }
+        TextEncoding::ShiftJis => {
+            if matches!(code, 0x3041..=0x30FF) {
+                2.0
+            } else if matches!(code, 0xFF61..=0xFF9F) {
+                // GBK 等误解码为 Shift-JIS 时最常见的结果
+                -1.0
+            } else if _is_in_rows(c, SHIFT_JIS, 0x88..=0x9F) {
+                1.0
+            } else {
+                0.0
+            }
+        }
+        TextEncoding::EucKr => {
+            if FREQUENT_HANGUL.contains(c) {
+                2.0
+            } else if _is_in_rows(c, EUC_KR, 0xB0..=0xC8) {
+                1.0
+            } else if matches!(code, 0xAC00..=0xD7A3) {
+                0.25
+            } else {
+                0.0
+            }
+        }
+        TextEncoding::Windows1252 => {
+            // 西文中的重音字母前后通常是 ASCII 字母
+            let near_ascii_letter = prev.is_some_and(|p| p.is_ascii_alphabetic())
+                || next.is_some_and(|n| n.is_ascii_alphabetic());
+            if c.is_alphabetic() && near_ascii_letter {
+                1.0
+            } else {
+                0.2
+            }
+        }
+        _ => 0.0,
+    }
+}
+
+/// 平均每个非 ASCII 字符的得分和非 ASCII 字符数。有非法字节时返回 None
+fn _score(bytes: &[u8], candidate: TextEncoding) -> Option<(String, f32, usize)> {
+    let text = candidate
+        .encoding()
+        .decode_without_bom_handling_and_without_replacement(bytes)?
+        .into_owned();
+
+    let chars: Vec<char> = text.chars().collect();
+    let mut total = 0.0;
+    let mut count = 0;
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_ascii() {
+            continue;
+        }
+        let prev = i.checked_sub(1).map(|i| chars[i]);
+        let next = chars.get(i + 1).copied();
+        total += _char_score(*c, candidate, prev, next);
+        count += 1;
+    }
+    Some((text, total / count.max(1) as f32, count))
+}
+
+/// 没有 BOM 的 UTF-16：大部分字符是 ASCII 时，奇数或偶数位置上大多是 0
+fn _detect_bomless_utf16(bytes: &[u8]) -> Option<(TextEncoding, f32)> {
+    if bytes.len() < 4 || !bytes.len().is_multiple_of(2) {
+        return None;
+    }
+    let pairs = bytes.len() / 2;
+    let even_zeros = bytes.iter().step_by(2).filter(|b| **b == 0).count();
+    let odd_zeros = bytes.iter().skip(1).step_by(2).filter(|b| **b == 0).count();
+    let (encoding, zeros) = if odd_zeros >= even_zeros {
+        (TextEncoding::Utf16Le, odd_zeros)
+    } else {
+        (TextEncoding::Utf16Be, even_zeros)
+    };
+    let ratio = zeros as f32 / pairs as f32;
+    (ratio >= 0.3).then_some((encoding, ratio.min(0.95)))
+}
+
+fn _decode_with(
+    bytes: &[u8],
+    encoding: TextEncoding,
+    has_bom: bool,
+    confidence: f32,
+) -> DecodedText {
+    let (text, _) = encoding.encoding().decode_without_bom_handling(bytes);
+    DecodedText {
+        text: text.into_owned(),
+        encoding,
+        has_bom,
+        confidence,
+    }
+}
+
+pub(crate) fn decode_bytes(bytes: &[u8]) -> DecodedText {
+    if let Some(rest) = bytes.strip_prefix(&[0xEF, 0xBB, 0xBF]) {
+        return _decode_with(rest, TextEncoding::Utf8, true, 1.0);
+    }
+    if let Some(rest) = bytes.strip_prefix(&[0xFF, 0xFE]) {
+        return _decode_with(rest, TextEncoding::Utf16Le, true, 1.0);
+    }
+    if let Some(rest) = bytes.strip_prefix(&[0xFE, 0xFF]) {
+        return _decode_with(rest, TextEncoding::Utf16Be, true, 1.0);
+    }
+    if let Some((encoding, confidence)) = _detect_bomless_utf16(bytes) {
+        return _decode_with(bytes, encoding, false, confidence);
+    }
+
+    if let Ok(text) = std::str::from_utf8(bytes) {
+        // 其他编码的多字节字符很难恰好是合法的 UTF-8
+        let multibyte = text.chars().filter(|c| !c.is_ascii()).count();
+        let confidence = match multibyte {
+            0 => 1.0,
+            1..=3 => 0.9,
+            _ => 0.99,
+        };
+        return DecodedText {
+            text: text.to_string(),
+            encoding: TextEncoding::Utf8,
+            has_bom: false,
+            confidence,
+        };
+    }
+
+    let mut candidates: Vec<(TextEncoding, String, f32, usize)> = [
+        TextEncoding::Gb18030,
+        TextEncoding::Big5,
+        TextEncoding::ShiftJis,
+        TextEncoding::EucKr,
+        TextEncoding::Windows1252,
+    ]
+    .into_iter()
+    .filter_map(|candidate| {
+        let (text, score, count) = _score(bytes, candidate)?;
+        Some((candidate, text, score, count))
+    })
+    .collect();
+    // 稳定排序，得分相同时按上面的顺序
+    candidates.sort_by(|a, b| b.2.total_cmp(&a.2));
+
+    let second_score = candidates.get(1).map(|c| c.2).unwrap_or(-1.0);
+    match candidates.into_iter().next() {
+        Some((encoding, text, score, count)) => {
+            let margin = (0.5 + (score - second_score)).clamp(0.5, 1.0);
+            let sample = if count < 4 { 0.75 } else { 1.0 };
+            DecodedText {
+                text,
+                encoding,
+                has_bom: false,
+                confidence: (score / 2.0).clamp(0.0, 1.0) * margin * sample,
+            }
+        }
+        None => _decode_with(bytes, TextEncoding::Windows1252, false, 0.0),
+    }
+}
+
+/// ID3 中声明为 Latin-1、实际上是 GBK 等本地编码的文本。
+/// 只有识别结果足够可信且不是西文时才返回修复后的文本
+pub(crate) fn repair_latin1(text: &str) -> Option<String> {
+    if text.is_ascii() || text.chars().any(|c| c as u32 > 0xFF) {
+        return None;
+    }
+    let bytes: Vec<u8> = text.chars().map(|c| c as u32 as u8).collect();
+    let decoded = decode_bytes(&bytes);
+    let repaired = decoded.encoding != TextEncoding::Windows1252 && decoded.confidence >= 0.4;
+    repaired.then_some(decoded.text)
+}
+
+/// for Flutter
+/// 识别文本编码（UTF-8, UTF-16 LE/BE, GB18030, Big5, Shift-JIS, EUC-KR）并解码
+pub fn decode_text(bytes: Vec<u8>) -> DecodedText {
+    decode_bytes(&bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode(text: &str, encoding: &'static Encoding) -> Vec<u8> {
+        encoding.encode(text).0.into_owned()
+    }
+
+    #[test]
+    fn detect_cjk_encodings() {
+        let cases = [
+            (
+                "[00:01.00]我们一起走过的日子\n[00:05.00]还有你的笑",
+                GBK,
+                TextEncoding::Gb18030,
+            ),
+            (
+                "[00:01.00]我們一起走過的日子\n[00:05.00]還有你的笑",
+                BIG5,
+                TextEncoding::Big5,
+            ),
+            (
+                "[00:01.00]君の名前を呼んでいる\n[00:05.00]夜空の星",
+                SHIFT_JIS,
+                TextEncoding::ShiftJis,
+            ),
+            (
+                "[00:01.00]사랑한다고 말해줘\n[00:05.00]너없이 살 수 없어",
+                EUC_KR,
+                TextEncoding::EucKr,
+            ),
+            (
+                "[00:01.00]Café déjà vu",
+                WINDOWS_1252,
+                TextEncoding::Windows1252,
+            ),
+        ];
+        for (text, encoding, expected) in cases {
+            let decoded = decode_bytes(&encode(text, encoding));
+            assert_eq!(decoded.encoding, expected, "{text}");
+            assert_eq!(decoded.text, text);
+            if expected != TextEncoding::Windows1252 {
+                assert!(decoded.confidence >= 0.5, "{text}: {}", decoded.confidence);
+            }
+        }
+    }
+
+    #[test]
+    fn detect_unicode_encodings() {
+        let text = "[00:01.00]你好 world";
+        let mut bom_utf8 = vec![0xEF, 0xBB, 0xBF];
+        bom_utf8.extend(text.as_bytes());
+        let decoded = decode_bytes(&bom_utf8);
+        assert_eq!(
+            (decoded.encoding, decoded.has_bom),
+            (TextEncoding::Utf8, true)
+        );
+        assert_eq!(decoded.text, text);
+
+        let decoded = decode_bytes(text.as_bytes());
+        assert_eq!(
+            (decoded.encoding, decoded.has_bom),
+            (TextEncoding::Utf8, false)
+        );
+
+        let utf16le: Vec<u8> = text.encode_utf16().flat_map(|u| u.to_le_bytes()).collect();
+        let decoded = decode_bytes(&utf16le);
+        assert_eq!(decoded.encoding, TextEncoding::Utf16Le);
+        assert_eq!(decoded.text, text);
+
+        let mut utf16be = vec![0xFE, 0xFF];
+        utf16be.extend(text.encode_utf16().flat_map(|u| u.to_be_bytes()));
+        let decoded = decode_bytes(&utf16be);
+        assert_eq!(
+            (decoded.encoding, decoded.has_bom),
+            (TextEncoding::Utf16Be, true)
+        );
+        assert_eq!(decoded.text, text);
+    }
+
+    #[test]
+    fn repair_mis_tagged_latin1() {
+        let latin1: String = encode("周杰伦 - 七里香", GBK)
+            .into_iter()
+            .map(|b| b as char)
+            .collect();
+        assert_eq!(repair_latin1(&latin1).as_deref(), Some("周杰伦 - 七里香"));
+        assert_eq!(repair_latin1("Beyoncé"), None);
+        assert_eq!(repair_latin1("Plain ASCII"), None);
+    }
+}
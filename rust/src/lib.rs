@@ -1,2 +1,3 @@
 pub mod api;
 mod frb_generated;
+mod platform;
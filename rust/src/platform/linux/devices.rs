@@ -0,0 +1,229 @@
+// PulseAudio 的所有输出设备（sink）。id 是 sink name
+
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    thread::{self, JoinHandle},
+};
+
+use anyhow::Result;
+
+use super::volume::{AudioServer, PactlServer, ServerEvent, Subscription};
+use super::Platform;
+use crate::api::system_volume::{OutputDevice, OutputDeviceEvent};
+use crate::frb_generated::StreamSink;
+use crate::platform::OutputDevices;
+
+fn _devices(server: &impl AudioServer) -> Result<Vec<OutputDevice>> {
+    let default_sink = server.default_sink().ok();
+    Ok(server
+        .sinks()?
+        .into_iter()
+        .map(|sink| OutputDevice {
+            is_default: default_sink.as_ref() == Some(&sink.name),
+            name: match sink.description.is_empty() {
+                true => sink.name.clone(),
+                false => sink.description,
+            },
+            id: sink.name,
+            form_factor: sink.form_factor,
+            state: sink.state,
+        })
+        .collect())
+}
+
+/// 把 sink 的添加、移除和默认设备变化转换为 OutputDeviceEvent
+struct DeviceWatcher {
+    stop: Option<Box<dyn FnOnce() + Send>>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl DeviceWatcher {
+    fn new<S: AudioServer>(
+        server: S,
+        on_event: impl Fn(OutputDeviceEvent) + Send + 'static,
+    ) -> Result<Self> {
+        // 移除事件只有序号，需要记住序号对应的 name
+        let mut names: HashMap<u32, String> = server
+            .sinks()?
+            .into_iter()
+            .map(|sink| (sink.index, sink.name))
+            .collect();
+        let mut default_sink = server.default_sink().ok();
+        let Subscription { events, stop } = server.subscribe()?;
+
+        let worker =
+            thread::spawn(move || {
+                for event in events {
+                    match event {
+                        ServerEvent::SinkAdded(index) => {
+                            let Some(sink) = server.sinks().ok().and_then(|sinks| {
+                                sinks.into_iter().find(|sink| sink.index == index)
+                            }) else {
+                                continue;
+                            };
+                            names.insert(index, sink.name.clone());
+                            on_event(OutputDeviceEvent::Added { id: sink.name });
+                        }
+                        ServerEvent::SinkRemoved(index) => {
+                            if let Some(id) = names.remove(&index) {
+                                on_event(OutputDeviceEvent::Removed { id });
+                            }
+                        }
+                        ServerEvent::ServerChanged => {
+                            let current = server.default_sink().ok();
+                            if current != default_sink {
+                                default_sink = current.clone();
+                                on_event(OutputDeviceEvent::DefaultChanged { id: current });
+                            }
+                        }
+                        ServerEvent::SinkChanged => {}
+                    }
+                }
+            });
+
+        Ok(Self {
+            stop: Some(stop),
+            worker: Some(worker),
+        })
+    }
+}
+
+impl Drop for DeviceWatcher {
+    fn drop(&mut self) {
+        if let Some(stop) = self.stop.take() {
+            stop();
+        }
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+static DEVICE_WATCHER: Mutex<Option<DeviceWatcher>> = Mutex::new(None);
+
+impl OutputDevices for Platform {
+    fn devices(&self) -> Result<Vec<OutputDevice>> {
+        _devices(&PactlServer)
+    }
+
+    fn device_volume(&self, id: &str) -> Result<f64> {
+        PactlServer.sink_volume(id)
+    }
+
+    fn set_device_volume(&self, id: &str, volume: f64) -> Result<()> {
+        PactlServer.set_sink_volume(id, volume)
+    }
+
+    fn device_mute(&self, id: &str) -> Result<bool> {
+        PactlServer.sink_mute(id)
+    }
+
+    fn set_device_mute(&self, id: &str, mute: bool) -> Result<()> {
+        PactlServer.set_sink_mute(id, mute)
+    }
+
+    fn watch_devices(&self, sink: StreamSink<OutputDeviceEvent>) -> Result<()> {
+        self.unwatch_devices();
+
+        let watcher = DeviceWatcher::new(PactlServer, move |event| {
+            let _ = sink.add(event);
+        })?;
+        *DEVICE_WATCHER.lock().unwrap_or_else(|err| err.into_inner()) = Some(watcher);
+        Ok(())
+    }
+
+    fn unwatch_devices(&self) {
+        let watcher = DEVICE_WATCHER
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .take();
+        drop(watcher);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{mpsc, Arc},
+        time::Duration,
+    };
+
+    use super::*;
+    use crate::api::system_volume::{OutputDeviceState, OutputFormFactor};
+    use crate::platform::linux::{volume::SinkInfo, MockServer};
+
+    fn sink(index: u32, name: &str, form_factor: OutputFormFactor) -> SinkInfo {
+        SinkInfo {
+            index,
+            name: name.to_string(),
+            description: format!("{name} description"),
+            form_factor,
+            state: OutputDeviceState::Active,
+        }
+    }
+
+    #[test]
+    fn device_events_from_server() {
+        let server = Arc::new(MockServer::default());
+        *server.default_sink.lock().unwrap() = Some("speakers".to_string());
+        server
+            .sinks
+            .lock()
+            .unwrap()
+            .push(sink(1, "speakers", OutputFormFactor::Speakers));
+
+        let devices = _devices(&server).unwrap();
+        assert_eq!(devices.len(), 1);
+        assert_eq!(devices[0].id, "speakers");
+        assert_eq!(devices[0].name, "speakers description");
+        assert!(devices[0].is_default);
+
+        let (sender, received) = mpsc::channel();
+        let watcher = DeviceWatcher::new(server.clone(), move |event| {
+            sender.send(event).unwrap();
+        })
+        .unwrap();
+        let next = || received.recv_timeout(Duration::from_secs(5)).unwrap();
+
+        // 默认设备没有变化时不通知。事件按顺序处理，收到下一个事件时这个已经处理完
+        server.send(ServerEvent::ServerChanged);
+        server
+            .sinks
+            .lock()
+            .unwrap()
+            .push(sink(2, "headphones", OutputFormFactor::Headphones));
+        server.send(ServerEvent::SinkAdded(2));
+        assert_eq!(
+            next(),
+            OutputDeviceEvent::Added {
+                id: "headphones".to_string()
+            }
+        );
+
+        *server.default_sink.lock().unwrap() = Some("headphones".to_string());
+        server.send(ServerEvent::ServerChanged);
+        assert_eq!(
+            next(),
+            OutputDeviceEvent::DefaultChanged {
+                id: Some("headphones".to_string())
+            }
+        );
+
+        server.sinks.lock().unwrap().retain(|sink| sink.index != 2);
+        *server.default_sink.lock().unwrap() = None;
+        server.send(ServerEvent::SinkRemoved(2));
+        server.send(ServerEvent::SinkRemoved(42));
+        server.send(ServerEvent::ServerChanged);
+        assert_eq!(
+            next(),
+            OutputDeviceEvent::Removed {
+                id: "headphones".to_string()
+            }
+        );
+        assert_eq!(next(), OutputDeviceEvent::DefaultChanged { id: None });
+
+        drop(watcher);
+        assert!(received.try_recv().is_err());
+    }
+}
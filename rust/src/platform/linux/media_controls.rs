@@ -0,0 +1,722 @@
+// MPRIS2：https://specifications.freedesktop.org/mpris-spec/latest/
+
+use std::{
+    collections::HashMap,
+    fs::{self, DirBuilder},
+    os::unix::fs::DirBuilderExt,
+    path::{Path, PathBuf},
+    time::Instant,
+};
+
+use anyhow::{anyhow, Result};
+use xxhash_rust::xxh3::xxh3_64;
+use zbus::{
+    blocking::{connection, object_server::InterfaceRef, Connection},
+    fdo, interface,
+    object_server::SignalEmitter,
+    zvariant::{ObjectPath, OwnedValue, Value},
+};
+
+use super::{_cache_dir, _file_uri};
+use crate::api::smtc_flutter::SMTCState;
+use crate::platform::{MediaControlEvent, MediaControls, MediaDisplay, RepeatMode, Timeline};
+
+const BUS_NAME: &str = "org.mpris.MediaPlayer2.coriander_player";
+const OBJECT_PATH: &str = "/org/mpris/MediaPlayer2";
+const NO_TRACK: &str = "/org/mpris/MediaPlayer2/TrackList/NoTrack";
+const TRACK_PATH: &str = "/org/coriander_player/track";
+
+const MINIMUM_RATE: f64 = 0.5;
+const MAXIMUM_RATE: f64 = 2.0;
+
+/// 和推算出的播放位置相差超过这个值时，认为发生了跳转，发出 Seeked 信号
+const SEEK_THRESHOLD_US: i64 = 1_000_000;
+
+type EventHandler = Box<dyn Fn(MediaControlEvent) + Send + Sync>;
+
+/// org.mpris.MediaPlayer2
+struct RootInterface;
+
+#[interface(name = "org.mpris.MediaPlayer2")]
+impl RootInterface {
+    fn raise(&self) {}
+
+    fn quit(&self) {}
+
+    #[zbus(property(emits_changed_signal = "const"))]
+    fn can_quit(&self) -> bool {
+        false
+    }
+
+    #[zbus(property(emits_changed_signal = "const"))]
+    fn can_raise(&self) -> bool {
+        false
+    }
+
+    #[zbus(property(emits_changed_signal = "const"))]
+    fn has_track_list(&self) -> bool {
+        false
+    }
+
+    #[zbus(property(emits_changed_signal = "const"))]
+    fn identity(&self) -> &str {
+        "Coriander Player"
+    }
+
+    #[zbus(property(emits_changed_signal = "const"))]
+    fn desktop_entry(&self) -> &str {
+        "coriander_player"
+    }
+
+    #[zbus(property(emits_changed_signal = "const"))]
+    fn supported_uri_schemes(&self) -> Vec<String> {
+        vec![]
+    }
+
+    #[zbus(property(emits_changed_signal = "const"))]
+    fn supported_mime_types(&self) -> Vec<String> {
+        vec![]
+    }
+}
+
+/// org.mpris.MediaPlayer2.Player
+struct PlayerInterface {
+    on_event: Option<EventHandler>,
+    playing: Option<bool>,
+    track_id: String,
+    metadata: HashMap<String, OwnedValue>,
+    /// us
+    position: i64,
+    /// 收到 position 的时间，用于推算当前位置
+    position_updated: Instant,
+    volume: f64,
+    shuffle: bool,
+    repeat: RepeatMode,
+    rate: f64,
+}
+
+impl PlayerInterface {
+    fn new() -> Self {
+        Self {
+            on_event: None,
+            playing: None,
+            track_id: NO_TRACK.to_string(),
+            metadata: HashMap::new(),
+            position: 0,
+            position_updated: Instant::now(),
+            volume: 1.0,
+            shuffle: false,
+            repeat: RepeatMode::None,
+            rate: 1.0,
+        }
+    }
+
+    fn _emit(&self, event: MediaControlEvent) {
+        if let Some(on_event) = &self.on_event {
+            on_event(event);
+        }
+    }
+
+    /// 播放时按经过的时间推算当前位置
+    fn _current_position(&self) -> i64 {
+        match self.playing {
+            Some(true) => {
+                let elapsed = self.position_updated.elapsed().as_micros() as f64 * self.rate;
+                self.position + elapsed as i64
+            }
+            _ => self.position,
+        }
+    }
+}
+
+#[interface(name = "org.mpris.MediaPlayer2.Player")]
+impl PlayerInterface {
+    fn next(&self) {
+        self._emit(MediaControlEvent::Next);
+    }
+
+    fn previous(&self) {
+        self._emit(MediaControlEvent::Previous);
+    }
+
+    fn pause(&self) {
+        self._emit(MediaControlEvent::Pause);
+    }
+
+    fn play_pause(&self) {
+        self._emit(match self.playing {
+            Some(true) => MediaControlEvent::Pause,
+            _ => MediaControlEvent::Play,
+        });
+    }
+
+    fn stop(&self) {
+        self._emit(MediaControlEvent::Stop);
+    }
+
+    fn play(&self) {
+        self._emit(MediaControlEvent::Play);
+    }
+
+    /// offset: us
+    fn seek(&self, offset: i64) {
+        self._emit(MediaControlEvent::Seek(offset / 1000));
+    }
+
+    /// 不是当前曲目时忽略
+    fn set_position(&self, track_id: ObjectPath<'_>, position: i64) {
+        if track_id.as_str() == self.track_id && position >= 0 {
+            self._emit(MediaControlEvent::SetPosition(position as u64 / 1000));
+        }
+    }
+
+    fn open_uri(&self, _uri: &str) -> fdo::Result<()> {
+        Err(fdo::Error::NotSupported("OpenUri is not supported".into()))
+    }
+
+    #[zbus(signal)]
+    async fn seeked(emitter: &SignalEmitter<'_>, position: i64) -> zbus::Result<()>;
+
+    #[zbus(property)]
+    fn playback_status(&self) -> &str {
+        match self.playing {
+            Some(true) => "Playing",
+            Some(false) => "Paused",
+            None => "Stopped",
+        }
+    }
+
+    #[zbus(property)]
+    fn loop_status(&self) -> &str {
+        match self.repeat {
+            RepeatMode::None => "None",
+            RepeatMode::Track => "Track",
+            RepeatMode::List => "Playlist",
+        }
+    }
+
+    #[zbus(property)]
+    fn set_loop_status(&mut self, loop_status: &str) -> fdo::Result<()> {
+        self.repeat = match loop_status {
+            "None" => RepeatMode::None,
+            "Track" => RepeatMode::Track,
+            "Playlist" => RepeatMode::List,
+            _ => {
+                return Err(fdo::Error::InvalidArgs(format!(
+                    "invalid loop status: {loop_status}"
+                )))
+            }
+        };
+        self._emit(MediaControlEvent::Repeat(self.repeat));
+        Ok(())
+    }
+
+    #[zbus(property)]
+    fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    /// 按规范 rate 为 0 时等同于暂停
+    #[zbus(property)]
+    fn set_rate(&mut self, rate: f64) {
+        if rate <= 0.0 {
+            self._emit(MediaControlEvent::Pause);
+            return;
+        }
+        self.position = self._current_position();
+        self.position_updated = Instant::now();
+        self.rate = rate.clamp(MINIMUM_RATE, MAXIMUM_RATE);
+        self._emit(MediaControlEvent::Rate(self.rate));
+    }
+
+    #[zbus(property)]
+    fn shuffle(&self) -> bool {
+        self.shuffle
+    }
+
+    #[zbus(property)]
+    fn set_shuffle(&mut self, shuffle: bool) {
+        self.shuffle = shuffle;
+        self._emit(MediaControlEvent::Shuffle(shuffle));
+    }
+
+    #[zbus(property)]
+    fn metadata(&self) -> HashMap<String, OwnedValue> {
+        self.metadata
+            .iter()
+            .filter_map(|(key, value)| Some((key.clone(), value.try_clone().ok()?)))
+            .collect()
+    }
+
+    #[zbus(property)]
+    fn volume(&self) -> f64 {
+        self.volume
+    }
+
+    #[zbus(property)]
+    fn set_volume(&mut self, volume: f64) {
+        self.volume = volume.clamp(0.0, 1.0);
+        self._emit(MediaControlEvent::Volume(self.volume));
+    }
+
+    /// us。按规范不发出 PropertiesChanged，跳转时发出 Seeked
+    #[zbus(property(emits_changed_signal = "false"))]
+    fn position(&self) -> i64 {
+        self._current_position()
+    }
+
+    #[zbus(property(emits_changed_signal = "const"))]
+    fn minimum_rate(&self) -> f64 {
+        MINIMUM_RATE
+    }
+
+    #[zbus(property(emits_changed_signal = "const"))]
+    fn maximum_rate(&self) -> f64 {
+        MAXIMUM_RATE
+    }
+
+    #[zbus(property(emits_changed_signal = "const"))]
+    fn can_go_next(&self) -> bool {
+        true
+    }
+
+    #[zbus(property(emits_changed_signal = "const"))]
+    fn can_go_previous(&self) -> bool {
+        true
+    }
+
+    #[zbus(property(emits_changed_signal = "const"))]
+    fn can_play(&self) -> bool {
+        true
+    }
+
+    #[zbus(property(emits_changed_signal = "const"))]
+    fn can_pause(&self) -> bool {
+        true
+    }
+
+    #[zbus(property(emits_changed_signal = "const"))]
+    fn can_seek(&self) -> bool {
+        true
+    }
+
+    #[zbus(property(emits_changed_signal = "const"))]
+    fn can_control(&self) -> bool {
+        true
+    }
+}
+
+/// 封面目录：$XDG_RUNTIME_DIR 只有当前用户可以访问，没有时使用缓存目录。
+/// 每个进程使用自己的子目录，多个实例不会删除对方的文件
+fn _art_dir() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_RUNTIME_DIR")
+        .map(PathBuf::from)
+        .filter(|dir| dir.is_absolute())
+        .or_else(_cache_dir)?;
+    Some(
+        base.join("coriander_player")
+            .join(format!("mpris_art_{}", std::process::id())),
+    )
+}
+
+/// 通过 MPRIS2 接入桌面环境的媒体控件
+pub(crate) struct PlatformMediaControls {
+    connection: Connection,
+    player: InterfaceRef<PlayerInterface>,
+    /// artUrl 指向的临时文件
+    art_file: std::sync::Mutex<Option<PathBuf>>,
+}
+
+impl PlatformMediaControls {
+    /// 已经有同名的服务（另一个实例）时使用 org.mpris.MediaPlayer2.coriander_player.instance<pid>
+    pub(crate) fn connect(address: Option<&str>) -> Result<Self> {
+        let builder = || match address {
+            Some(address) => connection::Builder::address(address),
+            None => connection::Builder::session(),
+        };
+        let connection = match Self::_build(builder()?, BUS_NAME.to_string()) {
+            Ok(connection) => connection,
+            Err(_) => Self::_build(
+                builder()?,
+                format!("{BUS_NAME}.instance{}", std::process::id()),
+            )?,
+        };
+        let player = connection
+            .object_server()
+            .interface::<_, PlayerInterface>(OBJECT_PATH)?;
+
+        Ok(Self {
+            connection,
+            player,
+            art_file: std::sync::Mutex::new(None),
+        })
+    }
+
+    fn _build(builder: connection::Builder<'_>, name: String) -> zbus::Result<Connection> {
+        builder
+            .serve_at(OBJECT_PATH, RootInterface)?
+            .serve_at(OBJECT_PATH, PlayerInterface::new())?
+            .name(name)?
+            .build()
+    }
+
+    /// 把封面写入当前进程的封面目录，返回 file:// uri。同一张图片使用同一个文件名
+    fn _write_art(&self, picture: &[u8]) -> Result<String> {
+        let extension = image::guess_format(picture)
+            .ok()
+            .and_then(|format| format.extensions_str().first().copied())
+            .unwrap_or("jpg");
+        let dir = _art_dir().ok_or_else(|| anyhow!("no runtime or cache dir"))?;
+        DirBuilder::new().recursive(true).mode(0o700).create(&dir)?;
+        let path = dir.join(format!("mpris_art_{:016x}.{extension}", xxh3_64(picture)));
+        fs::write(&path, picture)?;
+
+        let mut art_file = self.art_file.lock().unwrap_or_else(|err| err.into_inner());
+        if let Some(old) = art_file.replace(path.clone()) {
+            if old != path {
+                let _ = fs::remove_file(old);
+            }
+        }
+        Ok(_file_uri(&path))
+    }
+
+    fn _metadata(
+        &self,
+        track_id: &str,
+        display: &MediaDisplay,
+    ) -> Result<HashMap<String, OwnedValue>> {
+        let mut metadata: HashMap<String, Value> = HashMap::from([
+            (
+                "mpris:trackid".to_string(),
+                ObjectPath::try_from(track_id)?.into(),
+            ),
+            (
+                "mpris:length".to_string(),
+                (display.duration as i64 * 1000).into(),
+            ),
+            ("xesam:title".to_string(), display.title.as_str().into()),
+            (
+                "xesam:artist".to_string(),
+                vec![display.artist.as_str()].into(),
+            ),
+            ("xesam:album".to_string(), display.album.as_str().into()),
+            (
+                "xesam:url".to_string(),
+                _file_uri(Path::new(&display.path)).into(),
+            ),
+        ]);
+        if let Some(album_artist) = &display.album_artist {
+            metadata.insert(
+                "xesam:albumArtist".to_string(),
+                vec![album_artist.as_str()].into(),
+            );
+        }
+        if let Some(track_number) = display.track_number {
+            metadata.insert(
+                "xesam:trackNumber".to_string(),
+                (track_number as i32).into(),
+            );
+        }
+        if let Some(genre) = &display.genre {
+            metadata.insert("xesam:genre".to_string(), vec![genre.as_str()].into());
+        }
+        if let Some(picture) = &display.picture {
+            metadata.insert("mpris:artUrl".to_string(), self._write_art(picture)?.into());
+        }
+
+        Ok(metadata
+            .into_iter()
+            .filter_map(|(key, value)| Some((key, value.try_into().ok()?)))
+            .collect())
+    }
+}
+
+impl MediaControls for PlatformMediaControls {
+    fn new() -> Result<Self> {
+        Self::connect(None)
+    }
+
+    fn subscribe(&self, on_event: Box<dyn Fn(MediaControlEvent) + Send + Sync>) -> Result<()> {
+        self.player.get_mut().on_event = Some(on_event);
+        Ok(())
+    }
+
+    fn set_state(&self, state: SMTCState) -> Result<()> {
+        {
+            let mut player = self.player.get_mut();
+            player.position = player._current_position();
+            player.position_updated = Instant::now();
+            player.playing = Some(matches!(state, SMTCState::Playing));
+        }
+        let player = self.player.get();
+        zbus::block_on(player.playback_status_changed(self.player.signal_emitter()))?;
+        Ok(())
+    }
+
+    /// 结束时间由 metadata 中的 mpris:length 表示，这里只同步位置和播放速度
+    fn set_timeline(&self, timeline: &Timeline) -> Result<()> {
+        let position = timeline.position as i64 * 1000;
+        let rate = timeline.rate.clamp(MINIMUM_RATE, MAXIMUM_RATE);
+        let (seeked, rate_changed) = {
+            let mut player = self.player.get_mut();
+            let seeked = (player._current_position() - position).abs() > SEEK_THRESHOLD_US;
+            let rate_changed = player.rate != rate;
+            player.position = position;
+            player.position_updated = Instant::now();
+            player.rate = rate;
+            (seeked, rate_changed)
+        };
+        if seeked {
+            zbus::block_on(PlayerInterface::seeked(
+                self.player.signal_emitter(),
+                position,
+            ))?;
+        }
+        if rate_changed {
+            let player = self.player.get();
+            zbus::block_on(player.rate_changed(self.player.signal_emitter()))?;
+        }
+        Ok(())
+    }
+
+    fn set_display(&self, display: MediaDisplay) -> Result<()> {
+        let track_id = format!("{TRACK_PATH}/{:016x}", xxh3_64(display.path.as_bytes()));
+        let metadata = self._metadata(&track_id, &display)?;
+        {
+            let mut player = self.player.get_mut();
+            player.track_id = track_id;
+            player.metadata = metadata;
+            player.position = 0;
+            player.position_updated = Instant::now();
+        }
+        let player = self.player.get();
+        zbus::block_on(player.metadata_changed(self.player.signal_emitter()))?;
+        Ok(())
+    }
+
+    fn set_shuffle(&self, shuffle: bool) -> Result<()> {
+        self.player.get_mut().shuffle = shuffle;
+        let player = self.player.get();
+        zbus::block_on(player.shuffle_changed(self.player.signal_emitter()))?;
+        Ok(())
+    }
+
+    fn set_repeat(&self, repeat: RepeatMode) -> Result<()> {
+        self.player.get_mut().repeat = repeat;
+        let player = self.player.get();
+        zbus::block_on(player.loop_status_changed(self.player.signal_emitter()))?;
+        Ok(())
+    }
+
+    fn close(&self) -> Result<()> {
+        self.connection
+            .object_server()
+            .remove::<PlayerInterface, _>(OBJECT_PATH)?;
+        self.connection
+            .object_server()
+            .remove::<RootInterface, _>(OBJECT_PATH)?;
+        if let Some(art_file) = self
+            .art_file
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .take()
+        {
+            let _ = fs::remove_file(art_file);
+        }
+        if let Some(dir) = _art_dir() {
+            let _ = fs::remove_dir(dir);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        os::unix::fs::MetadataExt,
+        sync::{Arc, Mutex},
+    };
+
+    use zbus::{blocking::fdo::PropertiesProxy, names::InterfaceName};
+
+    use super::*;
+    use crate::platform::linux::PrivateBus;
+
+    const PLAYER: &str = "org.mpris.MediaPlayer2.Player";
+
+    #[test]
+    fn mpris_player_on_private_bus() {
+        let bus = PrivateBus::start().expect("dbus-daemon is required to test MPRIS");
+        let controls = PlatformMediaControls::connect(Some(&bus.address)).unwrap();
+        let events = Arc::new(Mutex::new(vec![]));
+        let sink = events.clone();
+        controls
+            .subscribe(Box::new(move |event| sink.lock().unwrap().push(event)))
+            .unwrap();
+
+        let client = connection::Builder::address(bus.address.as_str())
+            .unwrap()
+            .build()
+            .unwrap();
+        let call = |method: &str| {
+            client
+                .call_method(Some(BUS_NAME), OBJECT_PATH, Some(PLAYER), method, &())
+                .unwrap();
+        };
+        let take_events = || std::mem::take(&mut *events.lock().unwrap());
+        let properties = PropertiesProxy::builder(&client)
+            .destination(BUS_NAME)
+            .unwrap()
+            .path(OBJECT_PATH)
+            .unwrap()
+            .build()
+            .unwrap();
+        let player = InterfaceName::from_static_str_unchecked(PLAYER);
+        let get = |name: &str| properties.get(player.clone(), name).unwrap();
+
+        call("PlayPause");
+        controls.set_state(SMTCState::Playing).unwrap();
+        call("PlayPause");
+        call("Next");
+        assert_eq!(
+            take_events(),
+            [
+                MediaControlEvent::Play,
+                MediaControlEvent::Pause,
+                MediaControlEvent::Next
+            ]
+        );
+        assert_eq!(String::try_from(get("PlaybackStatus")).unwrap(), "Playing");
+
+        let picture = b"\x89PNG\r\n\x1a\n fake png".to_vec();
+        controls
+            .set_display(MediaDisplay {
+                title: "Song".to_string(),
+                artist: "Singer".to_string(),
+                album: "Album".to_string(),
+                album_artist: Some("Band".to_string()),
+                track_number: Some(3),
+                genre: None,
+                duration: 90_000,
+                picture: Some(picture.clone()),
+                path: "/music/a b.flac".to_string(),
+            })
+            .unwrap();
+        let metadata = HashMap::<String, OwnedValue>::try_from(get("Metadata")).unwrap();
+        let string = |key: &str| String::try_from(metadata[key].try_clone().unwrap()).unwrap();
+        assert_eq!(string("xesam:title"), "Song");
+        assert_eq!(
+            Vec::<String>::try_from(metadata["xesam:artist"].try_clone().unwrap()).unwrap(),
+            ["Singer"]
+        );
+        assert_eq!(string("xesam:url"), "file:///music/a%20b.flac");
+        assert_eq!(
+            Vec::<String>::try_from(metadata["xesam:albumArtist"].try_clone().unwrap()).unwrap(),
+            ["Band"]
+        );
+        assert_eq!(
+            i32::try_from(metadata["xesam:trackNumber"].try_clone().unwrap()).unwrap(),
+            3
+        );
+        assert!(!metadata.contains_key("xesam:genre"));
+        assert_eq!(
+            i64::try_from(metadata["mpris:length"].try_clone().unwrap()).unwrap(),
+            90_000_000
+        );
+        let art_url = string("mpris:artUrl");
+        assert!(art_url.ends_with(".png"));
+        let art_path = PathBuf::from(art_url.strip_prefix("file://").unwrap());
+        assert_eq!(fs::read(&art_path).unwrap(), picture);
+        let art_dir = art_path.parent().unwrap().to_path_buf();
+        assert_eq!(art_dir, _art_dir().unwrap());
+        assert_eq!(fs::metadata(&art_dir).unwrap().mode() & 0o777, 0o700);
+
+        let track_id =
+            ObjectPath::try_from(metadata["mpris:trackid"].try_clone().unwrap()).unwrap();
+        let seek = |offset: i64| {
+            client
+                .call_method(Some(BUS_NAME), OBJECT_PATH, Some(PLAYER), "Seek", &offset)
+                .unwrap();
+        };
+        let set_position = |track_id: &ObjectPath<'_>, position: i64| {
+            client
+                .call_method(
+                    Some(BUS_NAME),
+                    OBJECT_PATH,
+                    Some(PLAYER),
+                    "SetPosition",
+                    &(track_id, position),
+                )
+                .unwrap();
+        };
+        seek(-5_000_000);
+        set_position(&track_id, 10_000_000);
+        set_position(&ObjectPath::try_from(NO_TRACK).unwrap(), 20_000_000);
+        assert_eq!(
+            take_events(),
+            [
+                MediaControlEvent::Seek(-5000),
+                MediaControlEvent::SetPosition(10_000)
+            ]
+        );
+
+        let timeline = |position: u32, rate: f64| Timeline {
+            start: 0,
+            end: 90_000,
+            min_seek: 0,
+            max_seek: 90_000,
+            position,
+            rate,
+        };
+        controls.set_state(SMTCState::Paused).unwrap();
+        controls.set_timeline(&timeline(42_000, 1.0)).unwrap();
+        assert_eq!(i64::try_from(get("Position")).unwrap(), 42_000_000);
+
+        properties
+            .set(player.clone(), "Volume", Value::from(1.5))
+            .unwrap();
+        properties
+            .set(player.clone(), "Shuffle", Value::from(true))
+            .unwrap();
+        properties
+            .set(player.clone(), "LoopStatus", Value::from("Playlist"))
+            .unwrap();
+        assert!(properties
+            .set(player.clone(), "LoopStatus", Value::from("Forever"))
+            .is_err());
+        assert_eq!(
+            take_events(),
+            [
+                MediaControlEvent::Volume(1.0),
+                MediaControlEvent::Shuffle(true),
+                MediaControlEvent::Repeat(RepeatMode::List)
+            ]
+        );
+        assert_eq!(f64::try_from(get("Volume")).unwrap(), 1.0);
+        assert!(bool::try_from(get("Shuffle")).unwrap());
+        assert_eq!(String::try_from(get("LoopStatus")).unwrap(), "Playlist");
+
+        properties
+            .set(player.clone(), "Rate", Value::from(0.0))
+            .unwrap();
+        properties
+            .set(player.clone(), "Rate", Value::from(1.5))
+            .unwrap();
+        assert_eq!(
+            take_events(),
+            [MediaControlEvent::Pause, MediaControlEvent::Rate(1.5)]
+        );
+
+        // 播放器没有采用请求的值时同步回来
+        controls.set_shuffle(false).unwrap();
+        controls.set_repeat(RepeatMode::Track).unwrap();
+        controls.set_timeline(&timeline(42_000, 1.0)).unwrap();
+        assert!(!bool::try_from(get("Shuffle")).unwrap());
+        assert_eq!(String::try_from(get("LoopStatus")).unwrap(), "Track");
+        assert_eq!(f64::try_from(get("Rate")).unwrap(), 1.0);
+
+        controls.close().unwrap();
+        assert!(!art_path.exists());
+        assert!(!art_dir.exists());
+    }
+}
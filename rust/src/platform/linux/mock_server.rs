@@ -0,0 +1,83 @@
+use std::{
+    collections::HashMap,
+    sync::{mpsc, Arc, Mutex},
+};
+
+use anyhow::{anyhow, Result};
+
+use super::volume::{AudioServer, ServerEvent, SinkInfo, Subscription};
+
+/// 模拟的音频服务器，测试通过 send 发送事件
+#[derive(Default)]
+pub(crate) struct MockServer {
+    pub default_sink: Mutex<Option<String>>,
+    pub sinks: Mutex<Vec<SinkInfo>>,
+    pub volumes: Mutex<HashMap<String, f64>>,
+    pub mutes: Mutex<HashMap<String, bool>>,
+    pub events: Arc<Mutex<Option<mpsc::Sender<ServerEvent>>>>,
+}
+
+impl MockServer {
+    pub(crate) fn send(&self, event: ServerEvent) {
+        if let Some(sender) = self.events.lock().unwrap().as_ref() {
+            sender.send(event).unwrap();
+        }
+    }
+}
+
+impl AudioServer for Arc<MockServer> {
+    fn default_sink(&self) -> Result<String> {
+        self.default_sink
+            .lock()
+            .unwrap()
+            .clone()
+            .ok_or_else(|| anyhow!("no default sink"))
+    }
+
+    fn sink_volume(&self, sink: &str) -> Result<f64> {
+        self.volumes
+            .lock()
+            .unwrap()
+            .get(sink)
+            .copied()
+            .ok_or_else(|| anyhow!("no sink {sink}"))
+    }
+
+    fn set_sink_volume(&self, sink: &str, volume: f64) -> Result<()> {
+        self.volumes
+            .lock()
+            .unwrap()
+            .insert(sink.to_string(), volume);
+        Ok(())
+    }
+
+    fn sink_mute(&self, sink: &str) -> Result<bool> {
+        self.mutes
+            .lock()
+            .unwrap()
+            .get(sink)
+            .copied()
+            .ok_or_else(|| anyhow!("no sink {sink}"))
+    }
+
+    fn set_sink_mute(&self, sink: &str, mute: bool) -> Result<()> {
+        self.mutes.lock().unwrap().insert(sink.to_string(), mute);
+        Ok(())
+    }
+
+    fn sinks(&self) -> Result<Vec<SinkInfo>> {
+        Ok(self.sinks.lock().unwrap().clone())
+    }
+
+    fn subscribe(&self) -> Result<Subscription> {
+        let (sender, events) = mpsc::channel();
+        *self.events.lock().unwrap() = Some(sender);
+        let stop_events = self.events.clone();
+        Ok(Subscription {
+            events,
+            stop: Box::new(move || {
+                stop_events.lock().unwrap().take();
+            }),
+        })
+    }
+}
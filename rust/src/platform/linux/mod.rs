@@ -0,0 +1,219 @@
+// Linux：freedesktop 规范和常见的桌面工具
+
+use std::{
+    io,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+use anyhow::{anyhow, Result};
+use md5::{Digest, Md5};
+
+use super::{FileRevealer, FolderPicker, MusicProperties, ThemeProvider, ThumbnailProvider};
+use crate::api::system_theme::SystemTheme;
+
+mod devices;
+mod media_controls;
+#[cfg(test)]
+mod mock_server;
+#[cfg(test)]
+mod test_bus;
+mod volume;
+
+pub(crate) use media_controls::PlatformMediaControls;
+#[cfg(test)]
+pub(crate) use mock_server::MockServer;
+#[cfg(test)]
+pub(crate) use test_bus::PrivateBus;
+
+pub(crate) struct Platform;
+
+/// GNOME 47 的强调色，取自 libadwaita
+const ACCENT_COLORS: [(&str, (u8, u8, u8)); 9] = [
+    ("blue", (0x35, 0x84, 0xe4)),
+    ("teal", (0x21, 0x90, 0xa4)),
+    ("green", (0x3a, 0x94, 0x4a)),
+    ("yellow", (0xc8, 0x88, 0x00)),
+    ("orange", (0xed, 0x5b, 0x00)),
+    ("red", (0xe6, 0x2d, 0x42)),
+    ("pink", (0xd5, 0x61, 0x99)),
+    ("purple", (0x91, 0x41, 0xac)),
+    ("slate", (0x6f, 0x83, 0x96)),
+];
+
+/// 运行命令并返回去掉首尾空白的标准输出。命令不存在或者失败时返回 Err
+fn _command_output(program: &str, args: &[&str]) -> Result<String> {
+    let output = Command::new(program)
+        .args(args)
+        .stderr(Stdio::null())
+        .output()?;
+    if !output.status.success() {
+        return Err(anyhow!("{program} exited with {}", output.status));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// 命令不存在
+fn _not_found(err: &anyhow::Error) -> bool {
+    err.downcast_ref::<io::Error>()
+        .is_some_and(|err| err.kind() == io::ErrorKind::NotFound)
+}
+
+/// gsettings 输出的字符串带有单引号
+fn _gsettings_get(key: &str) -> Result<String> {
+    let value = _command_output("gsettings", &["get", "org.gnome.desktop.interface", key])?;
+    Ok(value.trim_matches('\'').to_string())
+}
+
+/// 启动程序，不等待它退出
+fn _spawn(program: &str, args: &[&str]) -> Result<bool> {
+    Command::new(program)
+        .args(args)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    Ok(true)
+}
+
+/// RFC 3986 中的非保留字符和 '/' 不转义
+fn _file_uri(path: &Path) -> String {
+    let mut uri = String::from("file://");
+    for byte in path.as_os_str().as_encoded_bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => {
+                uri.push(*byte as char)
+            }
+            _ => uri.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    uri
+}
+
+fn _cache_dir() -> Option<PathBuf> {
+    std::env::var_os("XDG_CACHE_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".cache")))
+}
+
+/// freedesktop 缩略图缓存：$XDG_CACHE_HOME/thumbnails/<size>/<md5(uri)>.png
+fn _thumbnail_paths(cache_dir: &Path, path: &Path) -> Vec<PathBuf> {
+    let hash = Md5::digest(_file_uri(path).as_bytes());
+    let name = format!(
+        "{}.png",
+        hash.iter().map(|b| format!("{b:02x}")).collect::<String>()
+    );
+    ["xx-large", "x-large", "large", "normal"]
+        .iter()
+        .map(|size| cache_dir.join("thumbnails").join(size).join(&name))
+        .collect()
+}
+
+impl ThemeProvider for Platform {
+    fn system_theme(&self) -> Result<SystemTheme> {
+        let dark = _gsettings_get("color-scheme")? == "prefer-dark";
+        let fore = if dark {
+            (255, 255, 255, 255)
+        } else {
+            (255, 0, 0, 0)
+        };
+        // 旧版本的 GNOME 没有 accent-color
+        let accent = _gsettings_get("accent-color")
+            .ok()
+            .and_then(|name| ACCENT_COLORS.iter().find(|(key, _)| *key == name))
+            .map_or((0, 0, 0, 0), |(_, (r, g, b))| (255, *r, *g, *b));
+
+        Ok(SystemTheme { fore, accent })
+    }
+}
+
+impl FileRevealer for Platform {
+    /// 优先使用 org.freedesktop.FileManager1 选中文件，不支持时打开父级目录
+    fn reveal(&self, path: &str) -> Result<bool> {
+        let path = Path::new(path);
+        let uri = _file_uri(path);
+        let show_items = _command_output(
+            "dbus-send",
+            &[
+                "--session",
+                "--print-reply",
+                "--dest=org.freedesktop.FileManager1",
+                "/org/freedesktop/FileManager1",
+                "org.freedesktop.FileManager1.ShowItems",
+                &format!("array:string:{uri}"),
+                "string:",
+            ],
+        );
+        if show_items.is_ok() {
+            return Ok(true);
+        }
+
+        let parent = path
+            .parent()
+            .ok_or_else(|| anyhow!("{:?} has no parent", path))?;
+        _spawn("xdg-open", &[&_file_uri(parent)])
+    }
+
+    fn open_uri(&self, uri: &str) -> Result<bool> {
+        _spawn("xdg-open", &[uri])
+    }
+}
+
+impl FolderPicker for Platform {
+    /// GNOME 使用 zenity，KDE 使用 kdialog
+    fn pick_folder(&self) -> Result<Option<String>> {
+        // 用户取消时 zenity 也会返回非零，只有没安装 zenity 时才换成 kdialog
+        let folder = match _command_output("zenity", &["--file-selection", "--directory"]) {
+            Err(err) if _not_found(&err) => _command_output("kdialog", &["--getexistingdirectory"]),
+            folder => folder,
+        };
+        Ok(folder.ok().filter(|folder| !folder.is_empty()))
+    }
+}
+
+impl ThumbnailProvider for Platform {
+    /// 只读取文件管理器已经生成的缩略图
+    fn thumbnail(&self, path: &str) -> Result<Vec<u8>> {
+        let path = std::fs::canonicalize(path)?;
+        let cache_dir = _cache_dir().ok_or_else(|| anyhow!("no cache dir"))?;
+        _thumbnail_paths(&cache_dir, &path)
+            .iter()
+            .find_map(|thumbnail| std::fs::read(thumbnail).ok())
+            .ok_or_else(|| anyhow!("no thumbnail for {:?}", path))
+    }
+
+    fn music_properties(&self, path: &Path) -> Result<MusicProperties> {
+        Err(anyhow!("no music properties provider for {:?}", path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn thumbnail_cache_path_uses_escaped_uri() {
+        let path = Path::new("/home/jens/photos/me.png");
+        assert_eq!(_file_uri(path), "file:///home/jens/photos/me.png");
+        assert_eq!(
+            _file_uri(Path::new("/music/a b/歌.flac")),
+            "file:///music/a%20b/%E6%AD%8C.flac"
+        );
+
+        // freedesktop 缩略图规范中的示例
+        assert_eq!(
+            _thumbnail_paths(Path::new("/cache"), path)[2],
+            Path::new("/cache/thumbnails/large/c6ee772d9e49320e97ec29a7eb5b1697.png")
+        );
+    }
+
+    #[test]
+    fn only_missing_commands_are_not_found() {
+        let missing = _command_output("coriander-player-no-such-command", &[]).unwrap_err();
+        assert!(_not_found(&missing));
+
+        // 取消对话框时命令存在但返回非零
+        let failed = _command_output("false", &[]).unwrap_err();
+        assert!(!_not_found(&failed));
+    }
+}
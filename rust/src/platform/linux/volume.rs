@@ -0,0 +1,627 @@
+// PulseAudio 的默认输出设备音量。PipeWire 通过 pipewire-pulse 提供相同的接口。
+// 通过 pactl 读写音量，`pactl subscribe` 监听变化。
+
+use std::{
+    io::{self, BufRead, BufReader},
+    process::{Child, Command, Stdio},
+    sync::{mpsc, Arc, Mutex},
+    thread::{self, JoinHandle},
+};
+
+use anyhow::{anyhow, Result};
+
+use super::Platform;
+use crate::api::system_volume::{OutputDeviceState, OutputFormFactor};
+use crate::frb_generated::StreamSink;
+use crate::platform::SystemVolume;
+
+/// PA_VOLUME_NORM，100% 音量
+const VOLUME_NORM: f64 = 65536.0;
+
+/// 音频服务器发出的事件
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum ServerEvent {
+    /// 某个输出设备的音量或静音状态变化
+    SinkChanged,
+    /// 服务器的设置变化，默认输出设备可能变了
+    ServerChanged,
+    /// 添加了输出设备，参数是 sink 的序号
+    SinkAdded(u32),
+    SinkRemoved(u32),
+}
+
+/// `pactl list sinks` 中的一个输出设备
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SinkInfo {
+    pub index: u32,
+    pub name: String,
+    pub description: String,
+    pub form_factor: OutputFormFactor,
+    pub state: OutputDeviceState,
+}
+
+/// 订阅服务器事件。调用 stop 后 events 结束
+pub(crate) struct Subscription {
+    pub events: mpsc::Receiver<ServerEvent>,
+    pub stop: Box<dyn FnOnce() + Send>,
+}
+
+/// 音频服务器的接口，测试时使用模拟的服务器
+pub(crate) trait AudioServer: Send + Sync + 'static {
+    fn default_sink(&self) -> Result<String>;
+
+    /// 0.0 ~ 1.0，多个声道时取平均值
+    fn sink_volume(&self, sink: &str) -> Result<f64>;
+
+    fn set_sink_volume(&self, sink: &str, volume: f64) -> Result<()>;
+
+    fn sink_mute(&self, sink: &str) -> Result<bool>;
+
+    fn set_sink_mute(&self, sink: &str, mute: bool) -> Result<()>;
+
+    fn sinks(&self) -> Result<Vec<SinkInfo>>;
+
+    fn subscribe(&self) -> Result<Subscription>;
+}
+
+/// 通过 pactl 访问 PulseAudio 或 pipewire-pulse
+pub(crate) struct PactlServer;
+
+impl PactlServer {
+    /// 输出不随语言变化
+    fn _command() -> Command {
+        let mut command = Command::new("pactl");
+        command.env("LC_ALL", "C").stderr(Stdio::null());
+        command
+    }
+
+    /// 没有安装 pactl 时给出明确的错误，而不是 No such file or directory
+    fn _spawn_error(err: io::Error) -> anyhow::Error {
+        match err.kind() {
+            io::ErrorKind::NotFound => anyhow!(
+                "pactl is not installed, install pulseaudio-utils or pipewire-pulse to control system volume"
+            ),
+            _ => anyhow!("fail to run pactl: {err}"),
+        }
+    }
+
+    fn _output(args: &[&str]) -> Result<String> {
+        let output = Self::_command()
+            .args(args)
+            .output()
+            .map_err(Self::_spawn_error)?;
+        if !output.status.success() {
+            return Err(anyhow!("pactl {} exited with {}", args[0], output.status));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    }
+}
+
+impl AudioServer for PactlServer {
+    fn default_sink(&self) -> Result<String> {
+        _parse_default_sink(&Self::_output(&["info"])?).ok_or_else(|| anyhow!("no default sink"))
+    }
+
+    fn sink_volume(&self, sink: &str) -> Result<f64> {
+        _parse_volume(&Self::_output(&["get-sink-volume", sink])?)
+            .ok_or_else(|| anyhow!("fail to parse volume of {sink}"))
+    }
+
+    fn set_sink_volume(&self, sink: &str, volume: f64) -> Result<()> {
+        let volume = ((volume.clamp(0.0, 1.0) * VOLUME_NORM).round() as u32).to_string();
+        Self::_output(&["set-sink-volume", sink, &volume])?;
+        Ok(())
+    }
+
+    fn sink_mute(&self, sink: &str) -> Result<bool> {
+        let output = Self::_output(&["get-sink-mute", sink])?;
+        match output.trim().strip_prefix("Mute:").map(str::trim) {
+            Some("yes") => Ok(true),
+            Some("no") => Ok(false),
+            _ => Err(anyhow!("fail to parse mute of {sink}")),
+        }
+    }
+
+    fn set_sink_mute(&self, sink: &str, mute: bool) -> Result<()> {
+        Self::_output(&["set-sink-mute", sink, if mute { "1" } else { "0" }])?;
+        Ok(())
+    }
+
+    fn sinks(&self) -> Result<Vec<SinkInfo>> {
+        Ok(_parse_sinks(&Self::_output(&["list", "sinks"])?))
+    }
+
+    fn subscribe(&self) -> Result<Subscription> {
+        let mut child: Child = Self::_command()
+            .arg("subscribe")
+            .stdout(Stdio::piped())
+            .spawn()
+            .map_err(Self::_spawn_error)?;
+        let stdout = child
+            .stdout
+            .take()
+            .ok_or_else(|| anyhow!("no stdout of pactl subscribe"))?;
+
+        let (sender, events) = mpsc::channel();
+        thread::spawn(move || {
+            for line in BufReader::new(stdout).lines() {
+                let Ok(line) = line else {
+                    break;
+                };
+                if let Some(event) = _parse_event(&line) {
+                    if sender.send(event).is_err() {
+                        break;
+                    }
+                }
+            }
+        });
+
+        Ok(Subscription {
+            events,
+            stop: Box::new(move || {
+                let _ = child.kill();
+                let _ = child.wait();
+            }),
+        })
+    }
+}
+
+/// `pactl info` 中的 `Default Sink: name`
+fn _parse_default_sink(info: &str) -> Option<String> {
+    info.lines()
+        .find_map(|line| line.trim().strip_prefix("Default Sink:"))
+        .map(|sink| sink.trim().to_string())
+        .filter(|sink| !sink.is_empty())
+}
+
+/// `Volume: front-left: 32768 /  50% / -18.06 dB,   front-right: ...`
+fn _parse_volume(output: &str) -> Option<f64> {
+    let line = output.lines().find(|line| line.contains("Volume:"))?;
+    let volumes: Vec<f64> = line
+        .split(',')
+        .filter_map(|channel| {
+            let (_, value) = channel.rsplit_once(':')?;
+            value.split_whitespace().next()?.parse::<f64>().ok()
+        })
+        .collect();
+    if volumes.is_empty() {
+        return None;
+    }
+    let average = volumes.iter().sum::<f64>() / volumes.len() as f64;
+    Some((average / VOLUME_NORM).clamp(0.0, 1.0))
+}
+
+/// `Event 'change' on sink #53`
+fn _parse_event(line: &str) -> Option<ServerEvent> {
+    let line = line.trim();
+    if !line.starts_with("Event ") {
+        return None;
+    }
+    if line.contains(" on server") {
+        return Some(ServerEvent::ServerChanged);
+    }
+    let (kind, index) = line.split_once(" on sink #")?;
+    let index = index.trim().parse().ok()?;
+    match kind {
+        "Event 'change'" => Some(ServerEvent::SinkChanged),
+        "Event 'new'" => Some(ServerEvent::SinkAdded(index)),
+        "Event 'remove'" => Some(ServerEvent::SinkRemoved(index)),
+        _ => None,
+    }
+}
+
+/// `pactl list sinks` 的输出，每个设备以 `Sink #53` 开头
+fn _parse_sinks(output: &str) -> Vec<SinkInfo> {
+    let mut sinks = vec![];
+    let mut lines = output.lines().peekable();
+    while let Some(line) = lines.next() {
+        let Some(index) = line.trim().strip_prefix("Sink #") else {
+            continue;
+        };
+        let Ok(index) = index.trim().parse() else {
+            continue;
+        };
+
+        let mut name = String::new();
+        let mut description = String::new();
+        let mut form_factor = None;
+        let mut ports = vec![];
+        let mut active_port = None;
+        while let Some(line) = lines.next_if(|line| !line.starts_with("Sink #")) {
+            let line = line.trim();
+            if let Some(value) = line.strip_prefix("Name:") {
+                name = value.trim().to_string();
+            } else if let Some(value) = line.strip_prefix("Description:") {
+                description = value.trim().to_string();
+            } else if let Some(value) = line.strip_prefix("device.form_factor =") {
+                form_factor = Some(value.trim().trim_matches('"').to_string());
+            } else if let Some(value) = line.strip_prefix("Active Port:") {
+                active_port = Some(value.trim().to_string());
+            } else if let Some((port, _)) = line.split_once(": ") {
+                if line.contains("(type:") || line.contains("(priority:") {
+                    ports.push((port.to_string(), line.contains("not available")));
+                }
+            }
+        }
+
+        let unplugged = active_port.as_ref().is_some_and(|active| {
+            ports
+                .iter()
+                .any(|(port, not_available)| port == active && *not_available)
+        });
+        sinks.push(SinkInfo {
+            index,
+            form_factor: _form_factor(form_factor.as_deref(), active_port.as_deref(), &name),
+            name,
+            description,
+            state: match unplugged {
+                true => OutputDeviceState::Unplugged,
+                false => OutputDeviceState::Active,
+            },
+        });
+    }
+    sinks
+}
+
+/// 优先使用 device.form_factor，没有时根据端口和设备名称猜测
+fn _form_factor(
+    form_factor: Option<&str>,
+    active_port: Option<&str>,
+    name: &str,
+) -> OutputFormFactor {
+    match form_factor {
+        Some("internal" | "speaker" | "computer") => return OutputFormFactor::Speakers,
+        Some("headphone") => return OutputFormFactor::Headphones,
+        Some("headset" | "handset" | "hands-free") => return OutputFormFactor::Headset,
+        Some("tv") => return OutputFormFactor::Hdmi,
+        Some("hifi") => return OutputFormFactor::LineOut,
+        _ => {}
+    }
+
+    let hint = format!("{} {}", active_port.unwrap_or_default(), name).to_lowercase();
+    if name.starts_with("tunnel.") || name.contains("raop") {
+        OutputFormFactor::Network
+    } else if hint.contains("hdmi") || hint.contains("displayport") {
+        OutputFormFactor::Hdmi
+    } else if hint.contains("iec958") || hint.contains("spdif") {
+        OutputFormFactor::Spdif
+    } else if hint.contains("headphone") {
+        OutputFormFactor::Headphones
+    } else if hint.contains("headset") {
+        OutputFormFactor::Headset
+    } else if hint.contains("lineout") || hint.contains("line-out") {
+        OutputFormFactor::LineOut
+    } else if hint.contains("speaker") {
+        OutputFormFactor::Speakers
+    } else {
+        OutputFormFactor::Unknown
+    }
+}
+
+type OnVolume = Arc<dyn Fn(f64) + Send + Sync>;
+
+/// 当前绑定的输出设备和最近一次通知的音量
+#[derive(Default)]
+struct BoundSink {
+    name: Option<String>,
+    notified: Option<f64>,
+}
+
+/// 绑定默认输出设备，默认设备变化后重新绑定
+struct VolumeManager<S: AudioServer> {
+    server: Arc<S>,
+    bound: Arc<Mutex<BoundSink>>,
+    stop: Option<Box<dyn FnOnce() + Send>>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl<S: AudioServer> VolumeManager<S> {
+    fn new(server: S, on_volume: impl Fn(f64) + Send + Sync + 'static) -> Result<Self> {
+        let server = Arc::new(server);
+        let bound = Arc::new(Mutex::new(BoundSink {
+            name: Some(server.default_sink()?),
+            notified: None,
+        }));
+        let on_volume: OnVolume = Arc::new(on_volume);
+
+        let Subscription { events, stop } = server.subscribe()?;
+
+        let worker = {
+            let server = server.clone();
+            let bound = bound.clone();
+            thread::spawn(move || {
+                for event in events {
+                    match event {
+                        ServerEvent::SinkChanged => {
+                            Self::_notify_volume(&server, &bound, &on_volume)
+                        }
+                        ServerEvent::ServerChanged => {
+                            let _ = Self::_rebind(&server, &bound, &on_volume);
+                        }
+                        ServerEvent::SinkAdded(_) | ServerEvent::SinkRemoved(_) => {}
+                    }
+                }
+            })
+        };
+
+        Ok(Self {
+            server,
+            bound,
+            stop: Some(stop),
+            worker: Some(worker),
+        })
+    }
+
+    fn _sink(bound: &Mutex<BoundSink>) -> Option<String> {
+        bound.lock().ok()?.name.clone()
+    }
+
+    /// 音量和上次通知的不同时才通知
+    fn _notify_volume(server: &S, bound: &Mutex<BoundSink>, on_volume: &OnVolume) {
+        let Some(sink) = Self::_sink(bound) else {
+            return;
+        };
+        let Ok(volume) = server.sink_volume(&sink) else {
+            return;
+        };
+        if let Ok(mut bound) = bound.lock() {
+            if bound.notified == Some(volume) {
+                return;
+            }
+            bound.notified = Some(volume);
+        }
+        on_volume(volume);
+    }
+
+    /// 和 Windows 相同：找不到默认设备时通知 0，否则通知新设备的音量
+    fn _rebind(server: &S, bound: &Mutex<BoundSink>, on_volume: &OnVolume) -> Result<()> {
+        let sink = match server.default_sink() {
+            Ok(sink) => sink,
+            Err(err) => {
+                if let Ok(mut bound) = bound.lock() {
+                    *bound = BoundSink {
+                        name: None,
+                        notified: Some(0.0),
+                    };
+                }
+                on_volume(0.0);
+                return Err(err);
+            }
+        };
+        if let Ok(mut bound) = bound.lock() {
+            if bound.name.as_deref() == Some(sink.as_str()) {
+                return Ok(());
+            }
+            *bound = BoundSink {
+                name: Some(sink),
+                notified: None,
+            };
+        }
+        Self::_notify_volume(server, bound, on_volume);
+        Ok(())
+    }
+
+    fn get_volume(&self) -> Result<f64> {
+        let sink = Self::_sink(&self.bound).ok_or_else(|| anyhow!("no default sink"))?;
+        self.server.sink_volume(&sink)
+    }
+
+    fn set_volume(&self, volume: f64) -> Result<()> {
+        let sink = Self::_sink(&self.bound).ok_or_else(|| anyhow!("no default sink"))?;
+        self.server.set_sink_volume(&sink, volume)
+    }
+}
+
+impl<S: AudioServer> Drop for VolumeManager<S> {
+    fn drop(&mut self) {
+        // 停止订阅后 events 结束，worker 自然退出
+        if let Some(stop) = self.stop.take() {
+            stop();
+        }
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+static GLOBAL_MANAGER: Mutex<Option<VolumeManager<PactlServer>>> = Mutex::new(None);
+
+impl SystemVolume for Platform {
+    fn init(&self, sink: StreamSink<f64>) -> Result<f64> {
+        self.dispose();
+
+        let manager = VolumeManager::new(PactlServer, move |volume| {
+            let _ = sink.add(volume);
+        })?;
+        let current_vol = manager.get_volume()?;
+
+        *GLOBAL_MANAGER.lock().unwrap_or_else(|err| err.into_inner()) = Some(manager);
+        Ok(current_vol)
+    }
+
+    fn set(&self, val: f64) -> Result<()> {
+        if let Some(manager) = GLOBAL_MANAGER
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .as_ref()
+        {
+            manager.set_volume(val)?;
+        }
+        Ok(())
+    }
+
+    fn get(&self) -> Result<f64> {
+        if let Some(manager) = GLOBAL_MANAGER
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .as_ref()
+        {
+            return manager.get_volume();
+        }
+        Ok(0.0)
+    }
+
+    fn dispose(&self) {
+        let manager = GLOBAL_MANAGER
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .take();
+        drop(manager);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::platform::linux::MockServer;
+
+    #[test]
+    fn parse_pactl_output() {
+        let info = "Server Name: PulseAudio (on PipeWire 1.0.5)\n\
+                    Default Sink: alsa_output.pci-0000_00_1f.3.analog-stereo\n\
+                    Default Source: alsa_input.pci-0000_00_1f.3.analog-stereo\n";
+        assert_eq!(
+            _parse_default_sink(info).as_deref(),
+            Some("alsa_output.pci-0000_00_1f.3.analog-stereo")
+        );
+        assert_eq!(_parse_default_sink("Default Sink: \n"), None);
+
+        let volume = "Volume: front-left: 32768 /  50% / -18.06 dB,   \
+                      front-right: 65536 / 100% / 0.00 dB\n        balance 0.00\n";
+        assert_eq!(_parse_volume(volume), Some(0.75));
+        assert_eq!(
+            _parse_volume("Volume: mono: 98304 / 150% / 10.57 dB"),
+            Some(1.0)
+        );
+        assert_eq!(_parse_volume("Failure: No such entity"), None);
+
+        assert_eq!(
+            _parse_event("Event 'change' on sink #53"),
+            Some(ServerEvent::SinkChanged)
+        );
+        assert_eq!(
+            _parse_event("Event 'change' on server #-1"),
+            Some(ServerEvent::ServerChanged)
+        );
+        assert_eq!(
+            _parse_event("Event 'new' on sink #54"),
+            Some(ServerEvent::SinkAdded(54))
+        );
+        assert_eq!(
+            _parse_event("Event 'remove' on sink #54"),
+            Some(ServerEvent::SinkRemoved(54))
+        );
+        assert_eq!(_parse_event("Event 'change' on sink-input #80"), None);
+        assert_eq!(_parse_event("Event 'new' on client #90"), None);
+    }
+
+    #[test]
+    fn missing_pactl_error() {
+        let err = PactlServer::_spawn_error(io::ErrorKind::NotFound.into());
+        assert!(err.to_string().starts_with("pactl is not installed"));
+    }
+
+    #[test]
+    fn parse_pactl_sinks() {
+        let output = "Sink #53
+\tState: SUSPENDED
+\tName: alsa_output.pci-0000_00_1f.3.analog-stereo
+\tDescription: Built-in Audio Analog Stereo
+\tMute: no
+\tVolume: front-left: 26214 /  40% / -23.88 dB,   front-right: 26214 /  40% / -23.88 dB
+\t        balance 0.00
+\tProperties:
+\t\talsa.card = \"0\"
+\t\tdevice.bus = \"pci\"
+\tPorts:
+\t\tanalog-output-speaker: Speakers (type: Speaker, priority: 10000, availability unknown)
+\t\tanalog-output-headphones: Headphones (type: Headphones, priority: 9900, not available)
+\tActive Port: analog-output-headphones
+\tFormats:
+\t\tpcm
+
+Sink #61
+\tState: RUNNING
+\tName: bluez_output.00_11_22_33_44_55.1
+\tDescription: WH-1000XM4
+\tProperties:
+\t\tdevice.form_factor = \"headset\"
+\t\tdevice.bus = \"bluetooth\"
+\tActive Port: unknown
+
+Sink #70
+\tName: alsa_output.pci-0000_01_00.1.hdmi-stereo
+\tDescription: HDMI Audio
+";
+        assert_eq!(
+            _parse_sinks(output),
+            [
+                SinkInfo {
+                    index: 53,
+                    name: "alsa_output.pci-0000_00_1f.3.analog-stereo".to_string(),
+                    description: "Built-in Audio Analog Stereo".to_string(),
+                    form_factor: OutputFormFactor::Headphones,
+                    state: OutputDeviceState::Unplugged,
+                },
+                SinkInfo {
+                    index: 61,
+                    name: "bluez_output.00_11_22_33_44_55.1".to_string(),
+                    description: "WH-1000XM4".to_string(),
+                    form_factor: OutputFormFactor::Headset,
+                    state: OutputDeviceState::Active,
+                },
+                SinkInfo {
+                    index: 70,
+                    name: "alsa_output.pci-0000_01_00.1.hdmi-stereo".to_string(),
+                    description: "HDMI Audio".to_string(),
+                    form_factor: OutputFormFactor::Hdmi,
+                    state: OutputDeviceState::Active,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn rebinds_when_default_sink_changes() {
+        let server = Arc::new(MockServer::default());
+        *server.default_sink.lock().unwrap() = Some("speakers".to_string());
+        server.volumes.lock().unwrap().extend([
+            ("speakers".to_string(), 0.5),
+            ("headphones".to_string(), 0.25),
+        ]);
+
+        let (sender, notified) = mpsc::channel();
+        let manager = VolumeManager::new(server.clone(), move |volume| {
+            sender.send(volume).unwrap();
+        })
+        .unwrap();
+        let next = || notified.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(manager.get_volume().unwrap(), 0.5);
+
+        manager.set_volume(0.8).unwrap();
+        assert_eq!(server.volumes.lock().unwrap()["speakers"], 0.8);
+        server.send(ServerEvent::SinkChanged);
+        assert_eq!(next(), 0.8);
+
+        // 音量没有变化时不通知
+        server.send(ServerEvent::SinkChanged);
+        *server.default_sink.lock().unwrap() = Some("headphones".to_string());
+        server.send(ServerEvent::ServerChanged);
+        assert_eq!(next(), 0.25);
+        assert_eq!(manager.get_volume().unwrap(), 0.25);
+        manager.set_volume(0.3).unwrap();
+        assert_eq!(server.volumes.lock().unwrap()["headphones"], 0.3);
+
+        // 没有输出设备时通知 0
+        *server.default_sink.lock().unwrap() = None;
+        server.send(ServerEvent::ServerChanged);
+        assert_eq!(next(), 0.0);
+        assert!(manager.get_volume().is_err());
+
+        drop(manager);
+        assert!(server.events.lock().unwrap().is_none());
+        assert!(notified.try_recv().is_err());
+    }
+}
@@ -0,0 +1,188 @@
+// 平台相关的功能：系统媒体控件、系统音量、系统主题、
+// 在文件管理器中显示文件、选择文件夹、系统缩略图等。
+//
+// api 中的模块只通过这里的 trait 调用平台接口，
+// 具体实现按 cfg 选择：Windows 使用 WinRT / Win32，其他平台（Linux）使用 freedesktop 的方式。
+
+use std::{path::Path, time::Duration};
+
+use anyhow::Result;
+
+use crate::api::{
+    smtc_flutter::SMTCState,
+    system_theme::SystemTheme,
+    system_volume::{OutputDevice, OutputDeviceEvent},
+};
+use crate::frb_generated::StreamSink;
+
+#[cfg(all(not(frb_expand), target_os = "windows"))]
+mod win;
+#[cfg(all(not(frb_expand), target_os = "windows"))]
+pub(crate) use win::{Platform, PlatformMediaControls};
+
+#[cfg(all(not(frb_expand), not(target_os = "windows")))]
+mod linux;
+#[cfg(all(test, not(frb_expand), not(target_os = "windows")))]
+pub(crate) use linux::PrivateBus;
+#[cfg(all(not(frb_expand), not(target_os = "windows")))]
+pub(crate) use linux::{Platform, PlatformMediaControls};
+
+// flutter_rust_bridge_codegen 展开代码时不包含平台实现：它不支持单元结构体，
+// 遇到单元结构体上的 trait 实现会直接崩溃。只需要名字能被解析
+#[cfg(frb_expand)]
+pub(crate) struct Platform;
+#[cfg(frb_expand)]
+pub(crate) struct PlatformMediaControls;
+
+mod timeline;
+
+pub(crate) use timeline::TimelineTracker;
+
+/// 媒体控件发出的事件
+// Seek 和 Volume 只有 MPRIS 会发出，FastForward 和 Rewind 只有 SMTC 会发出
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum MediaControlEvent {
+    Play,
+    Pause,
+    Stop,
+    Next,
+    Previous,
+    FastForward,
+    Rewind,
+    /// 相对当前位置跳转，ms
+    Seek(i64),
+    /// 跳转到指定位置，ms
+    SetPosition(u64),
+    /// 0.0 ~ 1.0
+    Volume(f64),
+    Shuffle(bool),
+    Repeat(RepeatMode),
+    Rate(f64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RepeatMode {
+    None,
+    Track,
+    List,
+}
+
+/// 媒体控件上显示的信息
+#[derive(Clone)]
+pub(crate) struct MediaDisplay {
+    pub title: String,
+    pub artist: String,
+    pub album: String,
+    pub album_artist: Option<String>,
+    pub track_number: Option<u32>,
+    pub genre: Option<String>,
+    /// ms
+    pub duration: u32,
+    /// 封面图片。None 时不显示封面
+    pub picture: Option<Vec<u8>>,
+    /// 音乐文件的路径，MPRIS 用来生成 track id 和 xesam:url
+    #[cfg_attr(target_os = "windows", allow(dead_code))]
+    pub path: String,
+}
+
+/// 媒体控件的时间线，单位 ms。每次都完整地发送，避免只更新位置时清空了其他属性
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Timeline {
+    pub start: u32,
+    pub end: u32,
+    pub min_seek: u32,
+    pub max_seek: u32,
+    pub position: u32,
+    pub rate: f64,
+}
+
+/// 系统媒体控件（Windows 的 SMTC）
+pub(crate) trait MediaControls: Sized + Send + Sync {
+    fn new() -> Result<Self>;
+
+    fn subscribe(&self, on_event: Box<dyn Fn(MediaControlEvent) + Send + Sync>) -> Result<()>;
+
+    fn set_state(&self, state: SMTCState) -> Result<()>;
+
+    fn set_timeline(&self, timeline: &Timeline) -> Result<()>;
+
+    fn set_display(&self, display: MediaDisplay) -> Result<()>;
+
+    /// 请求改变随机播放、循环模式和播放速度时控件会先显示请求的值，
+    /// 播放器实际采用的值需要通过这些方法（播放速度通过 set_timeline）同步回来
+    fn set_shuffle(&self, shuffle: bool) -> Result<()>;
+
+    fn set_repeat(&self, repeat: RepeatMode) -> Result<()>;
+
+    fn close(&self) -> Result<()>;
+}
+
+/// 默认输出设备的音量，范围 0.0 ~ 1.0
+pub(crate) trait SystemVolume {
+    /// 开始监听音量变化，返回当前音量
+    fn init(&self, sink: StreamSink<f64>) -> Result<f64>;
+
+    fn set(&self, volume: f64) -> Result<()>;
+
+    fn get(&self) -> Result<f64>;
+
+    fn dispose(&self);
+}
+
+/// 所有输出设备。id 和 OutputDevice::id 相同
+pub(crate) trait OutputDevices {
+    fn devices(&self) -> Result<Vec<OutputDevice>>;
+
+    /// 0.0 ~ 1.0
+    fn device_volume(&self, id: &str) -> Result<f64>;
+
+    fn set_device_volume(&self, id: &str, volume: f64) -> Result<()>;
+
+    fn device_mute(&self, id: &str) -> Result<bool>;
+
+    fn set_device_mute(&self, id: &str, mute: bool) -> Result<()>;
+
+    /// 开始监听设备变化。已经在监听时替换之前的 sink
+    fn watch_devices(&self, sink: StreamSink<OutputDeviceEvent>) -> Result<()>;
+
+    fn unwatch_devices(&self);
+}
+
+pub(crate) trait ThemeProvider {
+    fn system_theme(&self) -> Result<SystemTheme>;
+}
+
+/// 在文件管理器中显示文件、用默认程序打开链接
+pub(crate) trait FileRevealer {
+    /// 打开父级目录并选择 path 指向的项
+    fn reveal(&self, path: &str) -> Result<bool>;
+
+    fn open_uri(&self, uri: &str) -> Result<bool>;
+}
+
+pub(crate) trait FolderPicker {
+    /// 取消选择时返回 None
+    fn pick_folder(&self) -> Result<Option<String>>;
+}
+
+/// 系统为音乐文件生成的缩略图和属性，作为 lofty 读取不到时的后备
+pub(crate) trait ThumbnailProvider {
+    fn thumbnail(&self, path: &str) -> Result<Vec<u8>>;
+
+    fn music_properties(&self, path: &Path) -> Result<MusicProperties>;
+}
+
+/// 系统读取到的音乐属性。字符串可能为空
+pub(crate) struct MusicProperties {
+    /// 文件名，标题为空时使用
+    pub name: String,
+    pub title: String,
+    pub artist: String,
+    pub album: String,
+    pub album_artist: String,
+    pub track: u32,
+    pub duration: Duration,
+    /// bps
+    pub bitrate: u32,
+}
@@ -0,0 +1,152 @@
+// 记录完整的时间线，并限制发送位置的频率。
+// Flutter 每次播放进度变化都会更新位置，但媒体控件在播放时会自己推算位置，
+// 只需要定期校正；跳转、暂停、换歌和改变速度时立即发送。
+
+use std::time::{Duration, Instant};
+
+use super::Timeline;
+
+/// 播放时两次发送位置的最小间隔
+const POSITION_PUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 位置和推算的位置相差超过这个值时认为发生了跳转，ms
+const SEEK_THRESHOLD_MS: f64 = 1000.0;
+
+pub(crate) struct TimelineTracker {
+    timeline: Timeline,
+    playing: bool,
+    /// 上次发送的时间和位置
+    last_push: Option<(Instant, u32)>,
+}
+
+impl TimelineTracker {
+    pub fn new() -> Self {
+        Self {
+            timeline: Timeline {
+                start: 0,
+                end: 0,
+                min_seek: 0,
+                max_seek: 0,
+                position: 0,
+                rate: 1.0,
+            },
+            playing: false,
+            last_push: None,
+        }
+    }
+
+    pub fn current(&self) -> Timeline {
+        self.timeline
+    }
+
+    /// 换歌时调用，位置回到开头。总是需要发送
+    pub fn set_duration(&mut self, duration: u32, now: Instant) -> Timeline {
+        self.timeline = Timeline {
+            start: 0,
+            end: duration,
+            min_seek: 0,
+            max_seek: duration,
+            position: 0,
+            rate: self.timeline.rate,
+        };
+        self._push(now)
+    }
+
+    /// 返回 None 时不需要发送
+    pub fn set_position(&mut self, position: u32, now: Instant) -> Option<Timeline> {
+        self.timeline.position = match self.timeline.end {
+            0 => position,
+            end => position.min(end),
+        };
+
+        let Some((last_time, last_position)) = self.last_push else {
+            return Some(self._push(now));
+        };
+        let elapsed = now.saturating_duration_since(last_time);
+        let expected = match self.playing {
+            true => last_position as f64 + elapsed.as_millis() as f64 * self.timeline.rate,
+            false => last_position as f64,
+        };
+        let seeked = (self.timeline.position as f64 - expected).abs() > SEEK_THRESHOLD_MS;
+
+        if seeked || elapsed >= POSITION_PUSH_INTERVAL {
+            Some(self._push(now))
+        } else {
+            None
+        }
+    }
+
+    /// 暂停或恢复时发送当前位置，控件从这里开始推算
+    pub fn set_playing(&mut self, playing: bool, now: Instant) -> Option<Timeline> {
+        if self.playing == playing {
+            return None;
+        }
+        self.playing = playing;
+        Some(self._push(now))
+    }
+
+    /// 总是需要发送：控件上请求的速度可能已经显示出来，即使播放器没有采用也要改回去
+    pub fn set_rate(&mut self, rate: f64, now: Instant) -> Timeline {
+        self.timeline.rate = rate;
+        self._push(now)
+    }
+
+    fn _push(&mut self, now: Instant) -> Timeline {
+        self.last_push = Some((now, self.timeline.position));
+        self.timeline
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn position_pushes_are_throttled_unless_seeking() {
+        let start = Instant::now();
+        let at = |ms: u64| start + Duration::from_millis(ms);
+        let mut tracker = TimelineTracker::new();
+
+        let timeline = tracker.set_duration(180_000, at(0));
+        assert_eq!(
+            timeline,
+            Timeline {
+                start: 0,
+                end: 180_000,
+                min_seek: 0,
+                max_seek: 180_000,
+                position: 0,
+                rate: 1.0,
+            }
+        );
+        assert!(tracker.set_playing(true, at(0)).is_some());
+
+        // 正常播放时每秒最多发送一次
+        assert_eq!(tracker.set_position(200, at(200)), None);
+        assert_eq!(tracker.set_position(800, at(800)), None);
+        assert_eq!(
+            tracker.set_position(1000, at(1000)).map(|t| t.position),
+            Some(1000)
+        );
+
+        // 跳转立即发送，且不会超过结束时间
+        assert_eq!(
+            tracker.set_position(90_000, at(1200)).map(|t| t.position),
+            Some(90_000)
+        );
+        assert_eq!(
+            tracker.set_position(999_999, at(1300)).map(|t| t.position),
+            Some(180_000)
+        );
+
+        // 暂停后位置不再推算，小的变化也要等到间隔之后
+        assert!(tracker.set_playing(false, at(1400)).is_some());
+        assert_eq!(tracker.set_position(180_000, at(2000)), None);
+
+        // 两倍速时推算的位置也是两倍
+        tracker.set_position(10_000, at(3000)).unwrap();
+        assert!(tracker.set_playing(true, at(3000)).is_some());
+        assert_eq!(tracker.set_rate(2.0, at(3000)).rate, 2.0);
+        assert_eq!(tracker.set_position(11_000, at(3500)), None);
+    }
+}
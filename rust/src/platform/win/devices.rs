@@ -0,0 +1,278 @@
+use std::{collections::HashSet, sync::Mutex};
+
+use anyhow::Result;
+use windows::{
+    core::{implement, Interface, GUID, HSTRING, PCWSTR},
+    Win32::{
+        Devices::FunctionDiscovery::PKEY_Device_FriendlyName,
+        Media::Audio::{
+            self, eMultimedia, eRender, EDataFlow, ERole, EndpointFormFactor,
+            Endpoints::IAudioEndpointVolume, IMMDevice, IMMDeviceEnumerator, IMMEndpoint,
+            IMMNotificationClient, IMMNotificationClient_Impl, MMDeviceEnumerator,
+            PKEY_AudioEndpoint_FormFactor, DEVICE_STATE, DEVICE_STATEMASK_ALL, DEVICE_STATE_ACTIVE,
+            DEVICE_STATE_DISABLED, DEVICE_STATE_NOTPRESENT,
+        },
+        System::Com::{CoCreateInstance, CoTaskMemFree, CLSCTX_ALL, STGM_READ},
+        UI::Shell::PropertiesSystem::PROPERTYKEY,
+    },
+};
+
+use super::{volume::ComGuard, Platform};
+use crate::api::system_volume::{
+    OutputDevice, OutputDeviceEvent, OutputDeviceState, OutputFormFactor,
+};
+use crate::frb_generated::StreamSink;
+use crate::platform::OutputDevices;
+
+fn _enumerator() -> Result<IMMDeviceEnumerator> {
+    Ok(unsafe { CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL)? })
+}
+
+fn _device_id(device: &IMMDevice) -> Result<String> {
+    unsafe {
+        let id = device.GetId()?;
+        let result = id.to_string();
+        CoTaskMemFree(Some(id.0 as *const _));
+        Ok(result?)
+    }
+}
+
+fn _device_state(device: &IMMDevice) -> OutputDeviceState {
+    let mut state = 0u32;
+    let _ = unsafe { device.GetState(&mut state) };
+    _to_state(DEVICE_STATE(state))
+}
+
+fn _to_state(state: DEVICE_STATE) -> OutputDeviceState {
+    match state {
+        DEVICE_STATE_ACTIVE => OutputDeviceState::Active,
+        DEVICE_STATE_DISABLED => OutputDeviceState::Disabled,
+        DEVICE_STATE_NOTPRESENT => OutputDeviceState::NotPresent,
+        _ => OutputDeviceState::Unplugged,
+    }
+}
+
+fn _to_form_factor(form_factor: EndpointFormFactor) -> OutputFormFactor {
+    match form_factor {
+        Audio::Speakers => OutputFormFactor::Speakers,
+        Audio::Headphones => OutputFormFactor::Headphones,
+        Audio::Headset | Audio::Handset => OutputFormFactor::Headset,
+        Audio::LineLevel => OutputFormFactor::LineOut,
+        Audio::SPDIF => OutputFormFactor::Spdif,
+        Audio::DigitalAudioDisplayDevice => OutputFormFactor::Hdmi,
+        Audio::RemoteNetworkDevice => OutputFormFactor::Network,
+        _ => OutputFormFactor::Unknown,
+    }
+}
+
+fn _device_info(device: &IMMDevice, default_id: Option<&str>) -> Result<OutputDevice> {
+    let id = _device_id(device)?;
+    let (name, form_factor) = unsafe {
+        let store = device.OpenPropertyStore(STGM_READ)?;
+        let get = |key: &PROPERTYKEY| store.GetValue(key);
+        let name = get(&PKEY_Device_FriendlyName)?.to_string();
+        let form_factor = get(&PKEY_AudioEndpoint_FormFactor)
+            .ok()
+            .and_then(|val| u32::try_from(&val).ok())
+            .map(|val| _to_form_factor(EndpointFormFactor(val as i32)))
+            .unwrap_or(OutputFormFactor::Unknown);
+        (name, form_factor)
+    };
+
+    Ok(OutputDevice {
+        is_default: default_id == Some(id.as_str()),
+        name,
+        form_factor,
+        state: _device_state(device),
+        id,
+    })
+}
+
+fn _default_id(enumerator: &IMMDeviceEnumerator) -> Option<String> {
+    let device = unsafe { enumerator.GetDefaultAudioEndpoint(eRender, eMultimedia) }.ok()?;
+    _device_id(&device).ok()
+}
+
+fn _endpoint_volume(id: &str) -> Result<IAudioEndpointVolume> {
+    unsafe {
+        let device = _enumerator()?.GetDevice(&HSTRING::from(id))?;
+        Ok(device.Activate(CLSCTX_ALL, None)?)
+    }
+}
+
+/// 只关心输出设备
+fn _is_render(enumerator: &IMMDeviceEnumerator, id: &PCWSTR) -> bool {
+    unsafe {
+        enumerator
+            .GetDevice(*id)
+            .and_then(|device| device.cast::<IMMEndpoint>())
+            .and_then(|endpoint| endpoint.GetDataFlow())
+            .is_ok_and(|flow| flow == eRender)
+    }
+}
+
+#[implement(IMMNotificationClient)]
+struct DeviceEventCallback {
+    enumerator: IMMDeviceEnumerator,
+    sink: StreamSink<OutputDeviceEvent>,
+    /// 移除后无法再查询设备的类型，记住所有输出设备
+    render_ids: Mutex<HashSet<String>>,
+}
+
+impl IMMNotificationClient_Impl for DeviceEventCallback {
+    fn OnDeviceStateChanged(
+        &self,
+        pwstrdeviceid: &PCWSTR,
+        dwnewstate: DEVICE_STATE,
+    ) -> windows::core::Result<()> {
+        if _is_render(&self.enumerator, pwstrdeviceid) {
+            let _ = self.sink.add(OutputDeviceEvent::StateChanged {
+                id: unsafe { pwstrdeviceid.to_string() }?,
+                state: _to_state(dwnewstate),
+            });
+        }
+        Ok(())
+    }
+
+    fn OnDeviceAdded(&self, pwstrdeviceid: &PCWSTR) -> windows::core::Result<()> {
+        if _is_render(&self.enumerator, pwstrdeviceid) {
+            let id = unsafe { pwstrdeviceid.to_string() }?;
+            if let Ok(mut render_ids) = self.render_ids.lock() {
+                render_ids.insert(id.clone());
+            }
+            let _ = self.sink.add(OutputDeviceEvent::Added { id });
+        }
+        Ok(())
+    }
+
+    fn OnDeviceRemoved(&self, pwstrdeviceid: &PCWSTR) -> windows::core::Result<()> {
+        let id = unsafe { pwstrdeviceid.to_string() }?;
+        let removed = self
+            .render_ids
+            .lock()
+            .is_ok_and(|mut render_ids| render_ids.remove(&id));
+        if removed {
+            let _ = self.sink.add(OutputDeviceEvent::Removed { id });
+        }
+        Ok(())
+    }
+
+    fn OnDefaultDeviceChanged(
+        &self,
+        flow: EDataFlow,
+        role: ERole,
+        pwstrdefaultdeviceid: &PCWSTR,
+    ) -> windows::core::Result<()> {
+        if flow == eRender && role == eMultimedia {
+            // 没有默认设备时为空指针
+            let id = match pwstrdefaultdeviceid.is_null() {
+                true => None,
+                false => Some(unsafe { pwstrdefaultdeviceid.to_string() }?),
+            };
+            let _ = self.sink.add(OutputDeviceEvent::DefaultChanged { id });
+        }
+        Ok(())
+    }
+
+    fn OnPropertyValueChanged(
+        &self,
+        _pwstrdeviceid: &PCWSTR,
+        _key: &PROPERTYKEY,
+    ) -> windows::core::Result<()> {
+        Ok(())
+    }
+}
+
+struct DeviceWatcher {
+    enumerator: IMMDeviceEnumerator,
+    client: IMMNotificationClient,
+}
+
+unsafe impl Send for DeviceWatcher {}
+
+impl Drop for DeviceWatcher {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = self
+                .enumerator
+                .UnregisterEndpointNotificationCallback(&self.client);
+        }
+    }
+}
+
+static DEVICE_WATCHER: Mutex<Option<DeviceWatcher>> = Mutex::new(None);
+
+impl OutputDevices for Platform {
+    fn devices(&self) -> Result<Vec<OutputDevice>> {
+        let _com = ComGuard::new();
+        let enumerator = _enumerator()?;
+        let default_id = _default_id(&enumerator);
+
+        let collection =
+            unsafe { enumerator.EnumAudioEndpoints(eRender, DEVICE_STATE(DEVICE_STATEMASK_ALL))? };
+        let mut devices = vec![];
+        for i in 0..unsafe { collection.GetCount()? } {
+            let device = unsafe { collection.Item(i)? };
+            devices.push(_device_info(&device, default_id.as_deref())?);
+        }
+        Ok(devices)
+    }
+
+    fn device_volume(&self, id: &str) -> Result<f64> {
+        let _com = ComGuard::new();
+        Ok(unsafe { _endpoint_volume(id)?.GetMasterVolumeLevelScalar()? } as f64)
+    }
+
+    fn set_device_volume(&self, id: &str, volume: f64) -> Result<()> {
+        let _com = ComGuard::new();
+        unsafe {
+            _endpoint_volume(id)?
+                .SetMasterVolumeLevelScalar(volume.clamp(0.0, 1.0) as f32, &GUID::zeroed())?
+        };
+        Ok(())
+    }
+
+    fn device_mute(&self, id: &str) -> Result<bool> {
+        let _com = ComGuard::new();
+        Ok(unsafe { _endpoint_volume(id)?.GetMute()? }.as_bool())
+    }
+
+    fn set_device_mute(&self, id: &str, mute: bool) -> Result<()> {
+        let _com = ComGuard::new();
+        unsafe { _endpoint_volume(id)?.SetMute(mute, &GUID::zeroed())? };
+        Ok(())
+    }
+
+    fn watch_devices(&self, sink: StreamSink<OutputDeviceEvent>) -> Result<()> {
+        let _com = ComGuard::new();
+        self.unwatch_devices();
+
+        let enumerator = _enumerator()?;
+        let render_ids = self
+            .devices()?
+            .into_iter()
+            .map(|device| device.id)
+            .collect();
+        let client: IMMNotificationClient = DeviceEventCallback {
+            enumerator: enumerator.clone(),
+            sink,
+            render_ids: Mutex::new(render_ids),
+        }
+        .into();
+        unsafe { enumerator.RegisterEndpointNotificationCallback(&client)? };
+
+        *DEVICE_WATCHER.lock().unwrap_or_else(|err| err.into_inner()) =
+            Some(DeviceWatcher { enumerator, client });
+        Ok(())
+    }
+
+    fn unwatch_devices(&self) {
+        // 注销回调也需要 COM
+        let _com = ComGuard::new();
+        let watcher = DEVICE_WATCHER
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .take();
+        drop(watcher);
+    }
+}
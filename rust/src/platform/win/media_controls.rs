@@ -0,0 +1,242 @@
+use std::{sync::Arc, time::Duration};
+
+use anyhow::Result;
+use windows::{
+    core::HSTRING,
+    Foundation::{TimeSpan, TypedEventHandler},
+    Media::{
+        AutoRepeatModeChangeRequestedEventArgs, MediaPlaybackAutoRepeatMode, MediaPlaybackStatus,
+        MediaPlaybackType, Playback::MediaPlayer, PlaybackPositionChangeRequestedEventArgs,
+        PlaybackRateChangeRequestedEventArgs, ShuffleEnabledChangeRequestedEventArgs,
+        SystemMediaTransportControls, SystemMediaTransportControlsButton,
+        SystemMediaTransportControlsButtonPressedEventArgs,
+        SystemMediaTransportControlsTimelineProperties,
+    },
+    Storage::Streams::{DataWriter, InMemoryRandomAccessStream, RandomAccessStreamReference},
+};
+
+use crate::api::smtc_flutter::SMTCState;
+use crate::platform::{MediaControlEvent, MediaControls, MediaDisplay, RepeatMode, Timeline};
+
+pub(crate) struct PlatformMediaControls {
+    smtc: SystemMediaTransportControls,
+    player: MediaPlayer,
+}
+
+impl MediaControls for PlatformMediaControls {
+    fn new() -> Result<Self> {
+        let player = MediaPlayer::new()?;
+        player.CommandManager()?.SetIsEnabled(false)?;
+
+        let smtc = player.SystemMediaTransportControls()?;
+        Self::_init_controls(&smtc)?;
+
+        Ok(Self { smtc, player })
+    }
+
+    fn subscribe(&self, on_event: Box<dyn Fn(MediaControlEvent) + Send + Sync>) -> Result<()> {
+        let on_event: Arc<dyn Fn(MediaControlEvent) + Send + Sync> = Arc::from(on_event);
+
+        let on_button = on_event.clone();
+        self.smtc.ButtonPressed(&TypedEventHandler::<
+            SystemMediaTransportControls,
+            SystemMediaTransportControlsButtonPressedEventArgs,
+        >::new(move |_, event| {
+            let Some(event) = event.as_ref() else {
+                return Ok(());
+            };
+            let event = match event.Button()? {
+                SystemMediaTransportControlsButton::Play => MediaControlEvent::Play,
+                SystemMediaTransportControlsButton::Pause => MediaControlEvent::Pause,
+                SystemMediaTransportControlsButton::Stop => MediaControlEvent::Stop,
+                SystemMediaTransportControlsButton::Next => MediaControlEvent::Next,
+                SystemMediaTransportControlsButton::Previous => MediaControlEvent::Previous,
+                SystemMediaTransportControlsButton::FastForward => MediaControlEvent::FastForward,
+                SystemMediaTransportControlsButton::Rewind => MediaControlEvent::Rewind,
+                _ => return Ok(()),
+            };
+            on_button(event);
+
+            Ok(())
+        }))?;
+
+        let on_position = on_event.clone();
+        self.smtc
+            .PlaybackPositionChangeRequested(&TypedEventHandler::<
+                SystemMediaTransportControls,
+                PlaybackPositionChangeRequestedEventArgs,
+            >::new(move |_, args| {
+                if let Some(args) = args.as_ref() {
+                    let position: Duration = args.RequestedPlaybackPosition()?.into();
+                    on_position(MediaControlEvent::SetPosition(position.as_millis() as u64));
+                }
+                Ok(())
+            }))?;
+
+        // 先把请求的值显示出来，播放器不接受时再通过 set_* 改回去
+        let on_shuffle = on_event.clone();
+        self.smtc
+            .ShuffleEnabledChangeRequested(&TypedEventHandler::<
+                SystemMediaTransportControls,
+                ShuffleEnabledChangeRequestedEventArgs,
+            >::new(move |smtc, args| {
+                if let (Some(smtc), Some(args)) = (smtc.as_ref(), args.as_ref()) {
+                    let shuffle = args.RequestedShuffleEnabled()?;
+                    smtc.SetShuffleEnabled(shuffle)?;
+                    on_shuffle(MediaControlEvent::Shuffle(shuffle));
+                }
+                Ok(())
+            }))?;
+
+        let on_repeat = on_event.clone();
+        self.smtc
+            .AutoRepeatModeChangeRequested(&TypedEventHandler::<
+                SystemMediaTransportControls,
+                AutoRepeatModeChangeRequestedEventArgs,
+            >::new(move |smtc, args| {
+                if let (Some(smtc), Some(args)) = (smtc.as_ref(), args.as_ref()) {
+                    let mode = args.RequestedAutoRepeatMode()?;
+                    smtc.SetAutoRepeatMode(mode)?;
+                    on_repeat(MediaControlEvent::Repeat(match mode {
+                        MediaPlaybackAutoRepeatMode::Track => RepeatMode::Track,
+                        MediaPlaybackAutoRepeatMode::List => RepeatMode::List,
+                        _ => RepeatMode::None,
+                    }));
+                }
+                Ok(())
+            }))?;
+
+        let on_rate = on_event;
+        self.smtc.PlaybackRateChangeRequested(&TypedEventHandler::<
+            SystemMediaTransportControls,
+            PlaybackRateChangeRequestedEventArgs,
+        >::new(move |smtc, args| {
+            if let (Some(smtc), Some(args)) = (smtc.as_ref(), args.as_ref()) {
+                let rate = args.RequestedPlaybackRate()?;
+                smtc.SetPlaybackRate(rate)?;
+                on_rate(MediaControlEvent::Rate(rate));
+            }
+            Ok(())
+        }))?;
+
+        Ok(())
+    }
+
+    fn set_state(&self, state: SMTCState) -> Result<()> {
+        let state = match state {
+            SMTCState::Playing => MediaPlaybackStatus::Playing,
+            SMTCState::Paused => MediaPlaybackStatus::Paused,
+        };
+        self.smtc.SetPlaybackStatus(state)?;
+
+        Ok(())
+    }
+
+    fn set_timeline(&self, timeline: &Timeline) -> Result<()> {
+        let time_span = |ms: u32| TimeSpan::from(Duration::from_millis(ms.into()));
+        let time_properties = SystemMediaTransportControlsTimelineProperties::new()?;
+        time_properties.SetStartTime(time_span(timeline.start))?;
+        time_properties.SetEndTime(time_span(timeline.end))?;
+        time_properties.SetMinSeekTime(time_span(timeline.min_seek))?;
+        time_properties.SetMaxSeekTime(time_span(timeline.max_seek))?;
+        time_properties.SetPosition(time_span(timeline.position))?;
+        self.smtc.UpdateTimelineProperties(&time_properties)?;
+
+        if self.smtc.PlaybackRate()? != timeline.rate {
+            self.smtc.SetPlaybackRate(timeline.rate)?;
+        }
+
+        Ok(())
+    }
+
+    fn set_display(&self, display: MediaDisplay) -> Result<()> {
+        let updater = self.smtc.DisplayUpdater()?;
+        // 清除上一首的封面和其他属性
+        updater.ClearAll()?;
+        updater.SetType(MediaPlaybackType::Music)?;
+
+        let music_properties = updater.MusicProperties()?;
+        music_properties.SetTitle(&HSTRING::from(display.title))?;
+        music_properties.SetArtist(&HSTRING::from(display.artist))?;
+        music_properties.SetAlbumTitle(&HSTRING::from(display.album))?;
+        if let Some(album_artist) = display.album_artist {
+            music_properties.SetAlbumArtist(&HSTRING::from(album_artist))?;
+        }
+        if let Some(track_number) = display.track_number {
+            music_properties.SetTrackNumber(track_number)?;
+        }
+        if let Some(genre) = display.genre {
+            music_properties.Genres()?.Append(&HSTRING::from(genre))?;
+        }
+
+        if let Some(pic_data) = display.picture {
+            updater.SetThumbnail(&Self::_ras_ref_from_pic_data(&pic_data)?)?;
+        }
+
+        updater.Update()?;
+
+        if !(self.smtc.IsEnabled()?) {
+            self.smtc.SetIsEnabled(true)?;
+        }
+
+        Ok(())
+    }
+
+    fn set_shuffle(&self, shuffle: bool) -> Result<()> {
+        self.smtc.SetShuffleEnabled(shuffle)?;
+        Ok(())
+    }
+
+    fn set_repeat(&self, repeat: RepeatMode) -> Result<()> {
+        self.smtc.SetAutoRepeatMode(match repeat {
+            RepeatMode::None => MediaPlaybackAutoRepeatMode::None,
+            RepeatMode::Track => MediaPlaybackAutoRepeatMode::Track,
+            RepeatMode::List => MediaPlaybackAutoRepeatMode::List,
+        })?;
+        Ok(())
+    }
+
+    fn close(&self) -> Result<()> {
+        self.player.Close()?;
+        Ok(())
+    }
+}
+
+impl PlatformMediaControls {
+    fn _init_controls(smtc: &SystemMediaTransportControls) -> Result<(), windows::core::Error> {
+        // 下一首
+        smtc.SetIsNextEnabled(true)?;
+        // 暂停
+        smtc.SetIsPauseEnabled(true)?;
+        // 播放（恢复）
+        smtc.SetIsPlayEnabled(true)?;
+        // 上一首
+        smtc.SetIsPreviousEnabled(true)?;
+        // 停止
+        smtc.SetIsStopEnabled(true)?;
+        // 快进、快退
+        smtc.SetIsFastForwardEnabled(true)?;
+        smtc.SetIsRewindEnabled(true)?;
+
+        Ok(())
+    }
+
+    fn _ras_ref_from_pic_data(
+        picture_data: &[u8],
+    ) -> Result<RandomAccessStreamReference, windows::core::Error> {
+        let stream = InMemoryRandomAccessStream::new()?;
+
+        let writer = DataWriter::CreateDataWriter(&stream)?;
+        writer.WriteBytes(picture_data)?;
+        writer.StoreAsync()?.get()?;
+
+        // 调用 DetachStream() 的意义在于“把流从 DataWriter 脱附”，
+        // 这样可以安全地释放/关闭 DataWriter 而不影响流的生命周期。
+        // stream 不会因为 writer drop 而被销毁
+        writer.DetachStream()?;
+
+        stream.Seek(0)?;
+
+        RandomAccessStreamReference::CreateFromStream(&stream)
+    }
+}
@@ -0,0 +1,130 @@
+// Windows：WinRT 和 Win32 Api
+
+use std::path::Path;
+
+use anyhow::Result;
+use windows::{
+    core::{h, Interface, HSTRING},
+    Foundation::Uri,
+    Storage::{
+        FileProperties::ThumbnailMode,
+        Pickers::FolderPicker as WinFolderPicker,
+        StorageFile,
+        Streams::{DataReader, IInputStream},
+    },
+    System::{FolderLauncherOptions, Launcher},
+    Win32::UI::{Shell::IInitializeWithWindow, WindowsAndMessaging::GetForegroundWindow},
+    UI::ViewManagement::{UIColorType, UISettings},
+};
+
+use super::{FileRevealer, FolderPicker, MusicProperties, ThemeProvider, ThumbnailProvider};
+use crate::api::system_theme::SystemTheme;
+
+mod devices;
+mod media_controls;
+mod volume;
+
+pub(crate) use media_controls::PlatformMediaControls;
+
+pub(crate) struct Platform;
+
+impl ThemeProvider for Platform {
+    fn system_theme(&self) -> Result<SystemTheme> {
+        let ui_settings = UISettings::new()?;
+        let fore = ui_settings.GetColorValue(UIColorType::Foreground)?;
+        let accent = ui_settings.GetColorValue(UIColorType::Accent)?;
+
+        Ok(SystemTheme {
+            fore: (fore.A, fore.R, fore.G, fore.B),
+            accent: (accent.A, accent.R, accent.G, accent.B),
+        })
+    }
+}
+
+impl FileRevealer for Platform {
+    fn reveal(&self, path: &str) -> Result<bool> {
+        let file = StorageFile::GetFileFromPathAsync(&HSTRING::from(path))?.get()?;
+
+        let options: FolderLauncherOptions = FolderLauncherOptions::new()?;
+        let select_items = options.ItemsToSelect()?;
+        select_items.Append(&file)?;
+
+        Ok(Launcher::LaunchFolderPathWithOptionsAsync(
+            &file.GetParentAsync()?.get()?.Path()?,
+            &options,
+        )?
+        .get()?)
+    }
+
+    fn open_uri(&self, uri: &str) -> Result<bool> {
+        Ok(Launcher::LaunchUriAsync(&Uri::CreateUri(&HSTRING::from(uri))?)?.get()?)
+    }
+}
+
+impl FolderPicker for Platform {
+    fn pick_folder(&self) -> Result<Option<String>> {
+        let folder_picker = WinFolderPicker::new()?;
+
+        unsafe {
+            let hwnd = GetForegroundWindow();
+            // see https://learn.microsoft.com/en-us/windows/apps/develop/ui-input/display-ui-objects#winui-3-with-c
+            // see https://github.com/artiga033/winui_rust/blob/b90df60bfc18c33dfd63c380dcf0b615052105be/src/main.rs#L73
+            let initialize_with_window = folder_picker.cast::<IInitializeWithWindow>()?;
+            initialize_with_window.Initialize(hwnd)?;
+        }
+
+        folder_picker.FileTypeFilter()?.Append(h!("*"))?;
+        let folder = folder_picker.PickSingleFolderAsync()?.get()?;
+
+        Ok(Some(folder.Path()?.to_string()))
+    }
+}
+
+impl ThumbnailProvider for Platform {
+    fn thumbnail(&self, path: &str) -> Result<Vec<u8>> {
+        let file = StorageFile::GetFileFromPathAsync(&HSTRING::from(path))?.get()?;
+        let thumbnail = file
+            .GetThumbnailAsyncOverloadDefaultSizeDefaultOptions(ThumbnailMode::MusicView)?
+            .get()?;
+
+        let size = thumbnail.Size()? as u32;
+        let stream: IInputStream = thumbnail.cast()?;
+
+        let mut buffer = vec![0u8; size as usize];
+        let data_reader = DataReader::CreateDataReader(&stream)?;
+        data_reader.LoadAsync(size)?.get()?;
+        data_reader.ReadBytes(&mut buffer)?;
+
+        data_reader.Close()?;
+        stream.Close()?;
+
+        Ok(buffer)
+    }
+
+    fn music_properties(&self, path: &Path) -> Result<MusicProperties> {
+        let storage_file =
+            StorageFile::GetFileFromPathAsync(&HSTRING::from(path.as_os_str()))?.get()?;
+        let music_properties = storage_file
+            .Properties()?
+            .GetMusicPropertiesAsync()?
+            .get()?;
+
+        let name = storage_file.Name()?.to_string();
+        Ok(MusicProperties {
+            title: music_properties
+                .Title()
+                .map(|title| title.to_string())
+                .unwrap_or_default(),
+            name,
+            artist: music_properties.Artist().unwrap_or_default().to_string(),
+            album: music_properties.Album().unwrap_or_default().to_string(),
+            album_artist: music_properties
+                .AlbumArtist()
+                .unwrap_or_default()
+                .to_string(),
+            track: music_properties.TrackNumber()?,
+            duration: music_properties.Duration()?.into(),
+            bitrate: music_properties.Bitrate()?,
+        })
+    }
+}